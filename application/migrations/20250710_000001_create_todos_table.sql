@@ -0,0 +1,50 @@
+-- Create todos table
+CREATE TABLE IF NOT EXISTS todos (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    description TEXT,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    priority VARCHAR(10) NOT NULL DEFAULT 'medium',
+    due_date TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create indexes for better performance
+CREATE INDEX IF NOT EXISTS idx_todos_user_id ON todos(user_id);
+CREATE INDEX IF NOT EXISTS idx_todos_created_at ON todos(created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_todos_user_status ON todos(user_id, status);
+CREATE INDEX IF NOT EXISTS idx_todos_user_priority ON todos(user_id, priority);
+
+-- Partial indexes for the most common list queries
+CREATE INDEX IF NOT EXISTS idx_todos_active_user_created
+    ON todos(user_id, created_at DESC)
+    WHERE status != 'completed';
+
+CREATE INDEX IF NOT EXISTS idx_todos_with_due_date
+    ON todos(user_id, due_date ASC)
+    WHERE due_date IS NOT NULL;
+
+CREATE INDEX IF NOT EXISTS idx_todos_high_priority
+    ON todos(user_id, created_at DESC)
+    WHERE priority = 'high';
+
+-- Add constraints
+ALTER TABLE todos ADD CONSTRAINT chk_todos_status
+    CHECK (status IN ('pending', 'in_progress', 'completed'));
+
+ALTER TABLE todos ADD CONSTRAINT chk_todos_priority
+    CHECK (priority IN ('low', 'medium', 'high'));
+
+ALTER TABLE todos ADD CONSTRAINT chk_todos_title_length
+    CHECK (char_length(title) >= 1 AND char_length(title) <= 255);
+
+ALTER TABLE todos ADD CONSTRAINT chk_todos_description_length
+    CHECK (description IS NULL OR char_length(description) <= 1000);
+
+-- Create trigger to automatically update updated_at on todo updates
+CREATE TRIGGER update_todos_updated_at
+    BEFORE UPDATE ON todos
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,11 @@
+-- Track when a todo was started and completed (set by the status state machine)
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS started_at TIMESTAMPTZ;
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS completed_at TIMESTAMPTZ;
+
+-- Completed todos per user ordered by completion time (statistics, history)
+CREATE INDEX IF NOT EXISTS idx_todos_user_completed_at
+    ON todos(user_id, completed_at DESC)
+    WHERE completed_at IS NOT NULL;
+
+ALTER TABLE todos ADD CONSTRAINT chk_todos_completed_at
+    CHECK ((status = 'completed') = (completed_at IS NOT NULL));
@@ -0,0 +1,11 @@
+-- Korean-aware search (trigram indexes)
+-- 형태소 분석 없이 부분 문자열(ILIKE)과 트라이그램 유사도로 한국어/혼합 텍스트를 검색한다.
+-- 데이터베이스 인코딩이 UTF8이어야 한글 트라이그램이 추출된다.
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+DROP INDEX IF EXISTS idx_todos_title_search;
+DROP INDEX IF EXISTS idx_todos_description_search;
+
+CREATE INDEX IF NOT EXISTS idx_todos_title_trgm ON todos USING GIN (title gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_todos_description_trgm ON todos USING GIN (description gin_trgm_ops)
+    WHERE description IS NOT NULL;
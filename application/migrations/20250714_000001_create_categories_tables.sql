@@ -0,0 +1,38 @@
+-- Create categories table (V1.1)
+CREATE TABLE IF NOT EXISTS categories (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    color VARCHAR(7) NOT NULL DEFAULT '#2196F3',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Create todo_categories join table (V1.1)
+CREATE TABLE IF NOT EXISTS todo_categories (
+    todo_id UUID NOT NULL,
+    category_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (todo_id, category_id)
+);
+
+-- Create indexes for better performance
+CREATE INDEX IF NOT EXISTS idx_categories_user_id ON categories(user_id);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_user_name ON categories(user_id, name);
+CREATE INDEX IF NOT EXISTS idx_categories_name_trgm ON categories USING GIN (name gin_trgm_ops);
+CREATE INDEX IF NOT EXISTS idx_categories_created_at ON categories(created_at DESC);
+CREATE INDEX IF NOT EXISTS idx_todo_categories_todo_id ON todo_categories(todo_id);
+CREATE INDEX IF NOT EXISTS idx_todo_categories_category_id ON todo_categories(category_id);
+
+-- Add constraints
+ALTER TABLE categories ADD CONSTRAINT chk_categories_color_format
+    CHECK (color ~* '^#[0-9A-Fa-f]{6}$');
+
+ALTER TABLE categories ADD CONSTRAINT chk_categories_name_length
+    CHECK (char_length(name) >= 1 AND char_length(name) <= 100);
+
+-- Create trigger to automatically update updated_at on category updates
+CREATE TRIGGER update_categories_updated_at
+    BEFORE UPDATE ON categories
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,10 @@
+-- Remove category assignments together with their todo or category
+DELETE FROM todo_categories tc
+WHERE NOT EXISTS (SELECT 1 FROM todos t WHERE t.id = tc.todo_id)
+   OR NOT EXISTS (SELECT 1 FROM categories c WHERE c.id = tc.category_id);
+
+ALTER TABLE todo_categories ADD CONSTRAINT fk_todo_categories_todo
+    FOREIGN KEY (todo_id) REFERENCES todos(id) ON DELETE CASCADE;
+
+ALTER TABLE todo_categories ADD CONSTRAINT fk_todo_categories_category
+    FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE CASCADE;
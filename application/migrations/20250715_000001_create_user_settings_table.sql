@@ -0,0 +1,21 @@
+-- Per-user preferences used for date-based views (statistics, reports)
+CREATE TABLE IF NOT EXISTS user_settings (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    timezone VARCHAR(64) NOT NULL DEFAULT 'UTC',
+    week_start VARCHAR(10) NOT NULL DEFAULT 'monday',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE user_settings ADD CONSTRAINT chk_user_settings_week_start
+    CHECK (week_start IN ('monday', 'tuesday', 'wednesday', 'thursday', 'friday', 'saturday', 'sunday'));
+
+-- Overdue count for statistics (completed_at windows use idx_todos_user_completed_at)
+CREATE INDEX IF NOT EXISTS idx_todos_user_overdue
+    ON todos(user_id, due_date)
+    WHERE status != 'completed' AND due_date IS NOT NULL;
+
+CREATE TRIGGER update_user_settings_updated_at
+    BEFORE UPDATE ON user_settings
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
@@ -0,0 +1,30 @@
+-- Checklist items (subtasks) under a todo
+CREATE TABLE IF NOT EXISTS checklist_items (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    title VARCHAR(255) NOT NULL,
+    is_done BOOLEAN NOT NULL DEFAULT FALSE,
+    position INTEGER NOT NULL,
+    done_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_checklist_items_todo_position ON checklist_items(todo_id, position);
+
+ALTER TABLE checklist_items ADD CONSTRAINT chk_checklist_items_title_length
+    CHECK (char_length(title) >= 1 AND char_length(title) <= 255);
+
+ALTER TABLE checklist_items ADD CONSTRAINT chk_checklist_items_done_at
+    CHECK (is_done = (done_at IS NOT NULL));
+
+CREATE TRIGGER update_checklist_items_updated_at
+    BEFORE UPDATE ON checklist_items
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Progress counters kept on the todo so list responses need no extra queries
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS checklist_total INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS checklist_done INTEGER NOT NULL DEFAULT 0;
+-- Complete the todo automatically once every checklist item is done
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS checklist_auto_complete BOOLEAN NOT NULL DEFAULT FALSE;
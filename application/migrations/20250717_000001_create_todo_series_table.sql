@@ -0,0 +1,37 @@
+-- Recurring todo series (RFC 5545 RRULE)
+-- 반복 일정의 다음 발생은 이 템플릿으로 만든다. 발생 하나만 수정("이번 일정만")하면
+-- 템플릿은 그대로 두고, "이후 모든 일정" 수정은 그 발생부터 새 시리즈로 분리한다.
+CREATE TABLE IF NOT EXISTS todo_series (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL,
+    rrule VARCHAR(255) NOT NULL,
+    timezone VARCHAR(64) NOT NULL DEFAULT 'UTC',
+    dtstart TIMESTAMPTZ NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    description TEXT,
+    priority VARCHAR(10) NOT NULL DEFAULT 'medium',
+    checklist_auto_complete BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_todo_series_user_id ON todo_series(user_id);
+
+ALTER TABLE todo_series ADD CONSTRAINT chk_todo_series_priority
+    CHECK (priority IN ('low', 'medium', 'high'));
+
+CREATE TRIGGER update_todo_series_updated_at
+    BEFORE UPDATE ON todo_series
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- 각 할일은 시리즈의 몇 번째 발생(1부터)인지 기록한다
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS series_id UUID REFERENCES todo_series(id) ON DELETE SET NULL;
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS recurrence_index INTEGER;
+
+ALTER TABLE todos ADD CONSTRAINT chk_todos_recurrence
+    CHECK (recurrence_index IS NULL OR recurrence_index >= 1);
+
+-- 같은 발생이 두 번 만들어지지 않도록 한다 (다음 발생 생성은 이 제약으로 멱등하다)
+CREATE UNIQUE INDEX IF NOT EXISTS idx_todos_series_occurrence
+    ON todos(series_id, recurrence_index) WHERE series_id IS NOT NULL;
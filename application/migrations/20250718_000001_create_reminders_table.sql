@@ -0,0 +1,53 @@
+-- Due-date reminders
+-- 절대 시각(remind_at) 또는 마감 기준 상대 시간(offset_minutes) 중 하나로 지정한다.
+-- 실제 발송 시각은 snoozed_until > remind_at > due_date - offset 순으로 정해진다.
+CREATE TABLE IF NOT EXISTS reminders (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL,
+    remind_at TIMESTAMPTZ,
+    offset_minutes INTEGER,
+    snoozed_until TIMESTAMPTZ,
+    status VARCHAR(20) NOT NULL DEFAULT 'pending',
+    sent_at TIMESTAMPTZ,
+    -- 발송 중인 워커의 임대 만료 시각. 만료 전에는 다른 워커가 가져가지 않는다.
+    locked_until TIMESTAMPTZ,
+    attempts INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_reminders_todo_id ON reminders(todo_id);
+CREATE INDEX IF NOT EXISTS idx_reminders_pending ON reminders(todo_id) WHERE status = 'pending';
+
+ALTER TABLE reminders ADD CONSTRAINT chk_reminders_schedule
+    CHECK ((remind_at IS NULL) <> (offset_minutes IS NULL));
+
+ALTER TABLE reminders ADD CONSTRAINT chk_reminders_offset
+    CHECK (offset_minutes IS NULL OR (offset_minutes >= 0 AND offset_minutes <= 43200));
+
+ALTER TABLE reminders ADD CONSTRAINT chk_reminders_status
+    CHECK (status IN ('pending', 'sent', 'failed'));
+
+CREATE TRIGGER update_reminders_updated_at
+    BEFORE UPDATE ON reminders
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- 마감일이 바뀌면 상대 알림을 다시 발송 대기 상태로 돌린다
+CREATE OR REPLACE FUNCTION rearm_relative_reminders()
+RETURNS TRIGGER AS $$
+BEGIN
+    UPDATE reminders
+    SET status = 'pending', sent_at = NULL, snoozed_until = NULL, attempts = 0, last_error = NULL
+    WHERE todo_id = NEW.id AND offset_minutes IS NOT NULL;
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER rearm_reminders_on_due_date_change
+    AFTER UPDATE OF due_date ON todos
+    FOR EACH ROW
+    WHEN (OLD.due_date IS DISTINCT FROM NEW.due_date)
+    EXECUTE FUNCTION rearm_relative_reminders();
@@ -0,0 +1,28 @@
+-- Manual ordering with fractional indexing
+-- base62 소수부 키를 바이트 순서로 비교하도록 "C" collation을 쓴다.
+-- 기존 할일은 NULL로 두고 재배치 워커가 생성 순서대로 키를 채운다.
+ALTER TABLE todos ADD COLUMN position TEXT COLLATE "C";
+
+CREATE INDEX IF NOT EXISTS idx_todos_user_position ON todos(user_id, position);
+
+-- 재배치가 필요한 할일 (키가 없거나 16자를 넘음). 길이는 MAX_POSITION_KEY_LEN과 맞춘다.
+CREATE INDEX IF NOT EXISTS idx_todos_position_rebalance
+    ON todos(user_id)
+    WHERE position IS NULL OR char_length(position) > 16;
+
+-- 정렬 키만 바뀐 경우(이동, 재배치)는 수정 시각을 갱신하지 않는다
+CREATE OR REPLACE FUNCTION update_todos_updated_at_column()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF (to_jsonb(NEW) - 'position') IS DISTINCT FROM (to_jsonb(OLD) - 'position') THEN
+        NEW.updated_at = NOW();
+    END IF;
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+DROP TRIGGER IF EXISTS update_todos_updated_at ON todos;
+CREATE TRIGGER update_todos_updated_at
+    BEFORE UPDATE ON todos
+    FOR EACH ROW
+    EXECUTE FUNCTION update_todos_updated_at_column();
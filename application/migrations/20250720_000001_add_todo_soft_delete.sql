@@ -0,0 +1,13 @@
+-- Trash: soft delete with scheduled purge
+-- deleted_at이 있는 할일은 휴지통에 있으며 일반 조회에서 제외된다.
+ALTER TABLE todos ADD COLUMN deleted_at TIMESTAMPTZ;
+
+-- 휴지통 목록 (최근 삭제 순)
+CREATE INDEX IF NOT EXISTS idx_todos_user_trash
+    ON todos(user_id, deleted_at DESC)
+    WHERE deleted_at IS NOT NULL;
+
+-- 보관 기간이 지난 할일 영구 삭제
+CREATE INDEX IF NOT EXISTS idx_todos_trash_purge
+    ON todos(deleted_at)
+    WHERE deleted_at IS NOT NULL;
@@ -0,0 +1,14 @@
+-- Archive: hide old todos from lists and statistics
+-- archived_at은 상태(status)와 별개이며, 보관된 할일은 include_archived를 지정해야 조회된다.
+ALTER TABLE todos ADD COLUMN archived_at TIMESTAMPTZ;
+
+-- 완료 후 이 일수가 지나면 자동 보관 (NULL이면 사용 안 함)
+ALTER TABLE user_settings ADD COLUMN auto_archive_days INTEGER;
+
+ALTER TABLE user_settings ADD CONSTRAINT chk_user_settings_auto_archive_days
+    CHECK (auto_archive_days IS NULL OR auto_archive_days BETWEEN 1 AND 3650);
+
+-- 자동 보관 대상 (완료됐고 아직 보관/삭제되지 않은 할일)
+CREATE INDEX IF NOT EXISTS idx_todos_auto_archive
+    ON todos(user_id, completed_at)
+    WHERE status = 'completed' AND archived_at IS NULL AND deleted_at IS NULL;
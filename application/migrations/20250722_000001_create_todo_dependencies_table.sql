@@ -0,0 +1,16 @@
+-- "Blocked by" relationships between todos: todo_id cannot finish before blocked_by_id
+CREATE TABLE IF NOT EXISTS todo_dependencies (
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    blocked_by_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (todo_id, blocked_by_id)
+);
+
+ALTER TABLE todo_dependencies ADD CONSTRAINT chk_todo_dependencies_not_self
+    CHECK (todo_id <> blocked_by_id);
+
+-- Dependents of a todo (reverse lookups)
+CREATE INDEX IF NOT EXISTS idx_todo_dependencies_blocked_by ON todo_dependencies(blocked_by_id);
+
+-- Refuse completion while any blocker is still open
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS enforce_dependencies BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,35 @@
+-- Saved filters ("smart lists")
+-- 조회할 때마다 저장된 조건으로 할일 목록을 다시 계산한다. 마감 구간(due_window)은
+-- 저장 시각이 아니라 조회 시각과 사용자 시간대 기준으로 해석한다.
+CREATE TABLE IF NOT EXISTS smart_lists (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    statuses VARCHAR(20)[] NOT NULL DEFAULT '{}',
+    priorities VARCHAR(20)[] NOT NULL DEFAULT '{}',
+    due_window VARCHAR(20),
+    category_ids UUID[] NOT NULL DEFAULT '{}',
+    search VARCHAR(255),
+    sort VARCHAR(100),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_smart_lists_user_name ON smart_lists(user_id, name);
+
+ALTER TABLE smart_lists ADD CONSTRAINT chk_smart_lists_name_length
+    CHECK (char_length(name) >= 1 AND char_length(name) <= 100);
+
+ALTER TABLE smart_lists ADD CONSTRAINT chk_smart_lists_statuses
+    CHECK (statuses <@ ARRAY['pending', 'in_progress', 'completed']::VARCHAR(20)[]);
+
+ALTER TABLE smart_lists ADD CONSTRAINT chk_smart_lists_priorities
+    CHECK (priorities <@ ARRAY['low', 'medium', 'high']::VARCHAR(20)[]);
+
+ALTER TABLE smart_lists ADD CONSTRAINT chk_smart_lists_due_window
+    CHECK (due_window IS NULL OR due_window IN ('overdue', 'today', 'tomorrow', 'this_week', 'next_7_days', 'no_due_date'));
+
+CREATE TRIGGER update_smart_lists_updated_at
+    BEFORE UPDATE ON smart_lists
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
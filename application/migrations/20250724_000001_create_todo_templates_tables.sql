@@ -0,0 +1,49 @@
+-- Reusable sets of todo blueprints (onboarding, release checklists, ...)
+CREATE TABLE IF NOT EXISTS todo_templates (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    description TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_todo_templates_user_name ON todo_templates(user_id, name);
+
+ALTER TABLE todo_templates ADD CONSTRAINT chk_todo_templates_name_length
+    CHECK (char_length(name) >= 1 AND char_length(name) <= 100);
+
+CREATE TRIGGER update_todo_templates_updated_at
+    BEFORE UPDATE ON todo_templates
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- One blueprint per todo. The due date is relative to the start date chosen at instantiation:
+-- start date + due_offset_days, at due_time (local time) or the end of that day.
+CREATE TABLE IF NOT EXISTS todo_template_items (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    template_id UUID NOT NULL REFERENCES todo_templates(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    description TEXT,
+    priority VARCHAR(20) NOT NULL DEFAULT 'medium',
+    due_offset_days INTEGER,
+    due_time TIME,
+    category_ids UUID[] NOT NULL DEFAULT '{}',
+    checklist TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_todo_template_items_template_position ON todo_template_items(template_id, position);
+
+ALTER TABLE todo_template_items ADD CONSTRAINT chk_todo_template_items_title_length
+    CHECK (char_length(title) >= 1 AND char_length(title) <= 255);
+
+ALTER TABLE todo_template_items ADD CONSTRAINT chk_todo_template_items_priority
+    CHECK (priority IN ('low', 'medium', 'high'));
+
+ALTER TABLE todo_template_items ADD CONSTRAINT chk_todo_template_items_due_offset
+    CHECK (due_offset_days IS NULL OR due_offset_days BETWEEN -365 AND 365);
+
+ALTER TABLE todo_template_items ADD CONSTRAINT chk_todo_template_items_due_time
+    CHECK (due_time IS NULL OR due_offset_days IS NOT NULL);
@@ -0,0 +1,38 @@
+-- Time spent on todos: timer runs (ended_at IS NULL while running) and manual entries
+CREATE TABLE IF NOT EXISTS time_entries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL,
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    started_at TIMESTAMPTZ NOT NULL,
+    ended_at TIMESTAMPTZ,
+    source VARCHAR(20) NOT NULL,
+    note VARCHAR(500),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_time_entries_todo_started ON time_entries(todo_id, started_at);
+CREATE INDEX IF NOT EXISTS idx_time_entries_user_started ON time_entries(user_id, started_at);
+
+-- At most one running timer per user
+CREATE UNIQUE INDEX IF NOT EXISTS idx_time_entries_running ON time_entries(user_id) WHERE ended_at IS NULL;
+
+ALTER TABLE time_entries ADD CONSTRAINT chk_time_entries_source
+    CHECK (source IN ('timer', 'manual'));
+
+ALTER TABLE time_entries ADD CONSTRAINT chk_time_entries_range
+    CHECK (ended_at IS NULL OR ended_at >= started_at);
+
+ALTER TABLE time_entries ADD CONSTRAINT chk_time_entries_manual_closed
+    CHECK (source = 'timer' OR ended_at IS NOT NULL);
+
+CREATE TRIGGER update_time_entries_updated_at
+    BEFORE UPDATE ON time_entries
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Planned effort, compared against tracked time in reports
+ALTER TABLE todos ADD COLUMN IF NOT EXISTS estimate_minutes INTEGER;
+
+ALTER TABLE todos ADD CONSTRAINT chk_todos_estimate_minutes
+    CHECK (estimate_minutes IS NULL OR estimate_minutes BETWEEN 1 AND 100000);
@@ -0,0 +1,60 @@
+-- Kanban boards over the user's active todos
+CREATE TABLE IF NOT EXISTS boards (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL,
+    name VARCHAR(100) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_boards_user_name ON boards(user_id, name);
+
+ALTER TABLE boards ADD CONSTRAINT chk_boards_name_length
+    CHECK (char_length(name) >= 1 AND char_length(name) <= 100);
+
+CREATE TRIGGER update_boards_updated_at
+    BEFORE UPDATE ON boards
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- A column either maps to a todo status or is a custom lane (status IS NULL).
+-- Todos without a placement show up in the first column mapped to their status.
+CREATE TABLE IF NOT EXISTS board_columns (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    board_id UUID NOT NULL REFERENCES boards(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL,
+    name VARCHAR(50) NOT NULL,
+    status VARCHAR(20),
+    wip_limit INTEGER,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_board_columns_board_position ON board_columns(board_id, position);
+
+ALTER TABLE board_columns ADD CONSTRAINT chk_board_columns_name_length
+    CHECK (char_length(name) >= 1 AND char_length(name) <= 50);
+
+ALTER TABLE board_columns ADD CONSTRAINT chk_board_columns_status
+    CHECK (status IS NULL OR status IN ('pending', 'in_progress', 'completed'));
+
+ALTER TABLE board_columns ADD CONSTRAINT chk_board_columns_wip_limit
+    CHECK (wip_limit IS NULL OR wip_limit BETWEEN 1 AND 1000);
+
+CREATE TRIGGER update_board_columns_updated_at
+    BEFORE UPDATE ON board_columns
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Explicit placement of a todo on a board: its column and order within the column.
+-- A placement in a status column only holds while the todo still has that status.
+CREATE TABLE IF NOT EXISTS board_cards (
+    board_id UUID NOT NULL REFERENCES boards(id) ON DELETE CASCADE,
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    column_id UUID NOT NULL REFERENCES board_columns(id) ON DELETE CASCADE,
+    position TEXT COLLATE "C" NOT NULL,
+    PRIMARY KEY (board_id, todo_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_board_cards_column_position ON board_cards(column_id, position);
+CREATE INDEX IF NOT EXISTS idx_board_cards_todo ON board_cards(todo_id);
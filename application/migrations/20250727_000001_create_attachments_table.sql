@@ -0,0 +1,44 @@
+-- Files attached to todos. The bytes live in the configured blob store under storage_key.
+CREATE TABLE IF NOT EXISTS attachments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL,
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    file_name VARCHAR(255) NOT NULL,
+    content_type VARCHAR(100) NOT NULL,
+    size_bytes BIGINT NOT NULL,
+    sha256 CHAR(64) NOT NULL,
+    storage_key VARCHAR(200) NOT NULL UNIQUE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_attachments_todo_created ON attachments(todo_id, created_at);
+
+ALTER TABLE attachments ADD CONSTRAINT chk_attachments_size
+    CHECK (size_bytes > 0);
+
+ALTER TABLE attachments ADD CONSTRAINT chk_attachments_file_name
+    CHECK (LENGTH(TRIM(file_name)) > 0);
+
+-- Blobs whose rows are gone (including via todo/user cascades), drained by a background worker
+CREATE TABLE IF NOT EXISTS blob_deletions (
+    storage_key VARCHAR(200) PRIMARY KEY,
+    queued_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    next_attempt_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_blob_deletions_next_attempt ON blob_deletions(next_attempt_at);
+
+CREATE OR REPLACE FUNCTION queue_attachment_blob_deletion()
+RETURNS TRIGGER AS $$
+BEGIN
+    INSERT INTO blob_deletions (storage_key) VALUES (OLD.storage_key)
+    ON CONFLICT (storage_key) DO NOTHING;
+    RETURN OLD;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER queue_attachment_blob_deletion
+    AFTER DELETE ON attachments
+    FOR EACH ROW
+    EXECUTE FUNCTION queue_attachment_blob_deletion();
@@ -0,0 +1,50 @@
+-- Threaded comments on todos. Deleted comments keep their row (deleted_at) so replies stay in place.
+CREATE TABLE IF NOT EXISTS comments (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL,
+    parent_id UUID REFERENCES comments(id) ON DELETE CASCADE,
+    depth INTEGER NOT NULL DEFAULT 0,
+    -- Markdown source and the sanitized HTML rendered from it
+    body TEXT NOT NULL,
+    body_html TEXT NOT NULL,
+    edited_at TIMESTAMPTZ,
+    deleted_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_comments_todo_created ON comments(todo_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_comments_parent ON comments(parent_id) WHERE parent_id IS NOT NULL;
+
+ALTER TABLE comments ADD CONSTRAINT chk_comments_body_length
+    CHECK (CHAR_LENGTH(body) BETWEEN 1 AND 10000);
+
+ALTER TABLE comments ADD CONSTRAINT chk_comments_depth
+    CHECK (depth BETWEEN 0 AND 4 AND (depth = 0) = (parent_id IS NULL));
+
+CREATE TRIGGER update_comments_updated_at
+    BEFORE UPDATE ON comments
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Previous bodies of edited comments, oldest first
+CREATE TABLE IF NOT EXISTS comment_revisions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    comment_id UUID NOT NULL REFERENCES comments(id) ON DELETE CASCADE,
+    body TEXT NOT NULL,
+    -- When this body was written (the comment's creation or a previous edit)
+    written_at TIMESTAMPTZ NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_comment_revisions_comment ON comment_revisions(comment_id, written_at);
+
+-- Users mentioned in a comment; a user is notified once per comment, even across edits
+CREATE TABLE IF NOT EXISTS comment_mentions (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    comment_id UUID NOT NULL REFERENCES comments(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (comment_id, user_id)
+);
@@ -0,0 +1,149 @@
+-- Workspaces own todos and categories. Every user has one personal workspace that
+-- cannot be shared or deleted; team workspaces are shared through invitations.
+CREATE TABLE IF NOT EXISTS workspaces (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    name VARCHAR(100) NOT NULL,
+    is_personal BOOLEAN NOT NULL DEFAULT FALSE,
+    created_by UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_workspaces_personal ON workspaces(created_by) WHERE is_personal;
+
+ALTER TABLE workspaces ADD CONSTRAINT chk_workspaces_name_length
+    CHECK (char_length(name) >= 1 AND char_length(name) <= 100);
+
+CREATE TRIGGER update_workspaces_updated_at
+    BEFORE UPDATE ON workspaces
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TABLE IF NOT EXISTS workspace_members (
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL,
+    role VARCHAR(10) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (workspace_id, user_id)
+);
+
+-- Membership lookups by user back every todo and category query
+CREATE INDEX IF NOT EXISTS idx_workspace_members_user ON workspace_members(user_id, workspace_id, role);
+
+ALTER TABLE workspace_members ADD CONSTRAINT chk_workspace_members_role
+    CHECK (role IN ('owner', 'editor', 'viewer'));
+
+CREATE TRIGGER update_workspace_members_updated_at
+    BEFORE UPDATE ON workspace_members
+    FOR EACH ROW
+    EXECUTE FUNCTION update_updated_at_column();
+
+-- Pending invitations are matched by the SHA-256 hash of the emailed token; the token itself is never stored
+CREATE TABLE IF NOT EXISTS workspace_invitations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    email VARCHAR(255) NOT NULL,
+    role VARCHAR(10) NOT NULL,
+    token_hash CHAR(64) NOT NULL UNIQUE,
+    invited_by UUID NOT NULL,
+    status VARCHAR(10) NOT NULL DEFAULT 'pending',
+    expires_at TIMESTAMPTZ NOT NULL,
+    responded_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_workspace_invitations_workspace ON workspace_invitations(workspace_id, created_at DESC);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_workspace_invitations_pending
+    ON workspace_invitations(workspace_id, lower(email))
+    WHERE status = 'pending';
+
+ALTER TABLE workspace_invitations ADD CONSTRAINT chk_workspace_invitations_role
+    CHECK (role IN ('editor', 'viewer'));
+
+ALTER TABLE workspace_invitations ADD CONSTRAINT chk_workspace_invitations_status
+    CHECK (status IN ('pending', 'accepted', 'declined', 'revoked'));
+
+-- Returns the user's personal workspace, creating it (and the owner membership) on first use
+CREATE OR REPLACE FUNCTION personal_workspace_id(p_user_id UUID)
+RETURNS UUID AS $$
+DECLARE
+    v_workspace_id UUID;
+BEGIN
+    SELECT id INTO v_workspace_id FROM workspaces WHERE created_by = p_user_id AND is_personal;
+    IF v_workspace_id IS NOT NULL THEN
+        RETURN v_workspace_id;
+    END IF;
+
+    INSERT INTO workspaces (name, is_personal, created_by)
+    VALUES ('개인', TRUE, p_user_id)
+    ON CONFLICT (created_by) WHERE is_personal DO NOTHING
+    RETURNING id INTO v_workspace_id;
+
+    -- Lost a race with a concurrent call; use the workspace it created
+    IF v_workspace_id IS NULL THEN
+        SELECT id INTO v_workspace_id FROM workspaces WHERE created_by = p_user_id AND is_personal;
+    END IF;
+
+    INSERT INTO workspace_members (workspace_id, user_id, role)
+    VALUES (v_workspace_id, p_user_id, 'owner')
+    ON CONFLICT (workspace_id, user_id) DO NOTHING;
+
+    RETURN v_workspace_id;
+END;
+$$ language 'plpgsql';
+
+-- Rows inserted without a workspace land in the creator's personal workspace
+CREATE OR REPLACE FUNCTION default_personal_workspace()
+RETURNS TRIGGER AS $$
+BEGIN
+    IF NEW.workspace_id IS NULL THEN
+        NEW.workspace_id = personal_workspace_id(NEW.user_id);
+    END IF;
+    RETURN NEW;
+END;
+$$ language 'plpgsql';
+
+-- Move existing todos and categories into their owners' personal workspaces
+ALTER TABLE todos ADD COLUMN workspace_id UUID REFERENCES workspaces(id) ON DELETE CASCADE;
+ALTER TABLE categories ADD COLUMN workspace_id UUID REFERENCES workspaces(id) ON DELETE CASCADE;
+
+SELECT personal_workspace_id(owners.user_id)
+FROM (SELECT id AS user_id FROM users
+      UNION SELECT user_id FROM todos
+      UNION SELECT user_id FROM categories) AS owners;
+
+UPDATE todos SET workspace_id = w.id
+FROM workspaces w
+WHERE w.created_by = todos.user_id AND w.is_personal;
+
+UPDATE categories SET workspace_id = w.id
+FROM workspaces w
+WHERE w.created_by = categories.user_id AND w.is_personal;
+
+ALTER TABLE todos ALTER COLUMN workspace_id SET NOT NULL;
+ALTER TABLE categories ALTER COLUMN workspace_id SET NOT NULL;
+
+CREATE TRIGGER set_todos_workspace
+    BEFORE INSERT ON todos
+    FOR EACH ROW
+    EXECUTE FUNCTION default_personal_workspace();
+
+CREATE TRIGGER set_categories_workspace
+    BEFORE INSERT ON categories
+    FOR EACH ROW
+    EXECUTE FUNCTION default_personal_workspace();
+
+-- Category names are unique per workspace instead of per user
+DROP INDEX IF EXISTS idx_categories_user_name;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_categories_workspace_name ON categories(workspace_id, name);
+
+-- Manual ordering and list/stat scans are now per workspace
+CREATE INDEX IF NOT EXISTS idx_todos_workspace_position ON todos(workspace_id, position);
+CREATE INDEX IF NOT EXISTS idx_todos_workspace_status ON todos(workspace_id, status);
+CREATE INDEX IF NOT EXISTS idx_todos_workspace_created ON todos(workspace_id, created_at DESC);
+
+DROP INDEX IF EXISTS idx_todos_position_rebalance;
+CREATE INDEX IF NOT EXISTS idx_todos_position_rebalance
+    ON todos(workspace_id)
+    WHERE position IS NULL OR char_length(position) > 16;
@@ -0,0 +1,27 @@
+-- Workspace members assigned to a todo, with who assigned them
+CREATE TABLE IF NOT EXISTS todo_assignees (
+    todo_id UUID NOT NULL REFERENCES todos(id) ON DELETE CASCADE,
+    user_id UUID NOT NULL,
+    assigned_by UUID NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (todo_id, user_id)
+);
+
+-- "Assigned to me" across workspaces and per-member workload
+CREATE INDEX IF NOT EXISTS idx_todo_assignees_user ON todo_assignees(user_id, todo_id);
+
+-- Members who leave or are removed from a workspace drop off its todos
+CREATE OR REPLACE FUNCTION remove_departed_assignees()
+RETURNS TRIGGER AS $$
+BEGIN
+    DELETE FROM todo_assignees a
+    USING todos t
+    WHERE a.todo_id = t.id AND t.workspace_id = OLD.workspace_id AND a.user_id = OLD.user_id;
+    RETURN OLD;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER remove_workspace_member_assignees
+    AFTER DELETE ON workspace_members
+    FOR EACH ROW
+    EXECUTE FUNCTION remove_departed_assignees();
@@ -0,0 +1,30 @@
+-- Public read-only share links for a single todo or a filtered list of a workspace.
+-- Links are matched by the SHA-256 hash of the token in the URL; the token itself is never stored.
+CREATE TABLE IF NOT EXISTS share_links (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    workspace_id UUID NOT NULL REFERENCES workspaces(id) ON DELETE CASCADE,
+    created_by UUID NOT NULL,
+    -- NULL이면 아래 조건으로 거른 목록을 공유한다
+    todo_id UUID REFERENCES todos(id) ON DELETE CASCADE,
+    title VARCHAR(100),
+    statuses VARCHAR(20)[] NOT NULL DEFAULT '{}',
+    priorities VARCHAR(20)[] NOT NULL DEFAULT '{}',
+    category_ids UUID[] NOT NULL DEFAULT '{}',
+    search VARCHAR(255),
+    sort VARCHAR(100),
+    token_hash CHAR(64) NOT NULL UNIQUE,
+    password_hash VARCHAR(255),
+    expires_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    view_count BIGINT NOT NULL DEFAULT 0,
+    last_viewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX IF NOT EXISTS idx_share_links_workspace ON share_links(workspace_id, created_at DESC);
+
+ALTER TABLE share_links ADD CONSTRAINT chk_share_links_statuses
+    CHECK (statuses <@ ARRAY['pending', 'in_progress', 'completed']::VARCHAR(20)[]);
+
+ALTER TABLE share_links ADD CONSTRAINT chk_share_links_priorities
+    CHECK (priorities <@ ARRAY['low', 'medium', 'high']::VARCHAR(20)[]);
@@ -0,0 +1,25 @@
+-- Reminders of members who leave or are removed from a workspace stop firing
+-- (otherwise the notification would keep leaking the todo title to a non-member)
+CREATE OR REPLACE FUNCTION remove_departed_reminders()
+RETURNS TRIGGER AS $$
+BEGIN
+    DELETE FROM reminders r
+    USING todos t
+    WHERE r.todo_id = t.id AND t.workspace_id = OLD.workspace_id AND r.user_id = OLD.user_id;
+    RETURN OLD;
+END;
+$$ language 'plpgsql';
+
+CREATE TRIGGER remove_workspace_member_reminders
+    AFTER DELETE ON workspace_members
+    FOR EACH ROW
+    EXECUTE FUNCTION remove_departed_reminders();
+
+-- Members removed before this trigger existed
+DELETE FROM reminders r
+USING todos t
+WHERE r.todo_id = t.id
+  AND NOT EXISTS (
+      SELECT 1 FROM workspace_members m
+      WHERE m.workspace_id = t.workspace_id AND m.user_id = r.user_id
+  );
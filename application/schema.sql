@@ -130,11 +130,12 @@ CREATE INDEX idx_todos_user_status ON todos(user_id, status);
 -- 사용자별 우선순위 조회용 복합 인덱스 (성능 최적화)
 CREATE INDEX idx_todos_user_priority ON todos(user_id, priority);
 
--- 제목 검색용 인덱스 (전문 검색 기능)
-CREATE INDEX idx_todos_title_search ON todos USING gin(to_tsvector('english', title));
+-- 제목 검색용 트라이그램 인덱스 (한국어/혼합 텍스트 부분 일치 및 유사도 검색)
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+CREATE INDEX idx_todos_title_trgm ON todos USING gin(title gin_trgm_ops);
 
--- 설명 검색용 인덱스 (전문 검색 기능)
-CREATE INDEX idx_todos_description_search ON todos USING gin(to_tsvector('english', description)) WHERE description IS NOT NULL;
+-- 설명 검색용 트라이그램 인덱스
+CREATE INDEX idx_todos_description_trgm ON todos USING gin(description gin_trgm_ops) WHERE description IS NOT NULL;
 
 -- Categories table indexes
 -- 사용자별 카테고리 조회용 인덱스
@@ -143,6 +144,9 @@ CREATE INDEX idx_categories_user_id ON categories(user_id);
 -- 사용자별 카테고리명 중복 방지용 복합 유니크 인덱스
 CREATE UNIQUE INDEX idx_categories_user_name ON categories(user_id, name);
 
+-- 카테고리명 검색용 트라이그램 인덱스
+CREATE INDEX idx_categories_name_trgm ON categories USING gin(name gin_trgm_ops);
+
 -- 생성일시 기준 정렬용 인덱스
 CREATE INDEX idx_categories_created_at ON categories(created_at DESC);
 
@@ -11,6 +11,48 @@ pub struct AppConfig {
     pub environment: String,
     pub log_level: String,
     pub database_url: String,
+    /// 알림 발송 워커의 폴링 주기 (초)
+    pub reminder_poll_interval_secs: u64,
+    /// 알림 발송 워커가 한 번에 가져오는 최대 알림 수
+    pub reminder_batch_size: i64,
+    /// 정렬 키 재배치 워커의 실행 주기 (초)
+    pub position_rebalance_interval_secs: u64,
+    /// 휴지통에 보관하는 기간 (일). 지나면 영구 삭제한다.
+    pub trash_retention_days: i64,
+    /// 휴지통 비우기 워커의 실행 주기 (초)
+    pub trash_purge_interval_secs: u64,
+    /// 완료된 할일 자동 보관 워커의 실행 주기 (초)
+    pub auto_archive_interval_secs: u64,
+    /// 첨부 파일 저장소 (`local` 또는 `s3`)
+    pub storage_backend: String,
+    /// `local` 저장소의 루트 디렉터리
+    pub storage_local_root: String,
+    /// `s3` 저장소 설정 (`S3_ENDPOINT`, `S3_BUCKET`, `S3_REGION`, `S3_ACCESS_KEY_ID`, `S3_SECRET_ACCESS_KEY`)
+    pub s3_endpoint: Option<String>,
+    pub s3_bucket: Option<String>,
+    pub s3_region: String,
+    pub s3_access_key_id: Option<String>,
+    pub s3_secret_access_key: Option<String>,
+    /// 첨부 파일 하나의 최대 크기 (바이트)
+    pub attachment_max_file_bytes: u64,
+    /// 다운로드 URL 서명 키
+    pub attachment_url_secret: String,
+    /// 다운로드 URL 유효 기간 (초)
+    pub attachment_url_ttl_secs: i64,
+    /// 삭제된 첨부 파일 정리 워커의 실행 주기 (초)
+    pub blob_cleanup_interval_secs: u64,
+    /// 워크스페이스 초대 유효 기간 (시간)
+    pub invitation_ttl_hours: i64,
+    /// 초대 메일에 넣는 초대 수락 페이지 주소
+    pub invitation_accept_url: String,
+    /// 메일 발송 방식 (`log` 또는 `http`)
+    pub mail_backend: String,
+    /// `http` 메일 발송 설정 (`MAIL_API_URL`, `MAIL_API_KEY`, `MAIL_FROM`)
+    pub mail_api_url: Option<String>,
+    pub mail_api_key: Option<String>,
+    pub mail_from: String,
+    /// 공유 링크 페이지 주소. 뒤에 `/{token}`을 붙여 돌려준다.
+    pub share_link_base_url: String,
 }
 
 impl AppConfig {
@@ -26,6 +68,64 @@ impl AppConfig {
             log_level: env::var("LOG_LEVEL").unwrap_or_else(|_| "info".to_string()),
             database_url: env::var("DATABASE_URL")
                 .expect("DATABASE_URL must be set"),
+            reminder_poll_interval_secs: env::var("REMINDER_POLL_INTERVAL_SECS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .expect("REMINDER_POLL_INTERVAL_SECS must be a valid number"),
+            reminder_batch_size: env::var("REMINDER_BATCH_SIZE")
+                .unwrap_or_else(|_| "100".to_string())
+                .parse()
+                .expect("REMINDER_BATCH_SIZE must be a valid number"),
+            position_rebalance_interval_secs: env::var("POSITION_REBALANCE_INTERVAL_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .expect("POSITION_REBALANCE_INTERVAL_SECS must be a valid number"),
+            trash_retention_days: env::var("TRASH_RETENTION_DAYS")
+                .unwrap_or_else(|_| "30".to_string())
+                .parse()
+                .expect("TRASH_RETENTION_DAYS must be a valid number"),
+            trash_purge_interval_secs: env::var("TRASH_PURGE_INTERVAL_SECS")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .expect("TRASH_PURGE_INTERVAL_SECS must be a valid number"),
+            auto_archive_interval_secs: env::var("AUTO_ARCHIVE_INTERVAL_SECS")
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .expect("AUTO_ARCHIVE_INTERVAL_SECS must be a valid number"),
+            storage_backend: env::var("STORAGE_BACKEND").unwrap_or_else(|_| "local".to_string()),
+            storage_local_root: env::var("STORAGE_LOCAL_ROOT").unwrap_or_else(|_| "./data/attachments".to_string()),
+            s3_endpoint: env::var("S3_ENDPOINT").ok(),
+            s3_bucket: env::var("S3_BUCKET").ok(),
+            s3_region: env::var("S3_REGION").unwrap_or_else(|_| "us-east-1".to_string()),
+            s3_access_key_id: env::var("S3_ACCESS_KEY_ID").ok(),
+            s3_secret_access_key: env::var("S3_SECRET_ACCESS_KEY").ok(),
+            attachment_max_file_bytes: env::var("ATTACHMENT_MAX_FILE_BYTES")
+                .unwrap_or_else(|_| "10485760".to_string())
+                .parse()
+                .expect("ATTACHMENT_MAX_FILE_BYTES must be a valid number"),
+            // 개발 환경용 기본 시크릿 (실제 운영에서는 환경변수로 관리)
+            attachment_url_secret: env::var("ATTACHMENT_URL_SECRET")
+                .unwrap_or_else(|_| "attachment-url-secret-change-this-in-production".to_string()),
+            attachment_url_ttl_secs: env::var("ATTACHMENT_URL_TTL_SECS")
+                .unwrap_or_else(|_| "900".to_string())
+                .parse()
+                .expect("ATTACHMENT_URL_TTL_SECS must be a valid number"),
+            blob_cleanup_interval_secs: env::var("BLOB_CLEANUP_INTERVAL_SECS")
+                .unwrap_or_else(|_| "60".to_string())
+                .parse()
+                .expect("BLOB_CLEANUP_INTERVAL_SECS must be a valid number"),
+            invitation_ttl_hours: env::var("INVITATION_TTL_HOURS")
+                .unwrap_or_else(|_| "168".to_string())
+                .parse()
+                .expect("INVITATION_TTL_HOURS must be a valid number"),
+            invitation_accept_url: env::var("INVITATION_ACCEPT_URL")
+                .unwrap_or_else(|_| "http://localhost:3000/invitations".to_string()),
+            mail_backend: env::var("MAIL_BACKEND").unwrap_or_else(|_| "log".to_string()),
+            mail_api_url: env::var("MAIL_API_URL").ok(),
+            mail_api_key: env::var("MAIL_API_KEY").ok(),
+            mail_from: env::var("MAIL_FROM").unwrap_or_else(|_| "no-reply@localhost".to_string()),
+            share_link_base_url: env::var("SHARE_LINK_BASE_URL")
+                .unwrap_or_else(|_| "http://localhost:3000/shared".to_string()),
         }
     }
 
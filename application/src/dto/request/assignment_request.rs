@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AssignTodoRequest {
+    /// 담당자로 지정할 워크스페이스 멤버. 이미 담당자인 사용자는 무시한다.
+    #[validate(length(min = 1, max = 10, message = "담당자는 한 번에 1-10명까지 지정할 수 있습니다"))]
+    pub user_ids: Vec<Uuid>,
+}
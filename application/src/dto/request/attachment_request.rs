@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+/// 파일 업로드 (`multipart/form-data`). 내용으로 형식을 판별하므로 파트의 Content-Type은 참고만 한다.
+#[derive(Debug, ToSchema)]
+pub struct AttachmentUploadRequest {
+    /// 첨부할 파일 하나
+    #[schema(value_type = String, format = Binary)]
+    pub file: Vec<u8>,
+}
+
+/// 서명된 다운로드 URL의 쿼리
+#[derive(Debug, Clone, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AttachmentDownloadQuery {
+    /// 만료 시각 (Unix 초)
+    pub expires: i64,
+
+    /// HMAC-SHA256 서명 (16진수)
+    pub signature: String,
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use utoipa::ToSchema;
+use crate::entities::todo::TodoStatus;
+
+/// 보드 생성. 열을 지정하지 않으면 상태별 기본 열(할 일, 진행 중, 완료)을 만든다.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateBoardRequest {
+    #[validate(length(min = 1, max = 100, message = "보드 이름은 1-100자 사이여야 합니다"))]
+    pub name: String,
+
+    /// 만들 열 (이 순서대로)
+    #[serde(default)]
+    #[validate(length(max = 20, message = "열은 최대 20개까지 만들 수 있습니다"))]
+    pub columns: Vec<BoardColumnRequest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateBoardRequest {
+    #[validate(length(min = 1, max = 100, message = "보드 이름은 1-100자 사이여야 합니다"))]
+    pub name: String,
+}
+
+/// 열 생성/수정
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct BoardColumnRequest {
+    #[validate(length(min = 1, max = 50, message = "열 이름은 1-50자 사이여야 합니다"))]
+    pub name: String,
+
+    /// 이 상태의 할일이 모이는 열. 없으면 직접 옮긴 할일만 담는 레인
+    pub status: Option<TodoStatus>,
+
+    /// 담을 수 있는 카드 수 (없으면 제한 없음)
+    #[validate(range(min = 1, max = 1000, message = "WIP 제한은 1-1000 사이여야 합니다"))]
+    pub wip_limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ReorderColumnsRequest {
+    /// 보드의 열 ID 전체 (새 순서대로)
+    pub column_ids: Vec<Uuid>,
+}
+
+/// 카드를 `column_id` 열로 옮긴다. `after_id` 바로 뒤, `before_id` 바로 앞, 둘 다 없으면 열의 맨 뒤에 놓는다.
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct MoveCardRequest {
+    pub column_id: Uuid,
+    pub after_id: Option<Uuid>,
+    pub before_id: Option<Uuid>,
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use utoipa::ToSchema;
+use validator::Validate;
+use crate::entities::todo::{Priority, TodoStatus};
+
+/// 일괄 작업 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkAction {
+    /// `status` 필요
+    SetStatus,
+    /// `priority` 필요
+    SetPriority,
+    /// `category_ids` 필요. 이미 지정된 카테고리는 그대로 둔다.
+    AssignCategories,
+    /// 휴지통으로 이동
+    Delete,
+    /// 휴지통에서 복원
+    Restore,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct BulkTodoRequest {
+    /// 처리할 할일 ID (요청한 순서대로 처리)
+    #[validate(length(min = 1, max = 100, message = "ids는 1-100개 사이여야 합니다"))]
+    pub ids: Vec<Uuid>,
+    pub action: BulkAction,
+    pub status: Option<TodoStatus>,
+    pub priority: Option<Priority>,
+    #[validate(length(min = 1, max = 20, message = "카테고리는 한 번에 1-20개까지 지정할 수 있습니다"))]
+    pub category_ids: Option<Vec<Uuid>>,
+    /// `true`이면 하나라도 실패할 때 아무것도 반영하지 않는다
+    #[serde(default)]
+    pub atomic: bool,
+    /// `true`이면 결과만 미리 보고 반영하지 않는다
+    #[serde(default)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateCategoryRequest {
+    /// 카테고리를 만들 워크스페이스 (기본값: 개인 워크스페이스, owner 또는 editor여야 한다)
+    pub workspace_id: Option<Uuid>,
+
+    #[validate(length(min = 1, max = 100, message = "카테고리 이름은 1-100자 사이여야 합니다"))]
+    pub name: String,
+
+    /// `#RRGGBB` 형식 (기본값: `#2196F3`)
+    #[validate(regex(path = "crate::utils::validation::COLOR_REGEX", message = "색상은 #RRGGBB 형식이어야 합니다"))]
+    pub color: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateCategoryRequest {
+    #[validate(length(min = 1, max = 100, message = "카테고리 이름은 1-100자 사이여야 합니다"))]
+    pub name: String,
+
+    #[validate(regex(path = "crate::utils::validation::COLOR_REGEX", message = "색상은 #RRGGBB 형식이어야 합니다"))]
+    pub color: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct AssignCategoriesRequest {
+    #[validate(length(min = 1, max = 20, message = "카테고리는 한 번에 1-20개까지 지정할 수 있습니다"))]
+    pub category_ids: Vec<Uuid>,
+}
@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateChecklistItemRequest {
+    #[validate(length(min = 1, max = 255, message = "항목 제목은 1-255자 사이여야 합니다"))]
+    pub title: String,
+}
+
+/// 지정한 필드만 변경한다
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateChecklistItemRequest {
+    #[validate(length(min = 1, max = 255, message = "항목 제목은 1-255자 사이여야 합니다"))]
+    pub title: Option<String>,
+
+    pub is_done: Option<bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct ReorderChecklistRequest {
+    /// 새 순서대로 나열한 모든 항목 ID
+    #[validate(length(min = 1, max = 100, message = "항목은 1-100개 사이여야 합니다"))]
+    pub item_ids: Vec<Uuid>,
+}
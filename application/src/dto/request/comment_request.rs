@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateCommentRequest {
+    /// 마크다운 본문. `@username`으로 사용자를 언급할 수 있다.
+    #[validate(length(min = 1, max = 10000, message = "댓글은 1-10000자 사이여야 합니다"))]
+    pub body: String,
+
+    /// 답글을 달 댓글 ID (없으면 최상위 댓글)
+    pub parent_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateCommentRequest {
+    #[validate(length(min = 1, max = 10000, message = "댓글은 1-10000자 사이여야 합니다"))]
+    pub body: String,
+}
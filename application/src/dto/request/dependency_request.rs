@@ -0,0 +1,9 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AddDependencyRequest {
+    /// 먼저 끝나야 하는 선행 할일 ID
+    pub blocked_by_id: Uuid,
+}
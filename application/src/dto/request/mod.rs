@@ -2,7 +2,25 @@
 //!
 //! Contains request data structures for API endpoints.
 
+pub mod assignment_request;
+pub mod attachment_request;
 pub mod auth_request;
+pub mod board_request;
+pub mod bulk_request;
+pub mod category_request;
+pub mod recurrence_request;
+pub mod reminder_request;
+pub mod share_link_request;
+pub mod smart_list_request;
+pub mod template_request;
+pub mod time_entry_request;
+pub mod checklist_request;
+pub mod comment_request;
+pub mod dependency_request;
+pub mod quick_add_request;
+pub mod todo_request;
+pub mod user_settings_request;
+pub mod workspace_request;
 
 // Currently no request DTOs needed for health check
 // Future request DTOs will be added here
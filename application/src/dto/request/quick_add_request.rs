@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct QuickAddRequest {
+    /// 한 줄 입력 (예: `내일 오후 3시 회의 준비 !high #업무`)
+    #[validate(length(min = 1, max = 500, message = "입력은 1-500자 사이여야 합니다"))]
+    pub text: String,
+
+    /// 날짜와 시각을 해석할 시간대 (기본값: 사용자 설정)
+    pub timezone: Option<String>,
+}
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+use crate::entities::todo::Priority;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SetRecurrenceRequest {
+    /// RFC 5545 RRULE (예: `FREQ=WEEKLY;BYDAY=MO,WE`)
+    #[validate(length(min = 1, max = 255, message = "RRULE은 1-255자 사이여야 합니다"))]
+    pub rrule: String,
+
+    /// 발생 시각을 계산할 시간대 (기본값: 사용자 설정)
+    pub timezone: Option<String>,
+}
+
+/// 반복 일정 수정 범위
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum EditScope {
+    /// 이번 발생만 수정
+    #[default]
+    This,
+    /// 이번 발생과 이후 모든 발생 수정
+    AllFuture,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateOccurrenceRequest {
+    #[serde(default)]
+    pub scope: EditScope,
+
+    #[validate(length(min = 1, max = 255, message = "제목은 1-255자 사이여야 합니다"))]
+    pub title: String,
+
+    #[validate(length(max = 1000, message = "설명은 최대 1000자까지 입력 가능합니다"))]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub priority: Priority,
+
+    pub due_date: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub checklist_auto_complete: bool,
+
+    /// 새 RRULE (`all_future`에서만 사용, 생략하면 기존 규칙 유지)
+    #[validate(length(min = 1, max = 255, message = "RRULE은 1-255자 사이여야 합니다"))]
+    pub rrule: Option<String>,
+
+    /// 새 시간대 (`all_future`에서만 사용, 생략하면 기존 시간대 유지)
+    pub timezone: Option<String>,
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use chrono::{DateTime, Utc};
+use validator::Validate;
+use utoipa::ToSchema;
+
+/// `remind_at`과 `offset_minutes` 중 하나만 지정한다
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateReminderRequest {
+    /// 이 시각에 알린다
+    pub remind_at: Option<DateTime<Utc>>,
+
+    /// 마감 N분 전에 알린다 (0이면 마감 시각, 최대 30일)
+    #[validate(range(min = 0, max = 43200, message = "offset_minutes는 0-43200 사이여야 합니다"))]
+    pub offset_minutes: Option<i32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SnoozeReminderRequest {
+    /// 지금부터 N분 뒤에 다시 알린다 (기본값: 10, 최대: 1440)
+    #[validate(range(min = 1, max = 1440, message = "minutes는 1-1440 사이여야 합니다"))]
+    pub minutes: Option<u32>,
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use chrono::{DateTime, Utc};
+use utoipa::{IntoParams, ToSchema};
+use crate::entities::todo::{Priority, TodoStatus};
+
+/// 공유 링크 생성. `todo_id`(할일 하나)와 `workspace_id`(거른 목록) 중 하나만 지정한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateShareLinkRequest {
+    /// 공유할 할일
+    pub todo_id: Option<Uuid>,
+
+    /// 목록을 공유할 워크스페이스
+    pub workspace_id: Option<Uuid>,
+
+    /// 공유 페이지에 보여줄 제목
+    #[validate(length(min = 1, max = 100, message = "제목은 1-100자 사이여야 합니다"))]
+    pub title: Option<String>,
+
+    /// 목록 조건: 비어 있으면 모든 상태
+    #[serde(default)]
+    pub statuses: Vec<TodoStatus>,
+
+    /// 목록 조건: 비어 있으면 모든 우선순위
+    #[serde(default)]
+    pub priorities: Vec<Priority>,
+
+    /// 목록 조건: 하나라도 일치하면 포함
+    #[serde(default)]
+    #[validate(length(max = 20, message = "카테고리는 최대 20개까지 지정할 수 있습니다"))]
+    pub category_ids: Vec<Uuid>,
+
+    /// 목록 조건: 검색어
+    #[validate(length(min = 1, max = 255, message = "검색어는 1-255자 사이여야 합니다"))]
+    pub search: Option<String>,
+
+    /// 목록 정렬 (`GET /todos`의 `sort` 형식)
+    #[validate(length(min = 1, max = 100, message = "정렬은 1-100자 사이여야 합니다"))]
+    pub sort: Option<String>,
+
+    /// 지정하면 이 시각 이후 링크가 만료된다
+    pub expires_at: Option<DateTime<Utc>>,
+
+    /// 지정하면 열람할 때 `X-Share-Password` 헤더로 입력해야 한다.
+    /// 헤더로 보낼 수 있도록 공백을 제외한 ASCII 문자 4-72바이트만 허용한다.
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ShareLinkListQuery {
+    /// 공유 링크를 조회할 워크스페이스
+    pub workspace_id: Uuid,
+}
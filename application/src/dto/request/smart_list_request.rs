@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::entities::smart_list::DueWindow;
+use crate::entities::todo::{Priority, TodoStatus};
+
+/// 스마트 목록 생성/수정 (수정 시 모든 조건을 교체한다)
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct SmartListRequest {
+    #[validate(length(min = 1, max = 100, message = "스마트 목록 이름은 1-100자 사이여야 합니다"))]
+    pub name: String,
+
+    /// 비어 있으면 모든 상태
+    #[serde(default)]
+    pub statuses: Vec<TodoStatus>,
+
+    /// 비어 있으면 모든 우선순위
+    #[serde(default)]
+    pub priorities: Vec<Priority>,
+
+    pub due_window: Option<DueWindow>,
+
+    /// 하나라도 일치하면 포함
+    #[serde(default)]
+    #[validate(length(max = 20, message = "카테고리는 최대 20개까지 지정할 수 있습니다"))]
+    pub category_ids: Vec<Uuid>,
+
+    #[validate(length(min = 1, max = 255, message = "검색어는 1-255자 사이여야 합니다"))]
+    pub search: Option<String>,
+
+    /// 기본 정렬 (`GET /todos`의 `sort` 형식)
+    #[validate(length(min = 1, max = 100, message = "정렬은 1-100자 사이여야 합니다"))]
+    pub sort: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SmartListTodosQuery {
+    /// 페이지 번호 (기본값: 1)
+    #[validate(range(min = 1, message = "페이지 번호는 1 이상이어야 합니다"))]
+    pub page: Option<u32>,
+
+    /// 페이지당 항목 수 (기본값: 20, 최대: 100)
+    #[validate(range(min = 1, max = 100, message = "limit은 1-100 사이여야 합니다"))]
+    pub limit: Option<u32>,
+
+    /// 목록에 저장된 정렬 대신 사용할 정렬
+    pub sort: Option<String>,
+
+    /// 이 커서 다음 항목부터 조회. `page`와 함께 사용할 수 없음
+    pub after: Option<String>,
+
+    /// 이 커서 이전 항목까지 조회. `page`와 함께 사용할 수 없음
+    pub before: Option<String>,
+
+    /// 마감 구간을 계산할 시간대 (기본값: 사용자 설정)
+    pub timezone: Option<String>,
+}
@@ -0,0 +1,60 @@
+use chrono::{NaiveDate, NaiveTime};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+use utoipa::ToSchema;
+use crate::entities::todo::Priority;
+
+/// 템플릿 생성/수정 (수정 시 항목 전체를 교체한다)
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct TemplateRequest {
+    #[validate(length(min = 1, max = 100, message = "템플릿 이름은 1-100자 사이여야 합니다"))]
+    pub name: String,
+
+    #[validate(length(max = 1000, message = "설명은 최대 1000자까지 입력 가능합니다"))]
+    pub description: Option<String>,
+
+    /// 만들 할일 (이 순서대로 만든다)
+    #[validate(length(min = 1, max = 50, message = "템플릿 항목은 1-50개 사이여야 합니다"))]
+    pub items: Vec<TemplateItemRequest>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct TemplateItemRequest {
+    #[validate(length(min = 1, max = 255, message = "제목은 1-255자 사이여야 합니다"))]
+    pub title: String,
+
+    #[validate(length(max = 1000, message = "설명은 최대 1000자까지 입력 가능합니다"))]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub priority: Priority,
+
+    /// 시작일로부터 며칠 뒤가 마감인지 (0: 시작일, 음수: 시작일 전). 없으면 마감 없음
+    #[validate(range(min = -365, max = 365, message = "마감 오프셋은 -365-365일 사이여야 합니다"))]
+    pub due_offset_days: Option<i32>,
+
+    /// 마감 시각 (`HH:MM`, 현지 시각, 기본값: 23:59). `due_offset_days`가 있어야 한다
+    pub due_time: Option<NaiveTime>,
+
+    #[serde(default)]
+    #[validate(length(max = 20, message = "카테고리는 최대 20개까지 지정할 수 있습니다"))]
+    pub category_ids: Vec<Uuid>,
+
+    /// 체크리스트 항목 제목
+    #[serde(default)]
+    #[validate(length(max = 100, message = "체크리스트 항목은 최대 100개까지 추가할 수 있습니다"))]
+    pub checklist: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct InstantiateTemplateRequest {
+    /// 상대 마감의 기준일 (`YYYY-MM-DD`)
+    pub start_date: NaiveDate,
+
+    /// 마감 시각을 해석할 시간대 (기본값: 사용자 설정)
+    pub timezone: Option<String>,
+
+    /// 할일을 만들 워크스페이스 (기본값: 개인 워크스페이스, editor 이상 필요)
+    pub workspace_id: Option<Uuid>,
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::entities::time_entry::TimeReportGroup;
+
+/// 직접 입력하는 시간 기록 (수정 시 모든 필드를 교체한다)
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct TimeEntryRequest {
+    pub started_at: DateTime<Utc>,
+
+    pub ended_at: DateTime<Utc>,
+
+    #[validate(length(max = 500, message = "메모는 최대 500자까지 입력 가능합니다"))]
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TimeReportQuery {
+    /// 시작일 (`YYYY-MM-DD`, 포함)
+    pub from: NaiveDate,
+
+    /// 종료일 (`YYYY-MM-DD`, 포함)
+    pub to: NaiveDate,
+
+    /// 집계 기준 (기본값: `day`)
+    pub group_by: Option<TimeReportGroup>,
+
+    /// 날짜 경계를 계산할 시간대 (기본값: 사용자 설정)
+    pub timezone: Option<String>,
+}
@@ -0,0 +1,175 @@
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::{IntoParams, ToSchema};
+use crate::entities::todo::{Priority, TodoStatus};
+use crate::entities::user_settings::WeekStart;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateTodoRequest {
+    #[validate(length(min = 1, max = 255, message = "제목은 1-255자 사이여야 합니다"))]
+    pub title: String,
+
+    #[validate(length(max = 1000, message = "설명은 최대 1000자까지 입력 가능합니다"))]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub priority: Priority,
+
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// 체크리스트를 모두 완료하면 할일을 자동으로 완료 처리 (기본값: false)
+    #[serde(default)]
+    pub checklist_auto_complete: bool,
+
+    /// 선행 할일이 모두 완료되기 전에는 완료할 수 없음 (기본값: false)
+    #[serde(default)]
+    pub enforce_dependencies: bool,
+
+    /// 예상 소요 시간 (분)
+    #[validate(range(min = 1, max = 100000, message = "예상 소요 시간은 1-100000분 사이여야 합니다"))]
+    pub estimate_minutes: Option<i32>,
+
+    /// 할일을 만들 워크스페이스 (기본값: 개인 워크스페이스, editor 이상 필요)
+    pub workspace_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateTodoRequest {
+    #[validate(length(min = 1, max = 255, message = "제목은 1-255자 사이여야 합니다"))]
+    pub title: String,
+
+    #[validate(length(max = 1000, message = "설명은 최대 1000자까지 입력 가능합니다"))]
+    pub description: Option<String>,
+
+    #[serde(default)]
+    pub priority: Priority,
+
+    pub due_date: Option<DateTime<Utc>>,
+
+    /// 체크리스트를 모두 완료하면 할일을 자동으로 완료 처리 (기본값: false)
+    #[serde(default)]
+    pub checklist_auto_complete: bool,
+
+    /// 선행 할일이 모두 완료되기 전에는 완료할 수 없음 (기본값: false)
+    #[serde(default)]
+    pub enforce_dependencies: bool,
+
+    /// 예상 소요 시간 (분)
+    #[validate(range(min = 1, max = 100000, message = "예상 소요 시간은 1-100000분 사이여야 합니다"))]
+    pub estimate_minutes: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateTodoStatusRequest {
+    pub status: TodoStatus,
+}
+
+/// 이동할 자리의 이웃 할일. 둘 중 하나 이상 지정한다.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct MoveTodoRequest {
+    /// 이 할일 바로 뒤로 옮긴다
+    pub after_id: Option<Uuid>,
+
+    /// 이 할일 바로 앞으로 옮긴다
+    pub before_id: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TodoListQuery {
+    /// 상태 필터 (쉼표로 여러 개 지정: `pending,in_progress`)
+    pub status: Option<String>,
+
+    /// 우선순위 필터 (쉼표로 여러 개 지정: `high,medium`)
+    pub priority: Option<String>,
+
+    /// 페이지 번호 (기본값: 1)
+    #[validate(range(min = 1, message = "페이지 번호는 1 이상이어야 합니다"))]
+    pub page: Option<u32>,
+
+    /// 페이지당 항목 수 (기본값: 20, 최대: 100)
+    #[validate(range(min = 1, max = 100, message = "limit은 1-100 사이여야 합니다"))]
+    pub limit: Option<u32>,
+
+    /// 제목/설명 검색 키워드
+    #[validate(length(max = 255, message = "검색어는 최대 255자까지 입력 가능합니다"))]
+    pub search: Option<String>,
+
+    /// 마감일이 이 시각 이전인 할일
+    pub due_before: Option<DateTime<Utc>>,
+
+    /// 마감일이 이 시각 이후인 할일
+    pub due_after: Option<DateTime<Utc>>,
+
+    /// `true`이면 마감일이 지났고 완료되지 않은 할일만 조회
+    pub overdue: Option<bool>,
+
+    /// 마감일 존재 여부
+    pub has_due_date: Option<bool>,
+
+    /// 카테고리 ID 필터 (쉼표로 여러 개 지정, 하나라도 일치하면 포함)
+    pub category_ids: Option<String>,
+
+    /// 생성일이 이 시각 이후인 할일
+    pub created_after: Option<DateTime<Utc>>,
+
+    /// 생성일이 이 시각 이전인 할일
+    pub created_before: Option<DateTime<Utc>>,
+
+    /// `true`이면 보관된 할일도 포함 (기본값: `false`)
+    pub include_archived: Option<bool>,
+
+    /// 이 워크스페이스의 할일만 조회 (기본값: 멤버인 모든 워크스페이스)
+    pub workspace_id: Option<Uuid>,
+
+    /// 이 사용자가 담당자인 할일만 조회
+    pub assignee_id: Option<Uuid>,
+
+    /// 정렬 (쉼표로 여러 필드 지정, `-` 접두사는 내림차순: `-priority,due_date`)
+    pub sort: Option<String>,
+
+    /// 이 커서 다음 항목부터 조회 (응답의 `cursor.next_cursor`). `page`와 함께 사용할 수 없음
+    pub after: Option<String>,
+
+    /// 이 커서 이전 항목까지 조회 (응답의 `cursor.prev_cursor`). `page`와 함께 사용할 수 없음
+    pub before: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TrashListQuery {
+    /// 페이지 번호 (기본값: 1)
+    #[validate(range(min = 1, message = "페이지 번호는 1 이상이어야 합니다"))]
+    pub page: Option<u32>,
+
+    /// 페이지당 항목 수 (기본값: 20, 최대: 100)
+    #[validate(range(min = 1, max = 100, message = "limit은 1-100 사이여야 합니다"))]
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TodoStatsQuery {
+    /// 집계 기준 시간대 (기본값: 사용자 설정)
+    pub timezone: Option<String>,
+
+    /// 한 주의 시작 요일 (기본값: 사용자 설정)
+    pub week_start: Option<WeekStart>,
+
+    /// `true`이면 보관된 할일도 집계 (기본값: `false`)
+    pub include_archived: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, Validate, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TodoSearchQuery {
+    /// 검색어 (공백으로 구분된 모든 단어가 제목/설명/카테고리명 중 하나에 포함되어야 함)
+    #[validate(length(min = 1, max = 100, message = "검색어는 1-100자 사이여야 합니다"))]
+    pub q: String,
+
+    /// 최대 결과 수 (기본값: 20, 최대: 50)
+    #[validate(range(min = 1, max = 50, message = "limit은 1-50 사이여야 합니다"))]
+    pub limit: Option<u32>,
+}
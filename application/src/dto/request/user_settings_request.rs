@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+use crate::entities::user_settings::WeekStart;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateUserSettingsRequest {
+    /// IANA 시간대 이름 (예: `Asia/Seoul`)
+    #[validate(length(min = 1, max = 64, message = "시간대는 1-64자 사이여야 합니다"))]
+    pub timezone: String,
+
+    #[serde(default)]
+    pub week_start: WeekStart,
+
+    /// 개인 워크스페이스의 할일을 완료 후 이 일수가 지나면 자동 보관 (생략하면 사용 안 함)
+    #[validate(range(min = 1, max = 3650, message = "자동 보관 일수는 1-3650 사이여야 합니다"))]
+    pub auto_archive_days: Option<u32>,
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+use utoipa::ToSchema;
+use crate::entities::workspace::WorkspaceRole;
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateWorkspaceRequest {
+    #[validate(length(min = 1, max = 100, message = "워크스페이스 이름은 1-100자 사이여야 합니다"))]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct UpdateWorkspaceRequest {
+    #[validate(length(min = 1, max = 100, message = "워크스페이스 이름은 1-100자 사이여야 합니다"))]
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateMemberRoleRequest {
+    pub role: WorkspaceRole,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct CreateInvitationRequest {
+    #[validate(email(message = "유효한 이메일 주소를 입력해주세요"))]
+    pub email: String,
+
+    /// 수락하면 받을 역할 (`editor` 또는 `viewer`)
+    pub role: WorkspaceRole,
+}
+
+/// 초대 메일로 받은 토큰
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+pub struct InvitationTokenRequest {
+    #[validate(length(min = 1, max = 128, message = "초대 토큰이 올바르지 않습니다"))]
+    pub token: String,
+}
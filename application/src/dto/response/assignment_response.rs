@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::assignment::{MemberWorkload, TodoAssignee};
+use crate::entities::workspace::WorkspaceRole;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssigneeResponse {
+    pub user_id: Uuid,
+    /// 탈퇴한 사용자는 `null`
+    pub username: Option<String>,
+    pub email: Option<String>,
+    /// 담당자로 지정한 사용자
+    pub assigned_by: Uuid,
+    pub assigned_at: DateTime<Utc>,
+}
+
+impl From<TodoAssignee> for AssigneeResponse {
+    fn from(assignee: TodoAssignee) -> Self {
+        Self {
+            user_id: assignee.user_id,
+            username: assignee.username,
+            email: assignee.email,
+            assigned_by: assignee.assigned_by,
+            assigned_at: assignee.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AssigneeListResponse {
+    pub todo_id: Uuid,
+    /// 지정한 순서대로
+    pub assignees: Vec<AssigneeResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MemberWorkloadResponse {
+    pub user_id: Uuid,
+    pub username: Option<String>,
+    pub role: WorkspaceRole,
+    /// 완료되지 않은 담당 할일 (보관, 휴지통 제외)
+    pub open_todos: i64,
+    pub in_progress_todos: i64,
+    /// 마감일이 지난 담당 할일
+    pub overdue_todos: i64,
+}
+
+impl From<MemberWorkload> for MemberWorkloadResponse {
+    fn from(workload: MemberWorkload) -> Self {
+        Self {
+            user_id: workload.user_id,
+            username: workload.username,
+            role: workload.role,
+            open_todos: workload.open_todos,
+            in_progress_todos: workload.in_progress_todos,
+            overdue_todos: workload.overdue_todos,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WorkloadResponse {
+    pub workspace_id: Uuid,
+    /// 담당 할일이 많은 순서. 담당 할일이 없는 멤버도 포함한다.
+    pub members: Vec<MemberWorkloadResponse>,
+    /// 어떤 멤버에게도 맡겨지지 않은 미완료 할일
+    pub unassigned_todos: i64,
+}
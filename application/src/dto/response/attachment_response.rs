@@ -0,0 +1,47 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use crate::entities::attachment::Attachment;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AttachmentResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub file_name: String,
+    /// 내용으로 판별한 형식
+    pub content_type: String,
+    pub size_bytes: i64,
+    /// 내용의 SHA-256 (16진수)
+    pub sha256: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<Attachment> for AttachmentResponse {
+    fn from(attachment: Attachment) -> Self {
+        Self {
+            id: attachment.id,
+            todo_id: attachment.todo_id,
+            file_name: attachment.file_name,
+            content_type: attachment.content_type,
+            size_bytes: attachment.size_bytes,
+            sha256: attachment.sha256,
+            created_at: attachment.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AttachmentListResponse {
+    pub todo_id: Uuid,
+    pub attachments: Vec<AttachmentResponse>,
+    pub total_bytes: i64,
+}
+
+/// 인증 없이 내려받을 수 있는 만료 URL
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AttachmentUrlResponse {
+    /// 서버 기준 경로 (`/api/v1/attachments/{id}/content?expires=...&signature=...`)
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
@@ -0,0 +1,80 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use crate::dto::response::todo_response::TodoResponse;
+use crate::entities::board::{BoardColumn, BoardWithColumns};
+use crate::entities::todo::TodoStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BoardColumnResponse {
+    pub id: Uuid,
+    pub position: i32,
+    pub name: String,
+    /// 레인이면 `null`
+    pub status: Option<TodoStatus>,
+    pub wip_limit: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BoardResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub columns: Vec<BoardColumnResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BoardListResponse {
+    pub boards: Vec<BoardResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BoardColumnCardsResponse {
+    pub column: BoardColumnResponse,
+    pub card_count: usize,
+    /// 다른 곳에서 상태가 바뀌어 WIP 제한을 넘은 경우
+    pub over_limit: bool,
+    /// 열 안의 순서대로
+    pub cards: Vec<TodoResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BoardCardsResponse {
+    pub board_id: Uuid,
+    pub columns: Vec<BoardColumnCardsResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MoveCardResponse {
+    pub board_id: Uuid,
+    pub column_id: Uuid,
+    /// 상태 열로 옮기면서 상태가 바뀌었는지 여부
+    pub status_changed: bool,
+    pub todo: TodoResponse,
+}
+
+impl From<BoardColumn> for BoardColumnResponse {
+    fn from(column: BoardColumn) -> Self {
+        Self {
+            id: column.id,
+            position: column.position,
+            name: column.name,
+            status: column.status,
+            wip_limit: column.wip_limit,
+        }
+    }
+}
+
+impl From<BoardWithColumns> for BoardResponse {
+    fn from(board: BoardWithColumns) -> Self {
+        Self {
+            id: board.board.id,
+            name: board.board.name,
+            columns: board.columns.into_iter().map(BoardColumnResponse::from).collect(),
+            created_at: board.board.created_at,
+            updated_at: board.board.updated_at,
+        }
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use crate::dto::request::bulk_request::BulkAction;
+use crate::dto::response::todo_response::TodoResponse;
+use crate::entities::bulk::{BulkItemOutcome, BulkItemResult};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkItemResponse {
+    pub id: Uuid,
+    pub outcome: BulkItemOutcome,
+    /// 실패 사유
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// 반영 후의 할일
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub todo: Option<TodoResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct BulkTodoResponse {
+    pub action: BulkAction,
+    pub atomic: bool,
+    pub dry_run: bool,
+    /// 변경 사항이 반영되었는지 여부
+    pub committed: bool,
+    pub succeeded: usize,
+    pub unchanged: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    /// 요청한 ID 순서대로
+    pub results: Vec<BulkItemResponse>,
+}
+
+impl From<BulkItemResult> for BulkItemResponse {
+    fn from(result: BulkItemResult) -> Self {
+        Self {
+            id: result.id,
+            outcome: result.outcome,
+            error: result.error,
+            todo: result.todo.map(TodoResponse::from),
+        }
+    }
+}
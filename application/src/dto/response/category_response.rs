@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::category::{Category, CategoryWithCount};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CategoryResponse {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub name: String,
+    pub color: String,
+    pub todo_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CategoryListResponse {
+    pub categories: Vec<CategoryResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TodoCategoriesResponse {
+    pub todo_id: Uuid,
+    pub categories: Vec<CategoryResponse>,
+}
+
+impl From<CategoryWithCount> for CategoryResponse {
+    fn from(row: CategoryWithCount) -> Self {
+        Self {
+            todo_count: row.todo_count,
+            ..Self::from(row.category)
+        }
+    }
+}
+
+/// 새로 만든 카테고리처럼 할일 수를 모르는 경우 0으로 응답한다
+impl From<Category> for CategoryResponse {
+    fn from(category: Category) -> Self {
+        Self {
+            id: category.id,
+            workspace_id: category.workspace_id,
+            name: category.name,
+            color: category.color,
+            todo_count: 0,
+            created_at: category.created_at,
+            updated_at: category.updated_at,
+        }
+    }
+}
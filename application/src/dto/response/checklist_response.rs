@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::checklist::ChecklistItem;
+use crate::entities::todo::{Todo, TodoStatus};
+
+/// 체크리스트 진행률 (`done`/`total`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct ChecklistProgress {
+    pub done: i32,
+    pub total: i32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChecklistItemResponse {
+    pub id: Uuid,
+    pub title: String,
+    pub is_done: bool,
+    pub position: i32,
+    pub done_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChecklistResponse {
+    pub todo_id: Uuid,
+    pub items: Vec<ChecklistItemResponse>,
+    pub progress: ChecklistProgress,
+    /// 변경 후 할일 상태 (자동 완료된 경우 `completed`)
+    pub todo_status: TodoStatus,
+}
+
+impl From<&Todo> for ChecklistProgress {
+    fn from(todo: &Todo) -> Self {
+        Self {
+            done: todo.checklist_done,
+            total: todo.checklist_total,
+        }
+    }
+}
+
+impl From<ChecklistItem> for ChecklistItemResponse {
+    fn from(item: ChecklistItem) -> Self {
+        Self {
+            id: item.id,
+            title: item.title,
+            is_done: item.is_done,
+            position: item.position,
+            done_at: item.done_at,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+        }
+    }
+}
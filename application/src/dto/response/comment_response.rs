@@ -0,0 +1,82 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use crate::entities::comment::{Comment, CommentRevision};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CommentAuthorResponse {
+    pub id: Uuid,
+    /// 탈퇴한 사용자는 `null`
+    pub username: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CommentResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub author: CommentAuthorResponse,
+    /// 마크다운 원문 (삭제된 댓글은 `null`)
+    pub body: Option<String>,
+    /// 정제된 HTML (삭제된 댓글은 `null`)
+    pub body_html: Option<String>,
+    pub edited_at: Option<DateTime<Utc>>,
+    pub deleted: bool,
+    pub created_at: DateTime<Utc>,
+    /// 작성 순서대로 정렬한 답글
+    pub replies: Vec<CommentResponse>,
+}
+
+impl From<Comment> for CommentResponse {
+    fn from(comment: Comment) -> Self {
+        let deleted = comment.is_deleted();
+        Self {
+            id: comment.id,
+            todo_id: comment.todo_id,
+            parent_id: comment.parent_id,
+            author: CommentAuthorResponse {
+                id: comment.user_id,
+                username: comment.author_username,
+            },
+            body: (!deleted).then_some(comment.body),
+            body_html: (!deleted).then_some(comment.body_html),
+            edited_at: comment.edited_at,
+            deleted,
+            created_at: comment.created_at,
+            replies: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CommentThreadResponse {
+    pub todo_id: Uuid,
+    /// 최상위 댓글. 답글이 남아 있는 삭제된 댓글은 자리만 표시한다.
+    pub comments: Vec<CommentResponse>,
+    /// 삭제되지 않은 댓글 수 (답글 포함)
+    pub total: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CommentRevisionResponse {
+    pub body: String,
+    /// 이 본문을 쓴 시각
+    pub written_at: DateTime<Utc>,
+}
+
+impl From<CommentRevision> for CommentRevisionResponse {
+    fn from(revision: CommentRevision) -> Self {
+        Self {
+            body: revision.body,
+            written_at: revision.written_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CommentRevisionListResponse {
+    pub comment_id: Uuid,
+    /// 이전 본문, 오래된 순서 (현재 본문은 포함하지 않는다)
+    pub revisions: Vec<CommentRevisionResponse>,
+}
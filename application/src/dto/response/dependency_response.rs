@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::dependency::DependencyTodo;
+use crate::entities::todo::{Priority, TodoStatus};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DependencyTodoResponse {
+    pub id: Uuid,
+    pub title: String,
+    pub status: TodoStatus,
+    pub priority: Priority,
+    pub due_date: Option<DateTime<Utc>>,
+    pub linked_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DependencyListResponse {
+    pub todo_id: Uuid,
+    /// 이 할일을 막고 있는 선행 할일
+    pub blocked_by: Vec<DependencyTodoResponse>,
+    /// 이 할일이 막고 있는 후속 할일
+    pub dependents: Vec<DependencyTodoResponse>,
+    /// 완료되지 않은 선행 할일 수
+    pub open_blockers: usize,
+    /// `true`이면 선행 할일이 모두 완료되어야 완료할 수 있다
+    pub enforce_dependencies: bool,
+}
+
+impl From<DependencyTodo> for DependencyTodoResponse {
+    fn from(todo: DependencyTodo) -> Self {
+        Self {
+            id: todo.id,
+            title: todo.title,
+            status: todo.status,
+            priority: todo.priority,
+            due_date: todo.due_date,
+            linked_at: todo.linked_at,
+        }
+    }
+}
@@ -2,7 +2,25 @@
 //!
 //! Contains response data structures for API endpoints.
 
+pub mod assignment_response;
+pub mod attachment_response;
 pub mod auth_response;
+pub mod board_response;
+pub mod bulk_response;
+pub mod category_response;
+pub mod checklist_response;
+pub mod comment_response;
+pub mod dependency_response;
 pub mod health_response;
+pub mod quick_add_response;
+pub mod recurrence_response;
+pub mod reminder_response;
+pub mod share_link_response;
+pub mod smart_list_response;
+pub mod template_response;
+pub mod time_entry_response;
+pub mod todo_response;
+pub mod user_settings_response;
+pub mod workspace_response;
 
 pub use health_response::HealthResponse;
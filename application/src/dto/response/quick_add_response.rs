@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use chrono::{DateTime, Utc};
+use crate::dto::response::category_response::CategoryResponse;
+use crate::dto::response::recurrence_response::RecurrenceResponse;
+use crate::dto::response::todo_response::TodoResponse;
+use crate::entities::todo::Priority;
+use crate::utils::quick_add::{QuickAddToken, TokenKind};
+
+/// 인식한 부분의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QuickAddTokenKind {
+    Date,
+    Time,
+    Priority,
+    Category,
+    Recurrence,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuickAddTokenResponse {
+    pub kind: QuickAddTokenKind,
+    pub text: String,
+    /// 입력에서의 시작 위치 (문자 단위)
+    pub start: usize,
+    /// 입력에서의 끝 위치 (문자 단위, 포함하지 않음)
+    pub end: usize,
+}
+
+/// 입력을 해석한 결과
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuickAddParseResponse {
+    pub title: String,
+    pub due_date: Option<DateTime<Utc>>,
+    /// 시각 없이 날짜만 지정됨 (마감은 그날 23:59)
+    pub all_day: bool,
+    pub priority: Option<Priority>,
+    pub categories: Vec<String>,
+    /// 정규화된 RRULE
+    pub rrule: Option<String>,
+    /// 해석에 사용한 시간대
+    pub timezone: String,
+    /// 입력 순서대로
+    pub tokens: Vec<QuickAddTokenResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct QuickAddResponse {
+    pub todo: TodoResponse,
+    /// 지정된 카테고리 (없던 이름은 새로 만든다)
+    pub categories: Vec<CategoryResponse>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence: Option<RecurrenceResponse>,
+    pub parsed: QuickAddParseResponse,
+}
+
+impl From<QuickAddToken> for QuickAddTokenResponse {
+    fn from(token: QuickAddToken) -> Self {
+        let kind = match token.kind {
+            TokenKind::Date => QuickAddTokenKind::Date,
+            TokenKind::Time => QuickAddTokenKind::Time,
+            TokenKind::Priority => QuickAddTokenKind::Priority,
+            TokenKind::Category => QuickAddTokenKind::Category,
+            TokenKind::Recurrence => QuickAddTokenKind::Recurrence,
+        };
+
+        Self {
+            kind,
+            text: token.text,
+            start: token.start,
+            end: token.end,
+        }
+    }
+}
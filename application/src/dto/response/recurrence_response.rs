@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::dto::request::recurrence_request::EditScope;
+use crate::dto::response::todo_response::TodoResponse;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RecurrenceResponse {
+    pub todo_id: Uuid,
+    pub series_id: Uuid,
+    /// 정규화된 RRULE
+    pub rrule: String,
+    pub timezone: String,
+    /// 시리즈 첫 발생 시각
+    pub dtstart: DateTime<Utc>,
+    /// 이 할일이 시리즈의 몇 번째 발생인지 (1부터)
+    pub recurrence_index: i32,
+    /// 이후 예정된 발생 시각 (최대 5개, 규칙이 끝나면 비어 있음)
+    pub upcoming: Vec<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct OccurrenceUpdateResponse {
+    pub scope: EditScope,
+    pub todo: TodoResponse,
+    pub recurrence: RecurrenceResponse,
+}
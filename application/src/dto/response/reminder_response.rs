@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::reminder::{Reminder, ReminderStatus};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReminderResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub offset_minutes: Option<i32>,
+    /// 실제 발송 예정 시각 (다시 알림 반영, 마감일이 없는 상대 알림은 `null`)
+    pub fire_at: Option<DateTime<Utc>>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub status: ReminderStatus,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReminderListResponse {
+    pub todo_id: Uuid,
+    pub reminders: Vec<ReminderResponse>,
+}
+
+impl From<Reminder> for ReminderResponse {
+    fn from(reminder: Reminder) -> Self {
+        Self {
+            id: reminder.id,
+            todo_id: reminder.todo_id,
+            remind_at: reminder.remind_at,
+            offset_minutes: reminder.offset_minutes,
+            fire_at: reminder.fire_at,
+            snoozed_until: reminder.snoozed_until,
+            status: reminder.status,
+            sent_at: reminder.sent_at,
+            created_at: reminder.created_at,
+        }
+    }
+}
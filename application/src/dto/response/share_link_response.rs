@@ -0,0 +1,108 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::dto::response::checklist_response::ChecklistProgress;
+use crate::entities::share_link::{ShareKind, ShareLink};
+use crate::entities::todo::{Priority, Todo, TodoStatus};
+
+/// 링크를 관리하는 멤버에게 보여주는 공유 링크. 토큰 원문은 포함하지 않는다.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShareLinkResponse {
+    pub id: Uuid,
+    pub kind: ShareKind,
+    pub workspace_id: Uuid,
+    pub todo_id: Option<Uuid>,
+    pub title: Option<String>,
+    pub statuses: Vec<TodoStatus>,
+    pub priorities: Vec<Priority>,
+    pub category_ids: Vec<Uuid>,
+    pub search: Option<String>,
+    pub sort: Option<String>,
+    pub has_password: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    /// 회수되지 않았고 만료되지 않았으면 `true`
+    pub active: bool,
+    pub view_count: i64,
+    pub last_viewed_at: Option<DateTime<Utc>>,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShareLinkResponse {
+    pub fn new(link: ShareLink, now: DateTime<Utc>) -> Self {
+        Self {
+            id: link.id,
+            kind: link.kind(),
+            active: link.is_active(now),
+            workspace_id: link.workspace_id,
+            todo_id: link.todo_id,
+            title: link.title,
+            statuses: link.filter.statuses,
+            priorities: link.filter.priorities,
+            category_ids: link.filter.category_ids,
+            search: link.filter.search,
+            sort: link.filter.sort,
+            has_password: link.password_hash.is_some(),
+            expires_at: link.expires_at,
+            revoked_at: link.revoked_at,
+            view_count: link.view_count,
+            last_viewed_at: link.last_viewed_at,
+            created_by: link.created_by,
+            created_at: link.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShareLinkCreatedResponse {
+    pub link: ShareLinkResponse,
+    /// 링크 토큰. 생성할 때 한 번만 돌려준다.
+    pub token: String,
+    /// 공유할 주소 (`SHARE_LINK_BASE_URL/{token}`)
+    pub url: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ShareLinkListResponse {
+    /// 최근에 만든 순서
+    pub links: Vec<ShareLinkResponse>,
+}
+
+/// 공유 페이지에 노출하는 할일 필드. ID, 작성자, 워크스페이스 같은 내부 정보는 넣지 않는다.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SharedTodoResponse {
+    pub title: String,
+    pub description: Option<String>,
+    pub status: TodoStatus,
+    pub priority: Priority,
+    pub due_date: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub checklist: ChecklistProgress,
+}
+
+impl From<Todo> for SharedTodoResponse {
+    fn from(todo: Todo) -> Self {
+        Self {
+            checklist: ChecklistProgress::from(&todo),
+            title: todo.title,
+            description: todo.description,
+            status: todo.status,
+            priority: todo.priority,
+            due_date: todo.due_date,
+            completed_at: todo.completed_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SharedContentResponse {
+    pub kind: ShareKind,
+    pub title: Option<String>,
+    /// 할일 공유는 항상 한 건
+    pub todos: Vec<SharedTodoResponse>,
+    /// 목록이 최대 표시 개수에서 잘렸으면 `true`
+    pub truncated: bool,
+    pub expires_at: Option<DateTime<Utc>>,
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::smart_list::{BuiltinSmartList, DueWindow, SmartList, SmartListFilter};
+use crate::entities::todo::{Priority, TodoStatus};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SmartListResponse {
+    /// 저장된 목록은 UUID, 기본 목록은 `today`, `upcoming`, `overdue`
+    pub id: String,
+    pub name: String,
+    /// 기본 목록 여부 (수정/삭제 불가)
+    pub builtin: bool,
+    pub statuses: Vec<TodoStatus>,
+    pub priorities: Vec<Priority>,
+    pub due_window: Option<DueWindow>,
+    pub category_ids: Vec<Uuid>,
+    pub search: Option<String>,
+    pub sort: Option<String>,
+    /// 기본 목록은 생략
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SmartListListResponse {
+    /// 기본 목록, 저장된 목록(이름순) 순서
+    pub smart_lists: Vec<SmartListResponse>,
+}
+
+impl SmartListResponse {
+    fn new(id: String, name: String, builtin: bool, filter: SmartListFilter) -> Self {
+        Self {
+            id,
+            name,
+            builtin,
+            statuses: filter.statuses,
+            priorities: filter.priorities,
+            due_window: filter.due_window,
+            category_ids: filter.category_ids,
+            search: filter.search,
+            sort: filter.sort,
+            created_at: None,
+            updated_at: None,
+        }
+    }
+}
+
+impl From<SmartList> for SmartListResponse {
+    fn from(smart_list: SmartList) -> Self {
+        Self {
+            created_at: Some(smart_list.created_at),
+            updated_at: Some(smart_list.updated_at),
+            ..Self::new(smart_list.id.to_string(), smart_list.name, false, smart_list.filter)
+        }
+    }
+}
+
+impl From<BuiltinSmartList> for SmartListResponse {
+    fn from(builtin: BuiltinSmartList) -> Self {
+        Self::new(builtin.id().to_string(), builtin.name().to_string(), true, builtin.filter())
+    }
+}
@@ -0,0 +1,74 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use crate::dto::response::todo_response::TodoResponse;
+use crate::entities::template::{TemplateItem, TemplateWithItems};
+use crate::entities::todo::Priority;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TemplateItemResponse {
+    pub id: Uuid,
+    pub position: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub due_offset_days: Option<i32>,
+    pub due_time: Option<NaiveTime>,
+    pub category_ids: Vec<Uuid>,
+    pub checklist: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TemplateResponse {
+    pub id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub items: Vec<TemplateItemResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TemplateListResponse {
+    pub templates: Vec<TemplateResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InstantiateTemplateResponse {
+    pub template_id: Uuid,
+    pub start_date: NaiveDate,
+    /// 마감 시각을 계산한 시간대
+    pub timezone: String,
+    /// 만든 할일 (템플릿 항목 순서)
+    pub todos: Vec<TodoResponse>,
+}
+
+impl From<TemplateItem> for TemplateItemResponse {
+    fn from(item: TemplateItem) -> Self {
+        Self {
+            id: item.id,
+            position: item.position,
+            title: item.title,
+            description: item.description,
+            priority: item.priority,
+            due_offset_days: item.due.due_offset_days,
+            due_time: item.due.due_time,
+            category_ids: item.category_ids,
+            checklist: item.checklist,
+        }
+    }
+}
+
+impl From<TemplateWithItems> for TemplateResponse {
+    fn from(row: TemplateWithItems) -> Self {
+        Self {
+            id: row.template.id,
+            name: row.template.name,
+            description: row.template.description,
+            items: row.items.into_iter().map(TemplateItemResponse::from).collect(),
+            created_at: row.template.created_at,
+            updated_at: row.template.updated_at,
+        }
+    }
+}
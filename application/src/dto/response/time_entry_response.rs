@@ -0,0 +1,97 @@
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use crate::entities::time_entry::{TimeEntry, TimeEntrySource, TimeReportGroup};
+use crate::utils::time_report::ReportRow;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimeEntryResponse {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    /// 실행 중인 타이머는 `null`
+    pub ended_at: Option<DateTime<Utc>>,
+    pub running: bool,
+    /// 기록된 시간 (초, 실행 중이면 지금까지)
+    pub duration_seconds: i64,
+    pub source: TimeEntrySource,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimerResponse {
+    /// 실행 중인 타이머 (없으면 `null`)
+    pub running: Option<TimeEntryResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimeEntryListResponse {
+    pub todo_id: Uuid,
+    pub entries: Vec<TimeEntryResponse>,
+    pub tracked_minutes: i64,
+    pub estimate_minutes: Option<i32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimeReportRowResponse {
+    /// 날짜(`YYYY-MM-DD`), 카테고리 ID(`uncategorized`: 미분류) 또는 할일 ID
+    pub key: String,
+    pub label: String,
+    pub tracked_minutes: i64,
+    /// 행에 포함된 할일의 예상 시간 합계 (날짜별 보고서는 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub estimate_minutes: Option<i64>,
+    /// 예상 시간 대비 차이 (`tracked_minutes - estimate_minutes`, 양수면 초과)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub variance_minutes: Option<i64>,
+    pub todo_count: usize,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimeReportResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub timezone: String,
+    pub group_by: TimeReportGroup,
+    pub total_minutes: i64,
+    pub rows: Vec<TimeReportRowResponse>,
+}
+
+/// 초를 가장 가까운 분으로 반올림한다
+pub fn round_minutes(seconds: i64) -> i64 {
+    (seconds + 30) / 60
+}
+
+impl From<TimeEntry> for TimeEntryResponse {
+    fn from(entry: TimeEntry) -> Self {
+        Self {
+            running: entry.is_running(),
+            duration_seconds: entry.duration_seconds(Utc::now()),
+            id: entry.id,
+            todo_id: entry.todo_id,
+            started_at: entry.started_at,
+            ended_at: entry.ended_at,
+            source: entry.source,
+            note: entry.note,
+            created_at: entry.created_at,
+            updated_at: entry.updated_at,
+        }
+    }
+}
+
+impl From<ReportRow> for TimeReportRowResponse {
+    fn from(row: ReportRow) -> Self {
+        let tracked_minutes = round_minutes(row.tracked_seconds);
+        Self {
+            key: row.key,
+            label: row.label,
+            tracked_minutes,
+            estimate_minutes: row.estimate_minutes,
+            variance_minutes: row.estimate_minutes.map(|estimate| tracked_minutes - estimate),
+            todo_count: row.todo_count,
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::todo::{Priority, Todo, TodoStatus};
+use crate::entities::user_settings::WeekStart;
+use crate::dto::response::checklist_response::ChecklistProgress;
+use crate::utils::pagination::CursorInfo;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TodoResponse {
+    pub id: Uuid,
+    /// 할일이 속한 워크스페이스
+    pub workspace_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub status: TodoStatus,
+    pub priority: Priority,
+    pub due_date: Option<DateTime<Utc>>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// 체크리스트 진행률
+    pub checklist: ChecklistProgress,
+    pub checklist_auto_complete: bool,
+    pub enforce_dependencies: bool,
+    /// 예상 소요 시간 (분)
+    pub estimate_minutes: Option<i32>,
+    /// 반복 일정 시리즈 ID (반복하지 않으면 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub series_id: Option<Uuid>,
+    /// 시리즈 안에서 몇 번째 발생인지 (1부터)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recurrence_index: Option<i32>,
+    /// 수동 정렬 키 (`sort=position`으로 이 순서대로 조회)
+    pub position: Option<String>,
+    /// 보관한 시각 (보관되지 않았으면 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archived_at: Option<DateTime<Utc>>,
+    /// 휴지통으로 옮긴 시각 (휴지통 목록에만 포함)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 휴지통 목록 (최근 삭제 순)
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TrashListResponse {
+    pub todos: Vec<TodoResponse>,
+    pub pagination: PaginationInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TodoStatusResponse {
+    pub id: Uuid,
+    pub status: TodoStatus,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TodoListResponse {
+    pub todos: Vec<TodoResponse>,
+    /// 페이지 번호 기반 정보 (커서로 조회한 경우 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<PaginationInfo>,
+    pub cursor: CursorInfo,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TodoStatsResponse {
+    pub total_todos: i64,
+    pub completed_todos: i64,
+    pub pending_todos: i64,
+    pub in_progress_todos: i64,
+    /// 완료율 (%, 소수점 첫째 자리)
+    pub completion_rate: f64,
+    pub overdue_todos: i64,
+    pub today_completed: i64,
+    pub this_week_completed: i64,
+    /// 집계에 사용한 시간대
+    pub timezone: String,
+    /// 집계에 사용한 주 시작 요일
+    pub week_start: WeekStart,
+}
+
+/// 검색어가 일치한 필드
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchField {
+    Title,
+    Description,
+    Category,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SearchHighlight {
+    pub field: SearchField,
+    /// HTML 이스케이프된 스니펫. 일치한 부분은 `<mark>`로 감싼다.
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TodoSearchResult {
+    pub todo: TodoResponse,
+    /// 관련도 점수 (높을수록 관련도가 높음)
+    pub score: f64,
+    pub highlights: Vec<SearchHighlight>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TodoSearchResponse {
+    pub query: String,
+    pub results: Vec<TodoSearchResult>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PaginationInfo {
+    pub total: i64,
+    pub page: u32,
+    pub limit: u32,
+    pub total_pages: u32,
+    pub has_next: bool,
+    pub has_prev: bool,
+}
+
+impl PaginationInfo {
+    pub fn new(total: i64, page: u32, limit: u32) -> Self {
+        let total_pages = if total <= 0 {
+            0
+        } else {
+            ((total as u64).div_ceil(limit as u64)) as u32
+        };
+
+        Self {
+            total,
+            page,
+            limit,
+            total_pages,
+            has_next: page < total_pages,
+            has_prev: page > 1,
+        }
+    }
+}
+
+impl From<Todo> for TodoResponse {
+    fn from(todo: Todo) -> Self {
+        Self {
+            id: todo.id,
+            workspace_id: todo.workspace_id,
+            title: todo.title,
+            description: todo.description,
+            status: todo.status,
+            priority: todo.priority,
+            due_date: todo.due_date,
+            started_at: todo.started_at,
+            completed_at: todo.completed_at,
+            checklist: ChecklistProgress {
+                done: todo.checklist_done,
+                total: todo.checklist_total,
+            },
+            checklist_auto_complete: todo.checklist_auto_complete,
+            enforce_dependencies: todo.enforce_dependencies,
+            estimate_minutes: todo.estimate_minutes,
+            series_id: todo.series_id,
+            recurrence_index: todo.recurrence_index,
+            position: todo.position,
+            archived_at: todo.archived_at,
+            deleted_at: todo.deleted_at,
+            created_at: todo.created_at,
+            updated_at: todo.updated_at,
+        }
+    }
+}
+
+impl From<Todo> for TodoStatusResponse {
+    fn from(todo: Todo) -> Self {
+        Self {
+            id: todo.id,
+            status: todo.status,
+            started_at: todo.started_at,
+            completed_at: todo.completed_at,
+            updated_at: todo.updated_at,
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::entities::user_settings::{UserSettings, WeekStart};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UserSettingsResponse {
+    pub timezone: String,
+    pub week_start: WeekStart,
+    /// 완료 후 자동 보관까지의 일수 (`null`이면 사용 안 함)
+    pub auto_archive_days: Option<i32>,
+}
+
+impl From<UserSettings> for UserSettingsResponse {
+    fn from(settings: UserSettings) -> Self {
+        Self {
+            timezone: settings.timezone,
+            week_start: settings.week_start,
+            auto_archive_days: settings.auto_archive_days,
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use crate::entities::workspace::{InvitationStatus, WorkspaceInvitation, WorkspaceMember, WorkspaceRole, WorkspaceWithRole};
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceResponse {
+    pub id: Uuid,
+    pub name: String,
+    /// 개인 워크스페이스는 공유하거나 삭제할 수 없다
+    pub is_personal: bool,
+    /// 요청한 사용자의 역할
+    pub role: WorkspaceRole,
+    pub member_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl From<WorkspaceWithRole> for WorkspaceResponse {
+    fn from(workspace: WorkspaceWithRole) -> Self {
+        Self {
+            id: workspace.workspace.id,
+            name: workspace.workspace.name,
+            is_personal: workspace.workspace.is_personal,
+            role: workspace.role,
+            member_count: workspace.member_count,
+            created_at: workspace.workspace.created_at,
+            updated_at: workspace.workspace.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceListResponse {
+    /// 개인 워크스페이스가 먼저, 나머지는 이름순
+    pub workspaces: Vec<WorkspaceResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceMemberResponse {
+    pub user_id: Uuid,
+    /// 탈퇴한 사용자는 `null`
+    pub username: Option<String>,
+    pub email: Option<String>,
+    pub role: WorkspaceRole,
+    pub joined_at: DateTime<Utc>,
+}
+
+impl From<WorkspaceMember> for WorkspaceMemberResponse {
+    fn from(member: WorkspaceMember) -> Self {
+        Self {
+            user_id: member.user_id,
+            username: member.username,
+            email: member.email,
+            role: member.role,
+            joined_at: member.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct WorkspaceMemberListResponse {
+    pub workspace_id: Uuid,
+    /// 참여한 순서
+    pub members: Vec<WorkspaceMemberResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InvitationResponse {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub workspace_name: String,
+    pub email: String,
+    pub role: WorkspaceRole,
+    pub status: InvitationStatus,
+    pub expires_at: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl From<WorkspaceInvitation> for InvitationResponse {
+    fn from(invitation: WorkspaceInvitation) -> Self {
+        Self {
+            id: invitation.id,
+            workspace_id: invitation.workspace_id,
+            workspace_name: invitation.workspace_name,
+            email: invitation.email,
+            role: invitation.role,
+            status: invitation.status,
+            expires_at: invitation.expires_at,
+            created_at: invitation.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct InvitationListResponse {
+    /// 대기 중인 초대 (최근 순)
+    pub invitations: Vec<InvitationResponse>,
+}
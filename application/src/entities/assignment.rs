@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::workspace::WorkspaceRole;
+
+/// 할일 하나에 지정할 수 있는 최대 담당자 수
+pub const MAX_ASSIGNEES_PER_TODO: usize = 10;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TodoAssignee {
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    /// 탈퇴한 사용자는 `None`
+    pub username: Option<String>,
+    pub email: Option<String>,
+    /// 담당자로 지정한 사용자
+    pub assigned_by: Uuid,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 담당자 지정 결과
+#[derive(Debug, Clone, PartialEq)]
+pub enum AssignOutcome {
+    /// 새로 지정된 사용자 (이미 담당자였던 사용자는 빠진다)
+    Assigned(Vec<Uuid>),
+    /// 할일의 워크스페이스 멤버가 아닌 사용자가 있다
+    NotMembers(Vec<Uuid>),
+    /// 지정하면 담당자 수 제한을 넘는다
+    TooMany,
+}
+
+/// 워크스페이스 멤버 한 명에게 맡겨진 미완료 할일 (보관, 휴지통 제외)
+#[derive(Debug, Clone, FromRow)]
+pub struct MemberWorkload {
+    pub user_id: Uuid,
+    pub username: Option<String>,
+    pub role: WorkspaceRole,
+    /// 대기 + 진행 중
+    pub open_todos: i64,
+    pub in_progress_todos: i64,
+    pub overdue_todos: i64,
+}
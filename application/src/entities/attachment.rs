@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+/// 할일 하나에 붙일 수 있는 파일 수
+pub const MAX_ATTACHMENTS_PER_TODO: i64 = 20;
+
+/// 파일 이름 최대 길이 (문자 수)
+pub const MAX_FILE_NAME_CHARS: usize = 255;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Attachment {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub todo_id: Uuid,
+    pub file_name: String,
+    /// 업로드할 때 내용으로 판별한 형식
+    pub content_type: String,
+    pub size_bytes: i64,
+    /// 내용의 SHA-256 (16진수)
+    pub sha256: String,
+    /// 저장소 안의 위치. 외부에 노출하지 않는다.
+    pub storage_key: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewAttachment {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub todo_id: Uuid,
+    pub file_name: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+    pub sha256: String,
+    pub storage_key: String,
+}
+
+impl NewAttachment {
+    /// 저장소 키. 사용자별로 나누고 파일 이름은 쓰지 않는다.
+    pub fn storage_key(user_id: Uuid, id: Uuid) -> String {
+        format!("attachments/{}/{}", user_id, id)
+    }
+}
+
+/// 클라이언트가 보낸 파일 이름에서 경로와 제어 문자를 걷어낸다.
+/// 남는 것이 없으면 `None`.
+pub fn sanitize_file_name(raw: &str) -> Option<String> {
+    let base = raw.rsplit(['/', '\\']).next().unwrap_or_default();
+    let cleaned: String = base
+        .chars()
+        .filter(|c| !c.is_control() && !matches!(c, '"' | '<' | '>' | '|' | '*' | '?' | ':'))
+        .take(MAX_FILE_NAME_CHARS)
+        .collect();
+    let cleaned = cleaned.trim().trim_start_matches('.').trim();
+
+    (!cleaned.is_empty()).then(|| cleaned.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sanitize_file_name() {
+        assert_eq!(sanitize_file_name("영수증.png").as_deref(), Some("영수증.png"));
+        assert_eq!(sanitize_file_name("C:\\Users\\me\\shot 1.png").as_deref(), Some("shot 1.png"));
+        assert_eq!(sanitize_file_name("../../etc/passwd").as_deref(), Some("passwd"));
+        assert_eq!(sanitize_file_name("a\r\nb\".txt").as_deref(), Some("ab.txt"));
+        assert_eq!(sanitize_file_name(".."), None);
+        assert_eq!(sanitize_file_name("dir/"), None);
+        assert_eq!(sanitize_file_name(&"가".repeat(300)).map(|name| name.chars().count()), Some(255));
+    }
+}
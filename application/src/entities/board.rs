@@ -0,0 +1,282 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use thiserror::Error;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::todo::{StatusTransition, Todo, TodoStatus, TransitionError};
+use crate::utils::fractional_index::{key_between, spaced_keys};
+
+/// 보드 하나에 만들 수 있는 열 수
+pub const MAX_BOARD_COLUMNS: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Board {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 보드의 열. `status`가 있으면 그 상태의 할일이 모이는 열이고, 없으면 직접 옮겨 둔 할일만 담는 레인이다.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct BoardColumn {
+    pub id: Uuid,
+    pub board_id: Uuid,
+    /// 보드 안에서의 순서 (0부터 시작)
+    pub position: i32,
+    pub name: String,
+    pub status: Option<TodoStatus>,
+    /// 담을 수 있는 카드 수 (없으면 제한 없음)
+    pub wip_limit: Option<i32>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardWithColumns {
+    pub board: Board,
+    /// 순서대로
+    pub columns: Vec<BoardColumn>,
+}
+
+/// 열 생성/수정 내용
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoardColumnSpec {
+    pub name: String,
+    pub status: Option<TodoStatus>,
+    pub wip_limit: Option<i32>,
+}
+
+impl BoardColumnSpec {
+    /// 열을 지정하지 않고 만든 보드의 기본 열
+    pub fn defaults() -> Vec<BoardColumnSpec> {
+        [
+            ("할 일", TodoStatus::Pending),
+            ("진행 중", TodoStatus::InProgress),
+            ("완료", TodoStatus::Completed),
+        ]
+        .into_iter()
+        .map(|(name, status)| BoardColumnSpec {
+            name: name.to_string(),
+            status: Some(status),
+            wip_limit: None,
+        })
+        .collect()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewBoard {
+    pub user_id: Uuid,
+    pub name: String,
+    pub columns: Vec<BoardColumnSpec>,
+}
+
+/// 보드에 놓인 할일
+///
+/// 직접 옮긴 적이 있으면 그 열에, 아니면 상태가 같은 첫 번째 열에 놓인다. 상태 열에 옮겨 둔 할일의
+/// 상태가 다른 곳에서 바뀌면 다시 새 상태의 기본 열로 돌아간다.
+#[derive(Debug, Clone, FromRow)]
+pub struct BoardCard {
+    #[sqlx(flatten)]
+    pub todo: Todo,
+    pub column_id: Uuid,
+    /// 열 안에서의 정렬 키 (옮긴 적이 없으면 `None`, 이런 카드는 열의 맨 뒤에 놓인다)
+    pub card_position: Option<String>,
+}
+
+/// 카드를 `column_id` 열의 `after_id` 바로 뒤, `before_id` 바로 앞, 또는 맨 뒤로 옮긴다
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CardMove {
+    pub todo_id: Uuid,
+    pub column_id: Uuid,
+    pub after_id: Option<Uuid>,
+    pub before_id: Option<Uuid>,
+}
+
+/// 옮긴 결과
+#[derive(Debug, Clone)]
+pub struct MovedCard {
+    pub card: BoardCard,
+    /// 상태 열로 옮기면서 일어난 상태 전이 (이벤트 발행용)
+    pub transition: Option<StatusTransition>,
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum BoardMoveError {
+    #[error("'{column}' 열은 최대 {limit}개까지 담을 수 있습니다")]
+    WipLimitReached { column: String, limit: i32 },
+    #[error("기준 카드가 대상 열에 없습니다: {0}")]
+    AnchorNotInColumn(Uuid),
+    #[error("after_id의 카드가 before_id의 카드보다 앞에 있어야 합니다")]
+    Inverted,
+    #[error(transparent)]
+    Transition(#[from] TransitionError),
+}
+
+/// 다른 열에서 들어오는 카드는 WIP 제한을 확인하고, 상태 열로 옮기면 그 상태로 전이한다.
+///
+/// `target_count`는 옮길 카드를 뺀 대상 열의 카드 수, `open_blockers`는 완료되지 않은 선행 할일 수다.
+/// 반환값이 `Ok(None)`이면 상태는 그대로다.
+pub fn plan_move(
+    todo: &Todo,
+    from_column_id: Option<Uuid>,
+    target: &BoardColumn,
+    target_count: usize,
+    open_blockers: i64,
+    now: DateTime<Utc>,
+) -> Result<Option<StatusTransition>, BoardMoveError> {
+    if from_column_id != Some(target.id) {
+        if let Some(limit) = target.wip_limit {
+            if target_count >= limit as usize {
+                return Err(BoardMoveError::WipLimitReached { column: target.name.clone(), limit });
+            }
+        }
+    }
+
+    let status = match target.status {
+        Some(status) if status != todo.status => status,
+        _ => return Ok(None),
+    };
+    let transition = todo.transition_to(status, now)?;
+    if transition.to == TodoStatus::Completed && todo.enforce_dependencies && open_blockers > 0 {
+        return Err(TransitionError::Blocked { from: todo.status, open_blockers }.into());
+    }
+
+    Ok(Some(transition))
+}
+
+/// 열 안의 카드 순서(`cards`, 옮길 카드 제외)에 `id`를 끼워 넣을 정렬 키를 정한다.
+///
+/// 보통은 옮긴 카드의 키 하나만 돌려준다. 이웃 카드에 키가 없거나 키 사이에 자리가 없으면
+/// 열 전체의 키를 새로 매긴다.
+pub fn place_card(
+    cards: &[(Uuid, Option<String>)],
+    id: Uuid,
+    after_id: Option<Uuid>,
+    before_id: Option<Uuid>,
+) -> Result<Vec<(Uuid, String)>, BoardMoveError> {
+    let index_of = |anchor: Uuid| {
+        cards
+            .iter()
+            .position(|(card_id, _)| *card_id == anchor)
+            .ok_or(BoardMoveError::AnchorNotInColumn(anchor))
+    };
+
+    let index = match (after_id, before_id) {
+        (Some(after_id), before_id) => {
+            let after = index_of(after_id)?;
+            if let Some(before_id) = before_id {
+                if index_of(before_id)? <= after {
+                    return Err(BoardMoveError::Inverted);
+                }
+            }
+            after + 1
+        }
+        (None, Some(before_id)) => index_of(before_id)?,
+        (None, None) => cards.len(),
+    };
+
+    let lower = index.checked_sub(1).map(|i| cards[i].1.as_deref());
+    let upper = cards.get(index).map(|(_, key)| key.as_deref());
+    let key = match (lower, upper) {
+        (Some(None), _) | (_, Some(None)) => None,
+        (lower, upper) => key_between(lower.flatten(), upper.flatten()).ok(),
+    };
+    if let Some(key) = key {
+        return Ok(vec![(id, key)]);
+    }
+
+    let mut ids: Vec<Uuid> = cards.iter().map(|(card_id, _)| *card_id).collect();
+    ids.insert(index, id);
+    Ok(ids.into_iter().zip(spaced_keys(cards.len() + 1)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn todo(status: TodoStatus) -> Todo {
+        Todo { status, enforce_dependencies: true, ..Todo::fixture(Uuid::new_v4(), Uuid::new_v4()) }
+    }
+
+    fn column(status: Option<TodoStatus>, wip_limit: Option<i32>) -> BoardColumn {
+        let now = Utc::now();
+        BoardColumn {
+            id: Uuid::new_v4(),
+            board_id: Uuid::new_v4(),
+            position: 0,
+            name: "진행 중".to_string(),
+            status,
+            wip_limit,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_plan_move_enforces_wip_limit_only_when_entering() {
+        let todo = todo(TodoStatus::Pending);
+        let target = column(Some(TodoStatus::InProgress), Some(2));
+
+        assert_eq!(
+            plan_move(&todo, None, &target, 2, 0, Utc::now()),
+            Err(BoardMoveError::WipLimitReached { column: "진행 중".to_string(), limit: 2 })
+        );
+        assert!(plan_move(&todo, None, &target, 1, 0, Utc::now()).is_ok());
+        // 같은 열 안에서 순서만 바꾸는 것은 제한과 관계없다
+        let in_progress = Todo { status: TodoStatus::InProgress, ..todo };
+        assert_eq!(plan_move(&in_progress, Some(target.id), &target, 5, 0, Utc::now()), Ok(None));
+    }
+
+    #[test]
+    fn test_plan_move_follows_transition_rules() {
+        let now = Utc::now();
+        let started = plan_move(&todo(TodoStatus::Pending), None, &column(Some(TodoStatus::InProgress), None), 0, 0, now)
+            .unwrap()
+            .unwrap();
+        assert_eq!((started.to, started.started_at), (TodoStatus::InProgress, Some(now)));
+
+        assert_eq!(
+            plan_move(&todo(TodoStatus::Completed), None, &column(Some(TodoStatus::InProgress), None), 0, 0, now),
+            Err(BoardMoveError::Transition(TransitionError::NotAllowed {
+                from: TodoStatus::Completed,
+                to: TodoStatus::InProgress,
+            }))
+        );
+        assert_eq!(
+            plan_move(&todo(TodoStatus::InProgress), None, &column(Some(TodoStatus::Completed), None), 0, 2, now),
+            Err(BoardMoveError::Transition(TransitionError::Blocked { from: TodoStatus::InProgress, open_blockers: 2 }))
+        );
+        // 레인으로 옮기면 상태는 그대로다
+        assert_eq!(plan_move(&todo(TodoStatus::Completed), None, &column(None, None), 0, 0, now), Ok(None));
+    }
+
+    #[test]
+    fn test_place_card_between_neighbours() {
+        let (a, b, id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let cards = vec![(a, Some("V".to_string())), (b, Some("k".to_string()))];
+
+        let keys = place_card(&cards, id, Some(a), None).unwrap();
+        assert_eq!(keys.len(), 1);
+        assert!("V" < keys[0].1.as_str() && keys[0].1.as_str() < "k");
+
+        let keys = place_card(&cards, id, None, None).unwrap();
+        assert!(keys[0].1.as_str() > "k");
+
+        assert_eq!(place_card(&cards, id, Some(b), Some(a)), Err(BoardMoveError::Inverted));
+        assert_eq!(place_card(&cards, id, Some(id), None), Err(BoardMoveError::AnchorNotInColumn(id)));
+    }
+
+    #[test]
+    fn test_place_card_rekeys_column_with_unplaced_neighbour() {
+        let (a, b, id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let cards = vec![(a, Some("V".to_string())), (b, None)];
+
+        let keys = place_card(&cards, id, None, Some(b)).unwrap();
+        let ids: Vec<Uuid> = keys.iter().map(|(card_id, _)| *card_id).collect();
+        assert_eq!(ids, vec![a, id, b]);
+        assert!(keys.windows(2).all(|pair| pair[0].1 < pair[1].1));
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+use crate::entities::todo::{Priority, StatusTransition, Todo, TodoStatus, TransitionError};
+
+/// 한 번에 처리할 수 있는 할일 수
+pub const MAX_BULK_ITEMS: usize = 100;
+
+/// 여러 할일에 같은 변경을 적용하는 일괄 작업
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkOperation {
+    SetStatus(TodoStatus),
+    SetPriority(Priority),
+    /// 소유권을 확인한 카테고리 ID
+    AssignCategories(Vec<Uuid>),
+    /// 휴지통으로 이동
+    Delete,
+    /// 휴지통에서 복원
+    Restore,
+}
+
+/// 일괄 작업 실행 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BulkMode {
+    /// 하나라도 실패하면 아무것도 반영하지 않는다
+    pub atomic: bool,
+    /// 결과만 계산하고 반영하지 않는다
+    pub dry_run: bool,
+}
+
+/// 할일 하나에 대한 처리 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkItemOutcome {
+    /// 반영됨 (dry-run에서는 반영될 예정)
+    Succeeded,
+    /// 이미 요청한 상태여서 바뀐 것이 없음
+    Unchanged,
+    Failed,
+    /// 원자적 모드에서 다른 항목이 실패해 반영하지 않음
+    Skipped,
+}
+
+#[derive(Debug, Clone)]
+pub struct BulkItemResult {
+    pub id: Uuid,
+    pub outcome: BulkItemOutcome,
+    pub error: Option<String>,
+    /// 반영 후의 할일 (dry-run이거나 반영되지 않았으면 `None`)
+    pub todo: Option<Todo>,
+}
+
+/// 일괄 작업 실행 결과
+#[derive(Debug, Clone)]
+pub struct BulkExecution {
+    /// 요청한 ID 순서대로
+    pub results: Vec<BulkItemResult>,
+    /// 변경 사항을 커밋했는지 여부
+    pub committed: bool,
+    /// 커밋된 상태 전이 (이벤트 발행용)
+    pub transitions: Vec<StatusTransition>,
+}
+
+/// 할일 하나에 적용할 변경
+#[derive(Debug, Clone, PartialEq)]
+pub enum BulkChange {
+    Transition(StatusTransition),
+    Priority(Priority),
+    AssignCategories,
+    Delete,
+    Restore,
+}
+
+/// 요청 순서대로 각 할일에 적용할 변경을 정한다.
+///
+/// 상태 변경은 상태 전이 규칙을 따르며, `enforce_dependencies`인 할일은 열린 선행 할일이 있으면
+/// 완료할 수 없다. 같은 요청에서 앞서 완료한 선행 할일은 완료된 것으로 본다.
+pub struct BulkPlanner<'a> {
+    operation: &'a BulkOperation,
+    now: DateTime<Utc>,
+    completed: HashSet<Uuid>,
+}
+
+impl<'a> BulkPlanner<'a> {
+    pub fn new(operation: &'a BulkOperation, now: DateTime<Utc>) -> Self {
+        Self {
+            operation,
+            now,
+            completed: HashSet::new(),
+        }
+    }
+
+    /// `todo`가 `None`이면 없는 할일이다. `open_blockers`는 요청 전 기준 열린 선행 할일 ID.
+    /// 반환값이 `Ok(None)`이면 바뀔 것이 없다.
+    pub fn plan(&mut self, todo: Option<&Todo>, open_blockers: &[Uuid]) -> Result<Option<BulkChange>, String> {
+        let todo = match (todo, self.operation) {
+            (Some(todo), BulkOperation::Restore) if todo.deleted_at.is_none() => {
+                return Err("휴지통에 없는 할일입니다".to_string());
+            }
+            (Some(todo), BulkOperation::Restore) => todo,
+            (Some(todo), _) if todo.deleted_at.is_none() => todo,
+            _ => return Err("할일을 찾을 수 없습니다".to_string()),
+        };
+
+        let change = match self.operation {
+            BulkOperation::SetStatus(status) => {
+                let transition = match todo.transition_to(*status, self.now) {
+                    Ok(transition) => transition,
+                    Err(TransitionError::Unchanged(_)) => return Ok(None),
+                    Err(err) => return Err(err.to_string()),
+                };
+                if transition.to == TodoStatus::Completed {
+                    if todo.enforce_dependencies {
+                        let open = open_blockers.iter().filter(|id| !self.completed.contains(id)).count() as i64;
+                        if open > 0 {
+                            return Err(TransitionError::Blocked { from: todo.status, open_blockers: open }.to_string());
+                        }
+                    }
+                    self.completed.insert(todo.id);
+                }
+                BulkChange::Transition(transition)
+            }
+            BulkOperation::SetPriority(priority) if *priority == todo.priority => return Ok(None),
+            BulkOperation::SetPriority(priority) => BulkChange::Priority(*priority),
+            BulkOperation::AssignCategories(_) => BulkChange::AssignCategories,
+            BulkOperation::Delete => BulkChange::Delete,
+            BulkOperation::Restore => BulkChange::Restore,
+        };
+
+        Ok(Some(change))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn todo(status: TodoStatus, enforce_dependencies: bool) -> Todo {
+        Todo {
+            status,
+            completed_at: (status == TodoStatus::Completed).then(Utc::now),
+            enforce_dependencies,
+            ..Todo::fixture(Uuid::new_v4(), Uuid::new_v4())
+        }
+    }
+
+    #[test]
+    fn test_blocker_completed_earlier_in_batch_unblocks() {
+        let operation = BulkOperation::SetStatus(TodoStatus::Completed);
+        let mut planner = BulkPlanner::new(&operation, Utc::now());
+        let blocker = todo(TodoStatus::Pending, false);
+        let dependent = todo(TodoStatus::Pending, true);
+        let other = todo(TodoStatus::Pending, true);
+
+        assert!(matches!(planner.plan(Some(&blocker), &[]), Ok(Some(BulkChange::Transition(_)))));
+        assert!(planner.plan(Some(&dependent), &[blocker.id]).is_ok());
+        assert!(planner.plan(Some(&other), &[Uuid::new_v4()]).is_err());
+    }
+
+    #[test]
+    fn test_unchanged_and_missing_items() {
+        let operation = BulkOperation::SetStatus(TodoStatus::Completed);
+        let mut planner = BulkPlanner::new(&operation, Utc::now());
+
+        assert_eq!(planner.plan(Some(&todo(TodoStatus::Completed, false)), &[]), Ok(None));
+        assert!(planner.plan(None, &[]).is_err());
+
+        let mut trashed = todo(TodoStatus::Pending, false);
+        trashed.deleted_at = Some(Utc::now());
+        assert!(planner.plan(Some(&trashed), &[]).is_err());
+
+        let restore = BulkOperation::Restore;
+        let mut planner = BulkPlanner::new(&restore, Utc::now());
+        assert_eq!(planner.plan(Some(&trashed), &[]), Ok(Some(BulkChange::Restore)));
+        assert!(planner.plan(Some(&todo(TodoStatus::Pending, false)), &[]).is_err());
+    }
+}
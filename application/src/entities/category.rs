@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+/// 카테고리 색상 기본값
+pub const DEFAULT_CATEGORY_COLOR: &str = "#2196F3";
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Category {
+    pub id: Uuid,
+    /// 카테고리를 만든 사용자
+    pub user_id: Uuid,
+    pub workspace_id: Uuid,
+    pub name: String,
+    pub color: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 카테고리와 지정된 할일 수
+#[derive(Debug, Clone, FromRow)]
+pub struct CategoryWithCount {
+    #[sqlx(flatten)]
+    pub category: Category,
+    pub todo_count: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewCategory {
+    pub user_id: Uuid,
+    pub workspace_id: Uuid,
+    pub name: String,
+    pub color: String,
+}
+
+/// 카테고리 수정 내용 (PUT: 모든 필드를 교체한다)
+#[derive(Debug, Clone)]
+pub struct CategoryChanges {
+    pub name: String,
+    pub color: String,
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+/// 할일 하나에 추가할 수 있는 체크리스트 항목 수
+pub const MAX_CHECKLIST_ITEMS: i32 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct ChecklistItem {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub title: String,
+    pub is_done: bool,
+    /// 할일 안에서의 순서 (0부터 시작)
+    pub position: i32,
+    pub done_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 체크리스트 항목 수정 내용 (PATCH: 지정한 필드만 변경한다)
+#[derive(Debug, Clone, Default)]
+pub struct ChecklistItemChanges {
+    pub title: Option<String>,
+    pub is_done: Option<bool>,
+}
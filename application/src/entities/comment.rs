@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+
+/// 댓글 본문 최대 길이 (문자 수)
+pub const MAX_COMMENT_CHARS: u64 = 10_000;
+
+/// 답글 최대 깊이 (최상위 댓글이 0)
+pub const MAX_COMMENT_DEPTH: i32 = 4;
+
+/// 댓글 하나에서 알림을 보내는 최대 언급 수
+pub const MAX_MENTIONS_PER_COMMENT: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Comment {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    /// 탈퇴한 사용자는 `None`
+    pub author_username: Option<String>,
+    pub parent_id: Option<Uuid>,
+    pub depth: i32,
+    /// 마크다운 원문
+    pub body: String,
+    /// 원문에서 렌더링한 정제된 HTML
+    pub body_html: String,
+    pub edited_at: Option<DateTime<Utc>>,
+    /// 삭제된 댓글은 답글 자리를 지키기 위해 행을 남긴다
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Comment {
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewComment {
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub depth: i32,
+    pub body: String,
+    pub body_html: String,
+}
+
+/// 수정 전 본문
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CommentRevision {
+    pub id: Uuid,
+    pub comment_id: Uuid,
+    pub body: String,
+    /// 이 본문을 쓴 시각 (작성 또는 이전 수정)
+    pub written_at: DateTime<Utc>,
+}
+
+/// 댓글에서 처음 언급된 사용자. `id`는 알림 중복 제거 키로 쓴다.
+#[derive(Debug, Clone, PartialEq, Eq, FromRow)]
+pub struct CommentMention {
+    pub id: Uuid,
+    pub comment_id: Uuid,
+    pub user_id: Uuid,
+}
+
+/// 댓글을 저장한 결과
+#[derive(Debug, Clone)]
+pub struct SavedComment {
+    pub comment: Comment,
+    /// 이번 저장으로 새로 언급된 사용자 (수정 전부터 언급된 사용자는 제외)
+    pub new_mentions: Vec<CommentMention>,
+}
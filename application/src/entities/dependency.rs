@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::todo::{Priority, TodoStatus};
+
+/// 할일 하나에 연결할 수 있는 선행 할일 수
+pub const MAX_BLOCKERS_PER_TODO: usize = 50;
+
+/// 의존 관계 반대편 할일의 요약
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DependencyTodo {
+    pub id: Uuid,
+    pub title: String,
+    pub status: TodoStatus,
+    pub priority: Priority,
+    pub due_date: Option<DateTime<Utc>>,
+    /// 의존 관계를 연결한 시각
+    pub linked_at: DateTime<Utc>,
+}
+
+/// 의존 관계 추가 결과
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkOutcome {
+    Linked,
+    /// 이미 연결되어 있음
+    AlreadyLinked,
+    /// 연결하면 순환이 생김
+    WouldCycle,
+}
@@ -2,7 +2,24 @@
 //!
 //! Contains database models and entity definitions.
 
+pub mod assignment;
+pub mod attachment;
+pub mod board;
+pub mod bulk;
+pub mod category;
+pub mod checklist;
+pub mod comment;
+pub mod dependency;
+pub mod reminder;
+pub mod share_link;
+pub mod smart_list;
+pub mod template;
+pub mod time_entry;
+pub mod todo;
+pub mod todo_series;
 pub mod user;
+pub mod user_settings;
+pub mod workspace;
 
 // Future database entities will be added here
 // For example: product.rs, etc.
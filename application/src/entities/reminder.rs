@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// 할일 하나에 설정할 수 있는 알림 수
+pub const MAX_REMINDERS_PER_TODO: usize = 10;
+
+/// 상대 알림은 마감 30일 전까지 설정할 수 있다
+pub const MAX_REMINDER_OFFSET_MINUTES: i32 = 43_200;
+
+/// 알림 발송 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum ReminderStatus {
+    #[default]
+    Pending,
+    Sent,
+    /// 재시도 횟수를 모두 소진함
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Reminder {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    /// 절대 시각 알림
+    pub remind_at: Option<DateTime<Utc>>,
+    /// 마감 N분 전 알림
+    pub offset_minutes: Option<i32>,
+    pub snoozed_until: Option<DateTime<Utc>>,
+    pub status: ReminderStatus,
+    pub sent_at: Option<DateTime<Utc>>,
+    pub attempts: i32,
+    pub last_error: Option<String>,
+    /// 실제 발송 예정 시각 (상대 알림인데 마감일이 없으면 `None`)
+    pub fire_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewReminder {
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    pub remind_at: Option<DateTime<Utc>>,
+    pub offset_minutes: Option<i32>,
+}
+
+/// 발송 워커가 임대(lease)한 알림
+#[derive(Debug, Clone, PartialEq, FromRow)]
+pub struct DueReminder {
+    pub id: Uuid,
+    pub todo_id: Uuid,
+    pub user_id: Uuid,
+    pub title: String,
+    pub due_date: Option<DateTime<Utc>>,
+    pub fire_at: DateTime<Utc>,
+    /// 이번 시도를 포함한 시도 횟수
+    pub attempts: i32,
+    /// 이번 임대의 만료 시각. 발송 결과를 기록할 때 아직 이 임대를 쥐고 있는지 확인하는 데 쓴다.
+    pub locked_until: DateTime<Utc>,
+}
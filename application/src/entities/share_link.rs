@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+use crate::entities::todo::{Priority, TodoStatus};
+
+/// 워크스페이스마다 만들 수 있는 유효한(회수되거나 만료되지 않은) 공유 링크 수
+pub const MAX_ACTIVE_SHARE_LINKS_PER_WORKSPACE: i64 = 100;
+
+/// 목록 공유 링크가 한 번에 보여주는 최대 할일 수
+pub const MAX_SHARED_TODOS: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ShareKind {
+    /// 할일 하나
+    Todo,
+    /// 조건으로 거른 워크스페이스의 할일 목록
+    List,
+}
+
+/// 목록 공유 조건. 비어 있는 조건은 적용하지 않는다. 보관되거나 휴지통에 있는 할일은 항상 빠진다.
+#[derive(Debug, Clone, Default, PartialEq, FromRow)]
+pub struct ShareFilter {
+    pub statuses: Vec<TodoStatus>,
+    pub priorities: Vec<Priority>,
+    pub category_ids: Vec<Uuid>,
+    pub search: Option<String>,
+    /// `GET /todos`의 `sort` 형식
+    pub sort: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct ShareLink {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub created_by: Uuid,
+    /// `None`이면 목록 공유
+    pub todo_id: Option<Uuid>,
+    pub title: Option<String>,
+    #[sqlx(flatten)]
+    pub filter: ShareFilter,
+    pub password_hash: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub view_count: i64,
+    pub last_viewed_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ShareLink {
+    pub fn kind(&self) -> ShareKind {
+        if self.todo_id.is_some() {
+            ShareKind::Todo
+        } else {
+            ShareKind::List
+        }
+    }
+
+    /// 회수되지 않았고 만료되지 않았으면 `true`
+    pub fn is_active(&self, now: DateTime<Utc>) -> bool {
+        self.revoked_at.is_none() && self.expires_at.is_none_or(|expires_at| expires_at > now)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewShareLink {
+    pub workspace_id: Uuid,
+    pub created_by: Uuid,
+    pub todo_id: Option<Uuid>,
+    pub title: Option<String>,
+    pub filter: ShareFilter,
+    pub token_hash: String,
+    pub password_hash: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
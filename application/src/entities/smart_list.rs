@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+use crate::entities::todo::{Priority, TodoStatus};
+
+/// 사용자마다 저장할 수 있는 스마트 목록 수
+pub const MAX_SMART_LISTS_PER_USER: i64 = 50;
+
+/// 마감 구간. 조회 시각과 사용자 시간대 기준으로 해석한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum DueWindow {
+    /// 마감이 지났고 완료되지 않음
+    Overdue,
+    Today,
+    Tomorrow,
+    /// 주의 시작 요일 기준 이번 주
+    ThisWeek,
+    /// 내일부터 7일
+    #[serde(rename = "next_7_days")]
+    #[sqlx(rename = "next_7_days")]
+    Next7Days,
+    NoDueDate,
+}
+
+/// 스마트 목록의 조건. 비어 있는 조건은 적용하지 않는다.
+#[derive(Debug, Clone, Default, PartialEq, FromRow)]
+pub struct SmartListFilter {
+    pub statuses: Vec<TodoStatus>,
+    pub priorities: Vec<Priority>,
+    pub due_window: Option<DueWindow>,
+    pub category_ids: Vec<Uuid>,
+    pub search: Option<String>,
+    /// 기본 정렬 (`GET /todos`의 `sort` 형식)
+    pub sort: Option<String>,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct SmartList {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    #[sqlx(flatten)]
+    pub filter: SmartListFilter,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewSmartList {
+    pub user_id: Uuid,
+    pub name: String,
+    pub filter: SmartListFilter,
+}
+
+/// 스마트 목록 수정 내용 (PUT: 모든 필드를 교체한다)
+#[derive(Debug, Clone)]
+pub struct SmartListChanges {
+    pub name: String,
+    pub filter: SmartListFilter,
+}
+
+/// 모든 사용자에게 제공하는 기본 스마트 목록 (저장하지 않으며 수정할 수 없다)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuiltinSmartList {
+    Today,
+    Upcoming,
+    Overdue,
+}
+
+impl BuiltinSmartList {
+    pub const ALL: [BuiltinSmartList; 3] = [BuiltinSmartList::Today, BuiltinSmartList::Upcoming, BuiltinSmartList::Overdue];
+
+    /// 경로에 쓰는 ID
+    pub fn id(&self) -> &'static str {
+        match self {
+            BuiltinSmartList::Today => "today",
+            BuiltinSmartList::Upcoming => "upcoming",
+            BuiltinSmartList::Overdue => "overdue",
+        }
+    }
+
+    pub fn from_id(id: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|builtin| builtin.id() == id)
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            BuiltinSmartList::Today => "오늘",
+            BuiltinSmartList::Upcoming => "다가오는 할일",
+            BuiltinSmartList::Overdue => "기한 지남",
+        }
+    }
+
+    pub fn filter(&self) -> SmartListFilter {
+        let open = vec![TodoStatus::Pending, TodoStatus::InProgress];
+        match self {
+            BuiltinSmartList::Today => SmartListFilter {
+                statuses: open,
+                due_window: Some(DueWindow::Today),
+                sort: Some("due_date".to_string()),
+                ..SmartListFilter::default()
+            },
+            BuiltinSmartList::Upcoming => SmartListFilter {
+                statuses: open,
+                due_window: Some(DueWindow::Next7Days),
+                sort: Some("due_date".to_string()),
+                ..SmartListFilter::default()
+            },
+            BuiltinSmartList::Overdue => SmartListFilter {
+                due_window: Some(DueWindow::Overdue),
+                sort: Some("due_date".to_string()),
+                ..SmartListFilter::default()
+            },
+        }
+    }
+}
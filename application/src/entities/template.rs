@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
+use crate::entities::todo::Priority;
+use crate::utils::timezone::local_datetime;
+
+/// 템플릿 하나에 담을 수 있는 할일 수
+pub const MAX_TEMPLATE_ITEMS: usize = 50;
+
+/// 시각을 정하지 않은 항목은 그날 이 시각(현지)이 마감이다
+const ALL_DAY_DUE_TIME: (u32, u32) = (23, 59);
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TodoTemplate {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 템플릿으로 만들 할일 하나의 설계
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TemplateItem {
+    pub id: Uuid,
+    pub template_id: Uuid,
+    /// 템플릿 안에서의 순서 (0부터 시작)
+    pub position: i32,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    #[sqlx(flatten)]
+    pub due: RelativeDue,
+    pub category_ids: Vec<Uuid>,
+    /// 체크리스트 항목 제목
+    pub checklist: Vec<String>,
+}
+
+/// 시작일 기준 상대 마감. `due_offset_days`가 없으면 마감 없이 만든다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, FromRow)]
+pub struct RelativeDue {
+    pub due_offset_days: Option<i32>,
+    /// 현지 시각 (기본값: 23:59)
+    pub due_time: Option<NaiveTime>,
+}
+
+impl RelativeDue {
+    /// 시작일과 시간대를 기준으로 실제 마감 시각을 계산한다
+    pub fn resolve(&self, start_date: NaiveDate, tz: Tz) -> Option<DateTime<Utc>> {
+        let days = self.due_offset_days?;
+        let date = start_date.checked_add_signed(chrono::Duration::days(days as i64))?;
+        let at = self.due_time.unwrap_or_else(|| {
+            NaiveTime::from_hms_opt(ALL_DAY_DUE_TIME.0, ALL_DAY_DUE_TIME.1, 0).expect("valid all-day due time")
+        });
+        Some(local_datetime(tz, date.and_time(at)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TemplateWithItems {
+    pub template: TodoTemplate,
+    pub items: Vec<TemplateItem>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewTemplateItem {
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub due: RelativeDue,
+    pub category_ids: Vec<Uuid>,
+    pub checklist: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewTemplate {
+    pub user_id: Uuid,
+    pub name: String,
+    pub description: Option<String>,
+    pub items: Vec<NewTemplateItem>,
+}
+
+/// 템플릿 수정 내용 (PUT: 이름, 설명, 항목 전체를 교체한다)
+#[derive(Debug, Clone)]
+pub struct TemplateChanges {
+    pub name: String,
+    pub description: Option<String>,
+    pub items: Vec<NewTemplateItem>,
+}
+
+/// 템플릿 항목에서 만들 실제 할일
+#[derive(Debug, Clone)]
+pub struct TemplateTodo {
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub due_date: Option<DateTime<Utc>>,
+    pub category_ids: Vec<Uuid>,
+    pub checklist: Vec<String>,
+}
+
+impl TemplateTodo {
+    pub fn from_item(item: &TemplateItem, start_date: NaiveDate, tz: Tz) -> Self {
+        Self {
+            title: item.title.clone(),
+            description: item.description.clone(),
+            priority: item.priority,
+            due_date: item.due.resolve(start_date, tz),
+            category_ids: item.category_ids.clone(),
+            checklist: item.checklist.clone(),
+        }
+    }
+}
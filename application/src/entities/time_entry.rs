@@ -0,0 +1,84 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// 직접 입력하는 기록 하나의 최대 길이 (시간)
+pub const MAX_MANUAL_ENTRY_HOURS: i64 = 24;
+
+/// 보고서 집계 기준
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeReportGroup {
+    /// 사용자 시간대의 날짜별
+    #[default]
+    Day,
+    /// 카테고리별 (여러 카테고리의 할일은 각 카테고리에 모두 더한다)
+    Category,
+    Todo,
+}
+
+/// 시간 기록을 만든 방법
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum TimeEntrySource {
+    Timer,
+    Manual,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TimeEntry {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    pub todo_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    /// 실행 중인 타이머는 `None`
+    pub ended_at: Option<DateTime<Utc>>,
+    pub source: TimeEntrySource,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TimeEntry {
+    pub fn is_running(&self) -> bool {
+        self.ended_at.is_none()
+    }
+
+    /// 기록된 시간 (초). 실행 중인 타이머는 `now`까지 센다.
+    pub fn duration_seconds(&self, now: DateTime<Utc>) -> i64 {
+        (self.ended_at.unwrap_or(now) - self.started_at).num_seconds().max(0)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewTimeEntry {
+    pub user_id: Uuid,
+    pub todo_id: Uuid,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub source: TimeEntrySource,
+    pub note: Option<String>,
+}
+
+/// 완료된 기록 수정 내용 (PUT: 모든 필드를 교체한다)
+#[derive(Debug, Clone)]
+pub struct TimeEntryChanges {
+    pub started_at: DateTime<Utc>,
+    pub ended_at: DateTime<Utc>,
+    pub note: Option<String>,
+}
+
+/// 보고서 집계용 기록. 할일 정보와 카테고리를 함께 읽는다.
+#[derive(Debug, Clone, FromRow)]
+pub struct TrackedTime {
+    pub todo_id: Uuid,
+    pub title: String,
+    pub estimate_minutes: Option<i32>,
+    pub started_at: DateTime<Utc>,
+    pub ended_at: Option<DateTime<Utc>>,
+    pub category_ids: Vec<Uuid>,
+    pub category_names: Vec<String>,
+}
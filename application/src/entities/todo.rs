@@ -0,0 +1,346 @@
+use std::fmt;
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use sqlx::postgres::{PgHasArrayType, PgTypeInfo};
+use thiserror::Error;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// 할일 진행 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum TodoStatus {
+    #[default]
+    Pending,
+    InProgress,
+    Completed,
+}
+
+impl TodoStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TodoStatus::Pending => "pending",
+            TodoStatus::InProgress => "in_progress",
+            TodoStatus::Completed => "completed",
+        }
+    }
+
+    /// 현재 상태에서 전이 가능한 상태 목록
+    pub fn allowed_transitions(&self) -> &'static [TodoStatus] {
+        match self {
+            TodoStatus::Pending => &[TodoStatus::InProgress, TodoStatus::Completed],
+            TodoStatus::InProgress => &[TodoStatus::Completed, TodoStatus::Pending],
+            TodoStatus::Completed => &[TodoStatus::Pending],
+        }
+    }
+
+    pub fn can_transition_to(&self, next: TodoStatus) -> bool {
+        self.allowed_transitions().contains(&next)
+    }
+}
+
+impl fmt::Display for TodoStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for TodoStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pending" => Ok(TodoStatus::Pending),
+            "in_progress" => Ok(TodoStatus::InProgress),
+            "completed" => Ok(TodoStatus::Completed),
+            other => Err(format!("알 수 없는 상태입니다: {}", other)),
+        }
+    }
+}
+
+/// 할일 우선순위
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum Priority {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+impl Priority {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+        }
+    }
+}
+
+impl FromStr for Priority {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "low" => Ok(Priority::Low),
+            "medium" => Ok(Priority::Medium),
+            "high" => Ok(Priority::High),
+            other => Err(format!("알 수 없는 우선순위입니다: {}", other)),
+        }
+    }
+}
+
+// 스마트 목록은 상태와 우선순위 조건을 `VARCHAR[]` 컬럼에 저장한다
+impl PgHasArrayType for TodoStatus {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_varchar")
+    }
+}
+
+impl PgHasArrayType for Priority {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_varchar")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Todo {
+    pub id: Uuid,
+    /// 할일을 만든 사용자
+    pub user_id: Uuid,
+    /// 할일이 속한 워크스페이스
+    pub workspace_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub status: TodoStatus,
+    pub priority: Priority,
+    pub due_date: Option<DateTime<Utc>>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// 체크리스트 항목 수
+    pub checklist_total: i32,
+    /// 완료된 체크리스트 항목 수
+    pub checklist_done: i32,
+    /// 체크리스트를 모두 완료하면 할일을 자동으로 완료 처리할지 여부
+    pub checklist_auto_complete: bool,
+    /// 선행 할일이 모두 완료되어야 완료할 수 있는지 여부
+    pub enforce_dependencies: bool,
+    /// 예상 소요 시간 (분)
+    pub estimate_minutes: Option<i32>,
+    /// 반복 일정 시리즈 ID
+    pub series_id: Option<Uuid>,
+    /// 시리즈 안에서 몇 번째 발생인지 (1부터)
+    pub recurrence_index: Option<i32>,
+    /// 수동 정렬 키 (fractional index). 재배치 전의 기존 할일은 `None`.
+    pub position: Option<String>,
+    /// 보관한 시각 (상태와 별개)
+    pub archived_at: Option<DateTime<Utc>>,
+    /// 휴지통으로 옮긴 시각
+    pub deleted_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[cfg(test)]
+impl Todo {
+    /// 테스트용 할일: 대기 상태, 보통 우선순위이고 선택 필드는 모두 비어 있다.
+    /// 필요한 필드만 `Todo { status, ..Todo::fixture(user_id, id) }`처럼 바꿔 쓴다.
+    pub fn fixture(user_id: Uuid, id: Uuid) -> Self {
+        let now = Utc::now();
+        Self {
+            id,
+            user_id,
+            workspace_id: Uuid::new_v4(),
+            title: "회의 준비".to_string(),
+            description: None,
+            status: TodoStatus::Pending,
+            priority: Priority::Medium,
+            due_date: None,
+            started_at: None,
+            completed_at: None,
+            checklist_total: 0,
+            checklist_done: 0,
+            checklist_auto_complete: false,
+            enforce_dependencies: false,
+            estimate_minutes: None,
+            series_id: None,
+            recurrence_index: None,
+            position: None,
+            archived_at: None,
+            deleted_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+impl Todo {
+    /// 체크리스트 항목이 있고 모두 완료되었는지 여부
+    pub fn checklist_finished(&self) -> bool {
+        self.checklist_total > 0 && self.checklist_done >= self.checklist_total
+    }
+
+    /// 상태 전이 규칙을 검증하고 전이 후의 타임스탬프를 계산한다.
+    ///
+    /// - `in_progress` 진입 시 `started_at` 기록
+    /// - `completed` 진입 시 `completed_at` 기록 (`started_at` 유지)
+    /// - `pending` 복귀 시 두 타임스탬프 모두 초기화
+    pub fn transition_to(&self, next: TodoStatus, at: DateTime<Utc>) -> Result<StatusTransition, TransitionError> {
+        if self.status == next {
+            return Err(TransitionError::Unchanged(next));
+        }
+
+        if !self.status.can_transition_to(next) {
+            return Err(TransitionError::NotAllowed { from: self.status, to: next });
+        }
+
+        let (started_at, completed_at) = match next {
+            TodoStatus::Pending => (None, None),
+            TodoStatus::InProgress => (Some(at), None),
+            TodoStatus::Completed => (self.started_at, Some(at)),
+        };
+
+        Ok(StatusTransition {
+            todo_id: self.id,
+            from: self.status,
+            to: next,
+            started_at,
+            completed_at,
+            occurred_at: at,
+        })
+    }
+}
+
+/// 검증을 통과한 상태 전이
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusTransition {
+    pub todo_id: Uuid,
+    pub from: TodoStatus,
+    pub to: TodoStatus,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub occurred_at: DateTime<Utc>,
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum TransitionError {
+    #[error("이미 '{0}' 상태입니다")]
+    Unchanged(TodoStatus),
+    #[error("'{from}' 상태에서 '{to}' 상태로 변경할 수 없습니다")]
+    NotAllowed { from: TodoStatus, to: TodoStatus },
+    #[error("완료되지 않은 선행 할일이 {open_blockers}개 있어 완료할 수 없습니다")]
+    Blocked { from: TodoStatus, open_blockers: i64 },
+}
+
+/// 할일 통계 집계 결과
+#[derive(Debug, Clone, Default, PartialEq, FromRow)]
+pub struct TodoStats {
+    pub pending_todos: i64,
+    pub in_progress_todos: i64,
+    pub completed_todos: i64,
+    pub overdue_todos: i64,
+    pub today_completed: i64,
+    pub this_week_completed: i64,
+}
+
+/// 검색 결과 한 건: 할일, 연결된 카테고리명, 관련도 점수
+#[derive(Debug, Clone, FromRow)]
+pub struct TodoSearchHit {
+    #[sqlx(flatten)]
+    pub todo: Todo,
+    pub category_names: Vec<String>,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewTodo {
+    pub user_id: Uuid,
+    pub workspace_id: Uuid,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub due_date: Option<DateTime<Utc>>,
+    pub checklist_auto_complete: bool,
+    pub enforce_dependencies: bool,
+    pub estimate_minutes: Option<i32>,
+}
+
+/// 할일 수정 시 변경되는 필드 (PUT은 전체 교체)
+#[derive(Debug, Clone)]
+pub struct TodoChanges {
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub due_date: Option<DateTime<Utc>>,
+    pub checklist_auto_complete: bool,
+    pub enforce_dependencies: bool,
+    pub estimate_minutes: Option<i32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn todo_with_status(status: TodoStatus) -> Todo {
+        Todo { status, ..Todo::fixture(Uuid::new_v4(), Uuid::new_v4()) }
+    }
+
+    #[test]
+    fn test_documented_transitions_are_allowed() {
+        assert!(TodoStatus::Pending.can_transition_to(TodoStatus::InProgress));
+        assert!(TodoStatus::Pending.can_transition_to(TodoStatus::Completed));
+        assert!(TodoStatus::InProgress.can_transition_to(TodoStatus::Completed));
+        assert!(TodoStatus::InProgress.can_transition_to(TodoStatus::Pending));
+        assert!(TodoStatus::Completed.can_transition_to(TodoStatus::Pending));
+
+        assert!(!TodoStatus::Completed.can_transition_to(TodoStatus::InProgress));
+    }
+
+    #[test]
+    fn test_transition_records_timestamps() {
+        let at = Utc::now();
+        let todo = todo_with_status(TodoStatus::Pending);
+
+        let started = todo.transition_to(TodoStatus::InProgress, at).unwrap();
+        assert_eq!(started.started_at, Some(at));
+        assert_eq!(started.completed_at, None);
+
+        let mut in_progress = todo_with_status(TodoStatus::InProgress);
+        in_progress.started_at = Some(at);
+        let completed_at = at + chrono::Duration::hours(1);
+        let completed = in_progress.transition_to(TodoStatus::Completed, completed_at).unwrap();
+        assert_eq!(completed.started_at, Some(at));
+        assert_eq!(completed.completed_at, Some(completed_at));
+    }
+
+    #[test]
+    fn test_reopen_clears_timestamps() {
+        let at = Utc::now();
+        let mut todo = todo_with_status(TodoStatus::Completed);
+        todo.started_at = Some(at);
+        todo.completed_at = Some(at);
+
+        let reopened = todo.transition_to(TodoStatus::Pending, at).unwrap();
+        assert_eq!(reopened.started_at, None);
+        assert_eq!(reopened.completed_at, None);
+    }
+
+    #[test]
+    fn test_invalid_transitions() {
+        let todo = todo_with_status(TodoStatus::Completed);
+
+        assert_eq!(
+            todo.transition_to(TodoStatus::InProgress, Utc::now()),
+            Err(TransitionError::NotAllowed { from: TodoStatus::Completed, to: TodoStatus::InProgress })
+        );
+        assert_eq!(
+            todo.transition_to(TodoStatus::Completed, Utc::now()),
+            Err(TransitionError::Unchanged(TodoStatus::Completed))
+        );
+    }
+}
@@ -0,0 +1,97 @@
+use std::str::FromStr;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use crate::entities::todo::Priority;
+use crate::utils::recurrence::RecurrenceRule;
+
+/// 반복 할일 시리즈. 다음 발생은 이 템플릿과 `dtstart`, `rrule`로 만든다.
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TodoSeries {
+    pub id: Uuid,
+    pub user_id: Uuid,
+    /// 정규화된 RRULE 문자열
+    pub rrule: String,
+    /// 발생 시각을 계산하는 IANA 시간대
+    pub timezone: String,
+    /// 첫 발생의 마감 시각
+    pub dtstart: DateTime<Utc>,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub checklist_auto_complete: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TodoSeries {
+    /// `index`번째(1부터) 발생의 마감 시각. 시리즈가 그 전에 끝나면 `None`.
+    pub fn due_date_of(&self, index: i32) -> Result<Option<DateTime<Utc>>, String> {
+        let rule = RecurrenceRule::from_str(&self.rrule)?;
+        let timezone = Tz::from_str(&self.timezone).map_err(|_| format!("알 수 없는 시간대입니다: {}", self.timezone))?;
+        Ok(u32::try_from(index)
+            .ok()
+            .and_then(|index| rule.nth_occurrence(self.dtstart, timezone, index)))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewTodoSeries {
+    pub user_id: Uuid,
+    pub rrule: String,
+    pub timezone: String,
+    pub dtstart: DateTime<Utc>,
+    pub title: String,
+    pub description: Option<String>,
+    pub priority: Priority,
+    pub checklist_auto_complete: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn series(rrule: &str) -> TodoSeries {
+        let now = Utc::now();
+        TodoSeries {
+            id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            rrule: rrule.to_string(),
+            timezone: "Asia/Seoul".to_string(),
+            dtstart: utc("2025-07-14T00:00:00Z"),
+            title: "분리수거".to_string(),
+            description: None,
+            priority: Priority::Medium,
+            checklist_auto_complete: false,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[test]
+    fn test_due_date_of_next_occurrence() {
+        let series = series("FREQ=WEEKLY;BYDAY=MO,TH");
+        assert_eq!(series.due_date_of(1).unwrap(), Some(utc("2025-07-14T00:00:00Z")));
+        assert_eq!(series.due_date_of(2).unwrap(), Some(utc("2025-07-17T00:00:00Z")));
+    }
+
+    #[test]
+    fn test_finished_series_has_no_next_occurrence() {
+        let series = series("FREQ=WEEKLY;COUNT=2");
+        assert_eq!(series.due_date_of(2).unwrap(), Some(utc("2025-07-21T00:00:00Z")));
+        assert_eq!(series.due_date_of(3).unwrap(), None);
+    }
+
+    #[test]
+    fn test_unreadable_rule_is_an_error() {
+        let mut broken = series("FREQ=DAILY");
+        broken.rrule = "FREQ=SOMETIMES".to_string();
+        assert!(broken.due_date_of(2).is_err());
+    }
+}
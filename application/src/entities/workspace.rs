@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use utoipa::ToSchema;
+
+/// 워크스페이스 하나에 둘 수 있는 최대 멤버 수
+pub const MAX_WORKSPACE_MEMBERS: i64 = 50;
+
+/// 워크스페이스 멤버 역할
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum WorkspaceRole {
+    /// 멤버와 초대를 관리하고 워크스페이스를 삭제할 수 있다
+    Owner,
+    /// 할일과 카테고리를 만들고 수정할 수 있다
+    Editor,
+    /// 조회와 댓글만 할 수 있다
+    Viewer,
+}
+
+impl WorkspaceRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WorkspaceRole::Owner => "owner",
+            WorkspaceRole::Editor => "editor",
+            WorkspaceRole::Viewer => "viewer",
+        }
+    }
+
+    /// 할일과 카테고리를 만들고 수정할 수 있는지 여부
+    pub fn can_edit(&self) -> bool {
+        matches!(self, WorkspaceRole::Owner | WorkspaceRole::Editor)
+    }
+
+    pub fn is_owner(&self) -> bool {
+        *self == WorkspaceRole::Owner
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct Workspace {
+    pub id: Uuid,
+    pub name: String,
+    /// 사용자마다 하나씩 있는 개인 워크스페이스는 공유하거나 삭제할 수 없다
+    pub is_personal: bool,
+    pub created_by: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// 워크스페이스와 조회한 사용자의 역할
+#[derive(Debug, Clone, FromRow)]
+pub struct WorkspaceWithRole {
+    #[sqlx(flatten)]
+    pub workspace: Workspace,
+    pub role: WorkspaceRole,
+    pub member_count: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewWorkspace {
+    pub name: String,
+    pub created_by: Uuid,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct WorkspaceMember {
+    pub workspace_id: Uuid,
+    pub user_id: Uuid,
+    /// 탈퇴한 사용자는 `None`
+    pub username: Option<String>,
+    pub email: Option<String>,
+    pub role: WorkspaceRole,
+    pub created_at: DateTime<Utc>,
+}
+
+/// 초대 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[sqlx(type_name = "varchar", rename_all = "snake_case")]
+pub enum InvitationStatus {
+    Pending,
+    Accepted,
+    Declined,
+    /// 소유자가 취소했거나 같은 이메일로 다시 초대해 대체됨
+    Revoked,
+}
+
+#[derive(Debug, Clone, FromRow)]
+pub struct WorkspaceInvitation {
+    pub id: Uuid,
+    pub workspace_id: Uuid,
+    pub workspace_name: String,
+    pub email: String,
+    pub role: WorkspaceRole,
+    pub invited_by: Uuid,
+    pub status: InvitationStatus,
+    pub expires_at: DateTime<Utc>,
+    pub responded_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl WorkspaceInvitation {
+    /// 아직 응답하지 않았고 만료되지 않았는지 여부
+    pub fn is_open(&self, now: DateTime<Utc>) -> bool {
+        self.status == InvitationStatus::Pending && self.expires_at > now
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NewInvitation {
+    pub workspace_id: Uuid,
+    /// 소문자로 정규화한 이메일
+    pub email: String,
+    pub role: WorkspaceRole,
+    /// 메일로 보낸 토큰의 SHA-256 해시 (hex)
+    pub token_hash: String,
+    pub invited_by: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
@@ -6,6 +6,10 @@ use axum::{http::StatusCode, response::IntoResponse, Json};
 use serde_json::json;
 use thiserror::Error;
 use utoipa::ToSchema;
+use crate::entities::board::BoardMoveError;
+use crate::entities::todo::{TodoStatus, TransitionError};
+use crate::storage::{SpoolError, StorageError};
+use crate::utils::signed_url::SignatureError;
 
 #[derive(Error, Debug)]
 pub enum ApiError {
@@ -21,14 +25,26 @@ pub enum ApiError {
     BadRequest(String),
     #[error("Unauthorized: {0}")]
     Unauthorized(String),
+    #[error("Forbidden: {0}")]
+    Forbidden(String),
     #[error("Conflict: {0}")]
     Conflict(String),
+    #[error("Payload too large: {0}")]
+    PayloadTooLarge(String),
+    #[error("Unsupported media type: {0}")]
+    UnsupportedMediaType(String),
     #[error("Password hashing error: {0}")]
     PasswordHash(String),
+    #[error("Invalid status transition: {0}")]
+    InvalidStatusTransition(TransitionError),
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> axum::response::Response {
+        if let ApiError::InvalidStatusTransition(err) = &self {
+            return invalid_transition_response(err);
+        }
+
         let (status, message) = match self {
             ApiError::Database(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             ApiError::Validation(msg) => (StatusCode::UNPROCESSABLE_ENTITY, msg),
@@ -36,14 +52,53 @@ impl IntoResponse for ApiError {
             ApiError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
             ApiError::Unauthorized(msg) => (StatusCode::UNAUTHORIZED, msg),
+            ApiError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
             ApiError::Conflict(msg) => (StatusCode::CONFLICT, msg),
+            ApiError::PayloadTooLarge(msg) => (StatusCode::PAYLOAD_TOO_LARGE, msg),
+            ApiError::UnsupportedMediaType(msg) => (StatusCode::UNSUPPORTED_MEDIA_TYPE, msg),
             ApiError::PasswordHash(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
+            ApiError::InvalidStatusTransition(err) => (StatusCode::CONFLICT, err.to_string()),
         };
 
         (status, Json(json!({"error": message}))).into_response()
     }
 }
 
+/// 허용되지 않은 상태 전이와 선행 할일 때문에 막힌 완료는 409, 동일 상태로의 전이 요청은 422로 응답한다.
+fn invalid_transition_response(err: &TransitionError) -> axum::response::Response {
+    let (status, code, from, to) = match err {
+        TransitionError::NotAllowed { from, to } => {
+            (StatusCode::CONFLICT, "invalid_status_transition", *from, *to)
+        }
+        TransitionError::Unchanged(current) => {
+            (StatusCode::UNPROCESSABLE_ENTITY, "status_unchanged", *current, *current)
+        }
+        TransitionError::Blocked { from, .. } => {
+            (StatusCode::CONFLICT, "blocked_by_dependencies", *from, TodoStatus::Completed)
+        }
+    };
+    let allowed: Vec<TodoStatus> = from.allowed_transitions().to_vec();
+
+    let mut details = json!({
+        "from": from,
+        "to": to,
+        "allowed_transitions": allowed,
+    });
+    if let TransitionError::Blocked { open_blockers, .. } = err {
+        details["open_blockers"] = json!(open_blockers);
+    }
+
+    (
+        status,
+        Json(json!({
+            "error": err.to_string(),
+            "code": code,
+            "details": details,
+        })),
+    )
+        .into_response()
+}
+
 impl From<sqlx::Error> for ApiError {
     fn from(err: sqlx::Error) -> Self {
         ApiError::Database(err.to_string())
@@ -61,3 +116,42 @@ impl From<bcrypt::BcryptError> for ApiError {
         ApiError::PasswordHash(err.to_string())
     }
 }
+
+impl From<TransitionError> for ApiError {
+    fn from(err: TransitionError) -> Self {
+        ApiError::InvalidStatusTransition(err)
+    }
+}
+
+impl From<BoardMoveError> for ApiError {
+    fn from(err: BoardMoveError) -> Self {
+        match err {
+            BoardMoveError::WipLimitReached { .. } => ApiError::Conflict(err.to_string()),
+            BoardMoveError::AnchorNotInColumn(_) | BoardMoveError::Inverted => ApiError::Validation(err.to_string()),
+            BoardMoveError::Transition(err) => ApiError::InvalidStatusTransition(err),
+        }
+    }
+}
+
+impl From<StorageError> for ApiError {
+    fn from(err: StorageError) -> Self {
+        ApiError::Internal(err.to_string())
+    }
+}
+
+impl From<SpoolError> for ApiError {
+    fn from(err: SpoolError) -> Self {
+        match err {
+            SpoolError::TooLarge { .. } => ApiError::PayloadTooLarge(err.to_string()),
+            SpoolError::Empty => ApiError::Validation(err.to_string()),
+            SpoolError::Read(_) => ApiError::BadRequest(err.to_string()),
+            SpoolError::Io(_) => ApiError::Internal(err.to_string()),
+        }
+    }
+}
+
+impl From<SignatureError> for ApiError {
+    fn from(err: SignatureError) -> Self {
+        ApiError::Forbidden(err.to_string())
+    }
+}
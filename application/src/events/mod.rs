@@ -0,0 +1,102 @@
+//! Domain events module
+//!
+//! Publishes domain events so other parts of the system can react to them
+//! without coupling to the service that produced them.
+
+use chrono::{DateTime, Utc};
+use tokio::sync::broadcast;
+use uuid::Uuid;
+use crate::entities::todo::{StatusTransition, TodoStatus};
+
+const DEFAULT_CAPACITY: usize = 1024;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TodoEvent {
+    /// 할일 상태가 변경됨
+    StatusChanged {
+        todo_id: Uuid,
+        user_id: Uuid,
+        from: TodoStatus,
+        to: TodoStatus,
+        occurred_at: DateTime<Utc>,
+    },
+}
+
+impl TodoEvent {
+    pub fn status_changed(user_id: Uuid, transition: &StatusTransition) -> Self {
+        TodoEvent::StatusChanged {
+            todo_id: transition.todo_id,
+            user_id,
+            from: transition.from,
+            to: transition.to,
+            occurred_at: transition.occurred_at,
+        }
+    }
+}
+
+/// In-process event bus backed by a tokio broadcast channel.
+///
+/// Publishing never blocks and never fails the caller: events are dropped
+/// when nobody is subscribed, and slow subscribers observe `Lagged`.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<TodoEvent>,
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    /// Publish an event to all current subscribers
+    pub fn publish(&self, event: TodoEvent) {
+        tracing::debug!(?event, "publishing todo event");
+        let _ = self.sender.send(event);
+    }
+
+    /// Subscribe to events published after this call
+    pub fn subscribe(&self) -> broadcast::Receiver<TodoEvent> {
+        self.sender.subscribe()
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_subscriber_receives_published_event() {
+        let bus = EventBus::default();
+        let mut receiver = bus.subscribe();
+
+        let event = TodoEvent::StatusChanged {
+            todo_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            from: TodoStatus::Pending,
+            to: TodoStatus::Completed,
+            occurred_at: Utc::now(),
+        };
+        bus.publish(event.clone());
+
+        assert_eq!(receiver.recv().await.unwrap(), event);
+    }
+
+    #[test]
+    fn test_publish_without_subscribers_does_not_fail() {
+        let bus = EventBus::default();
+        bus.publish(TodoEvent::StatusChanged {
+            todo_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            from: TodoStatus::Pending,
+            to: TodoStatus::InProgress,
+            occurred_at: Utc::now(),
+        });
+    }
+}
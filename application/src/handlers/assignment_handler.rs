@@ -0,0 +1,135 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::assignment_service::AssignmentService;
+use crate::dto::request::assignment_request::AssignTodoRequest;
+use crate::dto::response::assignment_response::{AssigneeListResponse, WorkloadResponse};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct AssignmentHandler {
+    assignment_service: Arc<AssignmentService>,
+}
+
+impl AssignmentHandler {
+    pub fn new(assignment_service: Arc<AssignmentService>) -> Self {
+        Self { assignment_service }
+    }
+
+    /// 할일 담당자 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/assignees",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "담당자 조회 성공", body = AssigneeListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Assignments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_assignees(
+        State(handler): State<Arc<AssignmentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<Json<AssigneeListResponse>, ApiError> {
+        let response = handler.assignment_service.list(auth_user.id, todo_id).await?;
+        Ok(Json(response))
+    }
+
+    /// 담당자 지정
+    ///
+    /// 할일이 있는 워크스페이스의 멤버만 지정할 수 있다. 새로 지정된 멤버에게는 알림을 보낸다.
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/assignees",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = AssignTodoRequest,
+        responses(
+            (status = 200, description = "담당자 지정 성공", body = AssigneeListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "조회 권한만 있음"),
+            (status = 404, description = "할일을 찾을 수 없음"),
+            (status = 422, description = "워크스페이스 멤버가 아니거나 담당자 수 초과")
+        ),
+        tag = "Assignments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn assign(
+        State(handler): State<Arc<AssignmentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        Json(request): Json<AssignTodoRequest>,
+    ) -> Result<Json<AssigneeListResponse>, ApiError> {
+        let response = handler.assignment_service.assign(auth_user.id, todo_id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 담당자 해제
+    #[utoipa::path(
+        delete,
+        path = "/todos/{id}/assignees/{user_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("user_id" = Uuid, Path, description = "담당자 사용자 ID")
+        ),
+        responses(
+            (status = 204, description = "담당자 해제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "조회 권한만 있음"),
+            (status = 404, description = "할일 또는 담당자를 찾을 수 없음")
+        ),
+        tag = "Assignments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn unassign(
+        State(handler): State<Arc<AssignmentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, user_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.assignment_service.unassign(auth_user.id, todo_id, user_id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 멤버별 업무량 조회
+    #[utoipa::path(
+        get,
+        path = "/workspaces/{id}/workload",
+        params(
+            ("id" = Uuid, Path, description = "워크스페이스 ID")
+        ),
+        responses(
+            (status = 200, description = "멤버별 미완료 담당 할일 수", body = WorkloadResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "워크스페이스를 찾을 수 없음")
+        ),
+        tag = "Assignments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn workload(
+        State(handler): State<Arc<AssignmentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(workspace_id): Path<Uuid>,
+    ) -> Result<Json<WorkloadResponse>, ApiError> {
+        let response = handler.assignment_service.workload(auth_user.id, workspace_id).await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,244 @@
+use std::sync::Arc;
+use axum::{
+    body::Body,
+    extract::{Multipart, Path, Query, State},
+    http::{header, HeaderValue, StatusCode},
+    response::{IntoResponse, Json, Response},
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::attachment_service::AttachmentService;
+use crate::dto::request::attachment_request::AttachmentDownloadQuery;
+use crate::dto::response::attachment_response::{AttachmentListResponse, AttachmentResponse, AttachmentUrlResponse};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+use crate::utils::content_sniff;
+
+/// 업로드 파트 이름
+const FILE_FIELD: &str = "file";
+
+pub struct AttachmentHandler {
+    attachment_service: Arc<AttachmentService>,
+}
+
+impl AttachmentHandler {
+    pub fn new(attachment_service: Arc<AttachmentService>) -> Self {
+        Self { attachment_service }
+    }
+
+    /// 요청 본문 제한. 파일 크기 제한은 업로드 중에 따로 확인하므로 multipart 경계와 헤더 몫만 더한다.
+    pub fn body_limit(&self) -> usize {
+        self.attachment_service.max_file_bytes() as usize + 64 * 1024
+    }
+
+    /// 파일 첨부
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/attachments",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body(content = AttachmentUploadRequest, content_type = "multipart/form-data"),
+        responses(
+            (status = 201, description = "첨부 성공", body = AttachmentResponse),
+            (status = 400, description = "multipart 형식 오류 또는 file 파트 없음"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음"),
+            (status = 409, description = "첨부 개수 제한 초과"),
+            (status = 413, description = "파일 크기 제한 초과"),
+            (status = 415, description = "허용하지 않는 파일 형식"),
+            (status = 422, description = "빈 파일 또는 파일 이름 없음")
+        ),
+        tag = "Attachments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn upload(
+        State(handler): State<Arc<AttachmentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        mut multipart: Multipart,
+    ) -> Result<(StatusCode, Json<AttachmentResponse>), ApiError> {
+        while let Some(field) = multipart.next_field().await.map_err(Self::multipart_error)? {
+            if field.name() != Some(FILE_FIELD) {
+                continue;
+            }
+
+            let file_name = field.file_name().map(str::to_string);
+            let declared_type = field.content_type().map(str::to_string);
+            let response = handler
+                .attachment_service
+                .upload(auth_user.id, todo_id, file_name.as_deref(), declared_type.as_deref(), field)
+                .await?;
+            return Ok((StatusCode::CREATED, Json(response)));
+        }
+
+        Err(ApiError::BadRequest(format!("'{}' 파트가 필요합니다", FILE_FIELD)))
+    }
+
+    /// 할일의 첨부 파일 목록
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/attachments",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "첨부 파일 목록 조회 성공", body = AttachmentListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Attachments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list(
+        State(handler): State<Arc<AttachmentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<Json<AttachmentListResponse>, ApiError> {
+        let response = handler.attachment_service.list(auth_user.id, todo_id).await?;
+        Ok(Json(response))
+    }
+
+    /// 다운로드 URL 발급 (인증 없이 쓸 수 있고 정해진 시간 뒤 만료된다)
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/attachments/{attachment_id}/url",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("attachment_id" = Uuid, Path, description = "첨부 파일 ID")
+        ),
+        responses(
+            (status = 200, description = "URL 발급 성공", body = AttachmentUrlResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "첨부 파일을 찾을 수 없음")
+        ),
+        tag = "Attachments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn download_url(
+        State(handler): State<Arc<AttachmentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, attachment_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<Json<AttachmentUrlResponse>, ApiError> {
+        let response = handler
+            .attachment_service
+            .download_url(auth_user.id, todo_id, attachment_id)
+            .await?;
+        Ok(Json(response))
+    }
+
+    /// 첨부 파일 삭제
+    #[utoipa::path(
+        delete,
+        path = "/todos/{id}/attachments/{attachment_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("attachment_id" = Uuid, Path, description = "첨부 파일 ID")
+        ),
+        responses(
+            (status = 204, description = "첨부 파일 삭제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "첨부 파일을 찾을 수 없음")
+        ),
+        tag = "Attachments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete(
+        State(handler): State<Arc<AttachmentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, attachment_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.attachment_service.delete(auth_user.id, todo_id, attachment_id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 서명된 URL로 내용 내려받기 (인증 헤더 없음)
+    #[utoipa::path(
+        get,
+        path = "/attachments/{id}/content",
+        params(
+            ("id" = Uuid, Path, description = "첨부 파일 ID"),
+            AttachmentDownloadQuery
+        ),
+        responses(
+            (status = 200, description = "파일 내용", content_type = "application/octet-stream"),
+            (status = 403, description = "서명이 올바르지 않거나 만료됨"),
+            (status = 404, description = "첨부 파일을 찾을 수 없음")
+        ),
+        tag = "Attachments"
+    )]
+    pub async fn content(
+        State(handler): State<Arc<AttachmentHandler>>,
+        Path(attachment_id): Path<Uuid>,
+        Query(query): Query<AttachmentDownloadQuery>,
+    ) -> Result<Response, ApiError> {
+        let (attachment, body) = handler
+            .attachment_service
+            .open_signed(attachment_id, query.expires, &query.signature)
+            .await?;
+
+        let disposition = if content_sniff::is_inline_safe(&attachment.content_type) {
+            "inline"
+        } else {
+            "attachment"
+        };
+        let mut response = Body::from_stream(body.stream).into_response();
+        let headers = response.headers_mut();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_str(&attachment.content_type)
+                .unwrap_or_else(|_| HeaderValue::from_static("application/octet-stream")),
+        );
+        if let Some(size) = body.size {
+            headers.insert(header::CONTENT_LENGTH, HeaderValue::from(size));
+        }
+        if let Ok(value) = HeaderValue::from_str(&content_disposition(disposition, &attachment.file_name)) {
+            headers.insert(header::CONTENT_DISPOSITION, value);
+        }
+        // 저장된 형식 외로 해석하거나 스크립트를 실행하지 못하게 한다
+        headers.insert(header::X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+        headers.insert(
+            header::CONTENT_SECURITY_POLICY,
+            HeaderValue::from_static("default-src 'none'; sandbox"),
+        );
+        headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("private, no-store"));
+
+        Ok(response)
+    }
+
+    fn multipart_error(err: axum::extract::multipart::MultipartError) -> ApiError {
+        if err.status() == StatusCode::PAYLOAD_TOO_LARGE {
+            ApiError::PayloadTooLarge(err.body_text())
+        } else {
+            ApiError::BadRequest(err.body_text())
+        }
+    }
+}
+
+/// `filename`에는 ASCII로 바꾼 이름을, `filename*`에는 UTF-8 원래 이름을 넣는다 (RFC 6266)
+fn content_disposition(disposition: &str, file_name: &str) -> String {
+    let fallback: String = file_name
+        .chars()
+        .map(|c| if c.is_ascii_graphic() && c != '"' && c != '\\' || c == ' ' { c } else { '_' })
+        .collect();
+    let encoded: String = file_name
+        .bytes()
+        .map(|b| {
+            if b.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&b) {
+                (b as char).to_string()
+            } else {
+                format!("%{:02X}", b)
+            }
+        })
+        .collect();
+
+    format!("{}; filename=\"{}\"; filename*=UTF-8''{}", disposition, fallback, encoded)
+}
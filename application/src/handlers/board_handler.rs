@@ -0,0 +1,328 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::board_service::BoardService;
+use crate::dto::request::board_request::{
+    BoardColumnRequest, CreateBoardRequest, MoveCardRequest, ReorderColumnsRequest, UpdateBoardRequest,
+};
+use crate::dto::response::board_response::{
+    BoardCardsResponse, BoardColumnResponse, BoardListResponse, BoardResponse, MoveCardResponse,
+};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct BoardHandler {
+    board_service: Arc<BoardService>,
+}
+
+impl BoardHandler {
+    pub fn new(board_service: Arc<BoardService>) -> Self {
+        Self { board_service }
+    }
+
+    /// 보드 목록 조회
+    #[utoipa::path(
+        get,
+        path = "/boards",
+        responses(
+            (status = 200, description = "보드 목록 조회 성공", body = BoardListResponse),
+            (status = 401, description = "인증 필요")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_boards(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+    ) -> Result<Json<BoardListResponse>, ApiError> {
+        let response = handler.board_service.list_boards(auth_user.id).await?;
+        Ok(Json(response))
+    }
+
+    /// 보드 생성 (열을 지정하지 않으면 상태별 기본 열)
+    #[utoipa::path(
+        post,
+        path = "/boards",
+        request_body = CreateBoardRequest,
+        responses(
+            (status = 201, description = "보드 생성 성공", body = BoardResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 409, description = "이미 존재하는 보드 이름"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn create_board(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<CreateBoardRequest>,
+    ) -> Result<(StatusCode, Json<BoardResponse>), ApiError> {
+        let response = handler.board_service.create_board(auth_user.id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 보드 상세 조회
+    #[utoipa::path(
+        get,
+        path = "/boards/{id}",
+        params(
+            ("id" = Uuid, Path, description = "보드 ID")
+        ),
+        responses(
+            (status = 200, description = "보드 조회 성공", body = BoardResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "보드를 찾을 수 없음")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn get_board(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<BoardResponse>, ApiError> {
+        let response = handler.board_service.get_board(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+
+    /// 보드 이름 변경
+    #[utoipa::path(
+        put,
+        path = "/boards/{id}",
+        params(
+            ("id" = Uuid, Path, description = "보드 ID")
+        ),
+        request_body = UpdateBoardRequest,
+        responses(
+            (status = 200, description = "보드 수정 성공", body = BoardResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "보드를 찾을 수 없음"),
+            (status = 409, description = "이미 존재하는 보드 이름"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_board(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<UpdateBoardRequest>,
+    ) -> Result<Json<BoardResponse>, ApiError> {
+        let response = handler.board_service.update_board(auth_user.id, id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 보드 삭제
+    #[utoipa::path(
+        delete,
+        path = "/boards/{id}",
+        params(
+            ("id" = Uuid, Path, description = "보드 ID")
+        ),
+        responses(
+            (status = 204, description = "보드 삭제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "보드를 찾을 수 없음")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_board(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.board_service.delete_board(auth_user.id, id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 열 추가
+    #[utoipa::path(
+        post,
+        path = "/boards/{id}/columns",
+        params(
+            ("id" = Uuid, Path, description = "보드 ID")
+        ),
+        request_body = BoardColumnRequest,
+        responses(
+            (status = 201, description = "열 추가 성공", body = BoardColumnResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "보드를 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn add_column(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<BoardColumnRequest>,
+    ) -> Result<(StatusCode, Json<BoardColumnResponse>), ApiError> {
+        let response = handler.board_service.add_column(auth_user.id, id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 열 순서 변경
+    #[utoipa::path(
+        put,
+        path = "/boards/{id}/columns/order",
+        params(
+            ("id" = Uuid, Path, description = "보드 ID")
+        ),
+        request_body = ReorderColumnsRequest,
+        responses(
+            (status = 200, description = "열 순서 변경 성공", body = BoardResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "보드를 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn reorder_columns(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<ReorderColumnsRequest>,
+    ) -> Result<Json<BoardResponse>, ApiError> {
+        let response = handler.board_service.reorder_columns(auth_user.id, id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 열 수정
+    #[utoipa::path(
+        put,
+        path = "/boards/{id}/columns/{column_id}",
+        params(
+            ("id" = Uuid, Path, description = "보드 ID"),
+            ("column_id" = Uuid, Path, description = "열 ID")
+        ),
+        request_body = BoardColumnRequest,
+        responses(
+            (status = 200, description = "열 수정 성공", body = BoardColumnResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "열을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_column(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((id, column_id)): Path<(Uuid, Uuid)>,
+        Json(request): Json<BoardColumnRequest>,
+    ) -> Result<Json<BoardColumnResponse>, ApiError> {
+        let response = handler.board_service.update_column(auth_user.id, id, column_id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 열 삭제 (옮겨 둔 카드는 상태의 기본 열로 돌아간다)
+    #[utoipa::path(
+        delete,
+        path = "/boards/{id}/columns/{column_id}",
+        params(
+            ("id" = Uuid, Path, description = "보드 ID"),
+            ("column_id" = Uuid, Path, description = "열 ID")
+        ),
+        responses(
+            (status = 204, description = "열 삭제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "열을 찾을 수 없음"),
+            (status = 422, description = "마지막 열은 삭제할 수 없음")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_column(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((id, column_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.board_service.delete_column(auth_user.id, id, column_id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 열별 카드 조회
+    #[utoipa::path(
+        get,
+        path = "/boards/{id}/cards",
+        params(
+            ("id" = Uuid, Path, description = "보드 ID")
+        ),
+        responses(
+            (status = 200, description = "카드 조회 성공", body = BoardCardsResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "보드를 찾을 수 없음")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn get_cards(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<BoardCardsResponse>, ApiError> {
+        let response = handler.board_service.get_cards(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+
+    /// 카드 이동 (상태 열로 옮기면 할일 상태도 바뀐다)
+    #[utoipa::path(
+        post,
+        path = "/boards/{id}/cards/{todo_id}/move",
+        params(
+            ("id" = Uuid, Path, description = "보드 ID"),
+            ("todo_id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = MoveCardRequest,
+        responses(
+            (status = 200, description = "카드 이동 성공", body = MoveCardResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "보드, 열 또는 할일을 찾을 수 없음"),
+            (status = 409, description = "WIP 제한 초과 또는 허용되지 않은 상태 전이"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Boards",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn move_card(
+        State(handler): State<Arc<BoardHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((id, todo_id)): Path<(Uuid, Uuid)>,
+        Json(request): Json<MoveCardRequest>,
+    ) -> Result<Json<MoveCardResponse>, ApiError> {
+        let response = handler.board_service.move_card(auth_user.id, id, todo_id, request).await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use axum::{
+    extract::State,
+    response::Json,
+    Extension,
+};
+use crate::services::bulk_service::BulkService;
+use crate::dto::request::bulk_request::BulkTodoRequest;
+use crate::dto::response::bulk_response::BulkTodoResponse;
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct BulkHandler {
+    bulk_service: Arc<BulkService>,
+}
+
+impl BulkHandler {
+    pub fn new(bulk_service: Arc<BulkService>) -> Self {
+        Self { bulk_service }
+    }
+
+    /// 할일 일괄 처리
+    ///
+    /// 항목마다 결과(`succeeded`, `unchanged`, `failed`, `skipped`)를 돌려준다. 일부 항목이 실패해도
+    /// 200으로 응답하며, `atomic`이면 하나라도 실패할 때 아무것도 반영하지 않는다.
+    /// `dry_run`이면 결과만 미리 계산한다.
+    #[utoipa::path(
+        post,
+        path = "/todos/bulk",
+        request_body = BulkTodoRequest,
+        responses(
+            (status = 200, description = "일괄 처리 완료 (항목별 결과 포함)", body = BulkTodoResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "카테고리를 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn bulk_todos(
+        State(handler): State<Arc<BulkHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<BulkTodoRequest>,
+    ) -> Result<Json<BulkTodoResponse>, ApiError> {
+        let response = handler.bulk_service.execute(auth_user.id, request).await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,266 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::category_service::CategoryService;
+use crate::dto::request::category_request::{AssignCategoriesRequest, CreateCategoryRequest, UpdateCategoryRequest};
+use crate::dto::response::category_response::{CategoryListResponse, CategoryResponse, TodoCategoriesResponse};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct CategoryHandler {
+    category_service: Arc<CategoryService>,
+}
+
+impl CategoryHandler {
+    pub fn new(category_service: Arc<CategoryService>) -> Self {
+        Self { category_service }
+    }
+
+    /// 카테고리 목록 조회
+    #[utoipa::path(
+        get,
+        path = "/categories",
+        responses(
+            (status = 200, description = "카테고리 목록 조회 성공", body = CategoryListResponse),
+            (status = 401, description = "인증 필요")
+        ),
+        tag = "Categories",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_categories(
+        State(handler): State<Arc<CategoryHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+    ) -> Result<Json<CategoryListResponse>, ApiError> {
+        let response = handler.category_service.list_categories(auth_user.id).await?;
+        Ok(Json(response))
+    }
+
+    /// 카테고리 생성
+    #[utoipa::path(
+        post,
+        path = "/categories",
+        request_body = CreateCategoryRequest,
+        responses(
+            (status = 201, description = "카테고리 생성 성공", body = CategoryResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 409, description = "이미 존재하는 카테고리 이름"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Categories",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn create_category(
+        State(handler): State<Arc<CategoryHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<CreateCategoryRequest>,
+    ) -> Result<(StatusCode, Json<CategoryResponse>), ApiError> {
+        let response = handler.category_service.create_category(auth_user.id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 카테고리 상세 조회
+    #[utoipa::path(
+        get,
+        path = "/categories/{id}",
+        params(
+            ("id" = Uuid, Path, description = "카테고리 ID")
+        ),
+        responses(
+            (status = 200, description = "카테고리 조회 성공", body = CategoryResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "카테고리를 찾을 수 없음")
+        ),
+        tag = "Categories",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn get_category(
+        State(handler): State<Arc<CategoryHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<CategoryResponse>, ApiError> {
+        let response = handler.category_service.get_category(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+
+    /// 카테고리 수정
+    #[utoipa::path(
+        put,
+        path = "/categories/{id}",
+        params(
+            ("id" = Uuid, Path, description = "카테고리 ID")
+        ),
+        request_body = UpdateCategoryRequest,
+        responses(
+            (status = 200, description = "카테고리 수정 성공", body = CategoryResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "카테고리를 찾을 수 없음"),
+            (status = 409, description = "이미 존재하는 카테고리 이름"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Categories",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_category(
+        State(handler): State<Arc<CategoryHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<UpdateCategoryRequest>,
+    ) -> Result<Json<CategoryResponse>, ApiError> {
+        let response = handler.category_service.update_category(auth_user.id, id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 카테고리 삭제
+    #[utoipa::path(
+        delete,
+        path = "/categories/{id}",
+        params(
+            ("id" = Uuid, Path, description = "카테고리 ID")
+        ),
+        responses(
+            (status = 204, description = "카테고리 삭제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "카테고리를 찾을 수 없음")
+        ),
+        tag = "Categories",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_category(
+        State(handler): State<Arc<CategoryHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.category_service.delete_category(auth_user.id, id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 할일의 카테고리 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/categories",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "할일 카테고리 조회 성공", body = TodoCategoriesResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Categories",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_todo_categories(
+        State(handler): State<Arc<CategoryHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<Json<TodoCategoriesResponse>, ApiError> {
+        let response = handler.category_service.list_todo_categories(auth_user.id, todo_id).await?;
+        Ok(Json(response))
+    }
+
+    /// 할일에 카테고리 지정
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/categories",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = AssignCategoriesRequest,
+        responses(
+            (status = 200, description = "카테고리 지정 성공", body = TodoCategoriesResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일 또는 카테고리를 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Categories",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn assign_categories(
+        State(handler): State<Arc<CategoryHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        Json(request): Json<AssignCategoriesRequest>,
+    ) -> Result<Json<TodoCategoriesResponse>, ApiError> {
+        let response = handler.category_service.assign_categories(auth_user.id, todo_id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 할일에서 카테고리 지정 해제
+    #[utoipa::path(
+        delete,
+        path = "/todos/{id}/categories/{category_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("category_id" = Uuid, Path, description = "카테고리 ID")
+        ),
+        responses(
+            (status = 204, description = "카테고리 지정 해제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없거나 지정되지 않은 카테고리")
+        ),
+        tag = "Categories",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn unassign_category(
+        State(handler): State<Arc<CategoryHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, category_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.category_service.unassign_category(auth_user.id, todo_id, category_id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::category_repository::tests::MockCategoryRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+
+    fn auth_user() -> AuthUser {
+        AuthUser {
+            id: Uuid::new_v4(),
+            email: "test@example.com".to_string(),
+            username: "testuser".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_delete_category_not_found() {
+        let mut category_repo = MockCategoryRepository::new();
+        category_repo.expect_find_by_id().returning(|_, _| Ok(None));
+        category_repo.expect_delete().never();
+
+        let category_service = Arc::new(CategoryService::new(
+            Arc::new(category_repo),
+            Arc::new(MockTodoRepository::new()),
+            Arc::new(MockWorkspaceRepository::new()),
+        ));
+        let handler = Arc::new(CategoryHandler::new(category_service));
+
+        let result = CategoryHandler::delete_category(State(handler), Extension(auth_user()), Path(Uuid::new_v4())).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+}
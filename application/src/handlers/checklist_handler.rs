@@ -0,0 +1,166 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::checklist_service::ChecklistService;
+use crate::dto::request::checklist_request::{CreateChecklistItemRequest, ReorderChecklistRequest, UpdateChecklistItemRequest};
+use crate::dto::response::checklist_response::ChecklistResponse;
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct ChecklistHandler {
+    checklist_service: Arc<ChecklistService>,
+}
+
+impl ChecklistHandler {
+    pub fn new(checklist_service: Arc<ChecklistService>) -> Self {
+        Self { checklist_service }
+    }
+
+    /// 체크리스트 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/checklist",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "체크리스트 조회 성공", body = ChecklistResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Checklist",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_items(
+        State(handler): State<Arc<ChecklistHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<Json<ChecklistResponse>, ApiError> {
+        let response = handler.checklist_service.list_items(auth_user.id, todo_id).await?;
+        Ok(Json(response))
+    }
+
+    /// 체크리스트 항목 추가
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/checklist",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = CreateChecklistItemRequest,
+        responses(
+            (status = 201, description = "체크리스트 항목 추가 성공", body = ChecklistResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패 또는 항목 수 초과")
+        ),
+        tag = "Checklist",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn add_item(
+        State(handler): State<Arc<ChecklistHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        Json(request): Json<CreateChecklistItemRequest>,
+    ) -> Result<(StatusCode, Json<ChecklistResponse>), ApiError> {
+        let response = handler.checklist_service.add_item(auth_user.id, todo_id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 체크리스트 순서 변경
+    #[utoipa::path(
+        put,
+        path = "/todos/{id}/checklist",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = ReorderChecklistRequest,
+        responses(
+            (status = 200, description = "순서 변경 성공", body = ChecklistResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음"),
+            (status = 422, description = "항목 목록이 체크리스트와 일치하지 않음")
+        ),
+        tag = "Checklist",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn reorder_items(
+        State(handler): State<Arc<ChecklistHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        Json(request): Json<ReorderChecklistRequest>,
+    ) -> Result<Json<ChecklistResponse>, ApiError> {
+        let response = handler.checklist_service.reorder_items(auth_user.id, todo_id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 체크리스트 항목 수정 (제목 변경, 완료 토글)
+    ///
+    /// 자동 완료가 설정된 할일은 마지막 항목이 완료되면 `completed` 상태로 바뀐다.
+    #[utoipa::path(
+        patch,
+        path = "/todos/{id}/checklist/{item_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("item_id" = Uuid, Path, description = "체크리스트 항목 ID")
+        ),
+        request_body = UpdateChecklistItemRequest,
+        responses(
+            (status = 200, description = "체크리스트 항목 수정 성공", body = ChecklistResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일 또는 체크리스트 항목을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Checklist",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_item(
+        State(handler): State<Arc<ChecklistHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, item_id)): Path<(Uuid, Uuid)>,
+        Json(request): Json<UpdateChecklistItemRequest>,
+    ) -> Result<Json<ChecklistResponse>, ApiError> {
+        let response = handler.checklist_service.update_item(auth_user.id, todo_id, item_id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 체크리스트 항목 삭제
+    #[utoipa::path(
+        delete,
+        path = "/todos/{id}/checklist/{item_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("item_id" = Uuid, Path, description = "체크리스트 항목 ID")
+        ),
+        responses(
+            (status = 200, description = "체크리스트 항목 삭제 성공", body = ChecklistResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일 또는 체크리스트 항목을 찾을 수 없음")
+        ),
+        tag = "Checklist",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_item(
+        State(handler): State<Arc<ChecklistHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, item_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<Json<ChecklistResponse>, ApiError> {
+        let response = handler.checklist_service.delete_item(auth_user.id, todo_id, item_id).await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,167 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::comment_service::CommentService;
+use crate::dto::request::comment_request::{CreateCommentRequest, UpdateCommentRequest};
+use crate::dto::response::comment_response::{CommentResponse, CommentRevisionListResponse, CommentThreadResponse};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct CommentHandler {
+    comment_service: Arc<CommentService>,
+}
+
+impl CommentHandler {
+    pub fn new(comment_service: Arc<CommentService>) -> Self {
+        Self { comment_service }
+    }
+
+    /// 할일의 댓글 스레드 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/comments",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "댓글 조회 성공", body = CommentThreadResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Comments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_comments(
+        State(handler): State<Arc<CommentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<Json<CommentThreadResponse>, ApiError> {
+        let response = handler.comment_service.list(auth_user.id, todo_id).await?;
+        Ok(Json(response))
+    }
+
+    /// 댓글 또는 답글 작성
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/comments",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = CreateCommentRequest,
+        responses(
+            (status = 201, description = "댓글 작성 성공", body = CommentResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일 또는 답글을 달 댓글을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패 또는 답글 깊이 제한 초과")
+        ),
+        tag = "Comments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn create_comment(
+        State(handler): State<Arc<CommentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        Json(request): Json<CreateCommentRequest>,
+    ) -> Result<(StatusCode, Json<CommentResponse>), ApiError> {
+        let response = handler.comment_service.create(auth_user.id, todo_id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 댓글 수정
+    #[utoipa::path(
+        put,
+        path = "/todos/{id}/comments/{comment_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("comment_id" = Uuid, Path, description = "댓글 ID")
+        ),
+        request_body = UpdateCommentRequest,
+        responses(
+            (status = 200, description = "댓글 수정 성공", body = CommentResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "작성자가 아님"),
+            (status = 404, description = "댓글을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Comments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_comment(
+        State(handler): State<Arc<CommentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, comment_id)): Path<(Uuid, Uuid)>,
+        Json(request): Json<UpdateCommentRequest>,
+    ) -> Result<Json<CommentResponse>, ApiError> {
+        let response = handler
+            .comment_service
+            .update(auth_user.id, todo_id, comment_id, request)
+            .await?;
+        Ok(Json(response))
+    }
+
+    /// 댓글 삭제
+    #[utoipa::path(
+        delete,
+        path = "/todos/{id}/comments/{comment_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("comment_id" = Uuid, Path, description = "댓글 ID")
+        ),
+        responses(
+            (status = 204, description = "댓글 삭제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "삭제 권한 없음"),
+            (status = 404, description = "댓글을 찾을 수 없음")
+        ),
+        tag = "Comments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_comment(
+        State(handler): State<Arc<CommentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, comment_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.comment_service.delete(auth_user.id, todo_id, comment_id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 댓글 수정 이력 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/comments/{comment_id}/revisions",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("comment_id" = Uuid, Path, description = "댓글 ID")
+        ),
+        responses(
+            (status = 200, description = "수정 이력 조회 성공", body = CommentRevisionListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "댓글을 찾을 수 없음")
+        ),
+        tag = "Comments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_revisions(
+        State(handler): State<Arc<CommentHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, comment_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<Json<CommentRevisionListResponse>, ApiError> {
+        let response = handler.comment_service.revisions(auth_user.id, todo_id, comment_id).await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,111 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::dependency_service::DependencyService;
+use crate::dto::request::dependency_request::AddDependencyRequest;
+use crate::dto::response::dependency_response::DependencyListResponse;
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct DependencyHandler {
+    dependency_service: Arc<DependencyService>,
+}
+
+impl DependencyHandler {
+    pub fn new(dependency_service: Arc<DependencyService>) -> Self {
+        Self { dependency_service }
+    }
+
+    /// 할일의 선행 할일과 후속 할일 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/dependencies",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "의존 관계 조회 성공", body = DependencyListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Dependencies",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_dependencies(
+        State(handler): State<Arc<DependencyHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<Json<DependencyListResponse>, ApiError> {
+        let response = handler.dependency_service.list_dependencies(auth_user.id, todo_id).await?;
+        Ok(Json(response))
+    }
+
+    /// 선행 할일 연결
+    ///
+    /// 연결하면 순환이 생기는 경우(예: A가 B를 기다리는데 B가 A를 기다리게 되는 경우) 409로 거부한다.
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/dependencies",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = AddDependencyRequest,
+        responses(
+            (status = 201, description = "선행 할일 연결 성공", body = DependencyListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일 또는 선행 할일을 찾을 수 없음"),
+            (status = 409, description = "이미 연결되어 있거나 순환 의존 관계가 생김"),
+            (status = 422, description = "유효성 검사 실패 또는 선행 할일 수 초과")
+        ),
+        tag = "Dependencies",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn add_dependency(
+        State(handler): State<Arc<DependencyHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        Json(request): Json<AddDependencyRequest>,
+    ) -> Result<(StatusCode, Json<DependencyListResponse>), ApiError> {
+        let response = handler.dependency_service.add_dependency(auth_user.id, todo_id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 선행 할일 연결 해제
+    #[utoipa::path(
+        delete,
+        path = "/todos/{id}/dependencies/{blocked_by_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("blocked_by_id" = Uuid, Path, description = "선행 할일 ID")
+        ),
+        responses(
+            (status = 204, description = "연결 해제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일 또는 연결을 찾을 수 없음")
+        ),
+        tag = "Dependencies",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn remove_dependency(
+        State(handler): State<Arc<DependencyHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, blocked_by_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<StatusCode, ApiError> {
+        handler
+            .dependency_service
+            .remove_dependency(auth_user.id, todo_id, blocked_by_id)
+            .await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+}
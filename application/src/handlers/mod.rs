@@ -2,7 +2,26 @@
 //!
 //! Contains HTTP handlers (controllers) for API endpoints.
 
+pub mod assignment_handler;
+pub mod attachment_handler;
 pub mod auth_handler;
+pub mod board_handler;
+pub mod bulk_handler;
+pub mod category_handler;
+pub mod checklist_handler;
+pub mod comment_handler;
+pub mod dependency_handler;
 pub mod health_handler;
+pub mod quick_add_handler;
+pub mod recurrence_handler;
+pub mod reminder_handler;
+pub mod share_link_handler;
+pub mod smart_list_handler;
+pub mod stats_handler;
+pub mod template_handler;
+pub mod time_tracking_handler;
+pub mod todo_handler;
+pub mod user_settings_handler;
+pub mod workspace_handler;
 
 pub use health_handler::HealthHandler;
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+use axum::{
+    extract::State,
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use crate::services::quick_add_service::QuickAddService;
+use crate::dto::request::quick_add_request::QuickAddRequest;
+use crate::dto::response::quick_add_response::QuickAddResponse;
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct QuickAddHandler {
+    quick_add_service: Arc<QuickAddService>,
+}
+
+impl QuickAddHandler {
+    pub fn new(quick_add_service: Arc<QuickAddService>) -> Self {
+        Self { quick_add_service }
+    }
+
+    /// 한 줄 입력으로 할일 생성
+    ///
+    /// 입력에서 마감 날짜와 시각, 우선순위(`!high`), 카테고리(`#업무`), 반복(`매주 월요일`,
+    /// `every month on the 1st`)을 뽑아내고 나머지를 제목으로 쓴다. 응답의 `parsed.tokens`로
+    /// 인식한 부분을 표시할 수 있다.
+    #[utoipa::path(
+        post,
+        path = "/todos/quick",
+        request_body = QuickAddRequest,
+        responses(
+            (status = 201, description = "할일 생성 성공", body = QuickAddResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 422, description = "유효성 검사 실패 또는 제목으로 쓸 내용이 없음")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn quick_add(
+        State(handler): State<Arc<QuickAddHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<QuickAddRequest>,
+    ) -> Result<(StatusCode, Json<QuickAddResponse>), ApiError> {
+        let response = handler.quick_add_service.quick_add(auth_user.id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+}
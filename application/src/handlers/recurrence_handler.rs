@@ -0,0 +1,133 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::recurrence_service::RecurrenceService;
+use crate::dto::request::recurrence_request::{SetRecurrenceRequest, UpdateOccurrenceRequest};
+use crate::dto::response::recurrence_response::{OccurrenceUpdateResponse, RecurrenceResponse};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct RecurrenceHandler {
+    recurrence_service: Arc<RecurrenceService>,
+}
+
+impl RecurrenceHandler {
+    pub fn new(recurrence_service: Arc<RecurrenceService>) -> Self {
+        Self { recurrence_service }
+    }
+
+    /// 반복 규칙 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/recurrence",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "반복 규칙 조회 성공", body = RecurrenceResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없거나 반복 일정이 없음")
+        ),
+        tag = "Recurrence",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn get_recurrence(
+        State(handler): State<Arc<RecurrenceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<Json<RecurrenceResponse>, ApiError> {
+        let response = handler.recurrence_service.get_recurrence(auth_user.id, todo_id).await?;
+        Ok(Json(response))
+    }
+
+    /// 반복 규칙 지정 (이미 반복 중이면 이번 발생부터 새 규칙 적용)
+    #[utoipa::path(
+        put,
+        path = "/todos/{id}/recurrence",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = SetRecurrenceRequest,
+        responses(
+            (status = 200, description = "반복 규칙 지정 성공", body = RecurrenceResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음"),
+            (status = 422, description = "잘못된 RRULE 또는 마감일 없음")
+        ),
+        tag = "Recurrence",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn set_recurrence(
+        State(handler): State<Arc<RecurrenceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        Json(request): Json<SetRecurrenceRequest>,
+    ) -> Result<Json<RecurrenceResponse>, ApiError> {
+        let response = handler.recurrence_service.set_recurrence(auth_user.id, todo_id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 반복 중지
+    #[utoipa::path(
+        delete,
+        path = "/todos/{id}/recurrence",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 204, description = "반복 중지 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없거나 반복 일정이 없음")
+        ),
+        tag = "Recurrence",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn stop_recurrence(
+        State(handler): State<Arc<RecurrenceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.recurrence_service.stop_recurrence(auth_user.id, todo_id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 반복 할일 수정 (`scope`: `this` 이번 발생만, `all_future` 이후 모든 발생)
+    #[utoipa::path(
+        put,
+        path = "/todos/{id}/occurrence",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = UpdateOccurrenceRequest,
+        responses(
+            (status = 200, description = "반복 할일 수정 성공", body = OccurrenceUpdateResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없거나 반복 일정이 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Recurrence",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_occurrence(
+        State(handler): State<Arc<RecurrenceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        Json(request): Json<UpdateOccurrenceRequest>,
+    ) -> Result<Json<OccurrenceUpdateResponse>, ApiError> {
+        let response = handler.recurrence_service.update_occurrence(auth_user.id, todo_id, request).await?;
+        Ok(Json(response))
+    }
+}
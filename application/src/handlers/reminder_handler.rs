@@ -0,0 +1,142 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::reminder_service::ReminderService;
+use crate::dto::request::reminder_request::{CreateReminderRequest, SnoozeReminderRequest};
+use crate::dto::response::reminder_response::{ReminderListResponse, ReminderResponse};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct ReminderHandler {
+    reminder_service: Arc<ReminderService>,
+}
+
+impl ReminderHandler {
+    pub fn new(reminder_service: Arc<ReminderService>) -> Self {
+        Self { reminder_service }
+    }
+
+    /// 할일의 알림 목록 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/reminders",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "알림 목록 조회 성공", body = ReminderListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Reminders",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_reminders(
+        State(handler): State<Arc<ReminderHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<Json<ReminderListResponse>, ApiError> {
+        let response = handler.reminder_service.list_reminders(auth_user.id, todo_id).await?;
+        Ok(Json(response))
+    }
+
+    /// 알림 추가
+    ///
+    /// `remind_at`(절대 시각) 또는 `offset_minutes`(마감 N분 전) 중 하나를 지정한다.
+    /// 마감일이 바뀌면 마감 기준 알림은 새 마감일에 맞춰 다시 발송 대기 상태가 된다.
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/reminders",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = CreateReminderRequest,
+        responses(
+            (status = 201, description = "알림 추가 성공", body = ReminderResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "조회 권한만 있음"),
+            (status = 404, description = "할일을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패 또는 알림 수 초과")
+        ),
+        tag = "Reminders",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn add_reminder(
+        State(handler): State<Arc<ReminderHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        Json(request): Json<CreateReminderRequest>,
+    ) -> Result<(StatusCode, Json<ReminderResponse>), ApiError> {
+        let response = handler.reminder_service.add_reminder(auth_user.id, todo_id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 알림 삭제
+    #[utoipa::path(
+        delete,
+        path = "/todos/{id}/reminders/{reminder_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("reminder_id" = Uuid, Path, description = "알림 ID")
+        ),
+        responses(
+            (status = 204, description = "알림 삭제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일 또는 본인이 설정한 알림을 찾을 수 없음")
+        ),
+        tag = "Reminders",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_reminder(
+        State(handler): State<Arc<ReminderHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, reminder_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.reminder_service.delete_reminder(auth_user.id, todo_id, reminder_id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 다시 알림 (기본 10분 뒤)
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/reminders/{reminder_id}/snooze",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("reminder_id" = Uuid, Path, description = "알림 ID")
+        ),
+        request_body = SnoozeReminderRequest,
+        responses(
+            (status = 200, description = "다시 알림 설정 성공", body = ReminderResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일 또는 본인이 설정한 알림을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Reminders",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn snooze_reminder(
+        State(handler): State<Arc<ReminderHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, reminder_id)): Path<(Uuid, Uuid)>,
+        Json(request): Json<SnoozeReminderRequest>,
+    ) -> Result<Json<ReminderResponse>, ApiError> {
+        let response = handler
+            .reminder_service
+            .snooze_reminder(auth_user.id, todo_id, reminder_id, request)
+            .await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Json, Response},
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::share_link_service::ShareLinkService;
+use crate::dto::request::share_link_request::{CreateShareLinkRequest, ShareLinkListQuery};
+use crate::dto::response::share_link_response::{ShareLinkCreatedResponse, ShareLinkListResponse};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+/// 비밀번호를 건 공유 링크를 열 때 쓰는 헤더. URL에 남지 않도록 쿼리 대신 헤더로 받는다.
+const SHARE_PASSWORD_HEADER: &str = "x-share-password";
+
+pub struct ShareLinkHandler {
+    share_link_service: Arc<ShareLinkService>,
+}
+
+impl ShareLinkHandler {
+    pub fn new(share_link_service: Arc<ShareLinkService>) -> Self {
+        Self { share_link_service }
+    }
+
+    /// 공유 링크 생성
+    ///
+    /// `todo_id`를 지정하면 할일 하나를, `workspace_id`를 지정하면 조건으로 거른 목록을 공유한다.
+    /// 토큰과 공유 주소는 이 응답에서만 확인할 수 있다.
+    #[utoipa::path(
+        post,
+        path = "/share-links",
+        request_body = CreateShareLinkRequest,
+        responses(
+            (status = 201, description = "공유 링크 생성 성공", body = ShareLinkCreatedResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "조회 권한만 있음"),
+            (status = 404, description = "할일 또는 워크스페이스를 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패 또는 공유 링크 수 초과")
+        ),
+        tag = "Share Links",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn create(
+        State(handler): State<Arc<ShareLinkHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<CreateShareLinkRequest>,
+    ) -> Result<(StatusCode, Json<ShareLinkCreatedResponse>), ApiError> {
+        let response = handler.share_link_service.create(auth_user.id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 워크스페이스의 공유 링크 목록 조회
+    #[utoipa::path(
+        get,
+        path = "/share-links",
+        params(ShareLinkListQuery),
+        responses(
+            (status = 200, description = "공유 링크 목록 조회 성공 (회수되거나 만료된 링크 포함)", body = ShareLinkListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "조회 권한만 있음"),
+            (status = 404, description = "워크스페이스를 찾을 수 없음")
+        ),
+        tag = "Share Links",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list(
+        State(handler): State<Arc<ShareLinkHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Query(query): Query<ShareLinkListQuery>,
+    ) -> Result<Json<ShareLinkListResponse>, ApiError> {
+        let response = handler.share_link_service.list(auth_user.id, query.workspace_id).await?;
+        Ok(Json(response))
+    }
+
+    /// 공유 링크 회수
+    #[utoipa::path(
+        delete,
+        path = "/share-links/{id}",
+        params(
+            ("id" = Uuid, Path, description = "공유 링크 ID")
+        ),
+        responses(
+            (status = 204, description = "공유 링크 회수 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "조회 권한만 있음"),
+            (status = 404, description = "공유 링크를 찾을 수 없음"),
+            (status = 409, description = "이미 회수된 공유 링크")
+        ),
+        tag = "Share Links",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn revoke(
+        State(handler): State<Arc<ShareLinkHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.share_link_service.revoke(auth_user.id, id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 공유된 할일 조회 (인증 없음)
+    #[utoipa::path(
+        get,
+        path = "/shared/{token}",
+        params(
+            ("token" = String, Path, description = "공유 링크 토큰"),
+            ("X-Share-Password" = Option<String>, Header, description = "비밀번호를 건 링크의 비밀번호")
+        ),
+        responses(
+            (status = 200, description = "공유된 할일 (공개 필드만)", body = SharedContentResponse),
+            (status = 401, description = "비밀번호가 필요하거나 올바르지 않음"),
+            (status = 404, description = "없거나 회수되었거나 만료된 링크")
+        ),
+        tag = "Share Links"
+    )]
+    pub async fn view(
+        State(handler): State<Arc<ShareLinkHandler>>,
+        Path(token): Path<String>,
+        headers: HeaderMap,
+    ) -> Result<Response, ApiError> {
+        let password = headers
+            .get(SHARE_PASSWORD_HEADER)
+            .and_then(|value| value.to_str().ok());
+        let shared = handler.share_link_service.view(&token, password).await?;
+
+        // 중간 캐시에 남으면 링크를 회수한 뒤에도 내용이 보일 수 있다
+        let mut response = Json(shared).into_response();
+        response
+            .headers_mut()
+            .insert(header::CACHE_CONTROL, HeaderValue::from_static("private, no-store"));
+        Ok(response)
+    }
+}
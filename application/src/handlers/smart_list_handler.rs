@@ -0,0 +1,183 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use crate::services::smart_list_service::SmartListService;
+use crate::dto::request::smart_list_request::{SmartListRequest, SmartListTodosQuery};
+use crate::dto::response::smart_list_response::{SmartListListResponse, SmartListResponse};
+use crate::dto::response::todo_response::TodoListResponse;
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct SmartListHandler {
+    smart_list_service: Arc<SmartListService>,
+}
+
+impl SmartListHandler {
+    pub fn new(smart_list_service: Arc<SmartListService>) -> Self {
+        Self { smart_list_service }
+    }
+
+    /// 스마트 목록 조회 (기본 목록 포함)
+    #[utoipa::path(
+        get,
+        path = "/smart-lists",
+        responses(
+            (status = 200, description = "스마트 목록 조회 성공", body = SmartListListResponse),
+            (status = 401, description = "인증 필요")
+        ),
+        tag = "Smart Lists",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_smart_lists(
+        State(handler): State<Arc<SmartListHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+    ) -> Result<Json<SmartListListResponse>, ApiError> {
+        let response = handler.smart_list_service.list_smart_lists(auth_user.id).await?;
+        Ok(Json(response))
+    }
+
+    /// 스마트 목록 생성
+    #[utoipa::path(
+        post,
+        path = "/smart-lists",
+        request_body = SmartListRequest,
+        responses(
+            (status = 201, description = "스마트 목록 생성 성공", body = SmartListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "카테고리를 찾을 수 없음"),
+            (status = 409, description = "이미 존재하는 스마트 목록 이름"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Smart Lists",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn create_smart_list(
+        State(handler): State<Arc<SmartListHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<SmartListRequest>,
+    ) -> Result<(StatusCode, Json<SmartListResponse>), ApiError> {
+        let response = handler.smart_list_service.create_smart_list(auth_user.id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 스마트 목록 상세 조회
+    #[utoipa::path(
+        get,
+        path = "/smart-lists/{id}",
+        params(
+            ("id" = String, Path, description = "스마트 목록 ID (UUID 또는 `today`, `upcoming`, `overdue`)")
+        ),
+        responses(
+            (status = 200, description = "스마트 목록 조회 성공", body = SmartListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "스마트 목록을 찾을 수 없음")
+        ),
+        tag = "Smart Lists",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn get_smart_list(
+        State(handler): State<Arc<SmartListHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<String>,
+    ) -> Result<Json<SmartListResponse>, ApiError> {
+        let response = handler.smart_list_service.get_smart_list(auth_user.id, &id).await?;
+        Ok(Json(response))
+    }
+
+    /// 스마트 목록 수정
+    #[utoipa::path(
+        put,
+        path = "/smart-lists/{id}",
+        params(
+            ("id" = String, Path, description = "스마트 목록 ID")
+        ),
+        request_body = SmartListRequest,
+        responses(
+            (status = 200, description = "스마트 목록 수정 성공", body = SmartListResponse),
+            (status = 400, description = "기본 스마트 목록은 수정할 수 없음"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "스마트 목록 또는 카테고리를 찾을 수 없음"),
+            (status = 409, description = "이미 존재하는 스마트 목록 이름"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Smart Lists",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_smart_list(
+        State(handler): State<Arc<SmartListHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<String>,
+        Json(request): Json<SmartListRequest>,
+    ) -> Result<Json<SmartListResponse>, ApiError> {
+        let response = handler.smart_list_service.update_smart_list(auth_user.id, &id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 스마트 목록 삭제
+    #[utoipa::path(
+        delete,
+        path = "/smart-lists/{id}",
+        params(
+            ("id" = String, Path, description = "스마트 목록 ID")
+        ),
+        responses(
+            (status = 204, description = "스마트 목록 삭제 성공"),
+            (status = 400, description = "기본 스마트 목록은 삭제할 수 없음"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "스마트 목록을 찾을 수 없음")
+        ),
+        tag = "Smart Lists",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_smart_list(
+        State(handler): State<Arc<SmartListHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<String>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.smart_list_service.delete_smart_list(auth_user.id, &id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 스마트 목록의 할일 조회
+    #[utoipa::path(
+        get,
+        path = "/smart-lists/{id}/todos",
+        params(
+            ("id" = String, Path, description = "스마트 목록 ID (UUID 또는 `today`, `upcoming`, `overdue`)"),
+            SmartListTodosQuery
+        ),
+        responses(
+            (status = 200, description = "할일 목록 조회 성공", body = TodoListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "스마트 목록을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Smart Lists",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_todos(
+        State(handler): State<Arc<SmartListHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<String>,
+        Query(query): Query<SmartListTodosQuery>,
+    ) -> Result<Json<TodoListResponse>, ApiError> {
+        let response = handler.smart_list_service.list_todos(auth_user.id, &id, query).await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Query, State},
+    response::Json,
+    Extension,
+};
+use crate::services::stats_service::StatsService;
+use crate::dto::request::todo_request::TodoStatsQuery;
+use crate::dto::response::todo_response::TodoStatsResponse;
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct StatsHandler {
+    stats_service: Arc<StatsService>,
+}
+
+impl StatsHandler {
+    pub fn new(stats_service: Arc<StatsService>) -> Self {
+        Self { stats_service }
+    }
+
+    /// 할일 통계 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/stats",
+        params(TodoStatsQuery),
+        responses(
+            (status = 200, description = "통계 조회 성공", body = TodoStatsResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 422, description = "알 수 없는 시간대")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn todo_stats(
+        State(handler): State<Arc<StatsHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Query(query): Query<TodoStatsQuery>,
+    ) -> Result<Json<TodoStatsResponse>, ApiError> {
+        let response = handler.stats_service.todo_stats(auth_user.id, query).await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,182 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::template_service::TemplateService;
+use crate::dto::request::template_request::{InstantiateTemplateRequest, TemplateRequest};
+use crate::dto::response::template_response::{InstantiateTemplateResponse, TemplateListResponse, TemplateResponse};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct TemplateHandler {
+    template_service: Arc<TemplateService>,
+}
+
+impl TemplateHandler {
+    pub fn new(template_service: Arc<TemplateService>) -> Self {
+        Self { template_service }
+    }
+
+    /// 템플릿 목록 조회
+    #[utoipa::path(
+        get,
+        path = "/templates",
+        responses(
+            (status = 200, description = "템플릿 목록 조회 성공", body = TemplateListResponse),
+            (status = 401, description = "인증 필요")
+        ),
+        tag = "Templates",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_templates(
+        State(handler): State<Arc<TemplateHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+    ) -> Result<Json<TemplateListResponse>, ApiError> {
+        let response = handler.template_service.list_templates(auth_user.id).await?;
+        Ok(Json(response))
+    }
+
+    /// 템플릿 생성
+    #[utoipa::path(
+        post,
+        path = "/templates",
+        request_body = TemplateRequest,
+        responses(
+            (status = 201, description = "템플릿 생성 성공", body = TemplateResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "카테고리를 찾을 수 없음"),
+            (status = 409, description = "이미 존재하는 템플릿 이름"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Templates",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn create_template(
+        State(handler): State<Arc<TemplateHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<TemplateRequest>,
+    ) -> Result<(StatusCode, Json<TemplateResponse>), ApiError> {
+        let response = handler.template_service.create_template(auth_user.id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 템플릿 상세 조회
+    #[utoipa::path(
+        get,
+        path = "/templates/{id}",
+        params(
+            ("id" = Uuid, Path, description = "템플릿 ID")
+        ),
+        responses(
+            (status = 200, description = "템플릿 조회 성공", body = TemplateResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "템플릿을 찾을 수 없음")
+        ),
+        tag = "Templates",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn get_template(
+        State(handler): State<Arc<TemplateHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<TemplateResponse>, ApiError> {
+        let response = handler.template_service.get_template(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+
+    /// 템플릿 수정
+    #[utoipa::path(
+        put,
+        path = "/templates/{id}",
+        params(
+            ("id" = Uuid, Path, description = "템플릿 ID")
+        ),
+        request_body = TemplateRequest,
+        responses(
+            (status = 200, description = "템플릿 수정 성공", body = TemplateResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "템플릿 또는 카테고리를 찾을 수 없음"),
+            (status = 409, description = "이미 존재하는 템플릿 이름"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Templates",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_template(
+        State(handler): State<Arc<TemplateHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<TemplateRequest>,
+    ) -> Result<Json<TemplateResponse>, ApiError> {
+        let response = handler.template_service.update_template(auth_user.id, id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 템플릿 삭제
+    #[utoipa::path(
+        delete,
+        path = "/templates/{id}",
+        params(
+            ("id" = Uuid, Path, description = "템플릿 ID")
+        ),
+        responses(
+            (status = 204, description = "템플릿 삭제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "템플릿을 찾을 수 없음")
+        ),
+        tag = "Templates",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_template(
+        State(handler): State<Arc<TemplateHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.template_service.delete_template(auth_user.id, id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 템플릿으로 할일 만들기
+    #[utoipa::path(
+        post,
+        path = "/templates/{id}/instantiate",
+        params(
+            ("id" = Uuid, Path, description = "템플릿 ID")
+        ),
+        request_body = InstantiateTemplateRequest,
+        responses(
+            (status = 201, description = "할일 생성 성공", body = InstantiateTemplateResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "워크스페이스 조회 권한만 있음"),
+            (status = 404, description = "템플릿 또는 워크스페이스를 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패 또는 다른 워크스페이스의 카테고리 포함")
+        ),
+        tag = "Templates",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn instantiate(
+        State(handler): State<Arc<TemplateHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<InstantiateTemplateRequest>,
+    ) -> Result<(StatusCode, Json<InstantiateTemplateResponse>), ApiError> {
+        let response = handler.template_service.instantiate(auth_user.id, id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+}
@@ -0,0 +1,233 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::time_tracking_service::TimeTrackingService;
+use crate::dto::request::time_entry_request::{TimeEntryRequest, TimeReportQuery};
+use crate::dto::response::time_entry_response::{TimeEntryListResponse, TimeEntryResponse, TimeReportResponse, TimerResponse};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct TimeTrackingHandler {
+    time_tracking_service: Arc<TimeTrackingService>,
+}
+
+impl TimeTrackingHandler {
+    pub fn new(time_tracking_service: Arc<TimeTrackingService>) -> Self {
+        Self { time_tracking_service }
+    }
+
+    /// 실행 중인 타이머 조회
+    #[utoipa::path(
+        get,
+        path = "/timer",
+        responses(
+            (status = 200, description = "타이머 조회 성공", body = TimerResponse),
+            (status = 401, description = "인증 필요")
+        ),
+        tag = "Time Tracking",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn current_timer(
+        State(handler): State<Arc<TimeTrackingHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+    ) -> Result<Json<TimerResponse>, ApiError> {
+        let response = handler.time_tracking_service.current_timer(auth_user.id).await?;
+        Ok(Json(response))
+    }
+
+    /// 실행 중인 타이머 정지
+    #[utoipa::path(
+        post,
+        path = "/timer/stop",
+        responses(
+            (status = 200, description = "타이머 정지 성공", body = TimeEntryResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "실행 중인 타이머가 없음")
+        ),
+        tag = "Time Tracking",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn stop_timer(
+        State(handler): State<Arc<TimeTrackingHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+    ) -> Result<Json<TimeEntryResponse>, ApiError> {
+        let response = handler.time_tracking_service.stop_timer(auth_user.id).await?;
+        Ok(Json(response))
+    }
+
+    /// 할일의 타이머 시작
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/timer",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 201, description = "타이머 시작 성공", body = TimeEntryResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음"),
+            (status = 409, description = "이미 실행 중인 타이머가 있음")
+        ),
+        tag = "Time Tracking",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn start_timer(
+        State(handler): State<Arc<TimeTrackingHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<(StatusCode, Json<TimeEntryResponse>), ApiError> {
+        let response = handler.time_tracking_service.start_timer(auth_user.id, todo_id).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 할일의 시간 기록 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}/time-entries",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "시간 기록 조회 성공", body = TimeEntryListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Time Tracking",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_entries(
+        State(handler): State<Arc<TimeTrackingHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+    ) -> Result<Json<TimeEntryListResponse>, ApiError> {
+        let response = handler.time_tracking_service.list_entries(auth_user.id, todo_id).await?;
+        Ok(Json(response))
+    }
+
+    /// 시간 직접 기록
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/time-entries",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = TimeEntryRequest,
+        responses(
+            (status = 201, description = "시간 기록 성공", body = TimeEntryResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Time Tracking",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn add_entry(
+        State(handler): State<Arc<TimeTrackingHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(todo_id): Path<Uuid>,
+        Json(request): Json<TimeEntryRequest>,
+    ) -> Result<(StatusCode, Json<TimeEntryResponse>), ApiError> {
+        let response = handler.time_tracking_service.add_entry(auth_user.id, todo_id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 시간 기록 수정
+    #[utoipa::path(
+        put,
+        path = "/todos/{id}/time-entries/{entry_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("entry_id" = Uuid, Path, description = "시간 기록 ID")
+        ),
+        request_body = TimeEntryRequest,
+        responses(
+            (status = 200, description = "시간 기록 수정 성공", body = TimeEntryResponse),
+            (status = 400, description = "실행 중인 타이머는 수정할 수 없음"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "시간 기록을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Time Tracking",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_entry(
+        State(handler): State<Arc<TimeTrackingHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, entry_id)): Path<(Uuid, Uuid)>,
+        Json(request): Json<TimeEntryRequest>,
+    ) -> Result<Json<TimeEntryResponse>, ApiError> {
+        let response = handler
+            .time_tracking_service
+            .update_entry(auth_user.id, todo_id, entry_id, request)
+            .await?;
+        Ok(Json(response))
+    }
+
+    /// 시간 기록 삭제
+    #[utoipa::path(
+        delete,
+        path = "/todos/{id}/time-entries/{entry_id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID"),
+            ("entry_id" = Uuid, Path, description = "시간 기록 ID")
+        ),
+        responses(
+            (status = 204, description = "시간 기록 삭제 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "시간 기록을 찾을 수 없음")
+        ),
+        tag = "Time Tracking",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_entry(
+        State(handler): State<Arc<TimeTrackingHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((todo_id, entry_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.time_tracking_service.delete_entry(auth_user.id, todo_id, entry_id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 기록 시간과 예상 시간 보고서
+    #[utoipa::path(
+        get,
+        path = "/time-reports",
+        params(TimeReportQuery),
+        responses(
+            (status = 200, description = "보고서 조회 성공", body = TimeReportResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Time Tracking",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn report(
+        State(handler): State<Arc<TimeTrackingHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Query(query): Query<TimeReportQuery>,
+    ) -> Result<Json<TimeReportResponse>, ApiError> {
+        let response = handler.time_tracking_service.report(auth_user.id, query).await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,446 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::todo_service::TodoService;
+use crate::dto::request::todo_request::{
+    CreateTodoRequest, MoveTodoRequest, TodoListQuery, TodoSearchQuery, TrashListQuery, UpdateTodoRequest,
+    UpdateTodoStatusRequest,
+};
+use crate::dto::response::todo_response::{
+    TodoListResponse, TodoResponse, TodoSearchResponse, TodoStatusResponse, TrashListResponse,
+};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct TodoHandler {
+    todo_service: Arc<TodoService>,
+}
+
+impl TodoHandler {
+    pub fn new(todo_service: Arc<TodoService>) -> Self {
+        Self { todo_service }
+    }
+
+    /// 할일 생성
+    #[utoipa::path(
+        post,
+        path = "/todos",
+        request_body = CreateTodoRequest,
+        responses(
+            (status = 201, description = "할일 생성 성공", body = TodoResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn create_todo(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<CreateTodoRequest>,
+    ) -> Result<(StatusCode, Json<TodoResponse>), ApiError> {
+        let response = handler.todo_service.create_todo(auth_user.id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 할일 목록 조회
+    #[utoipa::path(
+        get,
+        path = "/todos",
+        params(TodoListQuery),
+        responses(
+            (status = 200, description = "할일 목록 조회 성공", body = TodoListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_todos(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Query(query): Query<TodoListQuery>,
+    ) -> Result<Json<TodoListResponse>, ApiError> {
+        let response = handler.todo_service.list_todos(auth_user.id, query).await?;
+        Ok(Json(response))
+    }
+
+    /// 나에게 맡겨진 할일 목록 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/assigned",
+        params(TodoListQuery),
+        responses(
+            (status = 200, description = "멤버인 모든 워크스페이스에서 나에게 맡겨진 할일 (`assignee_id`는 무시)", body = TodoListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Assignments",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_assigned_todos(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Query(query): Query<TodoListQuery>,
+    ) -> Result<Json<TodoListResponse>, ApiError> {
+        let response = handler.todo_service.list_assigned_todos(auth_user.id, query).await?;
+        Ok(Json(response))
+    }
+
+    /// 할일 검색
+    #[utoipa::path(
+        get,
+        path = "/todos/search",
+        params(TodoSearchQuery),
+        responses(
+            (status = 200, description = "할일 검색 성공 (관련도 순)", body = TodoSearchResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn search_todos(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Query(query): Query<TodoSearchQuery>,
+    ) -> Result<Json<TodoSearchResponse>, ApiError> {
+        let response = handler.todo_service.search_todos(auth_user.id, query).await?;
+        Ok(Json(response))
+    }
+
+    /// 할일 상세 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/{id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "할일 조회 성공", body = TodoResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn get_todo(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<TodoResponse>, ApiError> {
+        let response = handler.todo_service.get_todo(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+
+    /// 할일 수정
+    #[utoipa::path(
+        put,
+        path = "/todos/{id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = UpdateTodoRequest,
+        responses(
+            (status = 200, description = "할일 수정 성공", body = TodoResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_todo(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<UpdateTodoRequest>,
+    ) -> Result<Json<TodoResponse>, ApiError> {
+        let response = handler.todo_service.update_todo(auth_user.id, id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 할일 상태 변경
+    #[utoipa::path(
+        patch,
+        path = "/todos/{id}/status",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = UpdateTodoStatusRequest,
+        responses(
+            (status = 200, description = "상태 변경 성공", body = TodoStatusResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음"),
+            (status = 409, description = "허용되지 않은 상태 전이"),
+            (status = 422, description = "이미 요청한 상태이거나 유효하지 않은 상태 값")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_status(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<UpdateTodoStatusRequest>,
+    ) -> Result<Json<TodoStatusResponse>, ApiError> {
+        let response = handler.todo_service.change_status(auth_user.id, id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 할일 순서 이동 (드래그 앤 드롭)
+    ///
+    /// 이웃 할일 사이의 정렬 키를 새로 만들어 옮기는 할일 한 건만 수정한다.
+    /// `sort=position`으로 목록을 조회하면 이 순서대로 반환된다.
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/move",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        request_body = MoveTodoRequest,
+        responses(
+            (status = 200, description = "할일 이동 성공", body = TodoResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일 또는 기준 할일을 찾을 수 없음"),
+            (status = 422, description = "기준 할일이 없거나 순서가 맞지 않음")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn move_todo(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<MoveTodoRequest>,
+    ) -> Result<Json<TodoResponse>, ApiError> {
+        let response = handler.todo_service.move_todo(auth_user.id, id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 할일 삭제 (휴지통으로 이동)
+    #[utoipa::path(
+        delete,
+        path = "/todos/{id}",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 204, description = "할일을 휴지통으로 이동함"),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_todo(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.todo_service.delete_todo(auth_user.id, id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 할일 보관
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/archive",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "할일 보관 성공", body = TodoResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn archive_todo(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<TodoResponse>, ApiError> {
+        let response = handler.todo_service.archive_todo(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+
+    /// 할일 보관 해제
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/unarchive",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "할일 보관 해제 성공", body = TodoResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "할일을 찾을 수 없음")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn unarchive_todo(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<TodoResponse>, ApiError> {
+        let response = handler.todo_service.unarchive_todo(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+
+    /// 휴지통 목록 조회
+    #[utoipa::path(
+        get,
+        path = "/todos/trash",
+        params(TrashListQuery),
+        responses(
+            (status = 200, description = "휴지통 목록 조회 성공", body = TrashListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_trash(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Query(query): Query<TrashListQuery>,
+    ) -> Result<Json<TrashListResponse>, ApiError> {
+        let response = handler.todo_service.list_trash(auth_user.id, query).await?;
+        Ok(Json(response))
+    }
+
+    /// 휴지통의 할일 복원
+    #[utoipa::path(
+        post,
+        path = "/todos/{id}/restore",
+        params(
+            ("id" = Uuid, Path, description = "할일 ID")
+        ),
+        responses(
+            (status = 200, description = "할일 복원 성공", body = TodoResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "휴지통에서 할일을 찾을 수 없음")
+        ),
+        tag = "Todos",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn restore_todo(
+        State(handler): State<Arc<TodoHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<TodoResponse>, ApiError> {
+        let response = handler.todo_service.restore_todo(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+    use crate::entities::workspace::WorkspaceRole;
+    use crate::entities::todo::{Priority, Todo, TodoStatus};
+    use crate::events::EventBus;
+    use axum::extract::State;
+
+    fn auth_user() -> AuthUser {
+        AuthUser {
+            id: Uuid::new_v4(),
+            email: "test@example.com".to_string(),
+            username: "testuser".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_todo_success() {
+        let mut mock_repo = MockTodoRepository::new();
+
+        mock_repo.expect_create().returning(|new_todo| {
+            Ok(Todo {
+                title: new_todo.title,
+                description: new_todo.description,
+                priority: new_todo.priority,
+                due_date: new_todo.due_date,
+                checklist_auto_complete: new_todo.checklist_auto_complete,
+                ..Todo::fixture(new_todo.user_id, Uuid::new_v4())
+            })
+        });
+
+        let mut workspace_repo = MockWorkspaceRepository::new();
+        workspace_repo
+            .expect_ensure_personal()
+            .returning(|_| Ok(Uuid::new_v4()));
+
+        let todo_service = Arc::new(TodoService::new(Arc::new(mock_repo), Arc::new(workspace_repo), EventBus::default()));
+        let handler = Arc::new(TodoHandler::new(todo_service));
+
+        let request = CreateTodoRequest {
+            workspace_id: None,
+            title: "장보기".to_string(),
+            description: Some("우유, 빵, 계란 구매".to_string()),
+            priority: Priority::Medium,
+            due_date: None,
+            checklist_auto_complete: false,
+            enforce_dependencies: false,
+            estimate_minutes: None,
+        };
+
+        let result = TodoHandler::create_todo(State(handler), Extension(auth_user()), Json(request)).await;
+        assert!(result.is_ok());
+
+        let (status, response) = result.unwrap();
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(response.title, "장보기");
+        assert_eq!(response.status, TodoStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_delete_todo_success() {
+        let mut mock_repo = MockTodoRepository::new();
+        mock_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Editor)));
+        mock_repo.expect_delete().returning(|_, _| Ok(true));
+
+        let todo_service = Arc::new(TodoService::new(Arc::new(mock_repo), Arc::new(MockWorkspaceRepository::new()), EventBus::default()));
+        let handler = Arc::new(TodoHandler::new(todo_service));
+
+        let result = TodoHandler::delete_todo(State(handler), Extension(auth_user()), Path(Uuid::new_v4())).await;
+        assert_eq!(result.unwrap(), StatusCode::NO_CONTENT);
+    }
+}
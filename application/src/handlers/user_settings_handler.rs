@@ -0,0 +1,62 @@
+use std::sync::Arc;
+use axum::{extract::State, response::Json, Extension};
+use crate::services::user_settings_service::UserSettingsService;
+use crate::dto::request::user_settings_request::UpdateUserSettingsRequest;
+use crate::dto::response::user_settings_response::UserSettingsResponse;
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct UserSettingsHandler {
+    settings_service: Arc<UserSettingsService>,
+}
+
+impl UserSettingsHandler {
+    pub fn new(settings_service: Arc<UserSettingsService>) -> Self {
+        Self { settings_service }
+    }
+
+    /// 사용자 설정 조회
+    #[utoipa::path(
+        get,
+        path = "/users/settings",
+        responses(
+            (status = 200, description = "설정 조회 성공", body = UserSettingsResponse),
+            (status = 401, description = "인증 필요")
+        ),
+        tag = "Users",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn get_settings(
+        State(handler): State<Arc<UserSettingsHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+    ) -> Result<Json<UserSettingsResponse>, ApiError> {
+        let response = handler.settings_service.get_settings(auth_user.id).await?;
+        Ok(Json(response))
+    }
+
+    /// 사용자 설정 변경
+    #[utoipa::path(
+        put,
+        path = "/users/settings",
+        request_body = UpdateUserSettingsRequest,
+        responses(
+            (status = 200, description = "설정 변경 성공", body = UserSettingsResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Users",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_settings(
+        State(handler): State<Arc<UserSettingsHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<UpdateUserSettingsRequest>,
+    ) -> Result<Json<UserSettingsResponse>, ApiError> {
+        let response = handler.settings_service.update_settings(auth_user.id, request).await?;
+        Ok(Json(response))
+    }
+}
@@ -0,0 +1,383 @@
+use std::sync::Arc;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::Json,
+    Extension,
+};
+use uuid::Uuid;
+use crate::services::workspace_service::WorkspaceService;
+use crate::dto::request::workspace_request::{
+    CreateInvitationRequest, CreateWorkspaceRequest, InvitationTokenRequest, UpdateMemberRoleRequest,
+    UpdateWorkspaceRequest,
+};
+use crate::dto::response::workspace_response::{
+    InvitationListResponse, InvitationResponse, WorkspaceListResponse, WorkspaceMemberListResponse,
+    WorkspaceMemberResponse, WorkspaceResponse,
+};
+use crate::error::ApiError;
+use crate::middleware::auth::AuthUser;
+
+pub struct WorkspaceHandler {
+    workspace_service: Arc<WorkspaceService>,
+}
+
+impl WorkspaceHandler {
+    pub fn new(workspace_service: Arc<WorkspaceService>) -> Self {
+        Self { workspace_service }
+    }
+
+    /// 참여한 워크스페이스 목록 조회 (개인 워크스페이스가 먼저)
+    #[utoipa::path(
+        get,
+        path = "/workspaces",
+        responses(
+            (status = 200, description = "워크스페이스 목록 조회 성공", body = WorkspaceListResponse),
+            (status = 401, description = "인증 필요")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_workspaces(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+    ) -> Result<Json<WorkspaceListResponse>, ApiError> {
+        let response = handler.workspace_service.list(auth_user.id).await?;
+        Ok(Json(response))
+    }
+
+    /// 팀 워크스페이스 생성 (만든 사용자가 소유자가 된다)
+    #[utoipa::path(
+        post,
+        path = "/workspaces",
+        request_body = CreateWorkspaceRequest,
+        responses(
+            (status = 201, description = "워크스페이스 생성 성공", body = WorkspaceResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn create_workspace(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<CreateWorkspaceRequest>,
+    ) -> Result<(StatusCode, Json<WorkspaceResponse>), ApiError> {
+        let response = handler.workspace_service.create(auth_user.id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 워크스페이스 상세 조회
+    #[utoipa::path(
+        get,
+        path = "/workspaces/{id}",
+        params(
+            ("id" = Uuid, Path, description = "워크스페이스 ID")
+        ),
+        responses(
+            (status = 200, description = "워크스페이스 조회 성공", body = WorkspaceResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "워크스페이스를 찾을 수 없음")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn get_workspace(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<WorkspaceResponse>, ApiError> {
+        let response = handler.workspace_service.get(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+
+    /// 워크스페이스 이름 변경 (소유자)
+    #[utoipa::path(
+        put,
+        path = "/workspaces/{id}",
+        params(
+            ("id" = Uuid, Path, description = "워크스페이스 ID")
+        ),
+        request_body = UpdateWorkspaceRequest,
+        responses(
+            (status = 200, description = "워크스페이스 수정 성공", body = WorkspaceResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "소유자가 아님"),
+            (status = 404, description = "워크스페이스를 찾을 수 없음"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_workspace(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<UpdateWorkspaceRequest>,
+    ) -> Result<Json<WorkspaceResponse>, ApiError> {
+        let response = handler.workspace_service.rename(auth_user.id, id, request).await?;
+        Ok(Json(response))
+    }
+
+    /// 워크스페이스 삭제 (소유자). 할일과 카테고리도 함께 삭제된다.
+    #[utoipa::path(
+        delete,
+        path = "/workspaces/{id}",
+        params(
+            ("id" = Uuid, Path, description = "워크스페이스 ID")
+        ),
+        responses(
+            (status = 204, description = "워크스페이스 삭제 성공"),
+            (status = 400, description = "개인 워크스페이스는 삭제할 수 없음"),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "소유자가 아님"),
+            (status = 404, description = "워크스페이스를 찾을 수 없음")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn delete_workspace(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.workspace_service.delete(auth_user.id, id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 멤버 목록 조회
+    #[utoipa::path(
+        get,
+        path = "/workspaces/{id}/members",
+        params(
+            ("id" = Uuid, Path, description = "워크스페이스 ID")
+        ),
+        responses(
+            (status = 200, description = "멤버 목록 조회 성공", body = WorkspaceMemberListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 404, description = "워크스페이스를 찾을 수 없음")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_members(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<WorkspaceMemberListResponse>, ApiError> {
+        let response = handler.workspace_service.list_members(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+
+    /// 멤버 역할 변경 (소유자)
+    #[utoipa::path(
+        put,
+        path = "/workspaces/{id}/members/{user_id}",
+        params(
+            ("id" = Uuid, Path, description = "워크스페이스 ID"),
+            ("user_id" = Uuid, Path, description = "멤버 사용자 ID")
+        ),
+        request_body = UpdateMemberRoleRequest,
+        responses(
+            (status = 200, description = "역할 변경 성공", body = WorkspaceMemberResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "소유자가 아님"),
+            (status = 404, description = "워크스페이스 또는 멤버를 찾을 수 없음"),
+            (status = 409, description = "마지막 소유자의 역할은 바꿀 수 없음")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn update_member_role(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((id, user_id)): Path<(Uuid, Uuid)>,
+        Json(request): Json<UpdateMemberRoleRequest>,
+    ) -> Result<Json<WorkspaceMemberResponse>, ApiError> {
+        let response = handler
+            .workspace_service
+            .update_member_role(auth_user.id, id, user_id, request)
+            .await?;
+        Ok(Json(response))
+    }
+
+    /// 멤버 내보내기 (소유자) 또는 스스로 나가기
+    #[utoipa::path(
+        delete,
+        path = "/workspaces/{id}/members/{user_id}",
+        params(
+            ("id" = Uuid, Path, description = "워크스페이스 ID"),
+            ("user_id" = Uuid, Path, description = "멤버 사용자 ID")
+        ),
+        responses(
+            (status = 204, description = "멤버 제거 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "소유자가 아님"),
+            (status = 404, description = "워크스페이스 또는 멤버를 찾을 수 없음"),
+            (status = 409, description = "마지막 소유자는 나갈 수 없음")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn remove_member(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((id, user_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.workspace_service.remove_member(auth_user.id, id, user_id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 대기 중인 초대 목록 조회 (소유자)
+    #[utoipa::path(
+        get,
+        path = "/workspaces/{id}/invitations",
+        params(
+            ("id" = Uuid, Path, description = "워크스페이스 ID")
+        ),
+        responses(
+            (status = 200, description = "초대 목록 조회 성공", body = InvitationListResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "소유자가 아님"),
+            (status = 404, description = "워크스페이스를 찾을 수 없음")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn list_invitations(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+    ) -> Result<Json<InvitationListResponse>, ApiError> {
+        let response = handler.workspace_service.list_invitations(auth_user.id, id).await?;
+        Ok(Json(response))
+    }
+
+    /// 이메일로 초대 (소유자). 초대 링크가 담긴 메일을 보낸다.
+    #[utoipa::path(
+        post,
+        path = "/workspaces/{id}/invitations",
+        params(
+            ("id" = Uuid, Path, description = "워크스페이스 ID")
+        ),
+        request_body = CreateInvitationRequest,
+        responses(
+            (status = 201, description = "초대 성공", body = InvitationResponse),
+            (status = 400, description = "개인 워크스페이스에는 초대할 수 없음"),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "소유자가 아님"),
+            (status = 404, description = "워크스페이스를 찾을 수 없음"),
+            (status = 409, description = "멤버 수 제한 초과"),
+            (status = 422, description = "유효성 검사 실패")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn create_invitation(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path(id): Path<Uuid>,
+        Json(request): Json<CreateInvitationRequest>,
+    ) -> Result<(StatusCode, Json<InvitationResponse>), ApiError> {
+        let response = handler.workspace_service.invite(auth_user.id, id, request).await?;
+        Ok((StatusCode::CREATED, Json(response)))
+    }
+
+    /// 초대 취소 (소유자)
+    #[utoipa::path(
+        delete,
+        path = "/workspaces/{id}/invitations/{invitation_id}",
+        params(
+            ("id" = Uuid, Path, description = "워크스페이스 ID"),
+            ("invitation_id" = Uuid, Path, description = "초대 ID")
+        ),
+        responses(
+            (status = 204, description = "초대 취소 성공"),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "소유자가 아님"),
+            (status = 404, description = "초대를 찾을 수 없음")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn revoke_invitation(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Path((id, invitation_id)): Path<(Uuid, Uuid)>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.workspace_service.revoke_invitation(auth_user.id, id, invitation_id).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+
+    /// 초대 수락 (초대받은 이메일로 로그인한 사용자)
+    #[utoipa::path(
+        post,
+        path = "/invitations/accept",
+        request_body = InvitationTokenRequest,
+        responses(
+            (status = 200, description = "초대 수락 성공", body = WorkspaceResponse),
+            (status = 401, description = "인증 필요"),
+            (status = 403, description = "초대받은 이메일이 아님"),
+            (status = 404, description = "초대를 찾을 수 없음"),
+            (status = 409, description = "이미 응답했거나 만료된 초대, 또는 멤버 수 제한 초과")
+        ),
+        tag = "Workspaces",
+        security(
+            ("bearer_auth" = [])
+        )
+    )]
+    pub async fn accept_invitation(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Extension(auth_user): Extension<AuthUser>,
+        Json(request): Json<InvitationTokenRequest>,
+    ) -> Result<Json<WorkspaceResponse>, ApiError> {
+        let response = handler
+            .workspace_service
+            .accept_invitation(auth_user.id, &auth_user.email, request)
+            .await?;
+        Ok(Json(response))
+    }
+
+    /// 초대 거절 (인증 불필요, 메일의 토큰으로 확인)
+    #[utoipa::path(
+        post,
+        path = "/invitations/decline",
+        request_body = InvitationTokenRequest,
+        responses(
+            (status = 204, description = "초대 거절 성공"),
+            (status = 404, description = "초대를 찾을 수 없음"),
+            (status = 409, description = "이미 응답했거나 만료된 초대")
+        ),
+        tag = "Workspaces"
+    )]
+    pub async fn decline_invitation(
+        State(handler): State<Arc<WorkspaceHandler>>,
+        Json(request): Json<InvitationTokenRequest>,
+    ) -> Result<StatusCode, ApiError> {
+        handler.workspace_service.decline_invitation(request).await?;
+        Ok(StatusCode::NO_CONTENT)
+    }
+}
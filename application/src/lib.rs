@@ -7,10 +7,13 @@ pub mod config;
 pub mod dto;
 pub mod entities;
 pub mod error;
+pub mod events;
 pub mod handlers;
 pub mod middleware;
+pub mod notifications;
 pub mod repositories;
 pub mod services;
+pub mod storage;
 pub mod utils;
 
 pub use error::ApiError;
@@ -3,17 +3,127 @@
 //! This is the main entry point for the TBM application server.
 
 use std::sync::Arc;
+use std::time::Duration;
 
-use axum::{routing::{get, post}, Router};
+use axum::{extract::DefaultBodyLimit, middleware, routing::{delete, get, patch, post, put}, Router};
 use sqlx::PgPool;
 use tbm_application::{
     config::AppConfig,
-    handlers::{HealthHandler, auth_handler::AuthHandler},
-    services::{HealthService, user_service::UserService},
-    repositories::user_repository::PostgresUserRepository,
+    handlers::{
+        HealthHandler, auth_handler::AuthHandler, todo_handler::TodoHandler, category_handler::CategoryHandler,
+        stats_handler::StatsHandler, user_settings_handler::UserSettingsHandler, checklist_handler::ChecklistHandler,
+        recurrence_handler::RecurrenceHandler, reminder_handler::ReminderHandler,
+        dependency_handler::DependencyHandler, bulk_handler::BulkHandler,
+        quick_add_handler::QuickAddHandler, smart_list_handler::SmartListHandler,
+        template_handler::TemplateHandler, time_tracking_handler::TimeTrackingHandler, board_handler::BoardHandler,
+        attachment_handler::AttachmentHandler, comment_handler::CommentHandler,
+        workspace_handler::WorkspaceHandler, assignment_handler::AssignmentHandler,
+        share_link_handler::ShareLinkHandler,
+    },
+    services::{
+        HealthService, user_service::UserService, todo_service::TodoService, category_service::CategoryService,
+        stats_service::StatsService, user_settings_service::UserSettingsService, checklist_service::ChecklistService,
+        recurrence_service::RecurrenceService, reminder_service::{ReminderService, ReminderDispatchConfig},
+        dependency_service::DependencyService, bulk_service::BulkService,
+        quick_add_service::QuickAddService, smart_list_service::SmartListService,
+        template_service::TemplateService, time_tracking_service::TimeTrackingService, board_service::BoardService,
+        attachment_service::{AttachmentService, AttachmentConfig}, comment_service::CommentService,
+        workspace_service::{WorkspaceService, InvitationConfig}, assignment_service::AssignmentService,
+        share_link_service::ShareLinkService,
+    },
+    repositories::{
+        user_repository::PostgresUserRepository, todo_repository::PostgresTodoRepository,
+        category_repository::PostgresCategoryRepository, user_settings_repository::PostgresUserSettingsRepository,
+        checklist_repository::PostgresChecklistRepository, todo_series_repository::PostgresTodoSeriesRepository,
+        reminder_repository::PostgresReminderRepository, dependency_repository::PostgresDependencyRepository,
+        bulk_repository::PostgresBulkTodoRepository, smart_list_repository::PostgresSmartListRepository,
+        template_repository::PostgresTemplateRepository, time_entry_repository::PostgresTimeEntryRepository,
+        board_repository::PostgresBoardRepository, attachment_repository::PostgresAttachmentRepository,
+        comment_repository::PostgresCommentRepository, workspace_repository::PostgresWorkspaceRepository,
+        assignment_repository::PostgresAssignmentRepository, share_link_repository::PostgresShareLinkRepository,
+    },
+    middleware::auth::auth_middleware,
+    events::EventBus,
+    notifications::{HttpMailer, HttpMailerConfig, LogMailer, LogNotifier, Mailer},
+    storage::{BlobStore, LocalBlobStore, S3BlobStore, S3Config},
+    utils::signed_url::UrlSigner,
+    utils::jwt::JwtService,
+    utils::pagination::CursorInfo,
+    entities::todo::{TodoStatus, Priority},
+    entities::user_settings::WeekStart,
+    entities::reminder::ReminderStatus,
+    entities::bulk::BulkItemOutcome,
+    entities::smart_list::DueWindow,
+    entities::time_entry::{TimeEntrySource, TimeReportGroup},
+    entities::workspace::{WorkspaceRole, InvitationStatus},
+    entities::share_link::ShareKind,
     dto::response::HealthResponse,
     dto::request::auth_request::{RegisterRequest, LoginRequest},
+    dto::request::todo_request::{CreateTodoRequest, UpdateTodoRequest, UpdateTodoStatusRequest, MoveTodoRequest},
+    dto::request::category_request::{CreateCategoryRequest, UpdateCategoryRequest, AssignCategoriesRequest},
+    dto::request::user_settings_request::UpdateUserSettingsRequest,
+    dto::request::checklist_request::{CreateChecklistItemRequest, UpdateChecklistItemRequest, ReorderChecklistRequest},
+    dto::request::recurrence_request::{SetRecurrenceRequest, UpdateOccurrenceRequest, EditScope},
+    dto::request::reminder_request::{CreateReminderRequest, SnoozeReminderRequest},
+    dto::request::dependency_request::AddDependencyRequest,
+    dto::request::bulk_request::{BulkTodoRequest, BulkAction},
+    dto::request::quick_add_request::QuickAddRequest,
+    dto::request::smart_list_request::SmartListRequest,
+    dto::request::template_request::{TemplateRequest, TemplateItemRequest, InstantiateTemplateRequest},
+    dto::request::time_entry_request::TimeEntryRequest,
+    dto::request::board_request::{
+        CreateBoardRequest, UpdateBoardRequest, BoardColumnRequest, ReorderColumnsRequest, MoveCardRequest,
+    },
+    dto::request::attachment_request::AttachmentUploadRequest,
+    dto::request::comment_request::{CreateCommentRequest, UpdateCommentRequest},
+    dto::request::workspace_request::{
+        CreateWorkspaceRequest, UpdateWorkspaceRequest, UpdateMemberRoleRequest, CreateInvitationRequest,
+        InvitationTokenRequest,
+    },
+    dto::request::assignment_request::AssignTodoRequest,
+    dto::request::share_link_request::CreateShareLinkRequest,
     dto::response::auth_response::{RegisterResponse, LoginResponse, UserInfo},
+    dto::response::todo_response::{
+        TodoResponse, TodoListResponse, TodoStatusResponse, PaginationInfo,
+        TodoSearchResponse, TodoSearchResult, SearchHighlight, SearchField, TodoStatsResponse,
+        TrashListResponse,
+    },
+    dto::response::category_response::{CategoryResponse, CategoryListResponse, TodoCategoriesResponse},
+    dto::response::user_settings_response::UserSettingsResponse,
+    dto::response::checklist_response::{ChecklistResponse, ChecklistItemResponse, ChecklistProgress},
+    dto::response::recurrence_response::{RecurrenceResponse, OccurrenceUpdateResponse},
+    dto::response::reminder_response::{ReminderResponse, ReminderListResponse},
+    dto::response::dependency_response::{DependencyListResponse, DependencyTodoResponse},
+    dto::response::bulk_response::{BulkTodoResponse, BulkItemResponse},
+    dto::response::quick_add_response::{
+        QuickAddResponse, QuickAddParseResponse, QuickAddTokenResponse, QuickAddTokenKind,
+    },
+    dto::response::smart_list_response::{SmartListResponse, SmartListListResponse},
+    dto::response::template_response::{
+        TemplateResponse, TemplateItemResponse, TemplateListResponse, InstantiateTemplateResponse,
+    },
+    dto::response::time_entry_response::{
+        TimeEntryResponse, TimerResponse, TimeEntryListResponse, TimeReportResponse, TimeReportRowResponse,
+    },
+    dto::response::board_response::{
+        BoardResponse, BoardColumnResponse, BoardListResponse, BoardCardsResponse, BoardColumnCardsResponse,
+        MoveCardResponse,
+    },
+    dto::response::attachment_response::{AttachmentResponse, AttachmentListResponse, AttachmentUrlResponse},
+    dto::response::comment_response::{
+        CommentResponse, CommentAuthorResponse, CommentThreadResponse, CommentRevisionResponse,
+        CommentRevisionListResponse,
+    },
+    dto::response::workspace_response::{
+        WorkspaceResponse, WorkspaceListResponse, WorkspaceMemberResponse, WorkspaceMemberListResponse,
+        InvitationResponse, InvitationListResponse,
+    },
+    dto::response::assignment_response::{
+        AssigneeResponse, AssigneeListResponse, MemberWorkloadResponse, WorkloadResponse,
+    },
+    dto::response::share_link_response::{
+        ShareLinkResponse, ShareLinkCreatedResponse, ShareLinkListResponse, SharedTodoResponse, SharedContentResponse,
+    },
 };
 use tower::ServiceBuilder;
 use tower_http::{cors::CorsLayer, trace::TraceLayer};
@@ -29,6 +139,110 @@ use utoipa_swagger_ui::SwaggerUi;
         tbm_application::handlers::auth_handler::AuthHandler::register,
         tbm_application::handlers::auth_handler::AuthHandler::login,
         tbm_application::handlers::auth_handler::AuthHandler::logout,
+        tbm_application::handlers::todo_handler::TodoHandler::create_todo,
+        tbm_application::handlers::todo_handler::TodoHandler::list_todos,
+        tbm_application::handlers::todo_handler::TodoHandler::search_todos,
+        tbm_application::handlers::todo_handler::TodoHandler::list_assigned_todos,
+        tbm_application::handlers::stats_handler::StatsHandler::todo_stats,
+        tbm_application::handlers::todo_handler::TodoHandler::get_todo,
+        tbm_application::handlers::todo_handler::TodoHandler::update_todo,
+        tbm_application::handlers::todo_handler::TodoHandler::update_status,
+        tbm_application::handlers::todo_handler::TodoHandler::move_todo,
+        tbm_application::handlers::todo_handler::TodoHandler::delete_todo,
+        tbm_application::handlers::todo_handler::TodoHandler::archive_todo,
+        tbm_application::handlers::todo_handler::TodoHandler::unarchive_todo,
+        tbm_application::handlers::todo_handler::TodoHandler::list_trash,
+        tbm_application::handlers::todo_handler::TodoHandler::restore_todo,
+        tbm_application::handlers::category_handler::CategoryHandler::list_categories,
+        tbm_application::handlers::category_handler::CategoryHandler::create_category,
+        tbm_application::handlers::category_handler::CategoryHandler::get_category,
+        tbm_application::handlers::category_handler::CategoryHandler::update_category,
+        tbm_application::handlers::category_handler::CategoryHandler::delete_category,
+        tbm_application::handlers::category_handler::CategoryHandler::list_todo_categories,
+        tbm_application::handlers::category_handler::CategoryHandler::assign_categories,
+        tbm_application::handlers::category_handler::CategoryHandler::unassign_category,
+        tbm_application::handlers::checklist_handler::ChecklistHandler::list_items,
+        tbm_application::handlers::checklist_handler::ChecklistHandler::add_item,
+        tbm_application::handlers::checklist_handler::ChecklistHandler::reorder_items,
+        tbm_application::handlers::checklist_handler::ChecklistHandler::update_item,
+        tbm_application::handlers::checklist_handler::ChecklistHandler::delete_item,
+        tbm_application::handlers::recurrence_handler::RecurrenceHandler::get_recurrence,
+        tbm_application::handlers::recurrence_handler::RecurrenceHandler::set_recurrence,
+        tbm_application::handlers::recurrence_handler::RecurrenceHandler::stop_recurrence,
+        tbm_application::handlers::recurrence_handler::RecurrenceHandler::update_occurrence,
+        tbm_application::handlers::reminder_handler::ReminderHandler::list_reminders,
+        tbm_application::handlers::reminder_handler::ReminderHandler::add_reminder,
+        tbm_application::handlers::reminder_handler::ReminderHandler::delete_reminder,
+        tbm_application::handlers::reminder_handler::ReminderHandler::snooze_reminder,
+        tbm_application::handlers::dependency_handler::DependencyHandler::list_dependencies,
+        tbm_application::handlers::dependency_handler::DependencyHandler::add_dependency,
+        tbm_application::handlers::dependency_handler::DependencyHandler::remove_dependency,
+        tbm_application::handlers::bulk_handler::BulkHandler::bulk_todos,
+        tbm_application::handlers::quick_add_handler::QuickAddHandler::quick_add,
+        tbm_application::handlers::smart_list_handler::SmartListHandler::list_smart_lists,
+        tbm_application::handlers::smart_list_handler::SmartListHandler::create_smart_list,
+        tbm_application::handlers::smart_list_handler::SmartListHandler::get_smart_list,
+        tbm_application::handlers::smart_list_handler::SmartListHandler::update_smart_list,
+        tbm_application::handlers::smart_list_handler::SmartListHandler::delete_smart_list,
+        tbm_application::handlers::smart_list_handler::SmartListHandler::list_todos,
+        tbm_application::handlers::template_handler::TemplateHandler::list_templates,
+        tbm_application::handlers::template_handler::TemplateHandler::create_template,
+        tbm_application::handlers::template_handler::TemplateHandler::get_template,
+        tbm_application::handlers::template_handler::TemplateHandler::update_template,
+        tbm_application::handlers::template_handler::TemplateHandler::delete_template,
+        tbm_application::handlers::template_handler::TemplateHandler::instantiate,
+        tbm_application::handlers::time_tracking_handler::TimeTrackingHandler::current_timer,
+        tbm_application::handlers::time_tracking_handler::TimeTrackingHandler::stop_timer,
+        tbm_application::handlers::time_tracking_handler::TimeTrackingHandler::start_timer,
+        tbm_application::handlers::time_tracking_handler::TimeTrackingHandler::list_entries,
+        tbm_application::handlers::time_tracking_handler::TimeTrackingHandler::add_entry,
+        tbm_application::handlers::time_tracking_handler::TimeTrackingHandler::update_entry,
+        tbm_application::handlers::time_tracking_handler::TimeTrackingHandler::delete_entry,
+        tbm_application::handlers::time_tracking_handler::TimeTrackingHandler::report,
+        tbm_application::handlers::board_handler::BoardHandler::list_boards,
+        tbm_application::handlers::board_handler::BoardHandler::create_board,
+        tbm_application::handlers::board_handler::BoardHandler::get_board,
+        tbm_application::handlers::board_handler::BoardHandler::update_board,
+        tbm_application::handlers::board_handler::BoardHandler::delete_board,
+        tbm_application::handlers::board_handler::BoardHandler::add_column,
+        tbm_application::handlers::board_handler::BoardHandler::reorder_columns,
+        tbm_application::handlers::board_handler::BoardHandler::update_column,
+        tbm_application::handlers::board_handler::BoardHandler::delete_column,
+        tbm_application::handlers::board_handler::BoardHandler::get_cards,
+        tbm_application::handlers::board_handler::BoardHandler::move_card,
+        tbm_application::handlers::attachment_handler::AttachmentHandler::list,
+        tbm_application::handlers::attachment_handler::AttachmentHandler::upload,
+        tbm_application::handlers::attachment_handler::AttachmentHandler::download_url,
+        tbm_application::handlers::attachment_handler::AttachmentHandler::delete,
+        tbm_application::handlers::attachment_handler::AttachmentHandler::content,
+        tbm_application::handlers::comment_handler::CommentHandler::list_comments,
+        tbm_application::handlers::comment_handler::CommentHandler::create_comment,
+        tbm_application::handlers::comment_handler::CommentHandler::update_comment,
+        tbm_application::handlers::comment_handler::CommentHandler::delete_comment,
+        tbm_application::handlers::comment_handler::CommentHandler::list_revisions,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::list_workspaces,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::create_workspace,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::get_workspace,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::update_workspace,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::delete_workspace,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::list_members,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::update_member_role,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::remove_member,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::list_invitations,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::create_invitation,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::revoke_invitation,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::accept_invitation,
+        tbm_application::handlers::workspace_handler::WorkspaceHandler::decline_invitation,
+        tbm_application::handlers::assignment_handler::AssignmentHandler::list_assignees,
+        tbm_application::handlers::assignment_handler::AssignmentHandler::assign,
+        tbm_application::handlers::assignment_handler::AssignmentHandler::unassign,
+        tbm_application::handlers::assignment_handler::AssignmentHandler::workload,
+        tbm_application::handlers::share_link_handler::ShareLinkHandler::create,
+        tbm_application::handlers::share_link_handler::ShareLinkHandler::list,
+        tbm_application::handlers::share_link_handler::ShareLinkHandler::revoke,
+        tbm_application::handlers::share_link_handler::ShareLinkHandler::view,
+        tbm_application::handlers::user_settings_handler::UserSettingsHandler::get_settings,
+        tbm_application::handlers::user_settings_handler::UserSettingsHandler::update_settings,
     ),
     components(schemas(
         HealthResponse,
@@ -37,10 +251,147 @@ use utoipa_swagger_ui::SwaggerUi;
         RegisterResponse,
         LoginResponse,
         UserInfo,
+        TodoStatus,
+        Priority,
+        CreateTodoRequest,
+        UpdateTodoRequest,
+        UpdateTodoStatusRequest,
+        MoveTodoRequest,
+        TodoResponse,
+        TodoListResponse,
+        TodoStatusResponse,
+        TrashListResponse,
+        TodoSearchResponse,
+        TodoSearchResult,
+        SearchHighlight,
+        SearchField,
+        PaginationInfo,
+        CursorInfo,
+        CreateCategoryRequest,
+        UpdateCategoryRequest,
+        AssignCategoriesRequest,
+        CategoryResponse,
+        CategoryListResponse,
+        TodoCategoriesResponse,
+        CreateChecklistItemRequest,
+        UpdateChecklistItemRequest,
+        ReorderChecklistRequest,
+        ChecklistResponse,
+        ChecklistItemResponse,
+        ChecklistProgress,
+        SetRecurrenceRequest,
+        UpdateOccurrenceRequest,
+        EditScope,
+        RecurrenceResponse,
+        OccurrenceUpdateResponse,
+        ReminderStatus,
+        CreateReminderRequest,
+        SnoozeReminderRequest,
+        ReminderResponse,
+        ReminderListResponse,
+        AddDependencyRequest,
+        DependencyListResponse,
+        DependencyTodoResponse,
+        BulkTodoRequest,
+        BulkAction,
+        BulkTodoResponse,
+        BulkItemResponse,
+        BulkItemOutcome,
+        QuickAddRequest,
+        QuickAddResponse,
+        QuickAddParseResponse,
+        QuickAddTokenResponse,
+        QuickAddTokenKind,
+        SmartListRequest,
+        SmartListResponse,
+        SmartListListResponse,
+        DueWindow,
+        TemplateRequest,
+        TemplateItemRequest,
+        InstantiateTemplateRequest,
+        TemplateResponse,
+        TemplateItemResponse,
+        TemplateListResponse,
+        InstantiateTemplateResponse,
+        TimeEntryRequest,
+        TimeEntryResponse,
+        TimeEntrySource,
+        TimerResponse,
+        TimeEntryListResponse,
+        TimeReportResponse,
+        TimeReportRowResponse,
+        TimeReportGroup,
+        CreateBoardRequest,
+        UpdateBoardRequest,
+        BoardColumnRequest,
+        ReorderColumnsRequest,
+        MoveCardRequest,
+        BoardResponse,
+        BoardColumnResponse,
+        BoardListResponse,
+        BoardCardsResponse,
+        BoardColumnCardsResponse,
+        MoveCardResponse,
+        AttachmentUploadRequest,
+        AttachmentResponse,
+        AttachmentListResponse,
+        AttachmentUrlResponse,
+        CreateCommentRequest,
+        UpdateCommentRequest,
+        CommentResponse,
+        CommentAuthorResponse,
+        CommentThreadResponse,
+        CommentRevisionResponse,
+        CommentRevisionListResponse,
+        WorkspaceRole,
+        InvitationStatus,
+        CreateWorkspaceRequest,
+        UpdateWorkspaceRequest,
+        UpdateMemberRoleRequest,
+        CreateInvitationRequest,
+        InvitationTokenRequest,
+        WorkspaceResponse,
+        WorkspaceListResponse,
+        WorkspaceMemberResponse,
+        WorkspaceMemberListResponse,
+        InvitationResponse,
+        InvitationListResponse,
+        AssignTodoRequest,
+        AssigneeResponse,
+        AssigneeListResponse,
+        MemberWorkloadResponse,
+        WorkloadResponse,
+        ShareKind,
+        CreateShareLinkRequest,
+        ShareLinkResponse,
+        ShareLinkCreatedResponse,
+        ShareLinkListResponse,
+        SharedTodoResponse,
+        SharedContentResponse,
+        TodoStatsResponse,
+        WeekStart,
+        UpdateUserSettingsRequest,
+        UserSettingsResponse,
     )),
     tags(
         (name = "Health", description = "Health check endpoints"),
-        (name = "Authentication", description = "User authentication endpoints")
+        (name = "Authentication", description = "User authentication endpoints"),
+        (name = "Todos", description = "TODO management endpoints"),
+        (name = "Categories", description = "Category management and assignment endpoints"),
+        (name = "Checklist", description = "Todo checklist item endpoints"),
+        (name = "Recurrence", description = "Recurring todo (RRULE) endpoints"),
+        (name = "Reminders", description = "Due-date reminder endpoints"),
+        (name = "Dependencies", description = "Todo dependency (blocked by) endpoints"),
+        (name = "Smart Lists", description = "Saved todo filter (smart list) endpoints"),
+        (name = "Templates", description = "Todo template and instantiation endpoints"),
+        (name = "Time Tracking", description = "Timer, time entry and time report endpoints"),
+        (name = "Boards", description = "Kanban board, column and card endpoints"),
+        (name = "Attachments", description = "Todo file attachment and signed download endpoints"),
+        (name = "Comments", description = "Threaded todo comment, edit history and mention endpoints"),
+        (name = "Workspaces", description = "Shared workspace, member role and invitation endpoints"),
+        (name = "Assignments", description = "Todo assignee, assigned-to-me and member workload endpoints"),
+        (name = "Share Links", description = "Revocable public read-only share link endpoints"),
+        (name = "Users", description = "User preference endpoints")
     ),
     info(
         title = "TBM Application API",
@@ -87,14 +438,451 @@ async fn main() {
 
     // Initialize repositories
     let user_repository = Arc::new(PostgresUserRepository::new(pool.clone()));
+    let todo_repository = Arc::new(PostgresTodoRepository::new(pool.clone()));
+    let category_repository = Arc::new(PostgresCategoryRepository::new(pool.clone()));
+    let settings_repository = Arc::new(PostgresUserSettingsRepository::new(pool.clone()));
+    let checklist_repository = Arc::new(PostgresChecklistRepository::new(pool.clone()));
+    let series_repository = Arc::new(PostgresTodoSeriesRepository::new(pool.clone()));
+    let reminder_repository = Arc::new(PostgresReminderRepository::new(pool.clone()));
+    let dependency_repository = Arc::new(PostgresDependencyRepository::new(pool.clone()));
+    let bulk_repository = Arc::new(PostgresBulkTodoRepository::new(pool.clone()));
+    let smart_list_repository = Arc::new(PostgresSmartListRepository::new(pool.clone()));
+    let template_repository = Arc::new(PostgresTemplateRepository::new(pool.clone()));
+    let time_entry_repository = Arc::new(PostgresTimeEntryRepository::new(pool.clone()));
+    let board_repository = Arc::new(PostgresBoardRepository::new(pool.clone()));
+    let attachment_repository = Arc::new(PostgresAttachmentRepository::new(pool.clone()));
+    let comment_repository = Arc::new(PostgresCommentRepository::new(pool.clone()));
+    let workspace_repository = Arc::new(PostgresWorkspaceRepository::new(pool.clone()));
+    let assignment_repository = Arc::new(PostgresAssignmentRepository::new(pool.clone()));
+    let share_link_repository = Arc::new(PostgresShareLinkRepository::new(pool.clone()));
+
+    // Initialize attachment storage
+    let blob_store: Arc<dyn BlobStore> = match config.storage_backend.as_str() {
+        "local" => Arc::new(LocalBlobStore::new(&config.storage_local_root)),
+        "s3" => Arc::new(S3BlobStore::new(S3Config {
+            endpoint: config.s3_endpoint.clone().expect("S3_ENDPOINT must be set"),
+            bucket: config.s3_bucket.clone().expect("S3_BUCKET must be set"),
+            region: config.s3_region.clone(),
+            access_key_id: config.s3_access_key_id.clone().expect("S3_ACCESS_KEY_ID must be set"),
+            secret_access_key: config.s3_secret_access_key.clone().expect("S3_SECRET_ACCESS_KEY must be set"),
+        })),
+        other => panic!("STORAGE_BACKEND must be 'local' or 's3', got '{}'", other),
+    };
+    info!("Attachment storage backend: {}", config.storage_backend);
+
+    // Initialize mailer
+    let mailer: Arc<dyn Mailer> = match config.mail_backend.as_str() {
+        "log" => Arc::new(LogMailer),
+        "http" => Arc::new(HttpMailer::new(HttpMailerConfig {
+            url: config.mail_api_url.clone().expect("MAIL_API_URL must be set"),
+            api_key: config.mail_api_key.clone().expect("MAIL_API_KEY must be set"),
+            from: config.mail_from.clone(),
+        })),
+        other => panic!("MAIL_BACKEND must be 'log' or 'http', got '{}'", other),
+    };
+    info!("Mail backend: {}", config.mail_backend);
+
+    // Initialize domain event bus
+    let event_bus = EventBus::default();
 
     // Initialize services
     let health_service = Arc::new(HealthService::new());
-    let user_service = Arc::new(UserService::new(user_repository));
+    let user_service = Arc::new(UserService::new(user_repository.clone()));
+    let todo_service = Arc::new(TodoService::new(
+        todo_repository.clone(),
+        workspace_repository.clone(),
+        event_bus.clone(),
+    ));
+    let category_service = Arc::new(CategoryService::new(
+        category_repository.clone(),
+        todo_repository.clone(),
+        workspace_repository.clone(),
+    ));
+    let checklist_service = Arc::new(ChecklistService::new(
+        checklist_repository,
+        todo_repository.clone(),
+        todo_service.clone(),
+    ));
+    let settings_service = Arc::new(UserSettingsService::new(settings_repository));
+    let recurrence_service = Arc::new(RecurrenceService::new(
+        series_repository,
+        todo_repository.clone(),
+        settings_service.clone(),
+    ));
+    let reminder_service = Arc::new(ReminderService::new(
+        reminder_repository,
+        todo_repository.clone(),
+        Arc::new(LogNotifier),
+        ReminderDispatchConfig {
+            poll_interval: Duration::from_secs(config.reminder_poll_interval_secs),
+            batch_size: config.reminder_batch_size,
+            ..ReminderDispatchConfig::default()
+        },
+    ));
+    let dependency_service = Arc::new(DependencyService::new(dependency_repository, todo_repository.clone()));
+    let bulk_service = Arc::new(BulkService::new(bulk_repository, category_repository.clone(), event_bus.clone()));
+    let quick_add_service = Arc::new(QuickAddService::new(
+        todo_service.clone(),
+        category_service.clone(),
+        recurrence_service.clone(),
+        settings_service.clone(),
+    ));
+    let smart_list_service = Arc::new(SmartListService::new(
+        smart_list_repository,
+        category_repository.clone(),
+        todo_service.clone(),
+        settings_service.clone(),
+    ));
+    let template_service = Arc::new(TemplateService::new(
+        template_repository,
+        category_repository,
+        workspace_repository.clone(),
+        settings_service.clone(),
+    ));
+    let time_tracking_service = Arc::new(TimeTrackingService::new(
+        time_entry_repository,
+        todo_repository.clone(),
+        settings_service.clone(),
+    ));
+    let board_service = Arc::new(BoardService::new(board_repository, event_bus.clone()));
+    let attachment_service = Arc::new(AttachmentService::new(
+        attachment_repository,
+        todo_repository.clone(),
+        blob_store,
+        UrlSigner::new(&config.attachment_url_secret),
+        AttachmentConfig {
+            max_file_bytes: config.attachment_max_file_bytes,
+            url_ttl: chrono::Duration::seconds(config.attachment_url_ttl_secs),
+            cleanup_interval: Duration::from_secs(config.blob_cleanup_interval_secs),
+            ..AttachmentConfig::default()
+        },
+    ));
+    let comment_service = Arc::new(CommentService::new(
+        comment_repository,
+        todo_repository.clone(),
+        workspace_repository.clone(),
+        user_repository.clone(),
+        Arc::new(LogNotifier),
+    ));
+    let assignment_service = Arc::new(AssignmentService::new(
+        assignment_repository,
+        todo_repository.clone(),
+        workspace_repository.clone(),
+        user_repository,
+        Arc::new(LogNotifier),
+    ));
+    let share_link_service = Arc::new(ShareLinkService::new(
+        share_link_repository,
+        todo_repository.clone(),
+        workspace_repository.clone(),
+        config.share_link_base_url.clone(),
+    ));
+    let workspace_service = Arc::new(WorkspaceService::new(
+        workspace_repository,
+        mailer,
+        InvitationConfig {
+            ttl: chrono::Duration::hours(config.invitation_ttl_hours),
+            accept_url: config.invitation_accept_url.clone(),
+        },
+    ));
+    let stats_service = Arc::new(StatsService::new(todo_repository, settings_service.clone()));
+    let jwt_service = Arc::new(JwtService::default());
+
+    // Background workers
+    reminder_service.clone().start_dispatcher();
+    todo_service
+        .clone()
+        .start_rebalancer(Duration::from_secs(config.position_rebalance_interval_secs));
+    todo_service.clone().start_trash_purger(
+        chrono::Duration::days(config.trash_retention_days),
+        Duration::from_secs(config.trash_purge_interval_secs),
+    );
+    todo_service
+        .clone()
+        .start_auto_archiver(Duration::from_secs(config.auto_archive_interval_secs));
+    attachment_service.clone().start_blob_cleaner();
 
     // Initialize handlers
     let health_handler = Arc::new(HealthHandler::new(health_service));
     let auth_handler = Arc::new(AuthHandler::new(user_service));
+    let todo_handler = Arc::new(TodoHandler::new(todo_service));
+    let category_handler = Arc::new(CategoryHandler::new(category_service));
+    let checklist_handler = Arc::new(ChecklistHandler::new(checklist_service));
+    let recurrence_handler = Arc::new(RecurrenceHandler::new(recurrence_service.clone()));
+    let reminder_handler = Arc::new(ReminderHandler::new(reminder_service));
+    let dependency_handler = Arc::new(DependencyHandler::new(dependency_service));
+    let bulk_handler = Arc::new(BulkHandler::new(bulk_service));
+    let quick_add_handler = Arc::new(QuickAddHandler::new(quick_add_service));
+    let smart_list_handler = Arc::new(SmartListHandler::new(smart_list_service));
+    let template_handler = Arc::new(TemplateHandler::new(template_service));
+    let time_tracking_handler = Arc::new(TimeTrackingHandler::new(time_tracking_service));
+    let board_handler = Arc::new(BoardHandler::new(board_service));
+    let attachment_handler = Arc::new(AttachmentHandler::new(attachment_service));
+    let comment_handler = Arc::new(CommentHandler::new(comment_service));
+    let workspace_handler = Arc::new(WorkspaceHandler::new(workspace_service));
+    let assignment_handler = Arc::new(AssignmentHandler::new(assignment_service));
+    let share_link_handler = Arc::new(ShareLinkHandler::new(share_link_service));
+    let stats_handler = Arc::new(StatsHandler::new(stats_service));
+    let settings_handler = Arc::new(UserSettingsHandler::new(settings_service));
+
+    // Protected routes (JWT required)
+    let todo_routes = Router::new()
+        .route("/api/v1/todos", post(TodoHandler::create_todo).get(TodoHandler::list_todos))
+        .route("/api/v1/todos/search", get(TodoHandler::search_todos))
+        .route("/api/v1/todos/assigned", get(TodoHandler::list_assigned_todos))
+        .route("/api/v1/todos/trash", get(TodoHandler::list_trash))
+        .route(
+            "/api/v1/todos/:id",
+            get(TodoHandler::get_todo)
+                .put(TodoHandler::update_todo)
+                .delete(TodoHandler::delete_todo),
+        )
+        .route("/api/v1/todos/:id/status", patch(TodoHandler::update_status))
+        .route("/api/v1/todos/:id/move", post(TodoHandler::move_todo))
+        .route("/api/v1/todos/:id/archive", post(TodoHandler::archive_todo))
+        .route("/api/v1/todos/:id/unarchive", post(TodoHandler::unarchive_todo))
+        .route("/api/v1/todos/:id/restore", post(TodoHandler::restore_todo))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(todo_handler);
+
+    let category_routes = Router::new()
+        .route(
+            "/api/v1/categories",
+            get(CategoryHandler::list_categories).post(CategoryHandler::create_category),
+        )
+        .route(
+            "/api/v1/categories/:id",
+            get(CategoryHandler::get_category)
+                .put(CategoryHandler::update_category)
+                .delete(CategoryHandler::delete_category),
+        )
+        .route(
+            "/api/v1/todos/:id/categories",
+            get(CategoryHandler::list_todo_categories).post(CategoryHandler::assign_categories),
+        )
+        .route("/api/v1/todos/:id/categories/:category_id", delete(CategoryHandler::unassign_category))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(category_handler);
+
+    let checklist_routes = Router::new()
+        .route(
+            "/api/v1/todos/:id/checklist",
+            get(ChecklistHandler::list_items)
+                .post(ChecklistHandler::add_item)
+                .put(ChecklistHandler::reorder_items),
+        )
+        .route(
+            "/api/v1/todos/:id/checklist/:item_id",
+            patch(ChecklistHandler::update_item).delete(ChecklistHandler::delete_item),
+        )
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(checklist_handler);
+
+    let recurrence_routes = Router::new()
+        .route(
+            "/api/v1/todos/:id/recurrence",
+            get(RecurrenceHandler::get_recurrence)
+                .put(RecurrenceHandler::set_recurrence)
+                .delete(RecurrenceHandler::stop_recurrence),
+        )
+        .route("/api/v1/todos/:id/occurrence", put(RecurrenceHandler::update_occurrence))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(recurrence_handler);
+
+    let reminder_routes = Router::new()
+        .route(
+            "/api/v1/todos/:id/reminders",
+            get(ReminderHandler::list_reminders).post(ReminderHandler::add_reminder),
+        )
+        .route("/api/v1/todos/:id/reminders/:reminder_id", delete(ReminderHandler::delete_reminder))
+        .route("/api/v1/todos/:id/reminders/:reminder_id/snooze", post(ReminderHandler::snooze_reminder))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(reminder_handler);
+
+    let dependency_routes = Router::new()
+        .route(
+            "/api/v1/todos/:id/dependencies",
+            get(DependencyHandler::list_dependencies).post(DependencyHandler::add_dependency),
+        )
+        .route(
+            "/api/v1/todos/:id/dependencies/:blocked_by_id",
+            delete(DependencyHandler::remove_dependency),
+        )
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(dependency_handler);
+
+    let bulk_routes = Router::new()
+        .route("/api/v1/todos/bulk", post(BulkHandler::bulk_todos))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(bulk_handler);
+
+    let quick_add_routes = Router::new()
+        .route("/api/v1/todos/quick", post(QuickAddHandler::quick_add))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(quick_add_handler);
+
+    let smart_list_routes = Router::new()
+        .route(
+            "/api/v1/smart-lists",
+            get(SmartListHandler::list_smart_lists).post(SmartListHandler::create_smart_list),
+        )
+        .route(
+            "/api/v1/smart-lists/:id",
+            get(SmartListHandler::get_smart_list)
+                .put(SmartListHandler::update_smart_list)
+                .delete(SmartListHandler::delete_smart_list),
+        )
+        .route("/api/v1/smart-lists/:id/todos", get(SmartListHandler::list_todos))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(smart_list_handler);
+
+    let template_routes = Router::new()
+        .route(
+            "/api/v1/templates",
+            get(TemplateHandler::list_templates).post(TemplateHandler::create_template),
+        )
+        .route(
+            "/api/v1/templates/:id",
+            get(TemplateHandler::get_template)
+                .put(TemplateHandler::update_template)
+                .delete(TemplateHandler::delete_template),
+        )
+        .route("/api/v1/templates/:id/instantiate", post(TemplateHandler::instantiate))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(template_handler);
+
+    let time_tracking_routes = Router::new()
+        .route("/api/v1/timer", get(TimeTrackingHandler::current_timer))
+        .route("/api/v1/timer/stop", post(TimeTrackingHandler::stop_timer))
+        .route("/api/v1/todos/:id/timer", post(TimeTrackingHandler::start_timer))
+        .route(
+            "/api/v1/todos/:id/time-entries",
+            get(TimeTrackingHandler::list_entries).post(TimeTrackingHandler::add_entry),
+        )
+        .route(
+            "/api/v1/todos/:id/time-entries/:entry_id",
+            put(TimeTrackingHandler::update_entry).delete(TimeTrackingHandler::delete_entry),
+        )
+        .route("/api/v1/time-reports", get(TimeTrackingHandler::report))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(time_tracking_handler);
+
+    let board_routes = Router::new()
+        .route("/api/v1/boards", get(BoardHandler::list_boards).post(BoardHandler::create_board))
+        .route(
+            "/api/v1/boards/:id",
+            get(BoardHandler::get_board)
+                .put(BoardHandler::update_board)
+                .delete(BoardHandler::delete_board),
+        )
+        .route("/api/v1/boards/:id/columns", post(BoardHandler::add_column))
+        .route("/api/v1/boards/:id/columns/order", put(BoardHandler::reorder_columns))
+        .route(
+            "/api/v1/boards/:id/columns/:column_id",
+            put(BoardHandler::update_column).delete(BoardHandler::delete_column),
+        )
+        .route("/api/v1/boards/:id/cards", get(BoardHandler::get_cards))
+        .route("/api/v1/boards/:id/cards/:todo_id/move", post(BoardHandler::move_card))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(board_handler);
+
+    let attachment_upload_limit = attachment_handler.body_limit();
+    let attachment_routes = Router::new()
+        .route(
+            "/api/v1/todos/:id/attachments",
+            get(AttachmentHandler::list)
+                .post(AttachmentHandler::upload)
+                .layer(DefaultBodyLimit::max(attachment_upload_limit)),
+        )
+        .route("/api/v1/todos/:id/attachments/:attachment_id", delete(AttachmentHandler::delete))
+        .route("/api/v1/todos/:id/attachments/:attachment_id/url", get(AttachmentHandler::download_url))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(attachment_handler.clone());
+
+    // Signed download URLs carry their own authorization
+    let attachment_content_routes = Router::new()
+        .route("/api/v1/attachments/:id/content", get(AttachmentHandler::content))
+        .with_state(attachment_handler);
+
+    let comment_routes = Router::new()
+        .route(
+            "/api/v1/todos/:id/comments",
+            get(CommentHandler::list_comments).post(CommentHandler::create_comment),
+        )
+        .route(
+            "/api/v1/todos/:id/comments/:comment_id",
+            put(CommentHandler::update_comment).delete(CommentHandler::delete_comment),
+        )
+        .route("/api/v1/todos/:id/comments/:comment_id/revisions", get(CommentHandler::list_revisions))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(comment_handler);
+
+    let workspace_routes = Router::new()
+        .route(
+            "/api/v1/workspaces",
+            get(WorkspaceHandler::list_workspaces).post(WorkspaceHandler::create_workspace),
+        )
+        .route(
+            "/api/v1/workspaces/:id",
+            get(WorkspaceHandler::get_workspace)
+                .put(WorkspaceHandler::update_workspace)
+                .delete(WorkspaceHandler::delete_workspace),
+        )
+        .route("/api/v1/workspaces/:id/members", get(WorkspaceHandler::list_members))
+        .route(
+            "/api/v1/workspaces/:id/members/:user_id",
+            put(WorkspaceHandler::update_member_role).delete(WorkspaceHandler::remove_member),
+        )
+        .route(
+            "/api/v1/workspaces/:id/invitations",
+            get(WorkspaceHandler::list_invitations).post(WorkspaceHandler::create_invitation),
+        )
+        .route(
+            "/api/v1/workspaces/:id/invitations/:invitation_id",
+            delete(WorkspaceHandler::revoke_invitation),
+        )
+        .route("/api/v1/invitations/accept", post(WorkspaceHandler::accept_invitation))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(workspace_handler.clone());
+
+    // The emailed token is enough to decline an invitation
+    let invitation_public_routes = Router::new()
+        .route("/api/v1/invitations/decline", post(WorkspaceHandler::decline_invitation))
+        .with_state(workspace_handler);
+
+    let assignment_routes = Router::new()
+        .route(
+            "/api/v1/todos/:id/assignees",
+            get(AssignmentHandler::list_assignees).post(AssignmentHandler::assign),
+        )
+        .route("/api/v1/todos/:id/assignees/:user_id", delete(AssignmentHandler::unassign))
+        .route("/api/v1/workspaces/:id/workload", get(AssignmentHandler::workload))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(assignment_handler);
+
+    let share_link_routes = Router::new()
+        .route("/api/v1/share-links", get(ShareLinkHandler::list).post(ShareLinkHandler::create))
+        .route("/api/v1/share-links/:id", delete(ShareLinkHandler::revoke))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(share_link_handler.clone());
+
+    // Anyone holding the token can read what the link exposes
+    let shared_public_routes = Router::new()
+        .route("/api/v1/shared/:token", get(ShareLinkHandler::view))
+        .with_state(share_link_handler);
+
+    let stats_routes = Router::new()
+        .route("/api/v1/todos/stats", get(StatsHandler::todo_stats))
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(stats_handler);
+
+    let settings_routes = Router::new()
+        .route(
+            "/api/v1/users/settings",
+            get(UserSettingsHandler::get_settings).put(UserSettingsHandler::update_settings),
+        )
+        .route_layer(middleware::from_fn_with_state(jwt_service.clone(), auth_middleware))
+        .with_state(settings_handler);
 
     // Build the application router
     let app = Router::new()
@@ -104,6 +892,28 @@ async fn main() {
         .route("/api/v1/auth/login", post(AuthHandler::login))
         .route("/api/v1/auth/logout", post(AuthHandler::logout))
         .with_state(auth_handler)
+        .merge(todo_routes)
+        .merge(category_routes)
+        .merge(checklist_routes)
+        .merge(recurrence_routes)
+        .merge(reminder_routes)
+        .merge(dependency_routes)
+        .merge(bulk_routes)
+        .merge(quick_add_routes)
+        .merge(smart_list_routes)
+        .merge(template_routes)
+        .merge(time_tracking_routes)
+        .merge(board_routes)
+        .merge(attachment_routes)
+        .merge(attachment_content_routes)
+        .merge(comment_routes)
+        .merge(workspace_routes)
+        .merge(invitation_public_routes)
+        .merge(assignment_routes)
+        .merge(share_link_routes)
+        .merge(shared_public_routes)
+        .merge(stats_routes)
+        .merge(settings_routes)
         .merge(
             SwaggerUi::new("/swagger-ui")
                 .url("/api-docs/openapi.json", ApiDoc::openapi())
@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Client;
+use serde::Serialize;
+use super::{Email, Mailer, NotifyError};
+
+#[derive(Debug, Clone)]
+pub struct HttpMailerConfig {
+    /// 메일 발송 API 주소. 예: 사내 메일 릴레이의 `https://mail.internal/api/send`
+    pub url: String,
+    /// `Authorization: Bearer` 헤더로 보내는 API 키
+    pub api_key: String,
+    /// 보내는 사람 주소
+    pub from: String,
+}
+
+/// JSON 본문(`from`, `to`, `subject`, `text`)을 받는 HTTP 메일 발송 API로 메일을 보낸다.
+#[derive(Debug, Clone)]
+pub struct HttpMailer {
+    client: Client,
+    config: HttpMailerConfig,
+}
+
+#[derive(Serialize)]
+struct SendRequest<'a> {
+    from: &'a str,
+    to: &'a str,
+    subject: &'a str,
+    text: &'a str,
+}
+
+impl HttpMailer {
+    pub fn new(config: HttpMailerConfig) -> Self {
+        Self {
+            client: Client::new(),
+            config,
+        }
+    }
+}
+
+#[async_trait]
+impl Mailer for HttpMailer {
+    async fn send(&self, email: &Email) -> Result<(), NotifyError> {
+        let payload = serde_json::to_vec(&SendRequest {
+            from: &self.config.from,
+            to: &email.to,
+            subject: &email.subject,
+            text: &email.body,
+        })
+        .map_err(|err| NotifyError::Delivery(err.to_string()))?;
+
+        let response = self
+            .client
+            .post(&self.config.url)
+            .bearer_auth(&self.config.api_key)
+            .header(CONTENT_TYPE, "application/json")
+            .body(payload)
+            .send()
+            .await
+            .map_err(|err| NotifyError::Delivery(err.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            return Err(NotifyError::Delivery(format!(
+                "메일 API 응답 {}: {}",
+                status,
+                body.chars().take(200).collect::<String>()
+            )));
+        }
+        Ok(())
+    }
+}
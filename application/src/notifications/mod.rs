@@ -0,0 +1,126 @@
+//! Notifications module
+//!
+//! Delivers user-facing notifications through a pluggable [`Notifier`], and
+//! emails to addresses that may not belong to a user yet through a [`Mailer`].
+//! Implementations (SMTP, push, webhook, ...) can be swapped in `main.rs`
+//! without touching the services that produce notifications.
+//! Emails are only logged by default; an HTTP mail API backend can be
+//! selected in `main.rs` via configuration.
+
+pub mod http;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use thiserror::Error;
+use uuid::Uuid;
+
+pub use http::{HttpMailer, HttpMailerConfig};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationKind {
+    /// 마감 알림
+    Reminder,
+    /// 댓글에서 언급됨
+    Mention,
+    /// 할일 담당자로 지정됨
+    Assignment,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Notification {
+    /// 같은 알림을 다시 보낼 때도 바뀌지 않는 ID. 수신 측 중복 제거 키로 쓴다.
+    pub id: Uuid,
+    pub kind: NotificationKind,
+    pub user_id: Uuid,
+    pub todo_id: Option<Uuid>,
+    pub title: String,
+    pub body: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Error, Debug)]
+pub enum NotifyError {
+    #[error("알림 전송 실패: {0}")]
+    Delivery(String),
+}
+
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, notification: &Notification) -> Result<(), NotifyError>;
+}
+
+/// Writes notifications to the application log. Used when no other channel is configured.
+#[derive(Debug, Default, Clone)]
+pub struct LogNotifier;
+
+#[async_trait]
+impl Notifier for LogNotifier {
+    async fn notify(&self, notification: &Notification) -> Result<(), NotifyError> {
+        tracing::info!(
+            notification_id = %notification.id,
+            kind = ?notification.kind,
+            user_id = %notification.user_id,
+            title = %notification.title,
+            body = %notification.body,
+            "notification delivered"
+        );
+        Ok(())
+    }
+}
+
+/// 사용자 계정이 없을 수도 있는 주소로 보내는 메일 (예: 워크스페이스 초대)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Email {
+    pub to: String,
+    pub subject: String,
+    pub body: String,
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: &Email) -> Result<(), NotifyError>;
+}
+
+/// Writes emails to the application log. Used when no mail server is configured.
+///
+/// 본문에는 초대 토큰 같은 비밀이 들어가므로 받는 사람과 제목만 남긴다.
+#[derive(Debug, Default, Clone)]
+pub struct LogMailer;
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, email: &Email) -> Result<(), NotifyError> {
+        tracing::info!(
+            to = %email.to,
+            subject = %email.subject,
+            "email delivered (body not logged)"
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub Notifier {}
+
+        #[async_trait]
+        impl Notifier for Notifier {
+            async fn notify(&self, notification: &Notification) -> Result<(), NotifyError>;
+        }
+    }
+
+    mock! {
+        pub Mailer {}
+
+        #[async_trait]
+        impl Mailer for Mailer {
+            async fn send(&self, email: &Email) -> Result<(), NotifyError>;
+        }
+    }
+}
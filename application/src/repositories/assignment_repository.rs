@@ -0,0 +1,190 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::entities::assignment::{AssignOutcome, MemberWorkload, TodoAssignee, MAX_ASSIGNEES_PER_TODO};
+use crate::error::ApiError;
+
+/// `a`(todo_assignees)와 `u`(users)를 조인한 결과에서 읽는 열
+const ASSIGNEE_COLUMNS: &str = "a.todo_id, a.user_id, u.username, u.email, a.assigned_by, a.created_at";
+
+/// 할일 담당자. 호출 측에서 할일 권한을 확인한 뒤 사용한다.
+#[async_trait]
+pub trait AssignmentRepository: Send + Sync {
+    /// 지정한 순서대로
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<TodoAssignee>, ApiError>;
+    /// 할일의 워크스페이스 멤버만 지정한다. 이미 담당자인 사용자는 그대로 둔다.
+    async fn assign(&self, todo_id: Uuid, user_ids: &[Uuid], assigned_by: Uuid) -> Result<AssignOutcome, ApiError>;
+    async fn unassign(&self, todo_id: Uuid, user_id: Uuid) -> Result<bool, ApiError>;
+    /// 워크스페이스 멤버별 담당 할일 수 (담당 할일이 없는 멤버 포함, 많은 순서)
+    async fn workload(&self, workspace_id: Uuid, now: DateTime<Utc>) -> Result<Vec<MemberWorkload>, ApiError>;
+    /// 담당자가 없는 미완료 할일 수 (보관, 휴지통 제외)
+    async fn count_unassigned(&self, workspace_id: Uuid) -> Result<i64, ApiError>;
+}
+
+pub struct PostgresAssignmentRepository {
+    pool: PgPool,
+}
+
+impl PostgresAssignmentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AssignmentRepository for PostgresAssignmentRepository {
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<TodoAssignee>, ApiError> {
+        let assignees = sqlx::query_as::<_, TodoAssignee>(&format!(
+            r#"
+            SELECT {ASSIGNEE_COLUMNS}
+            FROM todo_assignees a LEFT JOIN users u ON u.id = a.user_id
+            WHERE a.todo_id = $1
+            ORDER BY a.created_at ASC, a.user_id ASC
+            "#
+        ))
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(assignees)
+    }
+
+    async fn assign(&self, todo_id: Uuid, user_ids: &[Uuid], assigned_by: Uuid) -> Result<AssignOutcome, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 동시에 지정해도 담당자 수 제한을 넘지 않도록 할일 행을 잠근다
+        let workspace_id: Uuid = sqlx::query_scalar("SELECT workspace_id FROM todos WHERE id = $1 FOR UPDATE")
+            .bind(todo_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+        let outsiders: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            SELECT t.user_id FROM UNNEST($2::uuid[]) AS t(user_id)
+            WHERE NOT EXISTS (
+                SELECT 1 FROM workspace_members m WHERE m.workspace_id = $1 AND m.user_id = t.user_id
+            )
+            "#,
+        )
+        .bind(workspace_id)
+        .bind(user_ids)
+        .fetch_all(&mut *tx)
+        .await?;
+        if !outsiders.is_empty() {
+            return Ok(AssignOutcome::NotMembers(outsiders));
+        }
+
+        let total: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM (
+                SELECT user_id FROM todo_assignees WHERE todo_id = $1
+                UNION
+                SELECT user_id FROM UNNEST($2::uuid[]) AS t(user_id)
+            ) AS combined
+            "#,
+        )
+        .bind(todo_id)
+        .bind(user_ids)
+        .fetch_one(&mut *tx)
+        .await?;
+        if total > MAX_ASSIGNEES_PER_TODO as i64 {
+            return Ok(AssignOutcome::TooMany);
+        }
+
+        let assigned: Vec<Uuid> = sqlx::query_scalar(
+            r#"
+            INSERT INTO todo_assignees (todo_id, user_id, assigned_by)
+            SELECT $1, user_id, $3 FROM UNNEST($2::uuid[]) AS t(user_id)
+            ON CONFLICT (todo_id, user_id) DO NOTHING
+            RETURNING user_id
+            "#,
+        )
+        .bind(todo_id)
+        .bind(user_ids)
+        .bind(assigned_by)
+        .fetch_all(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(AssignOutcome::Assigned(assigned))
+    }
+
+    async fn unassign(&self, todo_id: Uuid, user_id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM todo_assignees WHERE todo_id = $1 AND user_id = $2")
+            .bind(todo_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn workload(&self, workspace_id: Uuid, now: DateTime<Utc>) -> Result<Vec<MemberWorkload>, ApiError> {
+        let workload = sqlx::query_as::<_, MemberWorkload>(
+            r#"
+            SELECT
+                m.user_id,
+                u.username,
+                m.role,
+                COUNT(t.id) AS open_todos,
+                COUNT(t.id) FILTER (WHERE t.status = 'in_progress') AS in_progress_todos,
+                COUNT(t.id) FILTER (WHERE t.due_date < $2) AS overdue_todos
+            FROM workspace_members m
+            LEFT JOIN users u ON u.id = m.user_id
+            LEFT JOIN todo_assignees a ON a.user_id = m.user_id
+            LEFT JOIN todos t ON t.id = a.todo_id
+                AND t.workspace_id = m.workspace_id
+                AND t.status != 'completed'
+                AND t.archived_at IS NULL
+                AND t.deleted_at IS NULL
+            WHERE m.workspace_id = $1
+            GROUP BY m.user_id, u.username, m.role, m.created_at
+            ORDER BY open_todos DESC, m.created_at ASC, m.user_id ASC
+            "#,
+        )
+        .bind(workspace_id)
+        .bind(now)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(workload)
+    }
+
+    async fn count_unassigned(&self, workspace_id: Uuid) -> Result<i64, ApiError> {
+        let count: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM todos t
+            WHERE t.workspace_id = $1
+              AND t.status != 'completed'
+              AND t.archived_at IS NULL
+              AND t.deleted_at IS NULL
+              AND NOT EXISTS (SELECT 1 FROM todo_assignees a WHERE a.todo_id = t.id)
+            "#,
+        )
+        .bind(workspace_id)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub AssignmentRepository {}
+
+        #[async_trait]
+        impl AssignmentRepository for AssignmentRepository {
+            async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<TodoAssignee>, ApiError>;
+            async fn assign(&self, todo_id: Uuid, user_ids: &[Uuid], assigned_by: Uuid) -> Result<AssignOutcome, ApiError>;
+            async fn unassign(&self, todo_id: Uuid, user_id: Uuid) -> Result<bool, ApiError>;
+            async fn workload(&self, workspace_id: Uuid, now: DateTime<Utc>) -> Result<Vec<MemberWorkload>, ApiError>;
+            async fn count_unassigned(&self, workspace_id: Uuid) -> Result<i64, ApiError>;
+        }
+    }
+}
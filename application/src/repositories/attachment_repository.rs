@@ -0,0 +1,189 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::entities::attachment::{Attachment, NewAttachment};
+use crate::error::ApiError;
+use crate::repositories::workspace_repository::editable_workspaces;
+
+const ATTACHMENT_COLUMNS: &str = "id, user_id, todo_id, file_name, content_type, size_bytes, sha256, storage_key, created_at";
+
+/// 조회와 삭제는 할일 단위로만 다룬다. 호출 측에서 할일의 권한을 확인한 뒤 사용한다.
+///
+/// 행을 지우면 트리거가 저장소 키를 `blob_deletions`에 넣는다.
+/// 실제 파일은 백그라운드 작업이 [`claim_blob_deletions`](AttachmentRepository::claim_blob_deletions)로 가져가 지운다.
+#[async_trait]
+pub trait AttachmentRepository: Send + Sync {
+    /// 올린 사용자가 수정할 수 없는 할일이면 `NotFound`, 할일에 붙은 파일이 `max_per_todo`개 이상이면 `Conflict`
+    async fn create(&self, attachment: NewAttachment, max_per_todo: i64) -> Result<Attachment, ApiError>;
+    async fn find_by_id(&self, todo_id: Uuid, id: Uuid) -> Result<Option<Attachment>, ApiError>;
+    /// 서명된 다운로드 URL용. 서명이 소유자 확인을 대신한다.
+    async fn find(&self, id: Uuid) -> Result<Option<Attachment>, ApiError>;
+    /// 올린 순서대로
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<Attachment>, ApiError>;
+    async fn delete(&self, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// 지울 차례가 된 저장소 키를 최대 `limit`개 가져온다.
+    /// 가져간 키는 실패에 대비해 다음 시도 시각을 뒤로 미뤄 둔다.
+    async fn claim_blob_deletions(&self, limit: i64) -> Result<Vec<String>, ApiError>;
+    /// 파일을 지운 키를 큐에서 뺀다
+    async fn finish_blob_deletion(&self, storage_key: &str) -> Result<(), ApiError>;
+}
+
+pub struct PostgresAttachmentRepository {
+    pool: PgPool,
+}
+
+impl PostgresAttachmentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl AttachmentRepository for PostgresAttachmentRepository {
+    async fn create(&self, attachment: NewAttachment, max_per_todo: i64) -> Result<Attachment, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 할일 행을 잠가 동시에 올린 파일이 개수 제한을 함께 넘지 않게 한다
+        let todo = sqlx::query_scalar::<_, Uuid>(&format!(
+            "SELECT id FROM todos WHERE id = $1 AND workspace_id IN ({}) AND deleted_at IS NULL FOR UPDATE",
+            editable_workspaces(2)
+        ))
+        .bind(attachment.todo_id)
+        .bind(attachment.user_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        if todo.is_none() {
+            return Err(ApiError::NotFound("할일을 찾을 수 없습니다".to_string()));
+        }
+
+        let count = sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM attachments WHERE todo_id = $1")
+            .bind(attachment.todo_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        if count >= max_per_todo {
+            return Err(ApiError::Conflict(format!(
+                "할일 하나에 파일은 최대 {}개까지 첨부할 수 있습니다",
+                max_per_todo
+            )));
+        }
+
+        let created = sqlx::query_as::<_, Attachment>(&format!(
+            r#"
+            INSERT INTO attachments (id, user_id, todo_id, file_name, content_type, size_bytes, sha256, storage_key)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            RETURNING {ATTACHMENT_COLUMNS}
+            "#
+        ))
+        .bind(attachment.id)
+        .bind(attachment.user_id)
+        .bind(attachment.todo_id)
+        .bind(attachment.file_name)
+        .bind(attachment.content_type)
+        .bind(attachment.size_bytes)
+        .bind(attachment.sha256)
+        .bind(attachment.storage_key)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, todo_id: Uuid, id: Uuid) -> Result<Option<Attachment>, ApiError> {
+        let attachment = sqlx::query_as::<_, Attachment>(&format!(
+            "SELECT {ATTACHMENT_COLUMNS} FROM attachments WHERE id = $1 AND todo_id = $2"
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(attachment)
+    }
+
+    async fn find(&self, id: Uuid) -> Result<Option<Attachment>, ApiError> {
+        let attachment = sqlx::query_as::<_, Attachment>(&format!(
+            "SELECT {ATTACHMENT_COLUMNS} FROM attachments WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(attachment)
+    }
+
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<Attachment>, ApiError> {
+        let attachments = sqlx::query_as::<_, Attachment>(&format!(
+            "SELECT {ATTACHMENT_COLUMNS} FROM attachments WHERE todo_id = $1 ORDER BY created_at ASC, id ASC"
+        ))
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(attachments)
+    }
+
+    async fn delete(&self, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM attachments WHERE id = $1 AND todo_id = $2")
+            .bind(id)
+            .bind(todo_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn claim_blob_deletions(&self, limit: i64) -> Result<Vec<String>, ApiError> {
+        // 실패할수록 다시 시도하는 간격을 늘린다 (최대 1시간)
+        let keys = sqlx::query_scalar::<_, String>(
+            r#"
+            UPDATE blob_deletions SET
+                attempts = attempts + 1,
+                next_attempt_at = NOW() + LEAST(INTERVAL '1 minute' * POWER(2, attempts), INTERVAL '1 hour')
+            WHERE storage_key IN (
+                SELECT storage_key FROM blob_deletions
+                WHERE next_attempt_at <= NOW()
+                ORDER BY next_attempt_at
+                LIMIT $1
+                FOR UPDATE SKIP LOCKED
+            )
+            RETURNING storage_key
+            "#,
+        )
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(keys)
+    }
+
+    async fn finish_blob_deletion(&self, storage_key: &str) -> Result<(), ApiError> {
+        sqlx::query("DELETE FROM blob_deletions WHERE storage_key = $1")
+            .bind(storage_key)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub AttachmentRepository {}
+
+        #[async_trait]
+        impl AttachmentRepository for AttachmentRepository {
+            async fn create(&self, attachment: NewAttachment, max_per_todo: i64) -> Result<Attachment, ApiError>;
+            async fn find_by_id(&self, todo_id: Uuid, id: Uuid) -> Result<Option<Attachment>, ApiError>;
+            async fn find(&self, id: Uuid) -> Result<Option<Attachment>, ApiError>;
+            async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<Attachment>, ApiError>;
+            async fn delete(&self, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn claim_blob_deletions(&self, limit: i64) -> Result<Vec<String>, ApiError>;
+            async fn finish_blob_deletion(&self, storage_key: &str) -> Result<(), ApiError>;
+        }
+    }
+}
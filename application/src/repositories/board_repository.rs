@@ -0,0 +1,453 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use crate::entities::board::{
+    plan_move, place_card, Board, BoardCard, BoardColumn, BoardColumnSpec, BoardWithColumns, CardMove, MovedCard,
+    NewBoard,
+};
+use crate::entities::todo::{StatusTransition, Todo, TodoStatus};
+use crate::error::ApiError;
+use crate::repositories::todo_repository::{lock_positions, OPEN_BLOCKERS, TODO_COLUMNS};
+use crate::repositories::todo_series_repository::create_next_occurrence;
+use crate::repositories::workspace_repository::editable_workspaces;
+
+const BOARD_COLUMNS: &str = "id, user_id, name, created_at, updated_at";
+const COLUMN_COLUMNS: &str = "id, board_id, position, name, status, wip_limit, created_at, updated_at";
+
+/// `$1` 보드에서 `$2` 사용자가 볼 수 있는 활성 할일(보관, 휴지통 제외)이 놓인 열.
+/// 직접 옮긴 자리가 유효하면 그 열, 아니면 상태가 같은 첫 번째 열이다. 놓일 열이 없으면 `column_id`가 NULL이다.
+const CARD_PLACEMENTS: &str = r#"
+    SELECT t.id AS todo_id,
+           COALESCE(
+               placed.column_id,
+               (SELECT c.id FROM board_columns c WHERE c.board_id = $1 AND c.status = t.status ORDER BY c.position LIMIT 1)
+           ) AS column_id,
+           placed.position AS card_position
+    FROM todos t
+    LEFT JOIN LATERAL (
+        SELECT bc.column_id, bc.position
+        FROM board_cards bc
+        JOIN board_columns c ON c.id = bc.column_id
+        WHERE bc.board_id = $1 AND bc.todo_id = t.id AND (c.status IS NULL OR c.status = t.status)
+    ) placed ON TRUE
+    WHERE t.workspace_id IN (SELECT workspace_id FROM workspace_members WHERE user_id = $2)
+      AND t.deleted_at IS NULL AND t.archived_at IS NULL
+"#;
+
+/// 보드와 열은 소유자(user_id) 범위 안에서만 다룬다.
+/// 보드에는 참여한 모든 워크스페이스의 할일이 놓이고, 카드를 옮기려면 할일을 수정할 수 있어야 한다.
+#[async_trait]
+pub trait BoardRepository: Send + Sync {
+    async fn create(&self, board: NewBoard) -> Result<BoardWithColumns, ApiError>;
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<BoardWithColumns>, ApiError>;
+    async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<Board>, ApiError>;
+    /// 이름순
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<BoardWithColumns>, ApiError>;
+    async fn rename(&self, user_id: Uuid, id: Uuid, name: &str) -> Result<Option<BoardWithColumns>, ApiError>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// 맨 뒤에 열을 추가한다
+    async fn add_column(&self, user_id: Uuid, board_id: Uuid, column: BoardColumnSpec) -> Result<Option<BoardColumn>, ApiError>;
+    async fn update_column(&self, user_id: Uuid, board_id: Uuid, id: Uuid, column: BoardColumnSpec) -> Result<Option<BoardColumn>, ApiError>;
+    /// 열에 옮겨 둔 카드는 상태의 기본 열로 돌아간다
+    async fn delete_column(&self, user_id: Uuid, board_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// `column_ids`는 보드의 열 전체를 새 순서대로 담아야 한다
+    async fn reorder_columns(&self, user_id: Uuid, board_id: Uuid, column_ids: &[Uuid]) -> Result<Option<BoardWithColumns>, ApiError>;
+    /// 열에 놓인 카드 전체. 열마다 정렬 키 순이고, 옮긴 적 없는 카드는 할일 목록 순서대로 뒤에 붙는다.
+    async fn find_cards(&self, user_id: Uuid, board_id: Uuid) -> Result<Vec<BoardCard>, ApiError>;
+    /// WIP 제한 확인, 상태 전이, 자리 지정을 한 트랜잭션에서 처리한다.
+    /// 열이나 할일(보관, 휴지통 제외)이 없으면 `None`.
+    async fn move_card(&self, user_id: Uuid, board_id: Uuid, card_move: CardMove) -> Result<Option<MovedCard>, ApiError>;
+}
+
+pub struct PostgresBoardRepository {
+    pool: PgPool,
+}
+
+impl PostgresBoardRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn columns(&self, board_ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<BoardColumn>>, ApiError> {
+        let columns = sqlx::query_as::<_, BoardColumn>(&format!(
+            "SELECT {COLUMN_COLUMNS} FROM board_columns WHERE board_id = ANY($1) ORDER BY position ASC"
+        ))
+        .bind(board_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut by_board: HashMap<Uuid, Vec<BoardColumn>> = HashMap::new();
+        for column in columns {
+            by_board.entry(column.board_id).or_default().push(column);
+        }
+
+        Ok(by_board)
+    }
+
+    async fn with_columns(&self, board: Option<Board>) -> Result<Option<BoardWithColumns>, ApiError> {
+        let Some(board) = board else {
+            return Ok(None);
+        };
+        let columns = self.columns(&[board.id]).await?.remove(&board.id).unwrap_or_default();
+
+        Ok(Some(BoardWithColumns { board, columns }))
+    }
+}
+
+async fn cards(conn: &mut PgConnection, user_id: Uuid, board_id: Uuid) -> Result<Vec<BoardCard>, ApiError> {
+    let cards = sqlx::query_as::<_, BoardCard>(&format!(
+        r#"
+        WITH cards AS ({CARD_PLACEMENTS})
+        SELECT {TODO_COLUMNS}, cards.column_id, cards.card_position
+        FROM todos JOIN cards ON cards.todo_id = todos.id
+        WHERE cards.column_id IS NOT NULL
+        ORDER BY cards.card_position ASC NULLS LAST, todos.position ASC NULLS LAST, todos.created_at ASC, todos.id ASC
+        "#
+    ))
+    .bind(board_id)
+    .bind(user_id)
+    .fetch_all(conn)
+    .await?;
+
+    Ok(cards)
+}
+
+async fn update_status(conn: &mut PgConnection, todo: &Todo, transition: &StatusTransition) -> Result<Todo, ApiError> {
+    let updated = sqlx::query_as::<_, Todo>(&format!(
+        "UPDATE todos SET status = $2, started_at = $3, completed_at = $4, updated_at = $5 \
+         WHERE id = $1 RETURNING {TODO_COLUMNS}"
+    ))
+    .bind(todo.id)
+    .bind(transition.to)
+    .bind(transition.started_at)
+    .bind(transition.completed_at)
+    .bind(transition.occurred_at)
+    .fetch_one(&mut *conn)
+    .await?;
+    create_next_occurrence(conn, &updated).await?;
+
+    Ok(updated)
+}
+
+#[async_trait]
+impl BoardRepository for PostgresBoardRepository {
+    async fn create(&self, board: NewBoard) -> Result<BoardWithColumns, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, Board>(&format!(
+            "INSERT INTO boards (id, user_id, name) VALUES ($1, $2, $3) RETURNING {BOARD_COLUMNS}"
+        ))
+        .bind(Uuid::new_v4())
+        .bind(board.user_id)
+        .bind(board.name)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let mut columns = Vec::with_capacity(board.columns.len());
+        for (position, column) in board.columns.into_iter().enumerate() {
+            let inserted = sqlx::query_as::<_, BoardColumn>(&format!(
+                r#"
+                INSERT INTO board_columns (id, board_id, position, name, status, wip_limit)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING {COLUMN_COLUMNS}
+                "#
+            ))
+            .bind(Uuid::new_v4())
+            .bind(created.id)
+            .bind(position as i32)
+            .bind(column.name)
+            .bind(column.status)
+            .bind(column.wip_limit)
+            .fetch_one(&mut *tx)
+            .await?;
+            columns.push(inserted);
+        }
+        tx.commit().await?;
+
+        Ok(BoardWithColumns { board: created, columns })
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<BoardWithColumns>, ApiError> {
+        let board = sqlx::query_as::<_, Board>(&format!(
+            "SELECT {BOARD_COLUMNS} FROM boards WHERE id = $1 AND user_id = $2"
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        self.with_columns(board).await
+    }
+
+    async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<Board>, ApiError> {
+        let board = sqlx::query_as::<_, Board>(&format!(
+            "SELECT {BOARD_COLUMNS} FROM boards WHERE user_id = $1 AND name = $2"
+        ))
+        .bind(user_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(board)
+    }
+
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<BoardWithColumns>, ApiError> {
+        let boards = sqlx::query_as::<_, Board>(&format!(
+            "SELECT {BOARD_COLUMNS} FROM boards WHERE user_id = $1 ORDER BY name ASC"
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let ids: Vec<Uuid> = boards.iter().map(|board| board.id).collect();
+        let mut columns = self.columns(&ids).await?;
+
+        Ok(boards
+            .into_iter()
+            .map(|board| {
+                let columns = columns.remove(&board.id).unwrap_or_default();
+                BoardWithColumns { board, columns }
+            })
+            .collect())
+    }
+
+    async fn rename(&self, user_id: Uuid, id: Uuid, name: &str) -> Result<Option<BoardWithColumns>, ApiError> {
+        let board = sqlx::query_as::<_, Board>(&format!(
+            "UPDATE boards SET name = $3 WHERE id = $1 AND user_id = $2 RETURNING {BOARD_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(user_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        self.with_columns(board).await
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM boards WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn add_column(&self, user_id: Uuid, board_id: Uuid, column: BoardColumnSpec) -> Result<Option<BoardColumn>, ApiError> {
+        let inserted = sqlx::query_as::<_, BoardColumn>(&format!(
+            r#"
+            INSERT INTO board_columns (id, board_id, position, name, status, wip_limit)
+            SELECT $1, b.id,
+                   COALESCE((SELECT MAX(position) + 1 FROM board_columns WHERE board_id = b.id), 0),
+                   $4, $5, $6
+            FROM boards b
+            WHERE b.id = $2 AND b.user_id = $3
+            RETURNING {COLUMN_COLUMNS}
+            "#
+        ))
+        .bind(Uuid::new_v4())
+        .bind(board_id)
+        .bind(user_id)
+        .bind(column.name)
+        .bind(column.status)
+        .bind(column.wip_limit)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(inserted)
+    }
+
+    async fn update_column(&self, user_id: Uuid, board_id: Uuid, id: Uuid, column: BoardColumnSpec) -> Result<Option<BoardColumn>, ApiError> {
+        let updated = sqlx::query_as::<_, BoardColumn>(&format!(
+            r#"
+            UPDATE board_columns SET name = $4, status = $5, wip_limit = $6
+            WHERE id = $1 AND board_id = $2
+              AND EXISTS (SELECT 1 FROM boards WHERE id = $2 AND user_id = $3)
+            RETURNING {COLUMN_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(board_id)
+        .bind(user_id)
+        .bind(column.name)
+        .bind(column.status)
+        .bind(column.wip_limit)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(updated)
+    }
+
+    async fn delete_column(&self, user_id: Uuid, board_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query(
+            r#"
+            DELETE FROM board_columns
+            WHERE id = $1 AND board_id = $2
+              AND EXISTS (SELECT 1 FROM boards WHERE id = $2 AND user_id = $3)
+            "#,
+        )
+        .bind(id)
+        .bind(board_id)
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await?;
+        if result.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        // 남은 열의 순서를 0부터 다시 매긴다
+        sqlx::query(
+            r#"
+            UPDATE board_columns c SET position = ordered.position
+            FROM (
+                SELECT id, (ROW_NUMBER() OVER (ORDER BY position) - 1)::int AS position
+                FROM board_columns WHERE board_id = $1
+            ) ordered
+            WHERE c.id = ordered.id AND c.position <> ordered.position
+            "#,
+        )
+        .bind(board_id)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(true)
+    }
+
+    async fn reorder_columns(&self, user_id: Uuid, board_id: Uuid, column_ids: &[Uuid]) -> Result<Option<BoardWithColumns>, ApiError> {
+        sqlx::query(
+            r#"
+            UPDATE board_columns c SET position = (ordered.ordinality - 1)::int
+            FROM UNNEST($3::uuid[]) WITH ORDINALITY AS ordered(id, ordinality)
+            WHERE c.id = ordered.id AND c.board_id = $1
+              AND EXISTS (SELECT 1 FROM boards WHERE id = $1 AND user_id = $2)
+            "#,
+        )
+        .bind(board_id)
+        .bind(user_id)
+        .bind(column_ids)
+        .execute(&self.pool)
+        .await?;
+
+        self.find_by_id(user_id, board_id).await
+    }
+
+    async fn find_cards(&self, user_id: Uuid, board_id: Uuid) -> Result<Vec<BoardCard>, ApiError> {
+        let mut conn = self.pool.acquire().await?;
+        cards(&mut conn, user_id, board_id).await
+    }
+
+    async fn move_card(&self, user_id: Uuid, board_id: Uuid, card_move: CardMove) -> Result<Option<MovedCard>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+        // 같은 사용자의 이동을 직렬화해 WIP 제한을 넘지 않게 한다
+        lock_positions(&mut tx, user_id).await?;
+
+        let target = sqlx::query_as::<_, BoardColumn>(&format!(
+            r#"
+            SELECT {COLUMN_COLUMNS} FROM board_columns
+            WHERE id = $1 AND board_id = $2
+              AND EXISTS (SELECT 1 FROM boards WHERE id = $2 AND user_id = $3)
+            "#
+        ))
+        .bind(card_move.column_id)
+        .bind(board_id)
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let todo = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            SELECT {TODO_COLUMNS} FROM todos
+            WHERE id = $1 AND workspace_id IN ({}) AND deleted_at IS NULL AND archived_at IS NULL
+            FOR UPDATE
+            "#,
+            editable_workspaces(2)
+        ))
+        .bind(card_move.todo_id)
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let (Some(target), Some(todo)) = (target, todo) else {
+            return Ok(None);
+        };
+
+        let cards = cards(&mut tx, user_id, board_id).await?;
+        let from_column_id = cards.iter().find(|card| card.todo.id == todo.id).map(|card| card.column_id);
+        let target_cards: Vec<(Uuid, Option<String>)> = cards
+            .into_iter()
+            .filter(|card| card.column_id == target.id && card.todo.id != todo.id)
+            .map(|card| (card.todo.id, card.card_position))
+            .collect();
+
+        let open_blockers = if target.status == Some(TodoStatus::Completed) && todo.enforce_dependencies {
+            sqlx::query_scalar(&format!("SELECT COUNT(*) FROM ({OPEN_BLOCKERS}) AS open_blockers"))
+                .bind(todo.id)
+                .fetch_one(&mut *tx)
+                .await?
+        } else {
+            0
+        };
+
+        let transition = plan_move(&todo, from_column_id, &target, target_cards.len(), open_blockers, Utc::now())?;
+        let keys = place_card(&target_cards, todo.id, card_move.after_id, card_move.before_id)?;
+
+        let todo = match &transition {
+            Some(transition) => update_status(&mut tx, &todo, transition).await?,
+            None => todo,
+        };
+
+        let (ids, positions): (Vec<Uuid>, Vec<String>) = keys.into_iter().unzip();
+        sqlx::query(
+            r#"
+            INSERT INTO board_cards (board_id, todo_id, column_id, position)
+            SELECT $1, placed.todo_id, $2, placed.position
+            FROM UNNEST($3::uuid[], $4::text[]) AS placed(todo_id, position)
+            ON CONFLICT (board_id, todo_id) DO UPDATE
+            SET column_id = EXCLUDED.column_id, position = EXCLUDED.position
+            "#,
+        )
+        .bind(board_id)
+        .bind(target.id)
+        .bind(&ids)
+        .bind(&positions)
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        let card_position = ids.iter().zip(positions).find(|(id, _)| **id == todo.id).map(|(_, position)| position);
+        Ok(Some(MovedCard {
+            card: BoardCard { todo, column_id: target.id, card_position },
+            transition,
+        }))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub BoardRepository {}
+
+        #[async_trait]
+        impl BoardRepository for BoardRepository {
+            async fn create(&self, board: NewBoard) -> Result<BoardWithColumns, ApiError>;
+            async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<BoardWithColumns>, ApiError>;
+            async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<Board>, ApiError>;
+            async fn find_all(&self, user_id: Uuid) -> Result<Vec<BoardWithColumns>, ApiError>;
+            async fn rename(&self, user_id: Uuid, id: Uuid, name: &str) -> Result<Option<BoardWithColumns>, ApiError>;
+            async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn add_column(&self, user_id: Uuid, board_id: Uuid, column: BoardColumnSpec) -> Result<Option<BoardColumn>, ApiError>;
+            async fn update_column(&self, user_id: Uuid, board_id: Uuid, id: Uuid, column: BoardColumnSpec) -> Result<Option<BoardColumn>, ApiError>;
+            async fn delete_column(&self, user_id: Uuid, board_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn reorder_columns(&self, user_id: Uuid, board_id: Uuid, column_ids: &[Uuid]) -> Result<Option<BoardWithColumns>, ApiError>;
+            async fn find_cards(&self, user_id: Uuid, board_id: Uuid) -> Result<Vec<BoardCard>, ApiError>;
+            async fn move_card(&self, user_id: Uuid, board_id: Uuid, card_move: CardMove) -> Result<Option<MovedCard>, ApiError>;
+        }
+    }
+}
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use crate::entities::bulk::{
+    BulkChange, BulkExecution, BulkItemOutcome, BulkItemResult, BulkMode, BulkOperation, BulkPlanner,
+};
+use crate::entities::todo::{Todo, TodoStatus};
+use crate::error::ApiError;
+use crate::repositories::todo_repository::TODO_COLUMNS;
+use crate::repositories::todo_series_repository::create_next_occurrence;
+use crate::repositories::workspace_repository::editable_workspaces;
+
+/// 여러 할일을 한 트랜잭션 안에서 잠그고, 항목마다 변경을 정한 뒤 반영한다.
+/// 사용자가 owner 또는 editor인 워크스페이스의 할일만 대상이 된다.
+#[async_trait]
+pub trait BulkTodoRepository: Send + Sync {
+    /// `ids`는 중복이 없어야 한다. 결과는 `ids` 순서대로 반환한다.
+    async fn execute(&self, user_id: Uuid, ids: &[Uuid], operation: &BulkOperation, mode: BulkMode) -> Result<BulkExecution, ApiError>;
+}
+
+pub struct PostgresBulkTodoRepository {
+    pool: PgPool,
+}
+
+impl PostgresBulkTodoRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// 할일마다 요청 전 기준으로 완료되지 않은 선행 할일 ID
+async fn open_blockers(conn: &mut PgConnection, ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<Uuid>>, ApiError> {
+    let pairs: Vec<(Uuid, Uuid)> = sqlx::query_as(
+        r#"
+        SELECT d.todo_id, d.blocked_by_id
+        FROM todo_dependencies d
+        JOIN todos b ON b.id = d.blocked_by_id
+        WHERE d.todo_id = ANY($1) AND b.status != 'completed' AND b.deleted_at IS NULL
+        "#,
+    )
+    .bind(ids)
+    .fetch_all(conn)
+    .await?;
+
+    let mut blockers: HashMap<Uuid, Vec<Uuid>> = HashMap::new();
+    for (todo_id, blocked_by_id) in pairs {
+        blockers.entry(todo_id).or_default().push(blocked_by_id);
+    }
+
+    Ok(blockers)
+}
+
+async fn apply(conn: &mut PgConnection, todo: &Todo, change: &BulkChange, operation: &BulkOperation) -> Result<Todo, ApiError> {
+    let now = Utc::now();
+    let updated = match change {
+        BulkChange::Transition(transition) => {
+            let transitioned = sqlx::query_as::<_, Todo>(&format!(
+                "UPDATE todos SET status = $2, started_at = $3, completed_at = $4, updated_at = $5 \
+                 WHERE id = $1 RETURNING {TODO_COLUMNS}"
+            ))
+            .bind(todo.id)
+            .bind(transition.to)
+            .bind(transition.started_at)
+            .bind(transition.completed_at)
+            .bind(transition.occurred_at)
+            .fetch_one(&mut *conn)
+            .await?;
+            create_next_occurrence(conn, &transitioned).await?;
+            transitioned
+        }
+        BulkChange::Priority(priority) => {
+            sqlx::query_as::<_, Todo>(&format!(
+                "UPDATE todos SET priority = $2, updated_at = $3 WHERE id = $1 RETURNING {TODO_COLUMNS}"
+            ))
+            .bind(todo.id)
+            .bind(*priority)
+            .bind(now)
+            .fetch_one(conn)
+            .await?
+        }
+        BulkChange::AssignCategories => {
+            if let BulkOperation::AssignCategories(category_ids) = operation {
+                // 할일과 다른 워크스페이스의 카테고리는 지정하지 않는다
+                sqlx::query(
+                    r#"
+                    INSERT INTO todo_categories (todo_id, category_id)
+                    SELECT $1, id FROM categories WHERE id = ANY($2) AND workspace_id = $3
+                    ON CONFLICT (todo_id, category_id) DO NOTHING
+                    "#,
+                )
+                .bind(todo.id)
+                .bind(category_ids)
+                .bind(todo.workspace_id)
+                .execute(conn)
+                .await?;
+            }
+            todo.clone()
+        }
+        BulkChange::Delete => {
+            sqlx::query_as::<_, Todo>(&format!(
+                "UPDATE todos SET deleted_at = $2 WHERE id = $1 RETURNING {TODO_COLUMNS}"
+            ))
+            .bind(todo.id)
+            .bind(now)
+            .fetch_one(conn)
+            .await?
+        }
+        BulkChange::Restore => {
+            sqlx::query_as::<_, Todo>(&format!(
+                "UPDATE todos SET deleted_at = NULL WHERE id = $1 RETURNING {TODO_COLUMNS}"
+            ))
+            .bind(todo.id)
+            .fetch_one(conn)
+            .await?
+        }
+    };
+
+    Ok(updated)
+}
+
+#[async_trait]
+impl BulkTodoRepository for PostgresBulkTodoRepository {
+    async fn execute(&self, user_id: Uuid, ids: &[Uuid], operation: &BulkOperation, mode: BulkMode) -> Result<BulkExecution, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 요청 순서와 관계없이 같은 순서로 잠가 교착 상태를 피한다
+        let todos: Vec<Todo> = sqlx::query_as::<_, Todo>(&format!(
+            "SELECT {TODO_COLUMNS} FROM todos WHERE workspace_id IN ({}) AND id = ANY($2) ORDER BY id FOR UPDATE",
+            editable_workspaces(1)
+        ))
+        .bind(user_id)
+        .bind(ids)
+        .fetch_all(&mut *tx)
+        .await?;
+        let todos: HashMap<Uuid, Todo> = todos.into_iter().map(|todo| (todo.id, todo)).collect();
+
+        let blockers = match operation {
+            BulkOperation::SetStatus(TodoStatus::Completed) => open_blockers(&mut tx, ids).await?,
+            _ => HashMap::new(),
+        };
+
+        let mut planner = BulkPlanner::new(operation, Utc::now());
+        let plans: Vec<(Uuid, Result<Option<BulkChange>, String>)> = ids
+            .iter()
+            .map(|id| {
+                let open = blockers.get(id).map(Vec::as_slice).unwrap_or_default();
+                (*id, planner.plan(todos.get(id), open))
+            })
+            .collect();
+
+        // 원자적 모드에서는 하나라도 실패하면 나머지도 보류한다
+        let held_back = mode.atomic && plans.iter().any(|(_, plan)| plan.is_err());
+        let apply_changes = !mode.dry_run && !held_back;
+
+        let mut results = Vec::with_capacity(plans.len());
+        let mut transitions = Vec::new();
+        for (id, plan) in plans {
+            let result = match plan {
+                Err(error) => BulkItemResult { id, outcome: BulkItemOutcome::Failed, error: Some(error), todo: None },
+                Ok(None) => BulkItemResult { id, outcome: BulkItemOutcome::Unchanged, error: None, todo: None },
+                Ok(Some(_)) if held_back => {
+                    BulkItemResult { id, outcome: BulkItemOutcome::Skipped, error: None, todo: None }
+                }
+                Ok(Some(change)) => {
+                    let todo = if apply_changes {
+                        Some(apply(&mut tx, &todos[&id], &change, operation).await?)
+                    } else {
+                        None
+                    };
+                    if let (true, BulkChange::Transition(transition)) = (apply_changes, change) {
+                        transitions.push(transition);
+                    }
+                    BulkItemResult { id, outcome: BulkItemOutcome::Succeeded, error: None, todo }
+                }
+            };
+            results.push(result);
+        }
+
+        if apply_changes {
+            tx.commit().await?;
+        } else {
+            tx.rollback().await?;
+        }
+
+        Ok(BulkExecution {
+            results,
+            committed: apply_changes,
+            transitions,
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub BulkTodoRepository {}
+
+        #[async_trait]
+        impl BulkTodoRepository for BulkTodoRepository {
+            async fn execute(&self, user_id: Uuid, ids: &[Uuid], operation: &BulkOperation, mode: BulkMode) -> Result<BulkExecution, ApiError>;
+        }
+    }
+}
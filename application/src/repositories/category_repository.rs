@@ -0,0 +1,213 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::entities::category::{Category, CategoryChanges, CategoryWithCount, NewCategory};
+use crate::error::ApiError;
+use crate::repositories::workspace_repository::{editable_workspaces, member_workspaces};
+
+const CATEGORY_COLUMNS: &str = "categories.id, categories.user_id, categories.workspace_id, categories.name, \
+    categories.color, categories.created_at, categories.updated_at";
+
+const TODO_COUNT: &str =
+    "(SELECT COUNT(*) FROM todo_categories tc JOIN todos t ON t.id = tc.todo_id \
+     WHERE tc.category_id = categories.id AND t.deleted_at IS NULL) AS todo_count";
+
+/// 카테고리는 워크스페이스에 속한다. 조회는 멤버인 워크스페이스, 수정과 삭제는 owner/editor인 워크스페이스로 제한된다.
+/// 할일-카테고리 연결 메서드는 호출 측에서 두 대상의 권한을 확인한 뒤 사용한다.
+#[async_trait]
+pub trait CategoryRepository: Send + Sync {
+    async fn create(&self, category: NewCategory) -> Result<Category, ApiError>;
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<CategoryWithCount>, ApiError>;
+    async fn find_by_name(&self, workspace_id: Uuid, name: &str) -> Result<Option<Category>, ApiError>;
+    /// 요청한 ID 중 사용자가 볼 수 있는 카테고리만 반환한다
+    async fn find_by_ids(&self, user_id: Uuid, ids: &[Uuid]) -> Result<Vec<Category>, ApiError>;
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<CategoryWithCount>, ApiError>;
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<CategoryWithCount>, ApiError>;
+    async fn update(&self, user_id: Uuid, id: Uuid, changes: CategoryChanges) -> Result<Option<CategoryWithCount>, ApiError>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// 이미 지정된 카테고리는 무시한다
+    async fn assign(&self, todo_id: Uuid, category_ids: &[Uuid]) -> Result<(), ApiError>;
+    async fn unassign(&self, todo_id: Uuid, category_id: Uuid) -> Result<bool, ApiError>;
+}
+
+pub struct PostgresCategoryRepository {
+    pool: PgPool,
+}
+
+impl PostgresCategoryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CategoryRepository for PostgresCategoryRepository {
+    async fn create(&self, category: NewCategory) -> Result<Category, ApiError> {
+        let created_category = sqlx::query_as::<_, Category>(&format!(
+            r#"
+            INSERT INTO categories (id, user_id, workspace_id, name, color)
+            VALUES ($1, $2, $3, $4, $5)
+            RETURNING {CATEGORY_COLUMNS}
+            "#
+        ))
+        .bind(Uuid::new_v4())
+        .bind(category.user_id)
+        .bind(category.workspace_id)
+        .bind(category.name)
+        .bind(category.color)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created_category)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<CategoryWithCount>, ApiError> {
+        let category = sqlx::query_as::<_, CategoryWithCount>(&format!(
+            "SELECT {CATEGORY_COLUMNS}, {TODO_COUNT} FROM categories WHERE id = $1 AND workspace_id IN ({})",
+            member_workspaces(2)
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(category)
+    }
+
+    async fn find_by_name(&self, workspace_id: Uuid, name: &str) -> Result<Option<Category>, ApiError> {
+        let category = sqlx::query_as::<_, Category>(&format!(
+            "SELECT {CATEGORY_COLUMNS} FROM categories WHERE workspace_id = $1 AND name = $2"
+        ))
+        .bind(workspace_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(category)
+    }
+
+    async fn find_by_ids(&self, user_id: Uuid, ids: &[Uuid]) -> Result<Vec<Category>, ApiError> {
+        let categories = sqlx::query_as::<_, Category>(&format!(
+            "SELECT {CATEGORY_COLUMNS} FROM categories WHERE workspace_id IN ({}) AND id = ANY($2)",
+            member_workspaces(1)
+        ))
+        .bind(user_id)
+        .bind(ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(categories)
+    }
+
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<CategoryWithCount>, ApiError> {
+        let categories = sqlx::query_as::<_, CategoryWithCount>(&format!(
+            "SELECT {CATEGORY_COLUMNS}, {TODO_COUNT} FROM categories WHERE workspace_id IN ({}) ORDER BY name ASC, id ASC",
+            member_workspaces(1)
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(categories)
+    }
+
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<CategoryWithCount>, ApiError> {
+        let categories = sqlx::query_as::<_, CategoryWithCount>(&format!(
+            r#"
+            SELECT {CATEGORY_COLUMNS}, {TODO_COUNT}
+            FROM categories
+            JOIN todo_categories assigned ON assigned.category_id = categories.id
+            WHERE assigned.todo_id = $1
+            ORDER BY categories.name ASC
+            "#
+        ))
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(categories)
+    }
+
+    async fn update(&self, user_id: Uuid, id: Uuid, changes: CategoryChanges) -> Result<Option<CategoryWithCount>, ApiError> {
+        let updated_category = sqlx::query_as::<_, CategoryWithCount>(&format!(
+            r#"
+            UPDATE categories
+            SET name = $3, color = $4
+            WHERE id = $1 AND workspace_id IN ({})
+            RETURNING {CATEGORY_COLUMNS}, {TODO_COUNT}
+            "#,
+            editable_workspaces(2)
+        ))
+        .bind(id)
+        .bind(user_id)
+        .bind(changes.name)
+        .bind(changes.color)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(updated_category)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query(&format!(
+            "DELETE FROM categories WHERE id = $1 AND workspace_id IN ({})",
+            editable_workspaces(2)
+        ))
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn assign(&self, todo_id: Uuid, category_ids: &[Uuid]) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            INSERT INTO todo_categories (todo_id, category_id)
+            SELECT $1, category_id FROM UNNEST($2::uuid[]) AS category_id
+            ON CONFLICT (todo_id, category_id) DO NOTHING
+            "#,
+        )
+        .bind(todo_id)
+        .bind(category_ids)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn unassign(&self, todo_id: Uuid, category_id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM todo_categories WHERE todo_id = $1 AND category_id = $2")
+            .bind(todo_id)
+            .bind(category_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub CategoryRepository {}
+
+        #[async_trait]
+        impl CategoryRepository for CategoryRepository {
+            async fn create(&self, category: NewCategory) -> Result<Category, ApiError>;
+            async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<CategoryWithCount>, ApiError>;
+            async fn find_by_name(&self, workspace_id: Uuid, name: &str) -> Result<Option<Category>, ApiError>;
+            async fn find_by_ids(&self, user_id: Uuid, ids: &[Uuid]) -> Result<Vec<Category>, ApiError>;
+            async fn find_all(&self, user_id: Uuid) -> Result<Vec<CategoryWithCount>, ApiError>;
+            async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<CategoryWithCount>, ApiError>;
+            async fn update(&self, user_id: Uuid, id: Uuid, changes: CategoryChanges) -> Result<Option<CategoryWithCount>, ApiError>;
+            async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn assign(&self, todo_id: Uuid, category_ids: &[Uuid]) -> Result<(), ApiError>;
+            async fn unassign(&self, todo_id: Uuid, category_id: Uuid) -> Result<bool, ApiError>;
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use crate::entities::checklist::{ChecklistItem, ChecklistItemChanges, MAX_CHECKLIST_ITEMS};
+use crate::error::ApiError;
+
+const ITEM_COLUMNS: &str = "id, todo_id, title, is_done, position, done_at, created_at, updated_at";
+
+/// 체크리스트 항목은 할일 단위로만 다룬다. 호출 측에서 할일의 소유권을 확인한 뒤 사용한다.
+///
+/// 항목을 바꾸는 메서드는 같은 트랜잭션 안에서 할일의 진행률 카운터
+/// (`todos.checklist_total`, `todos.checklist_done`)를 다시 계산한다.
+#[async_trait]
+pub trait ChecklistRepository: Send + Sync {
+    /// 순서(position)대로 반환한다
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<ChecklistItem>, ApiError>;
+    /// 마지막 순서에 추가한다. 이미 `MAX_CHECKLIST_ITEMS`개 있으면 추가하지 않고 `None`.
+    async fn create(&self, todo_id: Uuid, title: String) -> Result<Option<ChecklistItem>, ApiError>;
+    async fn update(&self, todo_id: Uuid, id: Uuid, changes: ChecklistItemChanges) -> Result<Option<ChecklistItem>, ApiError>;
+    async fn delete(&self, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// `item_ids` 순서대로 position을 다시 매긴다. 목록에 없는 항목은 바뀌지 않는다.
+    async fn reorder(&self, todo_id: Uuid, item_ids: &[Uuid]) -> Result<(), ApiError>;
+}
+
+pub struct PostgresChecklistRepository {
+    pool: PgPool,
+}
+
+impl PostgresChecklistRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// 할일의 체크리스트 진행률 카운터를 다시 계산한다
+async fn refresh_progress(conn: &mut PgConnection, todo_id: Uuid) -> Result<(), ApiError> {
+    sqlx::query(
+        r#"
+        UPDATE todos
+        SET checklist_total = progress.total, checklist_done = progress.done
+        FROM (
+            SELECT COUNT(*)::int AS total, (COUNT(*) FILTER (WHERE is_done))::int AS done
+            FROM checklist_items
+            WHERE todo_id = $1
+        ) AS progress
+        WHERE todos.id = $1
+        "#,
+    )
+    .bind(todo_id)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl ChecklistRepository for PostgresChecklistRepository {
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<ChecklistItem>, ApiError> {
+        let items = sqlx::query_as::<_, ChecklistItem>(&format!(
+            "SELECT {ITEM_COLUMNS} FROM checklist_items WHERE todo_id = $1 ORDER BY position ASC, created_at ASC"
+        ))
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(items)
+    }
+
+    async fn create(&self, todo_id: Uuid, title: String) -> Result<Option<ChecklistItem>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 동시에 추가되는 항목이 같은 순서를 받거나 개수 제한을 넘지 않도록 할일 행을 잠근다
+        sqlx::query("SELECT id FROM todos WHERE id = $1 FOR UPDATE")
+            .bind(todo_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM checklist_items WHERE todo_id = $1")
+            .bind(todo_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        if count >= i64::from(MAX_CHECKLIST_ITEMS) {
+            return Ok(None);
+        }
+
+        let item = sqlx::query_as::<_, ChecklistItem>(&format!(
+            r#"
+            INSERT INTO checklist_items (id, todo_id, title, position)
+            VALUES ($1, $2, $3, (SELECT COALESCE(MAX(position) + 1, 0) FROM checklist_items WHERE todo_id = $2))
+            RETURNING {ITEM_COLUMNS}
+            "#
+        ))
+        .bind(Uuid::new_v4())
+        .bind(todo_id)
+        .bind(title)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        refresh_progress(&mut tx, todo_id).await?;
+        tx.commit().await?;
+
+        Ok(Some(item))
+    }
+
+    async fn update(&self, todo_id: Uuid, id: Uuid, changes: ChecklistItemChanges) -> Result<Option<ChecklistItem>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 이미 완료된 항목을 다시 완료해도 done_at은 처음 완료한 시각을 유지한다
+        let item = sqlx::query_as::<_, ChecklistItem>(&format!(
+            r#"
+            UPDATE checklist_items
+            SET title = COALESCE($3, title),
+                is_done = COALESCE($4::boolean, is_done),
+                done_at = CASE
+                    WHEN $4::boolean IS NULL THEN done_at
+                    WHEN $4::boolean THEN COALESCE(done_at, NOW())
+                    ELSE NULL
+                END
+            WHERE id = $1 AND todo_id = $2
+            RETURNING {ITEM_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .bind(changes.title)
+        .bind(changes.is_done)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if item.is_some() {
+            refresh_progress(&mut tx, todo_id).await?;
+        }
+        tx.commit().await?;
+
+        Ok(item)
+    }
+
+    async fn delete(&self, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        let result = sqlx::query("DELETE FROM checklist_items WHERE id = $1 AND todo_id = $2")
+            .bind(id)
+            .bind(todo_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let deleted = result.rows_affected() > 0;
+        if deleted {
+            refresh_progress(&mut tx, todo_id).await?;
+        }
+        tx.commit().await?;
+
+        Ok(deleted)
+    }
+
+    async fn reorder(&self, todo_id: Uuid, item_ids: &[Uuid]) -> Result<(), ApiError> {
+        sqlx::query(
+            r#"
+            UPDATE checklist_items
+            SET position = ordered.ordinality - 1
+            FROM UNNEST($2::uuid[]) WITH ORDINALITY AS ordered(id, ordinality)
+            WHERE checklist_items.id = ordered.id AND checklist_items.todo_id = $1
+            "#,
+        )
+        .bind(todo_id)
+        .bind(item_ids)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub ChecklistRepository {}
+
+        #[async_trait]
+        impl ChecklistRepository for ChecklistRepository {
+            async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<ChecklistItem>, ApiError>;
+            async fn create(&self, todo_id: Uuid, title: String) -> Result<Option<ChecklistItem>, ApiError>;
+            async fn update(&self, todo_id: Uuid, id: Uuid, changes: ChecklistItemChanges) -> Result<Option<ChecklistItem>, ApiError>;
+            async fn delete(&self, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn reorder(&self, todo_id: Uuid, item_ids: &[Uuid]) -> Result<(), ApiError>;
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use async_trait::async_trait;
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+use crate::entities::comment::{Comment, CommentMention, CommentRevision, NewComment, SavedComment};
+use crate::error::ApiError;
+
+/// `c`(comments)와 `u`(users)를 조인한 결과에서 읽는 열
+const COMMENT_COLUMNS: &str = "c.id, c.todo_id, c.user_id, u.username AS author_username, c.parent_id, c.depth, \
+     c.body, c.body_html, c.edited_at, c.deleted_at, c.created_at, c.updated_at";
+
+#[async_trait]
+pub trait CommentRepository: Send + Sync {
+    /// 삭제된 댓글을 포함해 작성 순서대로
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<Comment>, ApiError>;
+    async fn find_by_id(&self, todo_id: Uuid, id: Uuid) -> Result<Option<Comment>, ApiError>;
+    /// 댓글과 언급을 함께 저장한다
+    async fn create(&self, comment: NewComment, mentioned_user_ids: &[Uuid]) -> Result<SavedComment, ApiError>;
+    /// 이전 본문을 수정 이력에 남기고 바꾼다. 삭제된 댓글이면 `None`.
+    async fn update(
+        &self,
+        todo_id: Uuid,
+        id: Uuid,
+        body: String,
+        body_html: String,
+        mentioned_user_ids: &[Uuid],
+    ) -> Result<Option<SavedComment>, ApiError>;
+    /// 이미 삭제된 댓글이면 `false`
+    async fn soft_delete(&self, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// 오래된 순서
+    async fn find_revisions(&self, comment_id: Uuid) -> Result<Vec<CommentRevision>, ApiError>;
+}
+
+pub struct PostgresCommentRepository {
+    pool: PgPool,
+}
+
+impl PostgresCommentRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 처음 언급된 사용자만 반환한다
+    async fn insert_mentions(
+        tx: &mut Transaction<'_, Postgres>,
+        comment_id: Uuid,
+        user_ids: &[Uuid],
+    ) -> Result<Vec<CommentMention>, ApiError> {
+        if user_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mentions = sqlx::query_as::<_, CommentMention>(
+            r#"
+            INSERT INTO comment_mentions (comment_id, user_id)
+            SELECT $1, user_id FROM UNNEST($2::uuid[]) AS t(user_id)
+            ON CONFLICT (comment_id, user_id) DO NOTHING
+            RETURNING id, comment_id, user_id
+            "#,
+        )
+        .bind(comment_id)
+        .bind(user_ids)
+        .fetch_all(&mut **tx)
+        .await?;
+
+        Ok(mentions)
+    }
+}
+
+#[async_trait]
+impl CommentRepository for PostgresCommentRepository {
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<Comment>, ApiError> {
+        let comments = sqlx::query_as::<_, Comment>(&format!(
+            r#"
+            SELECT {COMMENT_COLUMNS}
+            FROM comments c LEFT JOIN users u ON u.id = c.user_id
+            WHERE c.todo_id = $1
+            ORDER BY c.created_at ASC, c.id ASC
+            "#
+        ))
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(comments)
+    }
+
+    async fn find_by_id(&self, todo_id: Uuid, id: Uuid) -> Result<Option<Comment>, ApiError> {
+        let comment = sqlx::query_as::<_, Comment>(&format!(
+            r#"
+            SELECT {COMMENT_COLUMNS}
+            FROM comments c LEFT JOIN users u ON u.id = c.user_id
+            WHERE c.id = $1 AND c.todo_id = $2
+            "#
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(comment)
+    }
+
+    async fn create(&self, comment: NewComment, mentioned_user_ids: &[Uuid]) -> Result<SavedComment, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, Comment>(&format!(
+            r#"
+            WITH c AS (
+                INSERT INTO comments (todo_id, user_id, parent_id, depth, body, body_html)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING *
+            )
+            SELECT {COMMENT_COLUMNS} FROM c LEFT JOIN users u ON u.id = c.user_id
+            "#
+        ))
+        .bind(comment.todo_id)
+        .bind(comment.user_id)
+        .bind(comment.parent_id)
+        .bind(comment.depth)
+        .bind(comment.body)
+        .bind(comment.body_html)
+        .fetch_one(&mut *tx)
+        .await?;
+        let new_mentions = Self::insert_mentions(&mut tx, created.id, mentioned_user_ids).await?;
+
+        tx.commit().await?;
+        Ok(SavedComment {
+            comment: created,
+            new_mentions,
+        })
+    }
+
+    async fn update(
+        &self,
+        todo_id: Uuid,
+        id: Uuid,
+        body: String,
+        body_html: String,
+        mentioned_user_ids: &[Uuid],
+    ) -> Result<Option<SavedComment>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 동시에 수정해도 이력이 빠지지 않도록 행을 잠근 뒤 이전 본문을 옮긴다
+        let archived = sqlx::query(
+            r#"
+            INSERT INTO comment_revisions (comment_id, body, written_at)
+            SELECT id, body, COALESCE(edited_at, created_at) FROM comments
+            WHERE id = $1 AND todo_id = $2 AND deleted_at IS NULL
+            FOR UPDATE
+            "#,
+        )
+        .bind(id)
+        .bind(todo_id)
+        .execute(&mut *tx)
+        .await?;
+        if archived.rows_affected() == 0 {
+            return Ok(None);
+        }
+
+        let updated = sqlx::query_as::<_, Comment>(&format!(
+            r#"
+            WITH c AS (
+                UPDATE comments SET body = $3, body_html = $4, edited_at = NOW()
+                WHERE id = $1 AND todo_id = $2
+                RETURNING *
+            )
+            SELECT {COMMENT_COLUMNS} FROM c LEFT JOIN users u ON u.id = c.user_id
+            "#
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .bind(body)
+        .bind(body_html)
+        .fetch_one(&mut *tx)
+        .await?;
+        let new_mentions = Self::insert_mentions(&mut tx, id, mentioned_user_ids).await?;
+
+        tx.commit().await?;
+        Ok(Some(SavedComment {
+            comment: updated,
+            new_mentions,
+        }))
+    }
+
+    async fn soft_delete(&self, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query(
+            "UPDATE comments SET deleted_at = NOW() WHERE id = $1 AND todo_id = $2 AND deleted_at IS NULL",
+        )
+        .bind(id)
+        .bind(todo_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_revisions(&self, comment_id: Uuid) -> Result<Vec<CommentRevision>, ApiError> {
+        let revisions = sqlx::query_as::<_, CommentRevision>(
+            "SELECT id, comment_id, body, written_at FROM comment_revisions WHERE comment_id = $1 ORDER BY written_at ASC, created_at ASC",
+        )
+        .bind(comment_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(revisions)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub CommentRepository {}
+
+        #[async_trait]
+        impl CommentRepository for CommentRepository {
+            async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<Comment>, ApiError>;
+            async fn find_by_id(&self, todo_id: Uuid, id: Uuid) -> Result<Option<Comment>, ApiError>;
+            async fn create(&self, comment: NewComment, mentioned_user_ids: &[Uuid]) -> Result<SavedComment, ApiError>;
+            async fn update(
+                &self,
+                todo_id: Uuid,
+                id: Uuid,
+                body: String,
+                body_html: String,
+                mentioned_user_ids: &[Uuid],
+            ) -> Result<Option<SavedComment>, ApiError>;
+            async fn soft_delete(&self, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn find_revisions(&self, comment_id: Uuid) -> Result<Vec<CommentRevision>, ApiError>;
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::entities::dependency::{DependencyTodo, LinkOutcome};
+use crate::error::ApiError;
+
+/// 의존 관계는 같은 워크스페이스의 할일끼리만 맺는다. 호출 측에서 양쪽 할일의 권한을 확인한 뒤 사용한다.
+///
+/// 휴지통의 할일은 목록에서 빠지지만, 복원될 수 있으므로 순환 검사에는 포함한다.
+#[async_trait]
+pub trait DependencyRepository: Send + Sync {
+    /// `todo_id`를 막고 있는 선행 할일 (연결한 순서대로)
+    async fn find_blockers(&self, todo_id: Uuid) -> Result<Vec<DependencyTodo>, ApiError>;
+    /// `todo_id`가 막고 있는 후속 할일 (연결한 순서대로)
+    async fn find_dependents(&self, todo_id: Uuid) -> Result<Vec<DependencyTodo>, ApiError>;
+    /// `todo_id`가 `blocked_by_id`에 막히도록 연결한다. 순환이 생기면 연결하지 않는다.
+    async fn link(&self, workspace_id: Uuid, todo_id: Uuid, blocked_by_id: Uuid) -> Result<LinkOutcome, ApiError>;
+    async fn unlink(&self, todo_id: Uuid, blocked_by_id: Uuid) -> Result<bool, ApiError>;
+}
+
+pub struct PostgresDependencyRepository {
+    pool: PgPool,
+}
+
+impl PostgresDependencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl DependencyRepository for PostgresDependencyRepository {
+    async fn find_blockers(&self, todo_id: Uuid) -> Result<Vec<DependencyTodo>, ApiError> {
+        let blockers = sqlx::query_as::<_, DependencyTodo>(
+            r#"
+            SELECT t.id, t.title, t.status, t.priority, t.due_date, d.created_at AS linked_at
+            FROM todo_dependencies d
+            JOIN todos t ON t.id = d.blocked_by_id
+            WHERE d.todo_id = $1 AND t.deleted_at IS NULL
+            ORDER BY d.created_at ASC, t.id ASC
+            "#,
+        )
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(blockers)
+    }
+
+    async fn find_dependents(&self, todo_id: Uuid) -> Result<Vec<DependencyTodo>, ApiError> {
+        let dependents = sqlx::query_as::<_, DependencyTodo>(
+            r#"
+            SELECT t.id, t.title, t.status, t.priority, t.due_date, d.created_at AS linked_at
+            FROM todo_dependencies d
+            JOIN todos t ON t.id = d.todo_id
+            WHERE d.blocked_by_id = $1 AND t.deleted_at IS NULL
+            ORDER BY d.created_at ASC, t.id ASC
+            "#,
+        )
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(dependents)
+    }
+
+    async fn link(&self, workspace_id: Uuid, todo_id: Uuid, blocked_by_id: Uuid) -> Result<LinkOutcome, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 반대 방향 연결이 동시에 들어와 둘 다 순환 검사를 통과하지 않도록 워크스페이스 단위로 직렬화한다
+        // (정렬 키 잠금과 겹치지 않게 seed를 다르게 준다)
+        sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1::text, 1))")
+            .bind(workspace_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let exists: bool = sqlx::query_scalar(
+            "SELECT EXISTS (SELECT 1 FROM todo_dependencies WHERE todo_id = $1 AND blocked_by_id = $2)",
+        )
+        .bind(todo_id)
+        .bind(blocked_by_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        if exists {
+            return Ok(LinkOutcome::AlreadyLinked);
+        }
+
+        // blocked_by_id의 선행 할일을 거슬러 올라가다 todo_id를 만나면 순환이다
+        let cycles: bool = sqlx::query_scalar(
+            r#"
+            WITH RECURSIVE upstream(id) AS (
+                SELECT blocked_by_id FROM todo_dependencies WHERE todo_id = $1
+                UNION
+                SELECT d.blocked_by_id
+                FROM todo_dependencies d
+                JOIN upstream u ON d.todo_id = u.id
+            )
+            SELECT EXISTS (SELECT 1 FROM upstream WHERE id = $2)
+            "#,
+        )
+        .bind(blocked_by_id)
+        .bind(todo_id)
+        .fetch_one(&mut *tx)
+        .await?;
+        if cycles {
+            return Ok(LinkOutcome::WouldCycle);
+        }
+
+        sqlx::query("INSERT INTO todo_dependencies (todo_id, blocked_by_id) VALUES ($1, $2)")
+            .bind(todo_id)
+            .bind(blocked_by_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+
+        Ok(LinkOutcome::Linked)
+    }
+
+    async fn unlink(&self, todo_id: Uuid, blocked_by_id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM todo_dependencies WHERE todo_id = $1 AND blocked_by_id = $2")
+            .bind(todo_id)
+            .bind(blocked_by_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub DependencyRepository {}
+
+        #[async_trait]
+        impl DependencyRepository for DependencyRepository {
+            async fn find_blockers(&self, todo_id: Uuid) -> Result<Vec<DependencyTodo>, ApiError>;
+            async fn find_dependents(&self, todo_id: Uuid) -> Result<Vec<DependencyTodo>, ApiError>;
+            async fn link(&self, workspace_id: Uuid, todo_id: Uuid, blocked_by_id: Uuid) -> Result<LinkOutcome, ApiError>;
+            async fn unlink(&self, todo_id: Uuid, blocked_by_id: Uuid) -> Result<bool, ApiError>;
+        }
+    }
+}
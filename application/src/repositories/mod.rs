@@ -2,7 +2,25 @@
 //!
 //! Contains data access layer implementations.
 
+pub mod assignment_repository;
+pub mod attachment_repository;
+pub mod board_repository;
+pub mod bulk_repository;
+pub mod category_repository;
+pub mod checklist_repository;
+pub mod comment_repository;
+pub mod dependency_repository;
+pub mod reminder_repository;
+pub mod share_link_repository;
+pub mod smart_list_repository;
+pub mod template_repository;
+pub mod time_entry_repository;
+pub mod todo_query;
+pub mod todo_repository;
+pub mod todo_series_repository;
 pub mod user_repository;
+pub mod user_settings_repository;
+pub mod workspace_repository;
 
 // Future repository implementations will be added here
 // For example: product_repository.rs, etc.
@@ -0,0 +1,268 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::entities::reminder::{DueReminder, NewReminder, Reminder, ReminderStatus, MAX_REMINDERS_PER_TODO};
+use crate::entities::todo::TodoStatus;
+use crate::error::ApiError;
+
+/// 알림의 실제 발송 시각: 다시 알림 > 절대 시각 > 마감 기준 상대 시각
+const FIRE_AT: &str = "COALESCE(r.snoozed_until, r.remind_at, t.due_date - make_interval(mins => r.offset_minutes))";
+
+fn reminder_columns() -> String {
+    format!(
+        "r.id, r.todo_id, r.user_id, r.remind_at, r.offset_minutes, r.snoozed_until, r.status, r.sent_at, \
+         r.attempts, r.last_error, {FIRE_AT} AS fire_at, r.created_at, r.updated_at"
+    )
+}
+
+/// 알림은 할일 단위로 다룬다. 호출 측에서 할일의 소유권을 확인한 뒤 사용한다.
+/// 삭제와 다시 알림은 알림을 설정한 사용자(`user_id`)의 것만 건드린다.
+///
+/// 발송은 `claim_due` → `mark_sent`/`mark_failed` 순서로 진행한다. `claim_due`는
+/// `FOR UPDATE SKIP LOCKED`로 행을 잠근 채 임대 만료 시각(`locked_until`)을 기록하므로
+/// 여러 서버 인스턴스가 동시에 폴링해도 같은 알림을 두 곳에서 가져가지 않는다.
+/// 결과 기록은 가져갈 때 받은 `locked_until`이 그대로일 때만 반영되므로, 임대가 만료되어
+/// 다른 워커가 다시 가져갔거나 그 사이 다시 알림을 설정한 경우에는 덮어쓰지 않는다.
+#[async_trait]
+pub trait ReminderRepository: Send + Sync {
+    /// 발송 예정 시각 순으로 반환한다
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<Reminder>, ApiError>;
+    /// 할일에 이미 `MAX_REMINDERS_PER_TODO`개 있으면 추가하지 않고 `None`
+    async fn create(&self, reminder: NewReminder) -> Result<Option<Reminder>, ApiError>;
+    async fn delete(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// `until`에 다시 알리도록 대기 상태로 되돌린다
+    async fn snooze(&self, user_id: Uuid, todo_id: Uuid, id: Uuid, until: DateTime<Utc>) -> Result<Option<Reminder>, ApiError>;
+    /// 발송 시각이 지난 대기 알림을 최대 `limit`개 가져와 `lease_until`까지 임대한다.
+    /// 완료되었거나 휴지통에 있는 할일의 알림은 가져오지 않는다.
+    async fn claim_due(&self, now: DateTime<Utc>, lease_until: DateTime<Utc>, limit: i64) -> Result<Vec<DueReminder>, ApiError>;
+    /// 발송 완료로 기록한다. 그 사이 임대를 잃었거나 다른 상태로 바뀌었으면 `false`.
+    async fn mark_sent(&self, id: Uuid, locked_until: DateTime<Utc>, sent_at: DateTime<Utc>) -> Result<bool, ApiError>;
+    /// 발송 실패를 기록한다. `retry_at`이 없으면 더 이상 재시도하지 않는다.
+    /// 그 사이 임대를 잃었거나 다른 상태로 바뀌었으면 `false`.
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        locked_until: DateTime<Utc>,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, ApiError>;
+}
+
+pub struct PostgresReminderRepository {
+    pool: PgPool,
+}
+
+impl PostgresReminderRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ReminderRepository for PostgresReminderRepository {
+    async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<Reminder>, ApiError> {
+        let reminders = sqlx::query_as::<_, Reminder>(&format!(
+            r#"
+            SELECT {}
+            FROM reminders r
+            JOIN todos t ON t.id = r.todo_id
+            WHERE r.todo_id = $1
+            ORDER BY fire_at ASC NULLS LAST, r.created_at ASC
+            "#,
+            reminder_columns()
+        ))
+        .bind(todo_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(reminders)
+    }
+
+    async fn create(&self, reminder: NewReminder) -> Result<Option<Reminder>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 동시에 추가해도 알림 수 제한을 넘지 않도록 할일 행을 잠근다
+        sqlx::query("SELECT id FROM todos WHERE id = $1 FOR UPDATE")
+            .bind(reminder.todo_id)
+            .execute(&mut *tx)
+            .await?;
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM reminders WHERE todo_id = $1")
+            .bind(reminder.todo_id)
+            .fetch_one(&mut *tx)
+            .await?;
+        if count >= MAX_REMINDERS_PER_TODO as i64 {
+            return Ok(None);
+        }
+
+        let created = sqlx::query_as::<_, Reminder>(&format!(
+            r#"
+            WITH r AS (
+                INSERT INTO reminders (id, todo_id, user_id, remind_at, offset_minutes, status)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING *
+            )
+            SELECT {}
+            FROM r
+            JOIN todos t ON t.id = r.todo_id
+            "#,
+            reminder_columns()
+        ))
+        .bind(Uuid::new_v4())
+        .bind(reminder.todo_id)
+        .bind(reminder.user_id)
+        .bind(reminder.remind_at)
+        .bind(reminder.offset_minutes)
+        .bind(ReminderStatus::Pending)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+
+        Ok(Some(created))
+    }
+
+    async fn delete(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM reminders WHERE id = $1 AND todo_id = $2 AND user_id = $3")
+            .bind(id)
+            .bind(todo_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn snooze(&self, user_id: Uuid, todo_id: Uuid, id: Uuid, until: DateTime<Utc>) -> Result<Option<Reminder>, ApiError> {
+        let reminder = sqlx::query_as::<_, Reminder>(&format!(
+            r#"
+            UPDATE reminders r
+            SET snoozed_until = $3, status = $4, sent_at = NULL, attempts = 0, last_error = NULL, locked_until = NULL
+            FROM todos t
+            WHERE r.id = $1 AND r.todo_id = $2 AND r.user_id = $5 AND t.id = r.todo_id
+            RETURNING {}
+            "#,
+            reminder_columns()
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .bind(until)
+        .bind(ReminderStatus::Pending)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(reminder)
+    }
+
+    async fn claim_due(&self, now: DateTime<Utc>, lease_until: DateTime<Utc>, limit: i64) -> Result<Vec<DueReminder>, ApiError> {
+        let reminders = sqlx::query_as::<_, DueReminder>(&format!(
+            r#"
+            WITH due AS (
+                SELECT r.id
+                FROM reminders r
+                JOIN todos t ON t.id = r.todo_id
+                WHERE r.status = $4
+                  AND t.status <> $5
+                  AND t.deleted_at IS NULL
+                  AND (r.locked_until IS NULL OR r.locked_until <= $1)
+                  AND {FIRE_AT} <= $1
+                ORDER BY {FIRE_AT} ASC
+                LIMIT $3
+                FOR UPDATE OF r SKIP LOCKED
+            )
+            UPDATE reminders r
+            SET locked_until = $2, attempts = r.attempts + 1
+            FROM due, todos t
+            WHERE r.id = due.id AND t.id = r.todo_id
+            RETURNING r.id, r.todo_id, r.user_id, t.title, t.due_date, {FIRE_AT} AS fire_at, r.attempts, r.locked_until
+            "#
+        ))
+        .bind(now)
+        .bind(lease_until)
+        .bind(limit)
+        .bind(ReminderStatus::Pending)
+        .bind(TodoStatus::Completed)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(reminders)
+    }
+
+    async fn mark_sent(&self, id: Uuid, locked_until: DateTime<Utc>, sent_at: DateTime<Utc>) -> Result<bool, ApiError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE reminders
+            SET status = $3, sent_at = $2, locked_until = NULL, last_error = NULL
+            WHERE id = $1 AND status = $4 AND locked_until = $5
+            "#,
+        )
+        .bind(id)
+        .bind(sent_at)
+        .bind(ReminderStatus::Sent)
+        .bind(ReminderStatus::Pending)
+        .bind(locked_until)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        locked_until: DateTime<Utc>,
+        error: String,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<bool, ApiError> {
+        let status = match retry_at {
+            Some(_) => ReminderStatus::Pending,
+            None => ReminderStatus::Failed,
+        };
+
+        let result = sqlx::query(
+            r#"
+            UPDATE reminders
+            SET status = $3, locked_until = $4, last_error = $2
+            WHERE id = $1 AND status = $5 AND locked_until = $6
+            "#,
+        )
+        .bind(id)
+        .bind(error)
+        .bind(status)
+        .bind(retry_at)
+        .bind(ReminderStatus::Pending)
+        .bind(locked_until)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub ReminderRepository {}
+
+        #[async_trait]
+        impl ReminderRepository for ReminderRepository {
+            async fn find_by_todo(&self, todo_id: Uuid) -> Result<Vec<Reminder>, ApiError>;
+            async fn create(&self, reminder: NewReminder) -> Result<Option<Reminder>, ApiError>;
+            async fn delete(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn snooze(&self, user_id: Uuid, todo_id: Uuid, id: Uuid, until: DateTime<Utc>) -> Result<Option<Reminder>, ApiError>;
+            async fn claim_due(&self, now: DateTime<Utc>, lease_until: DateTime<Utc>, limit: i64) -> Result<Vec<DueReminder>, ApiError>;
+            async fn mark_sent(&self, id: Uuid, locked_until: DateTime<Utc>, sent_at: DateTime<Utc>) -> Result<bool, ApiError>;
+            async fn mark_failed(
+                &self,
+                id: Uuid,
+                locked_until: DateTime<Utc>,
+                error: String,
+                retry_at: Option<DateTime<Utc>>,
+            ) -> Result<bool, ApiError>;
+        }
+    }
+}
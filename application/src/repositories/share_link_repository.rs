@@ -0,0 +1,156 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::entities::share_link::{NewShareLink, ShareLink};
+use crate::error::ApiError;
+
+const SHARE_LINK_COLUMNS: &str = "id, workspace_id, created_by, todo_id, title, statuses, priorities, category_ids, \
+    search, sort, password_hash, expires_at, revoked_at, view_count, last_viewed_at, created_at";
+
+/// 공유 링크. 권한 확인은 호출 측(서비스)에서 한다.
+#[async_trait]
+pub trait ShareLinkRepository: Send + Sync {
+    async fn create(&self, link: NewShareLink) -> Result<ShareLink, ApiError>;
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ShareLink>, ApiError>;
+    /// 회수되거나 만료된 링크도 찾는다
+    async fn find_by_token(&self, token_hash: &str) -> Result<Option<ShareLink>, ApiError>;
+    /// 최근에 만든 순서 (회수되거나 만료된 링크 포함)
+    async fn find_by_workspace(&self, workspace_id: Uuid) -> Result<Vec<ShareLink>, ApiError>;
+    async fn count_active(&self, workspace_id: Uuid, now: DateTime<Utc>) -> Result<i64, ApiError>;
+    /// 이미 회수된 링크면 `false`
+    async fn revoke(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, ApiError>;
+    async fn record_view(&self, id: Uuid, now: DateTime<Utc>) -> Result<(), ApiError>;
+}
+
+pub struct PostgresShareLinkRepository {
+    pool: PgPool,
+}
+
+impl PostgresShareLinkRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl ShareLinkRepository for PostgresShareLinkRepository {
+    async fn create(&self, link: NewShareLink) -> Result<ShareLink, ApiError> {
+        let filter = link.filter;
+        let created = sqlx::query_as::<_, ShareLink>(&format!(
+            r#"
+            INSERT INTO share_links (
+                workspace_id, created_by, todo_id, title, statuses, priorities, category_ids, search, sort,
+                token_hash, password_hash, expires_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            RETURNING {SHARE_LINK_COLUMNS}
+            "#
+        ))
+        .bind(link.workspace_id)
+        .bind(link.created_by)
+        .bind(link.todo_id)
+        .bind(link.title)
+        .bind(filter.statuses)
+        .bind(filter.priorities)
+        .bind(filter.category_ids)
+        .bind(filter.search)
+        .bind(filter.sort)
+        .bind(link.token_hash)
+        .bind(link.password_hash)
+        .bind(link.expires_at)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, id: Uuid) -> Result<Option<ShareLink>, ApiError> {
+        let link = sqlx::query_as::<_, ShareLink>(&format!(
+            "SELECT {SHARE_LINK_COLUMNS} FROM share_links WHERE id = $1"
+        ))
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(link)
+    }
+
+    async fn find_by_token(&self, token_hash: &str) -> Result<Option<ShareLink>, ApiError> {
+        let link = sqlx::query_as::<_, ShareLink>(&format!(
+            "SELECT {SHARE_LINK_COLUMNS} FROM share_links WHERE token_hash = $1"
+        ))
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(link)
+    }
+
+    async fn find_by_workspace(&self, workspace_id: Uuid) -> Result<Vec<ShareLink>, ApiError> {
+        let links = sqlx::query_as::<_, ShareLink>(&format!(
+            "SELECT {SHARE_LINK_COLUMNS} FROM share_links WHERE workspace_id = $1 ORDER BY created_at DESC, id ASC"
+        ))
+        .bind(workspace_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(links)
+    }
+
+    async fn count_active(&self, workspace_id: Uuid, now: DateTime<Utc>) -> Result<i64, ApiError> {
+        let count: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COUNT(*) FROM share_links
+            WHERE workspace_id = $1 AND revoked_at IS NULL AND (expires_at IS NULL OR expires_at > $2)
+            "#,
+        )
+        .bind(workspace_id)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count)
+    }
+
+    async fn revoke(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, ApiError> {
+        let result = sqlx::query("UPDATE share_links SET revoked_at = $2 WHERE id = $1 AND revoked_at IS NULL")
+            .bind(id)
+            .bind(now)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn record_view(&self, id: Uuid, now: DateTime<Utc>) -> Result<(), ApiError> {
+        sqlx::query("UPDATE share_links SET view_count = view_count + 1, last_viewed_at = $2 WHERE id = $1")
+            .bind(id)
+            .bind(now)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub ShareLinkRepository {}
+
+        #[async_trait]
+        impl ShareLinkRepository for ShareLinkRepository {
+            async fn create(&self, link: NewShareLink) -> Result<ShareLink, ApiError>;
+            async fn find_by_id(&self, id: Uuid) -> Result<Option<ShareLink>, ApiError>;
+            async fn find_by_token(&self, token_hash: &str) -> Result<Option<ShareLink>, ApiError>;
+            async fn find_by_workspace(&self, workspace_id: Uuid) -> Result<Vec<ShareLink>, ApiError>;
+            async fn count_active(&self, workspace_id: Uuid, now: DateTime<Utc>) -> Result<i64, ApiError>;
+            async fn revoke(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, ApiError>;
+            async fn record_view(&self, id: Uuid, now: DateTime<Utc>) -> Result<(), ApiError>;
+        }
+    }
+}
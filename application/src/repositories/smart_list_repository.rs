@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::entities::smart_list::{NewSmartList, SmartList, SmartListChanges};
+use crate::error::ApiError;
+
+const SMART_LIST_COLUMNS: &str =
+    "id, user_id, name, statuses, priorities, due_window, category_ids, search, sort, created_at, updated_at";
+
+#[async_trait]
+pub trait SmartListRepository: Send + Sync {
+    async fn create(&self, smart_list: NewSmartList) -> Result<SmartList, ApiError>;
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<SmartList>, ApiError>;
+    async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<SmartList>, ApiError>;
+    /// 이름순
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<SmartList>, ApiError>;
+    async fn count(&self, user_id: Uuid) -> Result<i64, ApiError>;
+    async fn update(&self, user_id: Uuid, id: Uuid, changes: SmartListChanges) -> Result<Option<SmartList>, ApiError>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+}
+
+pub struct PostgresSmartListRepository {
+    pool: PgPool,
+}
+
+impl PostgresSmartListRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SmartListRepository for PostgresSmartListRepository {
+    async fn create(&self, smart_list: NewSmartList) -> Result<SmartList, ApiError> {
+        let filter = smart_list.filter;
+        let created = sqlx::query_as::<_, SmartList>(&format!(
+            r#"
+            INSERT INTO smart_lists (id, user_id, name, statuses, priorities, due_window, category_ids, search, sort)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            RETURNING {SMART_LIST_COLUMNS}
+            "#
+        ))
+        .bind(Uuid::new_v4())
+        .bind(smart_list.user_id)
+        .bind(smart_list.name)
+        .bind(filter.statuses)
+        .bind(filter.priorities)
+        .bind(filter.due_window)
+        .bind(filter.category_ids)
+        .bind(filter.search)
+        .bind(filter.sort)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<SmartList>, ApiError> {
+        let smart_list = sqlx::query_as::<_, SmartList>(&format!(
+            "SELECT {SMART_LIST_COLUMNS} FROM smart_lists WHERE id = $1 AND user_id = $2"
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(smart_list)
+    }
+
+    async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<SmartList>, ApiError> {
+        let smart_list = sqlx::query_as::<_, SmartList>(&format!(
+            "SELECT {SMART_LIST_COLUMNS} FROM smart_lists WHERE user_id = $1 AND name = $2"
+        ))
+        .bind(user_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(smart_list)
+    }
+
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<SmartList>, ApiError> {
+        let smart_lists = sqlx::query_as::<_, SmartList>(&format!(
+            "SELECT {SMART_LIST_COLUMNS} FROM smart_lists WHERE user_id = $1 ORDER BY name ASC"
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(smart_lists)
+    }
+
+    async fn count(&self, user_id: Uuid) -> Result<i64, ApiError> {
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM smart_lists WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(count)
+    }
+
+    async fn update(&self, user_id: Uuid, id: Uuid, changes: SmartListChanges) -> Result<Option<SmartList>, ApiError> {
+        let filter = changes.filter;
+        let updated = sqlx::query_as::<_, SmartList>(&format!(
+            r#"
+            UPDATE smart_lists
+            SET name = $3, statuses = $4, priorities = $5, due_window = $6, category_ids = $7, search = $8, sort = $9
+            WHERE id = $1 AND user_id = $2
+            RETURNING {SMART_LIST_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(user_id)
+        .bind(changes.name)
+        .bind(filter.statuses)
+        .bind(filter.priorities)
+        .bind(filter.due_window)
+        .bind(filter.category_ids)
+        .bind(filter.search)
+        .bind(filter.sort)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(updated)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM smart_lists WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub SmartListRepository {}
+
+        #[async_trait]
+        impl SmartListRepository for SmartListRepository {
+            async fn create(&self, smart_list: NewSmartList) -> Result<SmartList, ApiError>;
+            async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<SmartList>, ApiError>;
+            async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<SmartList>, ApiError>;
+            async fn find_all(&self, user_id: Uuid) -> Result<Vec<SmartList>, ApiError>;
+            async fn count(&self, user_id: Uuid) -> Result<i64, ApiError>;
+            async fn update(&self, user_id: Uuid, id: Uuid, changes: SmartListChanges) -> Result<Option<SmartList>, ApiError>;
+            async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use crate::entities::template::{
+    NewTemplate, NewTemplateItem, TemplateChanges, TemplateItem, TemplateTodo, TemplateWithItems, TodoTemplate,
+};
+use crate::entities::todo::{Todo, TodoStatus};
+use crate::error::ApiError;
+use crate::repositories::todo_repository::{lock_positions, TODO_COLUMNS};
+use crate::utils::fractional_index::key_between;
+
+const TEMPLATE_COLUMNS: &str = "id, user_id, name, description, created_at, updated_at";
+const ITEM_COLUMNS: &str =
+    "id, template_id, position, title, description, priority, due_offset_days, due_time, category_ids, checklist";
+
+#[async_trait]
+pub trait TemplateRepository: Send + Sync {
+    async fn create(&self, template: NewTemplate) -> Result<TemplateWithItems, ApiError>;
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<TemplateWithItems>, ApiError>;
+    async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<TodoTemplate>, ApiError>;
+    /// 이름순
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<TemplateWithItems>, ApiError>;
+    /// 항목은 모두 지우고 새로 만든다
+    async fn update(&self, user_id: Uuid, id: Uuid, changes: TemplateChanges) -> Result<Option<TemplateWithItems>, ApiError>;
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// 할일, 카테고리 지정, 체크리스트를 `workspace_id`에 한 트랜잭션으로 만들고 `todos` 순서대로 반환한다.
+    /// 호출 측에서 쓰기 권한과 카테고리가 같은 워크스페이스에 있는지 확인한 뒤 사용한다.
+    /// 그 사이 삭제된 카테고리는 건너뛴다.
+    async fn instantiate(&self, user_id: Uuid, workspace_id: Uuid, todos: Vec<TemplateTodo>) -> Result<Vec<Todo>, ApiError>;
+}
+
+pub struct PostgresTemplateRepository {
+    pool: PgPool,
+}
+
+impl PostgresTemplateRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn items(&self, template_ids: &[Uuid]) -> Result<HashMap<Uuid, Vec<TemplateItem>>, ApiError> {
+        let items = sqlx::query_as::<_, TemplateItem>(&format!(
+            "SELECT {ITEM_COLUMNS} FROM todo_template_items WHERE template_id = ANY($1) ORDER BY position ASC"
+        ))
+        .bind(template_ids)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let mut by_template: HashMap<Uuid, Vec<TemplateItem>> = HashMap::new();
+        for item in items {
+            by_template.entry(item.template_id).or_default().push(item);
+        }
+
+        Ok(by_template)
+    }
+}
+
+async fn insert_items(conn: &mut PgConnection, template_id: Uuid, items: Vec<NewTemplateItem>) -> Result<Vec<TemplateItem>, ApiError> {
+    let mut inserted = Vec::with_capacity(items.len());
+    for (position, item) in items.into_iter().enumerate() {
+        let row = sqlx::query_as::<_, TemplateItem>(&format!(
+            r#"
+            INSERT INTO todo_template_items
+                (id, template_id, position, title, description, priority, due_offset_days, due_time, category_ids, checklist)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            RETURNING {ITEM_COLUMNS}
+            "#
+        ))
+        .bind(Uuid::new_v4())
+        .bind(template_id)
+        .bind(position as i32)
+        .bind(item.title)
+        .bind(item.description)
+        .bind(item.priority)
+        .bind(item.due.due_offset_days)
+        .bind(item.due.due_time)
+        .bind(item.category_ids)
+        .bind(item.checklist)
+        .fetch_one(&mut *conn)
+        .await?;
+        inserted.push(row);
+    }
+
+    Ok(inserted)
+}
+
+#[async_trait]
+impl TemplateRepository for PostgresTemplateRepository {
+    async fn create(&self, template: NewTemplate) -> Result<TemplateWithItems, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        let created = sqlx::query_as::<_, TodoTemplate>(&format!(
+            "INSERT INTO todo_templates (id, user_id, name, description) VALUES ($1, $2, $3, $4) RETURNING {TEMPLATE_COLUMNS}"
+        ))
+        .bind(Uuid::new_v4())
+        .bind(template.user_id)
+        .bind(template.name)
+        .bind(template.description)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        let items = insert_items(&mut tx, created.id, template.items).await?;
+        tx.commit().await?;
+
+        Ok(TemplateWithItems { template: created, items })
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<TemplateWithItems>, ApiError> {
+        let template = sqlx::query_as::<_, TodoTemplate>(&format!(
+            "SELECT {TEMPLATE_COLUMNS} FROM todo_templates WHERE id = $1 AND user_id = $2"
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        let Some(template) = template else {
+            return Ok(None);
+        };
+        let items = self.items(&[template.id]).await?.remove(&template.id).unwrap_or_default();
+
+        Ok(Some(TemplateWithItems { template, items }))
+    }
+
+    async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<TodoTemplate>, ApiError> {
+        let template = sqlx::query_as::<_, TodoTemplate>(&format!(
+            "SELECT {TEMPLATE_COLUMNS} FROM todo_templates WHERE user_id = $1 AND name = $2"
+        ))
+        .bind(user_id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(template)
+    }
+
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<TemplateWithItems>, ApiError> {
+        let templates = sqlx::query_as::<_, TodoTemplate>(&format!(
+            "SELECT {TEMPLATE_COLUMNS} FROM todo_templates WHERE user_id = $1 ORDER BY name ASC"
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        let ids: Vec<Uuid> = templates.iter().map(|template| template.id).collect();
+        let mut items = self.items(&ids).await?;
+
+        Ok(templates
+            .into_iter()
+            .map(|template| {
+                let items = items.remove(&template.id).unwrap_or_default();
+                TemplateWithItems { template, items }
+            })
+            .collect())
+    }
+
+    async fn update(&self, user_id: Uuid, id: Uuid, changes: TemplateChanges) -> Result<Option<TemplateWithItems>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        let updated = sqlx::query_as::<_, TodoTemplate>(&format!(
+            r#"
+            UPDATE todo_templates SET name = $3, description = $4
+            WHERE id = $1 AND user_id = $2
+            RETURNING {TEMPLATE_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(user_id)
+        .bind(changes.name)
+        .bind(changes.description)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        let Some(template) = updated else {
+            return Ok(None);
+        };
+
+        sqlx::query("DELETE FROM todo_template_items WHERE template_id = $1")
+            .bind(id)
+            .execute(&mut *tx)
+            .await?;
+        let items = insert_items(&mut tx, id, changes.items).await?;
+        tx.commit().await?;
+
+        Ok(Some(TemplateWithItems { template, items }))
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM todo_templates WHERE id = $1 AND user_id = $2")
+            .bind(id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn instantiate(&self, user_id: Uuid, workspace_id: Uuid, todos: Vec<TemplateTodo>) -> Result<Vec<Todo>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        // 새 할일들을 목록 맨 뒤에 템플릿 순서대로 붙인다
+        lock_positions(&mut tx, workspace_id).await?;
+        let mut last: Option<String> = sqlx::query_scalar("SELECT MAX(position) FROM todos WHERE workspace_id = $1")
+            .bind(workspace_id)
+            .fetch_one(&mut *tx)
+            .await?;
+
+        let mut created = Vec::with_capacity(todos.len());
+        for todo in todos {
+            let position = key_between(last.as_deref(), None).map_err(ApiError::Internal)?;
+            let id = Uuid::new_v4();
+
+            sqlx::query(
+                r#"
+                INSERT INTO todos (id, user_id, workspace_id, title, description, status, priority, due_date,
+                                   checklist_total, position)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                "#,
+            )
+            .bind(id)
+            .bind(user_id)
+            .bind(workspace_id)
+            .bind(todo.title)
+            .bind(todo.description)
+            .bind(TodoStatus::Pending)
+            .bind(todo.priority)
+            .bind(todo.due_date)
+            .bind(todo.checklist.len() as i32)
+            .bind(&position)
+            .execute(&mut *tx)
+            .await?;
+
+            // 같은 워크스페이스의 카테고리만 지정된다
+            sqlx::query(
+                r#"
+                INSERT INTO todo_categories (todo_id, category_id)
+                SELECT $1, id FROM categories WHERE workspace_id = $2 AND id = ANY($3)
+                "#,
+            )
+            .bind(id)
+            .bind(workspace_id)
+            .bind(&todo.category_ids)
+            .execute(&mut *tx)
+            .await?;
+
+            sqlx::query(
+                r#"
+                INSERT INTO checklist_items (id, todo_id, title, position)
+                SELECT gen_random_uuid(), $1, item.title, (item.ordinality - 1)::int
+                FROM UNNEST($2::text[]) WITH ORDINALITY AS item(title, ordinality)
+                "#,
+            )
+            .bind(id)
+            .bind(&todo.checklist)
+            .execute(&mut *tx)
+            .await?;
+
+            let row = sqlx::query_as::<_, Todo>(&format!("SELECT {TODO_COLUMNS} FROM todos WHERE id = $1"))
+                .bind(id)
+                .fetch_one(&mut *tx)
+                .await?;
+            created.push(row);
+            last = Some(position);
+        }
+
+        tx.commit().await?;
+
+        Ok(created)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub TemplateRepository {}
+
+        #[async_trait]
+        impl TemplateRepository for TemplateRepository {
+            async fn create(&self, template: NewTemplate) -> Result<TemplateWithItems, ApiError>;
+            async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<TemplateWithItems>, ApiError>;
+            async fn find_by_name(&self, user_id: Uuid, name: &str) -> Result<Option<TodoTemplate>, ApiError>;
+            async fn find_all(&self, user_id: Uuid) -> Result<Vec<TemplateWithItems>, ApiError>;
+            async fn update(&self, user_id: Uuid, id: Uuid, changes: TemplateChanges) -> Result<Option<TemplateWithItems>, ApiError>;
+            async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn instantiate(&self, user_id: Uuid, workspace_id: Uuid, todos: Vec<TemplateTodo>) -> Result<Vec<Todo>, ApiError>;
+        }
+    }
+}
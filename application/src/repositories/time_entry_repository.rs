@@ -0,0 +1,198 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::entities::time_entry::{NewTimeEntry, TimeEntry, TimeEntryChanges, TrackedTime};
+use crate::error::ApiError;
+
+const ENTRY_COLUMNS: &str = "id, user_id, todo_id, started_at, ended_at, source, note, created_at, updated_at";
+
+/// 사용자마다 실행 중인 타이머(`ended_at IS NULL`)는 하나뿐이다 (`idx_time_entries_running`).
+#[async_trait]
+pub trait TimeEntryRepository: Send + Sync {
+    async fn find_running(&self, user_id: Uuid) -> Result<Option<TimeEntry>, ApiError>;
+    /// 이미 실행 중인 타이머가 있으면 `Conflict`
+    async fn create(&self, entry: NewTimeEntry) -> Result<TimeEntry, ApiError>;
+    /// 실행 중인 타이머를 멈춘다. 없으면 `None`.
+    async fn stop_running(&self, user_id: Uuid, ended_at: DateTime<Utc>) -> Result<Option<TimeEntry>, ApiError>;
+    async fn find_by_id(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<Option<TimeEntry>, ApiError>;
+    /// 시작 시각순
+    async fn find_by_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Vec<TimeEntry>, ApiError>;
+    /// 완료된 기록만 수정한다
+    async fn update(&self, user_id: Uuid, todo_id: Uuid, id: Uuid, changes: TimeEntryChanges) -> Result<Option<TimeEntry>, ApiError>;
+    async fn delete(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// `[from, to)`와 겹치는 기록 (휴지통의 할일 제외)
+    async fn find_tracked(&self, user_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<TrackedTime>, ApiError>;
+}
+
+pub struct PostgresTimeEntryRepository {
+    pool: PgPool,
+}
+
+impl PostgresTimeEntryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TimeEntryRepository for PostgresTimeEntryRepository {
+    async fn find_running(&self, user_id: Uuid) -> Result<Option<TimeEntry>, ApiError> {
+        let entry = sqlx::query_as::<_, TimeEntry>(&format!(
+            "SELECT {ENTRY_COLUMNS} FROM time_entries WHERE user_id = $1 AND ended_at IS NULL"
+        ))
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    async fn create(&self, entry: NewTimeEntry) -> Result<TimeEntry, ApiError> {
+        let created = sqlx::query_as::<_, TimeEntry>(&format!(
+            r#"
+            INSERT INTO time_entries (id, user_id, todo_id, started_at, ended_at, source, note)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            RETURNING {ENTRY_COLUMNS}
+            "#
+        ))
+        .bind(Uuid::new_v4())
+        .bind(entry.user_id)
+        .bind(entry.todo_id)
+        .bind(entry.started_at)
+        .bind(entry.ended_at)
+        .bind(entry.source)
+        .bind(entry.note)
+        .fetch_one(&self.pool)
+        .await;
+
+        match created {
+            Ok(created) => Ok(created),
+            Err(sqlx::Error::Database(e)) if e.is_unique_violation() => {
+                Err(ApiError::Conflict("이미 실행 중인 타이머가 있습니다".to_string()))
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    async fn stop_running(&self, user_id: Uuid, ended_at: DateTime<Utc>) -> Result<Option<TimeEntry>, ApiError> {
+        // 시작 시각보다 이른 종료 시각은 시작 시각으로 맞춘다 (서버 간 시계 차이)
+        let stopped = sqlx::query_as::<_, TimeEntry>(&format!(
+            r#"
+            UPDATE time_entries SET ended_at = GREATEST($2, started_at)
+            WHERE user_id = $1 AND ended_at IS NULL
+            RETURNING {ENTRY_COLUMNS}
+            "#
+        ))
+        .bind(user_id)
+        .bind(ended_at)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(stopped)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<Option<TimeEntry>, ApiError> {
+        let entry = sqlx::query_as::<_, TimeEntry>(&format!(
+            "SELECT {ENTRY_COLUMNS} FROM time_entries WHERE id = $1 AND todo_id = $2 AND user_id = $3"
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(entry)
+    }
+
+    async fn find_by_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Vec<TimeEntry>, ApiError> {
+        let entries = sqlx::query_as::<_, TimeEntry>(&format!(
+            "SELECT {ENTRY_COLUMNS} FROM time_entries WHERE todo_id = $1 AND user_id = $2 ORDER BY started_at ASC"
+        ))
+        .bind(todo_id)
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(entries)
+    }
+
+    async fn update(&self, user_id: Uuid, todo_id: Uuid, id: Uuid, changes: TimeEntryChanges) -> Result<Option<TimeEntry>, ApiError> {
+        let updated = sqlx::query_as::<_, TimeEntry>(&format!(
+            r#"
+            UPDATE time_entries SET started_at = $4, ended_at = $5, note = $6
+            WHERE id = $1 AND todo_id = $2 AND user_id = $3 AND ended_at IS NOT NULL
+            RETURNING {ENTRY_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(todo_id)
+        .bind(user_id)
+        .bind(changes.started_at)
+        .bind(changes.ended_at)
+        .bind(changes.note)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(updated)
+    }
+
+    async fn delete(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM time_entries WHERE id = $1 AND todo_id = $2 AND user_id = $3")
+            .bind(id)
+            .bind(todo_id)
+            .bind(user_id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_tracked(&self, user_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<TrackedTime>, ApiError> {
+        let tracked = sqlx::query_as::<_, TrackedTime>(
+            r#"
+            SELECT e.todo_id, t.title, t.estimate_minutes, e.started_at, e.ended_at,
+                   COALESCE(c.ids, '{}') AS category_ids, COALESCE(c.names, '{}') AS category_names
+            FROM time_entries e
+            JOIN todos t ON t.id = e.todo_id AND t.deleted_at IS NULL
+            LEFT JOIN LATERAL (
+                SELECT ARRAY_AGG(cat.id ORDER BY cat.name) AS ids, ARRAY_AGG(cat.name::text ORDER BY cat.name) AS names
+                FROM todo_categories tc
+                JOIN categories cat ON cat.id = tc.category_id
+                WHERE tc.todo_id = e.todo_id
+            ) c ON TRUE
+            WHERE e.user_id = $1 AND e.started_at < $3 AND (e.ended_at IS NULL OR e.ended_at > $2)
+            ORDER BY e.started_at ASC
+            "#,
+        )
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(tracked)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub TimeEntryRepository {}
+
+        #[async_trait]
+        impl TimeEntryRepository for TimeEntryRepository {
+            async fn find_running(&self, user_id: Uuid) -> Result<Option<TimeEntry>, ApiError>;
+            async fn create(&self, entry: NewTimeEntry) -> Result<TimeEntry, ApiError>;
+            async fn stop_running(&self, user_id: Uuid, ended_at: DateTime<Utc>) -> Result<Option<TimeEntry>, ApiError>;
+            async fn find_by_id(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<Option<TimeEntry>, ApiError>;
+            async fn find_by_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Vec<TimeEntry>, ApiError>;
+            async fn update(&self, user_id: Uuid, todo_id: Uuid, id: Uuid, changes: TimeEntryChanges) -> Result<Option<TimeEntry>, ApiError>;
+            async fn delete(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn find_tracked(&self, user_id: Uuid, from: DateTime<Utc>, to: DateTime<Utc>) -> Result<Vec<TrackedTime>, ApiError>;
+        }
+    }
+}
@@ -0,0 +1,645 @@
+//! Typed query builder for todo listing
+//!
+//! Turns list filters and sort specifications into parameterized SQL.
+//! Enum values come from closed Rust enums and are rendered as SQL literals so
+//! the planner can match the partial indexes (`status != 'completed'`,
+//! `due_date IS NOT NULL`, `priority = 'high'`); every user-supplied value is
+//! bound as a parameter.
+
+use std::str::FromStr;
+use chrono::{DateTime, Utc};
+use sqlx::{Postgres, QueryBuilder};
+use uuid::Uuid;
+use crate::entities::todo::{Priority, Todo, TodoStatus};
+use crate::utils::pagination::{
+    push_keyset_condition, push_keyset_order_by, Cursor, CursorValue, Keyset, KeysetColumn, PageDirection,
+};
+
+const MAX_SORT_KEYS: usize = 4;
+const MAX_SEARCH_TERMS: usize = 8;
+/// 오타 허용 기준. 한글은 음절당 트라이그램 수가 적어 pg_trgm 기본값(0.6)보다 낮게 잡는다.
+/// `<%` 연산자가 쓰는 `pg_trgm.word_similarity_threshold`로 트랜잭션마다 설정한다.
+pub const FUZZY_TITLE_THRESHOLD: f32 = 0.5;
+
+/// 할일 목록 필터 조건
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TodoFilter {
+    pub statuses: Vec<TodoStatus>,
+    pub priorities: Vec<Priority>,
+    pub search: Option<String>,
+    pub due_before: Option<DateTime<Utc>>,
+    pub due_after: Option<DateTime<Utc>>,
+    pub overdue: bool,
+    pub has_due_date: Option<bool>,
+    pub category_ids: Vec<Uuid>,
+    pub created_after: Option<DateTime<Utc>>,
+    pub created_before: Option<DateTime<Utc>>,
+    /// `false`이면 보관된 할일은 제외한다
+    pub include_archived: bool,
+    /// 지정하면 이 워크스페이스의 할일만 조회한다
+    pub workspace_id: Option<Uuid>,
+    /// 지정하면 이 사용자가 담당자인 할일만 조회한다
+    pub assignee_id: Option<Uuid>,
+}
+
+impl TodoFilter {
+    /// `WHERE` 절을 추가한다. 항상 사용자가 멤버인 워크스페이스 조건으로 시작하며 휴지통의 할일은 제외한다.
+    pub fn push_conditions(&self, builder: &mut QueryBuilder<'_, Postgres>, user_id: Uuid) {
+        push_member_scope(builder, user_id);
+        builder.push(" AND todos.deleted_at IS NULL");
+
+        if let Some(workspace_id) = self.workspace_id {
+            builder.push(" AND todos.workspace_id = ").push_bind(workspace_id);
+        }
+
+        if let Some(assignee_id) = self.assignee_id {
+            builder
+                .push(" AND EXISTS (SELECT 1 FROM todo_assignees ta WHERE ta.todo_id = todos.id AND ta.user_id = ")
+                .push_bind(assignee_id)
+                .push(")");
+        }
+
+        if !self.include_archived {
+            builder.push(" AND todos.archived_at IS NULL");
+        }
+
+        let excludes_completed = self.overdue
+            || (!self.statuses.is_empty() && !self.statuses.contains(&TodoStatus::Completed));
+
+        if !self.statuses.is_empty() {
+            push_literal_in(builder, "todos.status", self.statuses.iter().map(|s| s.as_str()));
+        }
+        if excludes_completed {
+            builder.push(" AND todos.status != 'completed'");
+        }
+
+        if !self.priorities.is_empty() {
+            push_literal_in(builder, "todos.priority", self.priorities.iter().map(|p| p.as_str()));
+        }
+
+        if let Some(search) = self.search.as_deref() {
+            for term in search_terms(search) {
+                builder.push(" AND ");
+                push_term_match(builder, &term);
+            }
+        }
+
+        let needs_due_date = self.has_due_date == Some(true)
+            || self.due_before.is_some()
+            || self.due_after.is_some()
+            || self.overdue;
+
+        if needs_due_date {
+            builder.push(" AND todos.due_date IS NOT NULL");
+        } else if self.has_due_date == Some(false) {
+            builder.push(" AND todos.due_date IS NULL");
+        }
+
+        if let Some(due_before) = self.due_before {
+            builder.push(" AND todos.due_date < ").push_bind(due_before);
+        }
+        if let Some(due_after) = self.due_after {
+            builder.push(" AND todos.due_date >= ").push_bind(due_after);
+        }
+        if self.overdue {
+            builder.push(" AND todos.due_date < NOW()");
+        }
+
+        if !self.category_ids.is_empty() {
+            builder
+                .push(" AND EXISTS (SELECT 1 FROM todo_categories tc WHERE tc.todo_id = todos.id AND tc.category_id = ANY(")
+                .push_bind(self.category_ids.clone())
+                .push("))");
+        }
+
+        if let Some(created_after) = self.created_after {
+            builder.push(" AND todos.created_at >= ").push_bind(created_after);
+        }
+        if let Some(created_before) = self.created_before {
+            builder.push(" AND todos.created_at < ").push_bind(created_before);
+        }
+    }
+}
+
+/// ` WHERE todos.workspace_id IN (사용자가 멤버인 워크스페이스)`
+fn push_member_scope(builder: &mut QueryBuilder<'_, Postgres>, user_id: Uuid) {
+    builder
+        .push(" WHERE todos.workspace_id IN (SELECT workspace_id FROM workspace_members WHERE user_id = ")
+        .push_bind(user_id)
+        .push(")");
+}
+
+/// `column IN ('a', 'b')` — 값은 enum의 고정 문자열이므로 리터럴로 넣는다.
+fn push_literal_in<'a>(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    column: &str,
+    values: impl Iterator<Item = &'a str>,
+) {
+    let literals: Vec<String> = values.map(|v| format!("'{}'", v)).collect();
+    if literals.len() == 1 {
+        builder.push(format!(" AND {} = {}", column, literals[0]));
+    } else {
+        builder.push(format!(" AND {} IN ({})", column, literals.join(", ")));
+    }
+}
+
+/// 검색어를 공백 기준으로 나눈다 (중복 제거, 최대 `MAX_SEARCH_TERMS`개)
+pub fn search_terms(query: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for term in query.split_whitespace() {
+        let term = term.to_lowercase();
+        if !terms.contains(&term) {
+            terms.push(term);
+        }
+    }
+    terms.truncate(MAX_SEARCH_TERMS);
+    terms
+}
+
+/// `%`, `_`, `\`를 이스케이프한 부분 일치 패턴
+fn like_pattern(term: &str) -> String {
+    let mut pattern = String::with_capacity(term.len() + 2);
+    pattern.push('%');
+    for c in term.chars() {
+        if matches!(c, '%' | '_' | '\\') {
+            pattern.push('\\');
+        }
+        pattern.push(c);
+    }
+    pattern.push('%');
+    pattern
+}
+
+/// 한 검색어가 제목, 설명, 카테고리명 중 하나에 포함되는 조건
+///
+/// 카테고리명 조건은 할일마다 다시 계산하는 상관 서브쿼리 대신 카테고리에서 먼저 찾도록
+/// 독립 서브쿼리로 두어 `idx_categories_name_trgm`을 쓸 수 있게 한다.
+fn push_term_match(builder: &mut QueryBuilder<'_, Postgres>, term: &str) {
+    let pattern = like_pattern(term);
+    builder
+        .push("(todos.title ILIKE ")
+        .push_bind(pattern.clone())
+        .push(" OR todos.description ILIKE ")
+        .push_bind(pattern.clone())
+        .push(
+            " OR todos.id IN (SELECT tc.todo_id FROM categories c JOIN todo_categories tc ON tc.category_id = c.id \
+             WHERE c.name ILIKE ",
+        )
+        .push_bind(pattern)
+        .push("))");
+}
+
+/// 관련도 순 검색
+///
+/// 모든 검색어가 제목/설명/카테고리명 어딘가에 포함되거나, 검색어 전체가 제목과
+/// 충분히 유사하면(오타 허용) 결과에 포함된다. 점수는 트라이그램 단어 유사도에
+/// 필드별 가중치를 곱한 합이며, 제목에 검색어 전체가 그대로 들어 있으면 가산점을 준다.
+///
+/// 오타 허용 조건은 `idx_todos_title_trgm`을 쓰도록 `<%` 연산자로 만든다. 실행 전에 같은
+/// 트랜잭션에서 `pg_trgm.word_similarity_threshold`를 `FUZZY_TITLE_THRESHOLD`로 설정해야 한다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TodoSearch {
+    pub query: String,
+    pub terms: Vec<String>,
+    pub limit: i64,
+}
+
+impl TodoSearch {
+    pub fn new(query: &str, limit: i64) -> Self {
+        let query = query.split_whitespace().collect::<Vec<_>>().join(" ");
+        Self { terms: search_terms(&query), query, limit }
+    }
+
+    pub fn build(&self, user_id: Uuid, columns: &str) -> QueryBuilder<'_, Postgres> {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT ");
+        for column in columns.split(',').map(str::trim) {
+            builder.push("todos.").push(column).push(", ");
+        }
+        builder
+            .push("COALESCE(cat.category_names, ARRAY[]::text[]) AS category_names, (")
+            .push("2.0 * word_similarity(")
+            .push_bind(self.query.as_str())
+            .push(", todos.title) + word_similarity(")
+            .push_bind(self.query.as_str())
+            .push(", COALESCE(todos.description, '')) + 1.5 * COALESCE(cat.category_score, 0)")
+            .push(" + CASE WHEN todos.title ILIKE ")
+            .push_bind(like_pattern(&self.query.to_lowercase()))
+            .push(" THEN 1.0 ELSE 0 END)::float8 AS score")
+            .push(" FROM todos LEFT JOIN LATERAL (SELECT array_agg(c.name::text ORDER BY c.name) AS category_names, ")
+            .push("max(word_similarity(")
+            .push_bind(self.query.as_str())
+            .push(", c.name)) AS category_score FROM todo_categories tc JOIN categories c ON c.id = tc.category_id")
+            .push(" WHERE tc.todo_id = todos.id) cat ON TRUE");
+        push_member_scope(&mut builder, user_id);
+        builder.push(" AND todos.deleted_at IS NULL AND ((");
+
+        for (index, term) in self.terms.iter().enumerate() {
+            if index > 0 {
+                builder.push(" AND ");
+            }
+            push_term_match(&mut builder, term);
+        }
+
+        builder
+            .push(") OR ")
+            .push_bind(self.query.as_str())
+            .push(" <% todos.title)")
+            .push(" ORDER BY score DESC, todos.updated_at DESC, todos.id DESC LIMIT ")
+            .push_bind(self.limit);
+        builder
+    }
+}
+
+/// 정렬 가능한 필드
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortField {
+    CreatedAt,
+    UpdatedAt,
+    DueDate,
+    Priority,
+    Status,
+    Title,
+    /// 수동 정렬 키
+    Position,
+}
+
+impl SortField {
+    /// 정렬에 사용할 SQL 표현식. 우선순위/상태는 의미 순서대로 정렬되도록 순위로 변환한다.
+    pub fn sql_expr(&self) -> &'static str {
+        match self {
+            SortField::CreatedAt => "todos.created_at",
+            SortField::UpdatedAt => "todos.updated_at",
+            SortField::DueDate => "todos.due_date",
+            SortField::Priority => {
+                "(CASE todos.priority WHEN 'low' THEN 1 WHEN 'medium' THEN 2 ELSE 3 END)"
+            }
+            SortField::Status => {
+                "(CASE todos.status WHEN 'pending' THEN 1 WHEN 'in_progress' THEN 2 ELSE 3 END)"
+            }
+            SortField::Title => "todos.title",
+            SortField::Position => "todos.position",
+        }
+    }
+
+    pub fn is_nullable(&self) -> bool {
+        matches!(self, SortField::DueDate | SortField::Position)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SortField::CreatedAt => "created_at",
+            SortField::UpdatedAt => "updated_at",
+            SortField::DueDate => "due_date",
+            SortField::Priority => "priority",
+            SortField::Status => "status",
+            SortField::Title => "title",
+            SortField::Position => "position",
+        }
+    }
+
+    /// `sql_expr()`가 계산하는 값과 같은 커서 값
+    pub fn cursor_value(&self, todo: &Todo) -> CursorValue {
+        match self {
+            SortField::CreatedAt => todo.created_at.into(),
+            SortField::UpdatedAt => todo.updated_at.into(),
+            SortField::DueDate => todo.due_date.into(),
+            SortField::Priority => CursorValue::Int(match todo.priority {
+                Priority::Low => 1,
+                Priority::Medium => 2,
+                Priority::High => 3,
+            }),
+            SortField::Status => CursorValue::Int(match todo.status {
+                TodoStatus::Pending => 1,
+                TodoStatus::InProgress => 2,
+                TodoStatus::Completed => 3,
+            }),
+            SortField::Title => todo.title.clone().into(),
+            SortField::Position => todo.position.clone().into(),
+        }
+    }
+}
+
+impl FromStr for SortField {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "created_at" => Ok(SortField::CreatedAt),
+            "updated_at" => Ok(SortField::UpdatedAt),
+            "due_date" => Ok(SortField::DueDate),
+            "priority" => Ok(SortField::Priority),
+            "status" => Ok(SortField::Status),
+            "title" => Ok(SortField::Title),
+            "position" => Ok(SortField::Position),
+            other => Err(format!("정렬할 수 없는 필드입니다: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SortKey {
+    pub field: SortField,
+    pub descending: bool,
+}
+
+/// 다중 필드 정렬 (`sort=-priority,due_date`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TodoSort {
+    keys: Vec<SortKey>,
+}
+
+impl TodoSort {
+    pub fn keys(&self) -> &[SortKey] {
+        &self.keys
+    }
+
+    /// 마지막 키의 방향으로 id를 덧붙여 정렬 결과를 결정적으로 만든다.
+    pub fn tiebreaker_descending(&self) -> bool {
+        self.keys.last().map(|key| key.descending).unwrap_or(false)
+    }
+
+    /// 정렬 조건의 정규화된 문자열 (`-priority,due_date`). 커서 서명으로 쓰인다.
+    pub fn signature(&self) -> String {
+        self.keys
+            .iter()
+            .map(|key| format!("{}{}", if key.descending { "-" } else { "" }, key.field.as_str()))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// keyset 컬럼 목록 (정렬 키 + id)
+    pub fn keyset_columns(&self) -> Vec<KeysetColumn> {
+        self.keys
+            .iter()
+            .map(|key| KeysetColumn::new(key.field.sql_expr(), key.descending, key.field.is_nullable()))
+            .chain(std::iter::once(KeysetColumn::new("todos.id", self.tiebreaker_descending(), false)))
+            .collect()
+    }
+
+    /// 할일 한 건의 정렬 위치를 커서로 만든다
+    pub fn cursor_for(&self, todo: &Todo) -> Cursor {
+        let values = self
+            .keys
+            .iter()
+            .map(|key| key.field.cursor_value(todo))
+            .chain(std::iter::once(CursorValue::Uuid(todo.id)))
+            .collect();
+        Cursor::new(self.signature(), values)
+    }
+
+    pub fn push_order_by(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        push_keyset_order_by(builder, &self.keyset_columns(), PageDirection::Forward);
+    }
+}
+
+impl Default for TodoSort {
+    fn default() -> Self {
+        Self {
+            keys: vec![SortKey { field: SortField::CreatedAt, descending: true }],
+        }
+    }
+}
+
+impl FromStr for TodoSort {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut keys: Vec<SortKey> = Vec::new();
+
+        for part in s.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            let (descending, name) = match part.strip_prefix('-') {
+                Some(name) => (true, name),
+                None => (false, part.strip_prefix('+').unwrap_or(part)),
+            };
+            let field = SortField::from_str(name)?;
+
+            if keys.iter().any(|key| key.field == field) {
+                return Err(format!("정렬 필드가 중복되었습니다: {}", name));
+            }
+            keys.push(SortKey { field, descending });
+        }
+
+        if keys.is_empty() {
+            return Err("정렬 조건이 비어 있습니다".to_string());
+        }
+        if keys.len() > MAX_SORT_KEYS {
+            return Err(format!("정렬 필드는 최대 {}개까지 지정할 수 있습니다", MAX_SORT_KEYS));
+        }
+
+        Ok(Self { keys })
+    }
+}
+
+/// 필터 + 정렬 + 페이지 범위
+///
+/// `keyset`이 있으면 `offset` 대신 커서 위치부터 조회한다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TodoQuery {
+    pub filter: TodoFilter,
+    pub sort: TodoSort,
+    pub limit: i64,
+    pub offset: i64,
+    pub keyset: Option<Keyset>,
+}
+
+impl TodoQuery {
+    /// 필터 조건 뒤에 커서 조건, 정렬, 페이지 범위를 추가한다.
+    pub fn push_page(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        let columns = self.sort.keyset_columns();
+
+        match &self.keyset {
+            Some(keyset) => {
+                push_keyset_condition(builder, &columns, keyset);
+                push_keyset_order_by(builder, &columns, keyset.direction);
+                builder.push(" LIMIT ").push_bind(self.limit);
+            }
+            None => {
+                push_keyset_order_by(builder, &columns, PageDirection::Forward);
+                builder
+                    .push(" LIMIT ")
+                    .push_bind(self.limit)
+                    .push(" OFFSET ")
+                    .push_bind(self.offset);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn where_sql(filter: &TodoFilter) -> String {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT * FROM todos");
+        filter.push_conditions(&mut builder, Uuid::new_v4());
+        builder.sql().to_string()
+    }
+
+    #[test]
+    fn test_empty_filter_only_scopes_member_workspaces() {
+        assert_eq!(
+            where_sql(&TodoFilter::default()),
+            "SELECT * FROM todos WHERE todos.workspace_id IN (SELECT workspace_id FROM workspace_members WHERE user_id = $1) \
+             AND todos.deleted_at IS NULL AND todos.archived_at IS NULL"
+        );
+    }
+
+    #[test]
+    fn test_workspace_filter_narrows_member_scope() {
+        let filter = TodoFilter {
+            workspace_id: Some(Uuid::new_v4()),
+            ..Default::default()
+        };
+
+        assert!(where_sql(&filter).contains("AND todos.workspace_id = $2"));
+    }
+
+    #[test]
+    fn test_assignee_filter_binds_user() {
+        let filter = TodoFilter {
+            assignee_id: Some(Uuid::new_v4()),
+            ..Default::default()
+        };
+
+        assert!(where_sql(&filter)
+            .contains("EXISTS (SELECT 1 FROM todo_assignees ta WHERE ta.todo_id = todos.id AND ta.user_id = $2)"));
+    }
+
+    #[test]
+    fn test_include_archived_drops_archive_condition() {
+        let filter = TodoFilter {
+            include_archived: true,
+            ..Default::default()
+        };
+
+        assert!(!where_sql(&filter).contains("archived_at"));
+    }
+
+    #[test]
+    fn test_status_filter_matches_active_partial_index() {
+        let filter = TodoFilter {
+            statuses: vec![TodoStatus::Pending, TodoStatus::InProgress],
+            ..Default::default()
+        };
+        let sql = where_sql(&filter);
+
+        assert!(sql.contains("todos.status IN ('pending', 'in_progress')"));
+        assert!(sql.contains("todos.status != 'completed'"));
+    }
+
+    #[test]
+    fn test_single_high_priority_uses_equality() {
+        let filter = TodoFilter {
+            priorities: vec![Priority::High],
+            ..Default::default()
+        };
+
+        assert!(where_sql(&filter).contains("todos.priority = 'high'"));
+    }
+
+    #[test]
+    fn test_overdue_and_due_range() {
+        let filter = TodoFilter {
+            overdue: true,
+            due_after: Some(Utc::now()),
+            ..Default::default()
+        };
+        let sql = where_sql(&filter);
+
+        assert_eq!(sql.matches("todos.due_date IS NOT NULL").count(), 1);
+        assert!(sql.contains("todos.due_date >= $2"));
+        assert!(sql.contains("todos.due_date < NOW()"));
+        assert!(sql.contains("todos.status != 'completed'"));
+    }
+
+    #[test]
+    fn test_user_values_are_bound() {
+        let filter = TodoFilter {
+            search: Some("'; DROP TABLE todos; --".to_string()),
+            category_ids: vec![Uuid::new_v4()],
+            has_due_date: Some(false),
+            ..Default::default()
+        };
+        let sql = where_sql(&filter);
+
+        assert!(!sql.contains("DROP TABLE"));
+        assert!(sql.contains("todos.title ILIKE $2 OR todos.description ILIKE $3"));
+        assert!(sql.contains("c.name ILIKE $4"));
+        // 공백 기준 5개 검색어, 검색어마다 3개씩 바인딩된다
+        assert!(sql.contains("tc.category_id = ANY($17)"));
+        assert!(sql.contains("todos.due_date IS NULL"));
+    }
+
+    #[test]
+    fn test_parse_multi_field_sort() {
+        let sort = TodoSort::from_str("-priority,due_date").unwrap();
+        assert_eq!(
+            sort.keys(),
+            &[
+                SortKey { field: SortField::Priority, descending: true },
+                SortKey { field: SortField::DueDate, descending: false },
+            ]
+        );
+
+        let mut builder = QueryBuilder::<Postgres>::new("");
+        sort.push_order_by(&mut builder);
+        assert_eq!(
+            builder.sql(),
+            " ORDER BY (CASE todos.priority WHEN 'low' THEN 1 WHEN 'medium' THEN 2 ELSE 3 END) DESC, \
+             todos.due_date ASC NULLS LAST, todos.id ASC"
+        );
+    }
+
+    #[test]
+    fn test_keyset_page_replaces_offset() {
+        let sort = TodoSort::from_str("-priority").unwrap();
+        let todo = Todo { priority: Priority::High, ..Todo::fixture(Uuid::new_v4(), Uuid::new_v4()) };
+        let cursor = sort.cursor_for(&todo);
+        assert_eq!(cursor.sort, "-priority");
+        assert_eq!(cursor.values, vec![CursorValue::Int(3), CursorValue::Uuid(todo.id)]);
+
+        let query = TodoQuery {
+            sort,
+            limit: 21,
+            keyset: Some(Keyset { cursor, direction: PageDirection::Forward }),
+            ..Default::default()
+        };
+        let mut builder = QueryBuilder::<Postgres>::new("");
+        query.push_page(&mut builder);
+        let sql = builder.sql();
+
+        assert!(sql.contains("END) < $1) OR ("));
+        assert!(sql.contains("todos.id < $3)"));
+        assert!(sql.ends_with("todos.id DESC LIMIT $4"));
+        assert!(!sql.contains("OFFSET"));
+    }
+
+    #[test]
+    fn test_search_terms_and_like_escaping() {
+        assert_eq!(search_terms("  회의  준비 회의 Sync "), vec!["회의", "준비", "sync"]);
+        assert_eq!(like_pattern("100%_a\\b"), "%100\\%\\_a\\\\b%");
+    }
+
+    #[test]
+    fn test_search_requires_every_term_or_fuzzy_title() {
+        let search = TodoSearch::new("회의   준비", 20);
+        assert_eq!(search.query, "회의 준비");
+
+        let builder = search.build(Uuid::new_v4(), "id, title");
+        let sql = builder.sql();
+
+        assert!(sql.starts_with("SELECT todos.id, todos.title, COALESCE(cat.category_names"));
+        assert_eq!(sql.matches("todos.title ILIKE").count(), 3);
+        assert!(sql.contains("c.name ILIKE $8)) AND (todos.title ILIKE $9"));
+        assert!(sql.contains("OR $12 <% todos.title)"));
+        assert!(!sql.contains("WHERE tc.todo_id = todos.id AND c.name"));
+        assert!(sql.ends_with("ORDER BY score DESC, todos.updated_at DESC, todos.id DESC LIMIT $13"));
+    }
+
+    #[test]
+    fn test_parse_sort_errors() {
+        assert!(TodoSort::from_str("password_hash").is_err());
+        assert!(TodoSort::from_str("due_date,-due_date").is_err());
+        assert!(TodoSort::from_str(" , ").is_err());
+    }
+}
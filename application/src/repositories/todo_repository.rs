@@ -0,0 +1,671 @@
+use async_trait::async_trait;
+use sqlx::{PgConnection, PgExecutor, PgPool, Postgres, QueryBuilder};
+use uuid::Uuid;
+use chrono::{DateTime, Utc};
+use crate::entities::todo::{NewTodo, StatusTransition, Todo, TodoChanges, TodoSearchHit, TodoStats, TodoStatus};
+use crate::entities::workspace::WorkspaceRole;
+use crate::error::ApiError;
+use crate::repositories::todo_query::{TodoFilter, TodoQuery, TodoSearch, FUZZY_TITLE_THRESHOLD};
+use crate::repositories::todo_series_repository::create_next_occurrence;
+use crate::repositories::workspace_repository::{editable_workspaces, member_workspaces};
+use crate::utils::fractional_index::{key_between, spaced_keys};
+use crate::utils::timezone::TimeWindows;
+
+pub(crate) const TODO_COLUMNS: &str = "id, user_id, workspace_id, title, description, status, priority, due_date, \
+    started_at, completed_at, checklist_total, checklist_done, checklist_auto_complete, enforce_dependencies, \
+    estimate_minutes, series_id, recurrence_index, position, archived_at, deleted_at, created_at, updated_at";
+
+/// `$1` 할일의 완료되지 않은 선행 할일
+pub(crate) const OPEN_BLOCKERS: &str = "SELECT 1 FROM todo_dependencies d JOIN todos b ON b.id = d.blocked_by_id \
+    WHERE d.todo_id = $1 AND b.status != 'completed' AND b.deleted_at IS NULL";
+
+/// 이보다 긴 정렬 키는 재배치 대상이다. `idx_todos_position_rebalance` 인덱스 조건과 맞춘다.
+pub const MAX_POSITION_KEY_LEN: usize = 16;
+
+/// 자동 보관 대상: 개인 워크스페이스의 완료된 할일에 그 주인의 `auto_archive_days`를 적용한다.
+/// 공유 워크스페이스의 할일은 한 멤버의 개인 설정으로 모두에게서 숨기지 않도록 제외한다.
+/// 보관을 해제하거나 수정하면 updated_at이 바뀌므로 그때부터 다시 일수를 센다.
+const AUTO_ARCHIVE_SQL: &str = r#"
+    UPDATE todos
+    SET archived_at = $1
+    WHERE id IN (
+        SELECT t.id
+        FROM todos t
+        JOIN workspaces w ON w.id = t.workspace_id AND w.is_personal
+        JOIN user_settings s ON s.user_id = w.created_by
+        WHERE s.auto_archive_days IS NOT NULL
+          AND t.status = 'completed' AND t.archived_at IS NULL AND t.deleted_at IS NULL
+          AND t.completed_at <= $1 - make_interval(days => s.auto_archive_days)
+          AND t.updated_at <= $1 - make_interval(days => s.auto_archive_days)
+        LIMIT $2
+        FOR UPDATE OF t SKIP LOCKED
+    )
+"#;
+
+/// 조회는 사용자가 멤버인 워크스페이스, 수정은 사용자가 owner/editor인 워크스페이스의 할일로 한정된다.
+/// 메서드의 `user_id`는 요청한 사용자다 (할일을 만든 사용자와 다를 수 있다).
+/// 휴지통의 할일(`deleted_at`이 있음)은 휴지통 전용 메서드를 제외한 모든 조회/수정에서 제외된다.
+#[async_trait]
+pub trait TodoRepository: Send + Sync {
+    async fn create(&self, todo: NewTodo) -> Result<Todo, ApiError>;
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ApiError>;
+    /// 사용자가 할일의 워크스페이스에서 가진 역할 (휴지통의 할일 포함). 볼 수 없는 할일이면 `None`.
+    async fn find_role(&self, user_id: Uuid, id: Uuid) -> Result<Option<WorkspaceRole>, ApiError>;
+    async fn find_all(&self, user_id: Uuid, query: &TodoQuery) -> Result<Vec<Todo>, ApiError>;
+    async fn count(&self, user_id: Uuid, filter: &TodoFilter) -> Result<i64, ApiError>;
+    /// 관련도 순 검색 (제목, 설명, 카테고리명)
+    async fn search(&self, user_id: Uuid, search: &TodoSearch) -> Result<Vec<TodoSearchHit>, ApiError>;
+    /// 상태별 개수와 기한 초과, 구간별 완료 개수
+    /// `include_archived`가 `false`이면 보관된 할일은 집계에서 뺀다
+    async fn stats(&self, user_id: Uuid, now: DateTime<Utc>, windows: &TimeWindows, include_archived: bool) -> Result<TodoStats, ApiError>;
+    async fn update(&self, user_id: Uuid, id: Uuid, changes: TodoChanges) -> Result<Option<Todo>, ApiError>;
+    /// 전이 직전 상태(`transition.from`)가 그대로일 때만 반영한다 (동시 변경 방지).
+    /// `enforce_dependencies`인 할일은 완료되지 않은 선행 할일이 남아 있으면 완료하지 않는다.
+    /// 반복 할일을 완료하면 다음 발생도 같은 트랜잭션에서 만든다.
+    async fn update_status(&self, user_id: Uuid, transition: &StatusTransition) -> Result<Option<Todo>, ApiError>;
+    /// 완료되지 않은 선행 할일 수 (휴지통의 할일은 세지 않는다)
+    async fn count_open_blockers(&self, id: Uuid) -> Result<i64, ApiError>;
+    /// 휴지통으로 옮긴다 (영구 삭제는 `purge_trashed`)
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// 휴지통 목록 (최근 삭제 순)
+    async fn find_trashed(&self, user_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Todo>, ApiError>;
+    async fn count_trashed(&self, user_id: Uuid) -> Result<i64, ApiError>;
+    /// 휴지통에서 되살린다. 휴지통에 없으면 `None`.
+    async fn restore(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ApiError>;
+    /// `deleted_before` 이전에 휴지통으로 옮긴 할일을 최대 `limit`개 영구 삭제한다
+    async fn purge_trashed(&self, deleted_before: DateTime<Utc>, limit: i64) -> Result<u64, ApiError>;
+    /// 보관하거나(`archived = true`) 보관을 해제한다. 이미 보관된 할일의 보관 시각은 유지한다.
+    async fn set_archived(&self, user_id: Uuid, id: Uuid, archived: bool) -> Result<Option<Todo>, ApiError>;
+    /// 자동 보관을 켠 사용자의 할일 중 완료 후 설정한 일수가 지났고 그동안 수정되지 않은 할일을
+    /// 최대 `limit`개 보관한다
+    async fn auto_archive(&self, now: DateTime<Utc>, limit: i64) -> Result<u64, ApiError>;
+    /// `after_id` 바로 뒤, `before_id` 바로 앞 자리로 옮긴다. 보통 옮기는 할일의 정렬 키만 바뀐다.
+    /// 할일이나 기준 할일이 없으면 `None`.
+    async fn move_to(&self, user_id: Uuid, id: Uuid, after_id: Option<Uuid>, before_id: Option<Uuid>) -> Result<Option<Todo>, ApiError>;
+    /// 정렬 키가 없거나 너무 길어진 할일이 있는 워크스페이스
+    async fn find_workspaces_needing_rebalance(&self, limit: i64) -> Result<Vec<Uuid>, ApiError>;
+    /// 워크스페이스의 모든 할일에 현재 순서대로 짧은 정렬 키를 다시 매긴다
+    async fn rebalance_positions(&self, workspace_id: Uuid) -> Result<(), ApiError>;
+}
+
+/// 이동할 자리를 찾은 결과
+enum Slot {
+    /// 할일이나 기준 할일이 없음
+    Missing,
+    /// `after_id`가 `before_id`보다 뒤에 있음
+    Inverted,
+    /// 기준 할일의 키가 없거나 다른 할일과 같아서 재배치가 필요함
+    NeedsRebalance,
+    Between(Option<String>, Option<String>),
+}
+
+/// 목록 맨 뒤에 붙는 정렬 키. 동시에 추가된 할일이 같은 키를 받을 수 있지만,
+/// 그 자리로 이동할 때 재배치하므로 순서가 깨지지 않는다.
+pub(crate) async fn append_position<'e>(executor: impl PgExecutor<'e>, workspace_id: Uuid) -> Result<String, ApiError> {
+    let last: Option<String> = sqlx::query_scalar("SELECT MAX(position) FROM todos WHERE workspace_id = $1")
+        .bind(workspace_id)
+        .fetch_one(executor)
+        .await?;
+
+    key_between(last.as_deref(), None).map_err(ApiError::Internal)
+}
+
+/// 같은 범위(워크스페이스의 할일 목록, 사용자의 보드)의 정렬 키 변경을 트랜잭션 끝까지 직렬화한다
+pub(crate) async fn lock_positions(conn: &mut PgConnection, scope_id: Uuid) -> Result<(), ApiError> {
+    sqlx::query("SELECT pg_advisory_xact_lock(hashtextextended($1::text, 0))")
+        .bind(scope_id)
+        .execute(conn)
+        .await?;
+
+    Ok(())
+}
+
+/// 현재 순서(키가 없는 할일은 맨 뒤에 생성 순)대로 고르게 나눈 키를 매긴다
+async fn rebalance(conn: &mut PgConnection, workspace_id: Uuid) -> Result<(), ApiError> {
+    let ids: Vec<Uuid> = sqlx::query_scalar(
+        "SELECT id FROM todos WHERE workspace_id = $1 ORDER BY position ASC NULLS LAST, created_at ASC, id ASC",
+    )
+    .bind(workspace_id)
+    .fetch_all(&mut *conn)
+    .await?;
+    let keys = spaced_keys(ids.len());
+
+    sqlx::query(
+        r#"
+        UPDATE todos
+        SET position = ordered.position
+        FROM UNNEST($2::uuid[], $3::text[]) AS ordered(id, position)
+        WHERE todos.id = ordered.id AND todos.workspace_id = $1
+        "#,
+    )
+    .bind(workspace_id)
+    .bind(&ids)
+    .bind(&keys)
+    .execute(conn)
+    .await?;
+
+    Ok(())
+}
+
+async fn find_slot(
+    conn: &mut PgConnection,
+    workspace_id: Uuid,
+    id: Uuid,
+    after_id: Option<Uuid>,
+    before_id: Option<Uuid>,
+) -> Result<Slot, ApiError> {
+    let mut bounds: [Option<String>; 2] = [None, None];
+
+    for (bound, anchor_id) in bounds.iter_mut().zip([after_id, before_id]) {
+        let Some(anchor_id) = anchor_id else { continue };
+
+        let position: Option<Option<String>> = sqlx::query_scalar(
+            "SELECT position FROM todos WHERE id = $1 AND workspace_id = $2 AND deleted_at IS NULL",
+        )
+            .bind(anchor_id)
+            .bind(workspace_id)
+            .fetch_optional(&mut *conn)
+            .await?;
+        let position = match position {
+            None => return Ok(Slot::Missing),
+            Some(None) => return Ok(Slot::NeedsRebalance),
+            Some(Some(position)) => position,
+        };
+
+        let tied: bool = sqlx::query_scalar(
+            "SELECT EXISTS(SELECT 1 FROM todos WHERE workspace_id = $1 AND id <> $2 AND id <> $3 AND position = $4 AND deleted_at IS NULL)",
+        )
+        .bind(workspace_id)
+        .bind(anchor_id)
+        .bind(id)
+        .bind(&position)
+        .fetch_one(&mut *conn)
+        .await?;
+        if tied {
+            return Ok(Slot::NeedsRebalance);
+        }
+
+        *bound = Some(position);
+    }
+
+    let [lower, upper] = bounds;
+    let slot = match (lower, upper) {
+        (Some(lower), Some(upper)) if lower > upper => Slot::Inverted,
+        (Some(lower), None) => {
+            let next: Option<String> = sqlx::query_scalar(
+                "SELECT MIN(position) FROM todos WHERE workspace_id = $1 AND id <> $2 AND position > $3 AND deleted_at IS NULL",
+            )
+            .bind(workspace_id)
+            .bind(id)
+            .bind(&lower)
+            .fetch_one(&mut *conn)
+            .await?;
+            Slot::Between(Some(lower), next)
+        }
+        (None, Some(upper)) => {
+            let previous: Option<String> = sqlx::query_scalar(
+                "SELECT MAX(position) FROM todos WHERE workspace_id = $1 AND id <> $2 AND position < $3 AND deleted_at IS NULL",
+            )
+            .bind(workspace_id)
+            .bind(id)
+            .bind(&upper)
+            .fetch_one(&mut *conn)
+            .await?;
+            Slot::Between(previous, Some(upper))
+        }
+        (lower, upper) => Slot::Between(lower, upper),
+    };
+
+    Ok(slot)
+}
+
+pub struct PostgresTodoRepository {
+    pool: PgPool,
+}
+
+impl PostgresTodoRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TodoRepository for PostgresTodoRepository {
+    async fn create(&self, todo: NewTodo) -> Result<Todo, ApiError> {
+        let id = Uuid::new_v4();
+        let now = chrono::Utc::now();
+        let position = append_position(&self.pool, todo.workspace_id).await?;
+
+        let created_todo = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            INSERT INTO todos (id, user_id, workspace_id, title, description, status, priority, due_date,
+                               checklist_auto_complete, enforce_dependencies, estimate_minutes, position,
+                               created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+            RETURNING {TODO_COLUMNS}
+            "#
+        ))
+        .bind(id)
+        .bind(todo.user_id)
+        .bind(todo.workspace_id)
+        .bind(todo.title)
+        .bind(todo.description)
+        .bind(TodoStatus::Pending)
+        .bind(todo.priority)
+        .bind(todo.due_date)
+        .bind(todo.checklist_auto_complete)
+        .bind(todo.enforce_dependencies)
+        .bind(todo.estimate_minutes)
+        .bind(position)
+        .bind(now)
+        .bind(now)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(created_todo)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ApiError> {
+        let todo = sqlx::query_as::<_, Todo>(&format!(
+            "SELECT {TODO_COLUMNS} FROM todos WHERE id = $1 AND workspace_id IN ({}) AND deleted_at IS NULL",
+            member_workspaces(2)
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(todo)
+    }
+
+    async fn find_role(&self, user_id: Uuid, id: Uuid) -> Result<Option<WorkspaceRole>, ApiError> {
+        let role: Option<WorkspaceRole> = sqlx::query_scalar(
+            r#"
+            SELECT m.role
+            FROM todos t JOIN workspace_members m ON m.workspace_id = t.workspace_id AND m.user_id = $2
+            WHERE t.id = $1
+            "#,
+        )
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(role)
+    }
+
+    async fn find_all(&self, user_id: Uuid, query: &TodoQuery) -> Result<Vec<Todo>, ApiError> {
+        let mut builder = QueryBuilder::<Postgres>::new(format!("SELECT {TODO_COLUMNS} FROM todos"));
+        query.filter.push_conditions(&mut builder, user_id);
+        query.push_page(&mut builder);
+
+        let todos = builder
+            .build_query_as::<Todo>()
+            .fetch_all(&self.pool)
+            .await?;
+
+        Ok(todos)
+    }
+
+    async fn count(&self, user_id: Uuid, filter: &TodoFilter) -> Result<i64, ApiError> {
+        let mut builder = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM todos");
+        filter.push_conditions(&mut builder, user_id);
+
+        let total: i64 = builder
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(total)
+    }
+
+    async fn search(&self, user_id: Uuid, search: &TodoSearch) -> Result<Vec<TodoSearchHit>, ApiError> {
+        // `<%`가 트라이그램 인덱스를 타면서 우리 기준값을 쓰도록 이 트랜잭션에서만 바꾼다
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("SELECT set_config('pg_trgm.word_similarity_threshold', $1, true)")
+            .bind(FUZZY_TITLE_THRESHOLD.to_string())
+            .execute(&mut *tx)
+            .await?;
+        let hits = search
+            .build(user_id, TODO_COLUMNS)
+            .build_query_as::<TodoSearchHit>()
+            .fetch_all(&mut *tx)
+            .await?;
+        tx.commit().await?;
+
+        Ok(hits)
+    }
+
+    async fn stats(&self, user_id: Uuid, now: DateTime<Utc>, windows: &TimeWindows, include_archived: bool) -> Result<TodoStats, ApiError> {
+        // 사용자가 볼 수 있는 모든 워크스페이스의 할일을 집계한다
+        let stats = sqlx::query_as::<_, TodoStats>(&format!(
+            r#"
+            SELECT
+                COUNT(*) FILTER (WHERE status = 'pending') AS pending_todos,
+                COUNT(*) FILTER (WHERE status = 'in_progress') AS in_progress_todos,
+                COUNT(*) FILTER (WHERE status = 'completed') AS completed_todos,
+                (SELECT COUNT(*) FROM todos
+                    WHERE workspace_id IN ({members}) AND deleted_at IS NULL AND ($7 OR archived_at IS NULL) AND status != 'completed'
+                      AND due_date IS NOT NULL AND due_date < $2) AS overdue_todos,
+                (SELECT COUNT(*) FROM todos
+                    WHERE workspace_id IN ({members}) AND deleted_at IS NULL AND ($7 OR archived_at IS NULL) AND completed_at IS NOT NULL
+                      AND completed_at >= $3 AND completed_at < $4) AS today_completed,
+                (SELECT COUNT(*) FROM todos
+                    WHERE workspace_id IN ({members}) AND deleted_at IS NULL AND ($7 OR archived_at IS NULL) AND completed_at IS NOT NULL
+                      AND completed_at >= $5 AND completed_at < $6) AS this_week_completed
+            FROM todos
+            WHERE workspace_id IN ({members}) AND deleted_at IS NULL AND ($7 OR archived_at IS NULL)
+            "#,
+            members = member_workspaces(1)
+        ))
+        .bind(user_id)
+        .bind(now)
+        .bind(windows.today_start)
+        .bind(windows.tomorrow_start)
+        .bind(windows.week_start)
+        .bind(windows.next_week_start)
+        .bind(include_archived)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(stats)
+    }
+
+    async fn update(&self, user_id: Uuid, id: Uuid, changes: TodoChanges) -> Result<Option<Todo>, ApiError> {
+        let updated_todo = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            UPDATE todos
+            SET title = $3, description = $4, priority = $5, due_date = $6,
+                checklist_auto_complete = $7, enforce_dependencies = $8, estimate_minutes = $9, updated_at = $10
+            WHERE id = $1 AND workspace_id IN ({}) AND deleted_at IS NULL
+            RETURNING {TODO_COLUMNS}
+            "#,
+            editable_workspaces(2)
+        ))
+        .bind(id)
+        .bind(user_id)
+        .bind(changes.title)
+        .bind(changes.description)
+        .bind(changes.priority)
+        .bind(changes.due_date)
+        .bind(changes.checklist_auto_complete)
+        .bind(changes.enforce_dependencies)
+        .bind(changes.estimate_minutes)
+        .bind(chrono::Utc::now())
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(updated_todo)
+    }
+
+    async fn update_status(&self, user_id: Uuid, transition: &StatusTransition) -> Result<Option<Todo>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+        let updated_todo = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            UPDATE todos
+            SET status = $4, started_at = $5, completed_at = $6, updated_at = $7
+            WHERE id = $1 AND workspace_id IN ({}) AND status = $3 AND deleted_at IS NULL
+              AND NOT ($4 = 'completed' AND enforce_dependencies AND EXISTS ({OPEN_BLOCKERS}))
+            RETURNING {TODO_COLUMNS}
+            "#,
+            editable_workspaces(2)
+        ))
+        .bind(transition.todo_id)
+        .bind(user_id)
+        .bind(transition.from)
+        .bind(transition.to)
+        .bind(transition.started_at)
+        .bind(transition.completed_at)
+        .bind(transition.occurred_at)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        if let Some(todo) = &updated_todo {
+            create_next_occurrence(&mut tx, todo).await?;
+        }
+        tx.commit().await?;
+
+        Ok(updated_todo)
+    }
+
+    async fn count_open_blockers(&self, id: Uuid) -> Result<i64, ApiError> {
+        let count: i64 = sqlx::query_scalar(&format!("SELECT COUNT(*) FROM ({OPEN_BLOCKERS}) AS open_blockers"))
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(count)
+    }
+
+    async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query(&format!(
+            "UPDATE todos SET deleted_at = $3 WHERE id = $1 AND workspace_id IN ({}) AND deleted_at IS NULL",
+            editable_workspaces(2)
+        ))
+            .bind(id)
+            .bind(user_id)
+            .bind(chrono::Utc::now())
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_trashed(&self, user_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Todo>, ApiError> {
+        let todos = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            SELECT {TODO_COLUMNS}
+            FROM todos
+            WHERE workspace_id IN ({}) AND deleted_at IS NOT NULL
+            ORDER BY deleted_at DESC, id DESC
+            LIMIT $2 OFFSET $3
+            "#,
+            member_workspaces(1)
+        ))
+        .bind(user_id)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(todos)
+    }
+
+    async fn count_trashed(&self, user_id: Uuid) -> Result<i64, ApiError> {
+        let total: i64 = sqlx::query_scalar(&format!(
+            "SELECT COUNT(*) FROM todos WHERE workspace_id IN ({}) AND deleted_at IS NOT NULL",
+            member_workspaces(1)
+        ))
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(total)
+    }
+
+    async fn restore(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ApiError> {
+        let restored_todo = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            UPDATE todos
+            SET deleted_at = NULL
+            WHERE id = $1 AND workspace_id IN ({}) AND deleted_at IS NOT NULL
+            RETURNING {TODO_COLUMNS}
+            "#,
+            editable_workspaces(2)
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(restored_todo)
+    }
+
+    async fn purge_trashed(&self, deleted_before: DateTime<Utc>, limit: i64) -> Result<u64, ApiError> {
+        // 여러 인스턴스가 동시에 실행해도 서로 기다리지 않도록 잠긴 행은 건너뛴다
+        let result = sqlx::query(
+            r#"
+            DELETE FROM todos
+            WHERE id IN (
+                SELECT id FROM todos
+                WHERE deleted_at IS NOT NULL AND deleted_at < $1
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            )
+            "#,
+        )
+        .bind(deleted_before)
+        .bind(limit)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn set_archived(&self, user_id: Uuid, id: Uuid, archived: bool) -> Result<Option<Todo>, ApiError> {
+        let todo = sqlx::query_as::<_, Todo>(&format!(
+            r#"
+            UPDATE todos
+            SET archived_at = CASE WHEN $3 THEN COALESCE(archived_at, NOW()) END
+            WHERE id = $1 AND workspace_id IN ({}) AND deleted_at IS NULL
+            RETURNING {TODO_COLUMNS}
+            "#,
+            editable_workspaces(2)
+        ))
+        .bind(id)
+        .bind(user_id)
+        .bind(archived)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(todo)
+    }
+
+    async fn auto_archive(&self, now: DateTime<Utc>, limit: i64) -> Result<u64, ApiError> {
+        let result = sqlx::query(AUTO_ARCHIVE_SQL)
+            .bind(now)
+            .bind(limit)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected())
+    }
+
+    async fn move_to(&self, user_id: Uuid, id: Uuid, after_id: Option<Uuid>, before_id: Option<Uuid>) -> Result<Option<Todo>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+        let workspace_id: Option<Uuid> = sqlx::query_scalar(&format!(
+            "SELECT workspace_id FROM todos WHERE id = $1 AND workspace_id IN ({}) AND deleted_at IS NULL",
+            editable_workspaces(2)
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some(workspace_id) = workspace_id else {
+            return Ok(None);
+        };
+        lock_positions(&mut tx, workspace_id).await?;
+
+        let mut rebalanced = false;
+        let position = loop {
+            match find_slot(&mut tx, workspace_id, id, after_id, before_id).await? {
+                Slot::Missing => return Ok(None),
+                Slot::Inverted => {
+                    return Err(ApiError::Validation(
+                        "after_id의 할일이 before_id의 할일보다 앞에 있어야 합니다".to_string(),
+                    ));
+                }
+                Slot::NeedsRebalance if !rebalanced => {
+                    rebalance(&mut tx, workspace_id).await?;
+                    rebalanced = true;
+                }
+                Slot::NeedsRebalance => {
+                    return Err(ApiError::Internal("정렬 키를 재배치하지 못했습니다".to_string()));
+                }
+                Slot::Between(lower, upper) => {
+                    break key_between(lower.as_deref(), upper.as_deref()).map_err(ApiError::Internal)?;
+                }
+            }
+        };
+
+        let moved_todo = sqlx::query_as::<_, Todo>(&format!(
+            "UPDATE todos SET position = $3 WHERE id = $1 AND workspace_id = $2 AND deleted_at IS NULL RETURNING {TODO_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(workspace_id)
+        .bind(position)
+        .fetch_optional(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(moved_todo)
+    }
+
+    async fn find_workspaces_needing_rebalance(&self, limit: i64) -> Result<Vec<Uuid>, ApiError> {
+        // 조건식을 부분 인덱스(idx_todos_position_rebalance)와 똑같이 맞춘다
+        let workspace_ids: Vec<Uuid> = sqlx::query_scalar(&format!(
+            r#"
+            SELECT DISTINCT workspace_id
+            FROM todos
+            WHERE position IS NULL OR char_length(position) > {MAX_POSITION_KEY_LEN}
+            LIMIT $1
+            "#
+        ))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(workspace_ids)
+    }
+
+    async fn rebalance_positions(&self, workspace_id: Uuid) -> Result<(), ApiError> {
+        let mut tx = self.pool.begin().await?;
+        lock_positions(&mut tx, workspace_id).await?;
+        rebalance(&mut tx, workspace_id).await?;
+        tx.commit().await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub TodoRepository {}
+
+        #[async_trait]
+        impl TodoRepository for TodoRepository {
+            async fn create(&self, todo: NewTodo) -> Result<Todo, ApiError>;
+            async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ApiError>;
+            async fn find_role(&self, user_id: Uuid, id: Uuid) -> Result<Option<WorkspaceRole>, ApiError>;
+            async fn find_all(&self, user_id: Uuid, query: &TodoQuery) -> Result<Vec<Todo>, ApiError>;
+            async fn count(&self, user_id: Uuid, filter: &TodoFilter) -> Result<i64, ApiError>;
+            async fn search(&self, user_id: Uuid, search: &TodoSearch) -> Result<Vec<TodoSearchHit>, ApiError>;
+            async fn stats(&self, user_id: Uuid, now: DateTime<Utc>, windows: &TimeWindows, include_archived: bool) -> Result<TodoStats, ApiError>;
+            async fn update(&self, user_id: Uuid, id: Uuid, changes: TodoChanges) -> Result<Option<Todo>, ApiError>;
+            async fn update_status(&self, user_id: Uuid, transition: &StatusTransition) -> Result<Option<Todo>, ApiError>;
+            async fn count_open_blockers(&self, id: Uuid) -> Result<i64, ApiError>;
+            async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn find_trashed(&self, user_id: Uuid, limit: i64, offset: i64) -> Result<Vec<Todo>, ApiError>;
+            async fn count_trashed(&self, user_id: Uuid) -> Result<i64, ApiError>;
+            async fn restore(&self, user_id: Uuid, id: Uuid) -> Result<Option<Todo>, ApiError>;
+            async fn purge_trashed(&self, deleted_before: DateTime<Utc>, limit: i64) -> Result<u64, ApiError>;
+            async fn set_archived(&self, user_id: Uuid, id: Uuid, archived: bool) -> Result<Option<Todo>, ApiError>;
+            async fn auto_archive(&self, now: DateTime<Utc>, limit: i64) -> Result<u64, ApiError>;
+            async fn move_to(&self, user_id: Uuid, id: Uuid, after_id: Option<Uuid>, before_id: Option<Uuid>) -> Result<Option<Todo>, ApiError>;
+            async fn find_workspaces_needing_rebalance(&self, limit: i64) -> Result<Vec<Uuid>, ApiError>;
+            async fn rebalance_positions(&self, workspace_id: Uuid) -> Result<(), ApiError>;
+        }
+    }
+
+    #[test]
+    fn test_auto_archive_skips_shared_workspaces() {
+        // 만든 사람의 개인 설정이 공유 워크스페이스의 할일을 다른 멤버에게서 숨기면 안 된다
+        assert!(AUTO_ARCHIVE_SQL.contains("JOIN workspaces w ON w.id = t.workspace_id AND w.is_personal"));
+        assert!(AUTO_ARCHIVE_SQL.contains("JOIN user_settings s ON s.user_id = w.created_by"));
+        assert!(!AUTO_ARCHIVE_SQL.contains("s.user_id = t.user_id"));
+    }
+}
@@ -0,0 +1,248 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sqlx::{PgConnection, PgPool};
+use uuid::Uuid;
+use crate::entities::todo::{Todo, TodoChanges, TodoStatus};
+use crate::entities::todo_series::{NewTodoSeries, TodoSeries};
+use crate::error::ApiError;
+use crate::repositories::todo_repository::{append_position, TODO_COLUMNS};
+
+const SERIES_COLUMNS: &str = "id, user_id, rrule, timezone, dtstart, title, description, priority, \
+    checklist_auto_complete, created_at, updated_at";
+
+/// 반복 일정 시리즈와 그 발생(할일)을 관리한다.
+/// 시리즈의 소유자는 첫 발생을 만든 사용자이고, 모든 조회/수정은 소유자 범위 안에서만 수행된다.
+/// 워크스페이스 권한은 호출 측에서 확인한다.
+#[async_trait]
+pub trait TodoSeriesRepository: Send + Sync {
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<TodoSeries>, ApiError>;
+    /// 새 시리즈를 만들고 할일을 첫 발생으로 연결한다
+    async fn start(&self, series: NewTodoSeries, todo_id: Uuid) -> Result<TodoSeries, ApiError>;
+    /// `from_index`번째 발생부터 새 시리즈로 옮기고 그 발생을 `changes`로 수정한다.
+    /// 발생이 하나도 남지 않은 이전 시리즈는 삭제한다.
+    async fn split(
+        &self,
+        old_series_id: Uuid,
+        from_index: i32,
+        series: NewTodoSeries,
+        todo_id: Uuid,
+        changes: TodoChanges,
+    ) -> Result<TodoSeries, ApiError>;
+    /// 할일을 시리즈에서 분리한다 (이후 발생이 더 만들어지지 않는다)
+    async fn detach(&self, user_id: Uuid, todo_id: Uuid) -> Result<bool, ApiError>;
+}
+
+pub struct PostgresTodoSeriesRepository {
+    pool: PgPool,
+}
+
+impl PostgresTodoSeriesRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+async fn insert_series(conn: &mut PgConnection, series: NewTodoSeries) -> Result<TodoSeries, ApiError> {
+    let created_series = sqlx::query_as::<_, TodoSeries>(&format!(
+        r#"
+        INSERT INTO todo_series (id, user_id, rrule, timezone, dtstart, title, description, priority, checklist_auto_complete)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        RETURNING {SERIES_COLUMNS}
+        "#
+    ))
+    .bind(Uuid::new_v4())
+    .bind(series.user_id)
+    .bind(series.rrule)
+    .bind(series.timezone)
+    .bind(series.dtstart)
+    .bind(series.title)
+    .bind(series.description)
+    .bind(series.priority)
+    .bind(series.checklist_auto_complete)
+    .fetch_one(conn)
+    .await?;
+
+    Ok(created_series)
+}
+
+/// 방금 완료한 발생의 다음 발생을 시리즈 템플릿으로 만든다. 완료와 같은 트랜잭션에서 호출하므로
+/// 완료가 커밋되면 다음 발생도 함께 남는다. 반복 할일이 아니거나 시리즈가 끝났거나 다음 발생이
+/// 이미 있으면 `None` (`(series_id, recurrence_index)` 유니크 인덱스로 멱등).
+/// 새 발생은 완료한 발생과 같은 워크스페이스에 만든다.
+pub(crate) async fn create_next_occurrence(conn: &mut PgConnection, completed: &Todo) -> Result<Option<Todo>, ApiError> {
+    let (TodoStatus::Completed, Some(series_id), Some(index)) = (completed.status, completed.series_id, completed.recurrence_index) else {
+        return Ok(None);
+    };
+    let series = sqlx::query_as::<_, TodoSeries>(&format!("SELECT {SERIES_COLUMNS} FROM todo_series WHERE id = $1"))
+        .bind(series_id)
+        .fetch_optional(&mut *conn)
+        .await?;
+    let Some(series) = series else {
+        return Ok(None);
+    };
+
+    let next_index = index + 1;
+    let due_date = series
+        .due_date_of(next_index)
+        .map_err(|e| ApiError::Internal(format!("저장된 반복 규칙을 해석할 수 없습니다: {}", e)))?;
+    let Some(due_date) = due_date else {
+        return Ok(None);
+    };
+
+    let now = Utc::now();
+    let position = append_position(&mut *conn, completed.workspace_id).await?;
+    let created_todo = sqlx::query_as::<_, Todo>(&format!(
+        r#"
+        INSERT INTO todos (id, user_id, workspace_id, title, description, status, priority, due_date,
+                           checklist_auto_complete, series_id, recurrence_index, position, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+        ON CONFLICT (series_id, recurrence_index) WHERE series_id IS NOT NULL DO NOTHING
+        RETURNING {TODO_COLUMNS}
+        "#
+    ))
+    .bind(Uuid::new_v4())
+    .bind(series.user_id)
+    .bind(completed.workspace_id)
+    .bind(&series.title)
+    .bind(&series.description)
+    .bind(TodoStatus::Pending)
+    .bind(series.priority)
+    .bind(due_date)
+    .bind(series.checklist_auto_complete)
+    .bind(series.id)
+    .bind(next_index)
+    .bind(position)
+    .bind(now)
+    .bind(now)
+    .fetch_optional(conn)
+    .await?;
+
+    Ok(created_todo)
+}
+
+#[async_trait]
+impl TodoSeriesRepository for PostgresTodoSeriesRepository {
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<TodoSeries>, ApiError> {
+        let series = sqlx::query_as::<_, TodoSeries>(&format!(
+            "SELECT {SERIES_COLUMNS} FROM todo_series WHERE id = $1 AND user_id = $2"
+        ))
+        .bind(id)
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(series)
+    }
+
+    async fn start(&self, series: NewTodoSeries, todo_id: Uuid) -> Result<TodoSeries, ApiError> {
+        let mut tx = self.pool.begin().await?;
+        let user_id = series.user_id;
+
+        let created_series = insert_series(&mut tx, series).await?;
+        sqlx::query("UPDATE todos SET series_id = $3, recurrence_index = 1 WHERE id = $1 AND user_id = $2")
+            .bind(todo_id)
+            .bind(user_id)
+            .bind(created_series.id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(created_series)
+    }
+
+    async fn split(
+        &self,
+        old_series_id: Uuid,
+        from_index: i32,
+        series: NewTodoSeries,
+        todo_id: Uuid,
+        changes: TodoChanges,
+    ) -> Result<TodoSeries, ApiError> {
+        let mut tx = self.pool.begin().await?;
+        let user_id = series.user_id;
+
+        let created_series = insert_series(&mut tx, series).await?;
+
+        sqlx::query(
+            r#"
+            UPDATE todos
+            SET series_id = $3, recurrence_index = recurrence_index - $4 + 1
+            WHERE series_id = $1 AND user_id = $2 AND recurrence_index >= $4
+            "#,
+        )
+        .bind(old_series_id)
+        .bind(user_id)
+        .bind(created_series.id)
+        .bind(from_index)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE todos
+            SET title = $3, description = $4, priority = $5, due_date = $6, checklist_auto_complete = $7
+            WHERE id = $1 AND user_id = $2
+            "#,
+        )
+        .bind(todo_id)
+        .bind(user_id)
+        .bind(changes.title)
+        .bind(changes.description)
+        .bind(changes.priority)
+        .bind(changes.due_date)
+        .bind(changes.checklist_auto_complete)
+        .execute(&mut *tx)
+        .await?;
+
+        sqlx::query(
+            "DELETE FROM todo_series WHERE id = $1 AND NOT EXISTS (SELECT 1 FROM todos WHERE series_id = $1)",
+        )
+        .bind(old_series_id)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(created_series)
+    }
+
+    async fn detach(&self, user_id: Uuid, todo_id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE todos
+            SET series_id = NULL, recurrence_index = NULL
+            WHERE id = $1 AND user_id = $2 AND series_id IS NOT NULL
+            "#,
+        )
+        .bind(todo_id)
+        .bind(user_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub TodoSeriesRepository {}
+
+        #[async_trait]
+        impl TodoSeriesRepository for TodoSeriesRepository {
+            async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<TodoSeries>, ApiError>;
+            async fn start(&self, series: NewTodoSeries, todo_id: Uuid) -> Result<TodoSeries, ApiError>;
+            async fn split(
+                &self,
+                old_series_id: Uuid,
+                from_index: i32,
+                series: NewTodoSeries,
+                todo_id: Uuid,
+                changes: TodoChanges,
+            ) -> Result<TodoSeries, ApiError>;
+            async fn detach(&self, user_id: Uuid, todo_id: Uuid) -> Result<bool, ApiError>;
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use sqlx::PgPool;
+use uuid::Uuid;
+use crate::entities::user_settings::{UserSettings, UserSettingsChanges};
+use crate::error::ApiError;
+
+const USER_SETTINGS_COLUMNS: &str = "user_id, timezone, week_start, auto_archive_days, created_at, updated_at";
+
+#[async_trait]
+pub trait UserSettingsRepository: Send + Sync {
+    /// 설정을 저장한 적 없는 사용자는 `None`
+    async fn find(&self, user_id: Uuid) -> Result<Option<UserSettings>, ApiError>;
+    async fn upsert(&self, user_id: Uuid, changes: UserSettingsChanges) -> Result<UserSettings, ApiError>;
+}
+
+pub struct PostgresUserSettingsRepository {
+    pool: PgPool,
+}
+
+impl PostgresUserSettingsRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UserSettingsRepository for PostgresUserSettingsRepository {
+    async fn find(&self, user_id: Uuid) -> Result<Option<UserSettings>, ApiError> {
+        let settings = sqlx::query_as::<_, UserSettings>(&format!(
+            "SELECT {USER_SETTINGS_COLUMNS} FROM user_settings WHERE user_id = $1"
+        ))
+        .bind(user_id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(settings)
+    }
+
+    async fn upsert(&self, user_id: Uuid, changes: UserSettingsChanges) -> Result<UserSettings, ApiError> {
+        let settings = sqlx::query_as::<_, UserSettings>(&format!(
+            r#"
+            INSERT INTO user_settings (user_id, timezone, week_start, auto_archive_days)
+            VALUES ($1, $2, $3, $4)
+            ON CONFLICT (user_id) DO UPDATE
+            SET timezone = EXCLUDED.timezone, week_start = EXCLUDED.week_start,
+                auto_archive_days = EXCLUDED.auto_archive_days
+            RETURNING {USER_SETTINGS_COLUMNS}
+            "#
+        ))
+        .bind(user_id)
+        .bind(changes.timezone)
+        .bind(changes.week_start)
+        .bind(changes.auto_archive_days)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(settings)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub UserSettingsRepository {}
+
+        #[async_trait]
+        impl UserSettingsRepository for UserSettingsRepository {
+            async fn find(&self, user_id: Uuid) -> Result<Option<UserSettings>, ApiError>;
+            async fn upsert(&self, user_id: Uuid, changes: UserSettingsChanges) -> Result<UserSettings, ApiError>;
+        }
+    }
+}
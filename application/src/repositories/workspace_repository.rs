@@ -0,0 +1,470 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, Postgres, Transaction};
+use uuid::Uuid;
+use crate::entities::workspace::{
+    NewInvitation, NewWorkspace, Workspace, WorkspaceInvitation, WorkspaceMember, WorkspaceRole, WorkspaceWithRole,
+    MAX_WORKSPACE_MEMBERS,
+};
+use crate::error::ApiError;
+
+const WORKSPACE_COLUMNS: &str = "w.id, w.name, w.is_personal, w.created_by, w.created_at, w.updated_at";
+
+/// `w`(workspaces)와 `$1` 사용자의 멤버십 `m`을 조인한 결과에서 읽는 열
+const WORKSPACE_WITH_ROLE: &str = "w.id, w.name, w.is_personal, w.created_by, w.created_at, w.updated_at, m.role, \
+    (SELECT COUNT(*) FROM workspace_members c WHERE c.workspace_id = w.id) AS member_count";
+
+/// `m`(workspace_members)과 `u`(users)를 조인한 결과에서 읽는 열
+const MEMBER_COLUMNS: &str = "m.workspace_id, m.user_id, u.username, u.email, m.role, m.created_at";
+
+/// `i`(workspace_invitations)와 `w`(workspaces)를 조인한 결과에서 읽는 열
+const INVITATION_COLUMNS: &str = "i.id, i.workspace_id, w.name AS workspace_name, i.email, i.role, i.invited_by, \
+    i.status, i.expires_at, i.responded_at, i.created_at";
+
+/// `$n` 사용자가 멤버인 워크스페이스. `workspace_id IN (...)` 조건에 넣는 서브쿼리다.
+pub(crate) fn member_workspaces(user_param: usize) -> String {
+    format!("SELECT workspace_id FROM workspace_members WHERE user_id = ${user_param}")
+}
+
+/// `$n` 사용자가 할일과 카테고리를 수정할 수 있는(owner, editor) 워크스페이스
+pub(crate) fn editable_workspaces(user_param: usize) -> String {
+    format!("SELECT workspace_id FROM workspace_members WHERE user_id = ${user_param} AND role IN ('owner', 'editor')")
+}
+
+/// 멤버십 조회와 변경. 역할 확인은 호출 측(서비스)에서 한다.
+#[async_trait]
+pub trait WorkspaceRepository: Send + Sync {
+    /// 사용자의 개인 워크스페이스. 없으면 만든다.
+    async fn ensure_personal(&self, user_id: Uuid) -> Result<Uuid, ApiError>;
+    /// 만든 사용자를 소유자로 추가한다
+    async fn create(&self, workspace: NewWorkspace) -> Result<WorkspaceWithRole, ApiError>;
+    /// 사용자가 멤버인 워크스페이스만 조회된다
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<WorkspaceWithRole>, ApiError>;
+    /// 개인 워크스페이스가 먼저, 나머지는 이름순
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<WorkspaceWithRole>, ApiError>;
+    /// 멤버가 아니면 `None`
+    async fn find_role(&self, workspace_id: Uuid, user_id: Uuid) -> Result<Option<WorkspaceRole>, ApiError>;
+    async fn rename(&self, id: Uuid, name: &str) -> Result<Option<Workspace>, ApiError>;
+    /// 할일과 카테고리도 함께 삭제된다. 개인 워크스페이스는 삭제하지 않는다.
+    async fn delete(&self, id: Uuid) -> Result<bool, ApiError>;
+    /// 참여한 순서
+    async fn find_members(&self, workspace_id: Uuid) -> Result<Vec<WorkspaceMember>, ApiError>;
+    /// 마지막 소유자는 다른 역할로 바꿀 수 없다 (`Conflict`). 멤버가 아니면 `None`.
+    async fn update_member_role(&self, workspace_id: Uuid, user_id: Uuid, role: WorkspaceRole) -> Result<Option<WorkspaceMember>, ApiError>;
+    /// 마지막 소유자는 내보낼 수 없다 (`Conflict`)
+    async fn remove_member(&self, workspace_id: Uuid, user_id: Uuid) -> Result<bool, ApiError>;
+    /// 같은 이메일로 대기 중인 초대가 있으면 취소하고 새로 만든다
+    async fn create_invitation(&self, invitation: NewInvitation) -> Result<WorkspaceInvitation, ApiError>;
+    /// 대기 중인 초대 (최근 순, 만료된 초대 포함)
+    async fn find_pending_invitations(&self, workspace_id: Uuid) -> Result<Vec<WorkspaceInvitation>, ApiError>;
+    async fn find_invitation_by_token(&self, token_hash: &str) -> Result<Option<WorkspaceInvitation>, ApiError>;
+    /// 대기 중인 초대만 취소된다
+    async fn revoke_invitation(&self, workspace_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+    /// 초대를 수락하고 멤버로 추가한다. 이미 멤버이면 역할은 그대로 둔다.
+    /// 이미 응답했거나 만료된 초대면 `None`, 멤버 수가 가득 찼으면 `Conflict`.
+    async fn accept_invitation(&self, id: Uuid, user_id: Uuid, now: DateTime<Utc>) -> Result<Option<WorkspaceMember>, ApiError>;
+    /// 이미 응답했거나 만료된 초대면 `false`
+    async fn decline_invitation(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, ApiError>;
+}
+
+pub struct PostgresWorkspaceRepository {
+    pool: PgPool,
+}
+
+impl PostgresWorkspaceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    async fn find_member(
+        tx: &mut Transaction<'_, Postgres>,
+        workspace_id: Uuid,
+        user_id: Uuid,
+    ) -> Result<Option<WorkspaceMember>, ApiError> {
+        let member = sqlx::query_as::<_, WorkspaceMember>(&format!(
+            r#"
+            SELECT {MEMBER_COLUMNS}
+            FROM workspace_members m LEFT JOIN users u ON u.id = m.user_id
+            WHERE m.workspace_id = $1 AND m.user_id = $2
+            "#
+        ))
+        .bind(workspace_id)
+        .bind(user_id)
+        .fetch_optional(&mut **tx)
+        .await?;
+
+        Ok(member)
+    }
+
+    /// 워크스페이스 행을 잠가 멤버 변경을 직렬화하고, 소유자가 한 명 이상 남는지 확인한다
+    async fn lock_and_check_owners(
+        tx: &mut Transaction<'_, Postgres>,
+        workspace_id: Uuid,
+        user_id: Uuid,
+        demoting: bool,
+    ) -> Result<Option<WorkspaceRole>, ApiError> {
+        sqlx::query("SELECT id FROM workspaces WHERE id = $1 FOR UPDATE")
+            .bind(workspace_id)
+            .execute(&mut **tx)
+            .await?;
+
+        let role: Option<WorkspaceRole> =
+            sqlx::query_scalar("SELECT role FROM workspace_members WHERE workspace_id = $1 AND user_id = $2")
+                .bind(workspace_id)
+                .bind(user_id)
+                .fetch_optional(&mut **tx)
+                .await?;
+
+        if demoting && role == Some(WorkspaceRole::Owner) {
+            let owners: i64 = sqlx::query_scalar(
+                "SELECT COUNT(*) FROM workspace_members WHERE workspace_id = $1 AND role = 'owner'",
+            )
+            .bind(workspace_id)
+            .fetch_one(&mut **tx)
+            .await?;
+            if owners <= 1 {
+                return Err(ApiError::Conflict("워크스페이스에는 소유자가 한 명 이상 있어야 합니다".to_string()));
+            }
+        }
+
+        Ok(role)
+    }
+}
+
+#[async_trait]
+impl WorkspaceRepository for PostgresWorkspaceRepository {
+    async fn ensure_personal(&self, user_id: Uuid) -> Result<Uuid, ApiError> {
+        let id: Uuid = sqlx::query_scalar("SELECT personal_workspace_id($1)")
+            .bind(user_id)
+            .fetch_one(&self.pool)
+            .await?;
+
+        Ok(id)
+    }
+
+    async fn create(&self, workspace: NewWorkspace) -> Result<WorkspaceWithRole, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        let id: Uuid = sqlx::query_scalar("INSERT INTO workspaces (name, created_by) VALUES ($1, $2) RETURNING id")
+            .bind(&workspace.name)
+            .bind(workspace.created_by)
+            .fetch_one(&mut *tx)
+            .await?;
+        sqlx::query("INSERT INTO workspace_members (workspace_id, user_id, role) VALUES ($1, $2, 'owner')")
+            .bind(id)
+            .bind(workspace.created_by)
+            .execute(&mut *tx)
+            .await?;
+
+        let created = sqlx::query_as::<_, WorkspaceWithRole>(&format!(
+            r#"
+            SELECT {WORKSPACE_WITH_ROLE}
+            FROM workspaces w JOIN workspace_members m ON m.workspace_id = w.id AND m.user_id = $1
+            WHERE w.id = $2
+            "#
+        ))
+        .bind(workspace.created_by)
+        .bind(id)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(created)
+    }
+
+    async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<WorkspaceWithRole>, ApiError> {
+        let workspace = sqlx::query_as::<_, WorkspaceWithRole>(&format!(
+            r#"
+            SELECT {WORKSPACE_WITH_ROLE}
+            FROM workspaces w JOIN workspace_members m ON m.workspace_id = w.id AND m.user_id = $1
+            WHERE w.id = $2
+            "#
+        ))
+        .bind(user_id)
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(workspace)
+    }
+
+    async fn find_all(&self, user_id: Uuid) -> Result<Vec<WorkspaceWithRole>, ApiError> {
+        let workspaces = sqlx::query_as::<_, WorkspaceWithRole>(&format!(
+            r#"
+            SELECT {WORKSPACE_WITH_ROLE}
+            FROM workspaces w JOIN workspace_members m ON m.workspace_id = w.id AND m.user_id = $1
+            ORDER BY w.is_personal DESC, w.name ASC, w.id ASC
+            "#
+        ))
+        .bind(user_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(workspaces)
+    }
+
+    async fn find_role(&self, workspace_id: Uuid, user_id: Uuid) -> Result<Option<WorkspaceRole>, ApiError> {
+        let role: Option<WorkspaceRole> =
+            sqlx::query_scalar("SELECT role FROM workspace_members WHERE workspace_id = $1 AND user_id = $2")
+                .bind(workspace_id)
+                .bind(user_id)
+                .fetch_optional(&self.pool)
+                .await?;
+
+        Ok(role)
+    }
+
+    async fn rename(&self, id: Uuid, name: &str) -> Result<Option<Workspace>, ApiError> {
+        let workspace = sqlx::query_as::<_, Workspace>(&format!(
+            "UPDATE workspaces w SET name = $2 WHERE w.id = $1 RETURNING {WORKSPACE_COLUMNS}"
+        ))
+        .bind(id)
+        .bind(name)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(workspace)
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query("DELETE FROM workspaces WHERE id = $1 AND NOT is_personal")
+            .bind(id)
+            .execute(&self.pool)
+            .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn find_members(&self, workspace_id: Uuid) -> Result<Vec<WorkspaceMember>, ApiError> {
+        let members = sqlx::query_as::<_, WorkspaceMember>(&format!(
+            r#"
+            SELECT {MEMBER_COLUMNS}
+            FROM workspace_members m LEFT JOIN users u ON u.id = m.user_id
+            WHERE m.workspace_id = $1
+            ORDER BY m.created_at ASC, m.user_id ASC
+            "#
+        ))
+        .bind(workspace_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(members)
+    }
+
+    async fn update_member_role(&self, workspace_id: Uuid, user_id: Uuid, role: WorkspaceRole) -> Result<Option<WorkspaceMember>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        let demoting = role != WorkspaceRole::Owner;
+        if Self::lock_and_check_owners(&mut tx, workspace_id, user_id, demoting).await?.is_none() {
+            return Ok(None);
+        }
+
+        sqlx::query("UPDATE workspace_members SET role = $3 WHERE workspace_id = $1 AND user_id = $2")
+            .bind(workspace_id)
+            .bind(user_id)
+            .bind(role)
+            .execute(&mut *tx)
+            .await?;
+        let member = Self::find_member(&mut tx, workspace_id, user_id).await?;
+
+        tx.commit().await?;
+        Ok(member)
+    }
+
+    async fn remove_member(&self, workspace_id: Uuid, user_id: Uuid) -> Result<bool, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        if Self::lock_and_check_owners(&mut tx, workspace_id, user_id, true).await?.is_none() {
+            return Ok(false);
+        }
+
+        let result = sqlx::query("DELETE FROM workspace_members WHERE workspace_id = $1 AND user_id = $2")
+            .bind(workspace_id)
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn create_invitation(&self, invitation: NewInvitation) -> Result<WorkspaceInvitation, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        sqlx::query(
+            r#"
+            UPDATE workspace_invitations
+            SET status = 'revoked', responded_at = NOW()
+            WHERE workspace_id = $1 AND lower(email) = lower($2) AND status = 'pending'
+            "#,
+        )
+        .bind(invitation.workspace_id)
+        .bind(&invitation.email)
+        .execute(&mut *tx)
+        .await?;
+
+        let created = sqlx::query_as::<_, WorkspaceInvitation>(&format!(
+            r#"
+            WITH i AS (
+                INSERT INTO workspace_invitations (workspace_id, email, role, token_hash, invited_by, expires_at)
+                VALUES ($1, $2, $3, $4, $5, $6)
+                RETURNING *
+            )
+            SELECT {INVITATION_COLUMNS} FROM i JOIN workspaces w ON w.id = i.workspace_id
+            "#
+        ))
+        .bind(invitation.workspace_id)
+        .bind(invitation.email)
+        .bind(invitation.role)
+        .bind(invitation.token_hash)
+        .bind(invitation.invited_by)
+        .bind(invitation.expires_at)
+        .fetch_one(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        Ok(created)
+    }
+
+    async fn find_pending_invitations(&self, workspace_id: Uuid) -> Result<Vec<WorkspaceInvitation>, ApiError> {
+        let invitations = sqlx::query_as::<_, WorkspaceInvitation>(&format!(
+            r#"
+            SELECT {INVITATION_COLUMNS}
+            FROM workspace_invitations i JOIN workspaces w ON w.id = i.workspace_id
+            WHERE i.workspace_id = $1 AND i.status = 'pending'
+            ORDER BY i.created_at DESC, i.id DESC
+            "#
+        ))
+        .bind(workspace_id)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(invitations)
+    }
+
+    async fn find_invitation_by_token(&self, token_hash: &str) -> Result<Option<WorkspaceInvitation>, ApiError> {
+        let invitation = sqlx::query_as::<_, WorkspaceInvitation>(&format!(
+            r#"
+            SELECT {INVITATION_COLUMNS}
+            FROM workspace_invitations i JOIN workspaces w ON w.id = i.workspace_id
+            WHERE i.token_hash = $1
+            "#
+        ))
+        .bind(token_hash)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(invitation)
+    }
+
+    async fn revoke_invitation(&self, workspace_id: Uuid, id: Uuid) -> Result<bool, ApiError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE workspace_invitations
+            SET status = 'revoked', responded_at = NOW()
+            WHERE id = $1 AND workspace_id = $2 AND status = 'pending'
+            "#,
+        )
+        .bind(id)
+        .bind(workspace_id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+
+    async fn accept_invitation(&self, id: Uuid, user_id: Uuid, now: DateTime<Utc>) -> Result<Option<WorkspaceMember>, ApiError> {
+        let mut tx = self.pool.begin().await?;
+
+        let accepted = sqlx::query_as::<_, (Uuid, WorkspaceRole)>(
+            r#"
+            UPDATE workspace_invitations
+            SET status = 'accepted', responded_at = $2
+            WHERE id = $1 AND status = 'pending' AND expires_at > $2
+            RETURNING workspace_id, role
+            "#,
+        )
+        .bind(id)
+        .bind(now)
+        .fetch_optional(&mut *tx)
+        .await?;
+        let Some((workspace_id, role)) = accepted else {
+            return Ok(None);
+        };
+
+        // 동시에 수락한 초대가 함께 멤버 수 제한을 넘지 않게 한다
+        sqlx::query("SELECT id FROM workspaces WHERE id = $1 FOR UPDATE")
+            .bind(workspace_id)
+            .execute(&mut *tx)
+            .await?;
+        if Self::find_member(&mut tx, workspace_id, user_id).await?.is_none() {
+            let members: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM workspace_members WHERE workspace_id = $1")
+                .bind(workspace_id)
+                .fetch_one(&mut *tx)
+                .await?;
+            if members >= MAX_WORKSPACE_MEMBERS {
+                return Err(ApiError::Conflict(format!(
+                    "워크스페이스 멤버는 최대 {}명까지 둘 수 있습니다",
+                    MAX_WORKSPACE_MEMBERS
+                )));
+            }
+
+            sqlx::query("INSERT INTO workspace_members (workspace_id, user_id, role) VALUES ($1, $2, $3)")
+                .bind(workspace_id)
+                .bind(user_id)
+                .bind(role)
+                .execute(&mut *tx)
+                .await?;
+        }
+        let member = Self::find_member(&mut tx, workspace_id, user_id).await?;
+
+        tx.commit().await?;
+        Ok(member)
+    }
+
+    async fn decline_invitation(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, ApiError> {
+        let result = sqlx::query(
+            r#"
+            UPDATE workspace_invitations
+            SET status = 'declined', responded_at = $2
+            WHERE id = $1 AND status = 'pending' AND expires_at > $2
+            "#,
+        )
+        .bind(id)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use mockall::mock;
+
+    mock! {
+        pub WorkspaceRepository {}
+
+        #[async_trait]
+        impl WorkspaceRepository for WorkspaceRepository {
+            async fn ensure_personal(&self, user_id: Uuid) -> Result<Uuid, ApiError>;
+            async fn create(&self, workspace: NewWorkspace) -> Result<WorkspaceWithRole, ApiError>;
+            async fn find_by_id(&self, user_id: Uuid, id: Uuid) -> Result<Option<WorkspaceWithRole>, ApiError>;
+            async fn find_all(&self, user_id: Uuid) -> Result<Vec<WorkspaceWithRole>, ApiError>;
+            async fn find_role(&self, workspace_id: Uuid, user_id: Uuid) -> Result<Option<WorkspaceRole>, ApiError>;
+            async fn rename(&self, id: Uuid, name: &str) -> Result<Option<Workspace>, ApiError>;
+            async fn delete(&self, id: Uuid) -> Result<bool, ApiError>;
+            async fn find_members(&self, workspace_id: Uuid) -> Result<Vec<WorkspaceMember>, ApiError>;
+            async fn update_member_role(&self, workspace_id: Uuid, user_id: Uuid, role: WorkspaceRole) -> Result<Option<WorkspaceMember>, ApiError>;
+            async fn remove_member(&self, workspace_id: Uuid, user_id: Uuid) -> Result<bool, ApiError>;
+            async fn create_invitation(&self, invitation: NewInvitation) -> Result<WorkspaceInvitation, ApiError>;
+            async fn find_pending_invitations(&self, workspace_id: Uuid) -> Result<Vec<WorkspaceInvitation>, ApiError>;
+            async fn find_invitation_by_token(&self, token_hash: &str) -> Result<Option<WorkspaceInvitation>, ApiError>;
+            async fn revoke_invitation(&self, workspace_id: Uuid, id: Uuid) -> Result<bool, ApiError>;
+            async fn accept_invitation(&self, id: Uuid, user_id: Uuid, now: DateTime<Utc>) -> Result<Option<WorkspaceMember>, ApiError>;
+            async fn decline_invitation(&self, id: Uuid, now: DateTime<Utc>) -> Result<bool, ApiError>;
+        }
+    }
+}
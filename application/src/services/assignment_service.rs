@@ -0,0 +1,293 @@
+use std::sync::Arc;
+use chrono::Utc;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::assignment_repository::AssignmentRepository;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::repositories::user_repository::UserRepository;
+use crate::repositories::workspace_repository::WorkspaceRepository;
+use crate::services::workspace_service::authorize_todo_edit;
+use crate::dto::request::assignment_request::AssignTodoRequest;
+use crate::dto::response::assignment_response::{
+    AssigneeListResponse, AssigneeResponse, MemberWorkloadResponse, WorkloadResponse,
+};
+use crate::entities::assignment::{AssignOutcome, MAX_ASSIGNEES_PER_TODO};
+use crate::entities::todo::Todo;
+use crate::error::ApiError;
+use crate::notifications::{Notification, NotificationKind, Notifier};
+
+/// 할일 담당자 지정과 워크스페이스 멤버별 업무량. 담당자는 할일이 있는 워크스페이스의 멤버여야 한다.
+pub struct AssignmentService {
+    assignment_repository: Arc<dyn AssignmentRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    notifier: Arc<dyn Notifier>,
+}
+
+impl AssignmentService {
+    pub fn new(
+        assignment_repository: Arc<dyn AssignmentRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        notifier: Arc<dyn Notifier>,
+    ) -> Self {
+        Self {
+            assignment_repository,
+            todo_repository,
+            workspace_repository,
+            user_repository,
+            notifier,
+        }
+    }
+
+    /// 할일 담당자 조회
+    pub async fn list(&self, user_id: Uuid, todo_id: Uuid) -> Result<AssigneeListResponse, ApiError> {
+        self.find_todo(user_id, todo_id).await?;
+        self.assignees(todo_id).await
+    }
+
+    /// 담당자 지정 (owner, editor). 새로 지정된 사용자에게 알림을 보낸다.
+    pub async fn assign(&self, user_id: Uuid, todo_id: Uuid, request: AssignTodoRequest) -> Result<AssigneeListResponse, ApiError> {
+        request.validate()?;
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+        let todo = self.find_todo(user_id, todo_id).await?;
+
+        let mut user_ids = request.user_ids;
+        user_ids.sort();
+        user_ids.dedup();
+
+        let assigned = match self.assignment_repository.assign(todo_id, &user_ids, user_id).await? {
+            AssignOutcome::Assigned(assigned) => assigned,
+            AssignOutcome::NotMembers(_) => {
+                return Err(ApiError::Validation("워크스페이스 멤버만 담당자로 지정할 수 있습니다".to_string()));
+            }
+            AssignOutcome::TooMany => {
+                return Err(ApiError::Validation(format!(
+                    "담당자는 할일마다 최대 {}명까지 지정할 수 있습니다",
+                    MAX_ASSIGNEES_PER_TODO
+                )));
+            }
+        };
+
+        self.notify_assignees(user_id, &todo, &assigned).await;
+        self.assignees(todo_id).await
+    }
+
+    /// 담당자 해제 (owner, editor)
+    pub async fn unassign(&self, user_id: Uuid, todo_id: Uuid, assignee_id: Uuid) -> Result<(), ApiError> {
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+        if !self.assignment_repository.unassign(todo_id, assignee_id).await? {
+            return Err(ApiError::NotFound("담당자를 찾을 수 없습니다".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// 멤버별 미완료 담당 할일 수 (워크스페이스 멤버 누구나)
+    pub async fn workload(&self, user_id: Uuid, workspace_id: Uuid) -> Result<WorkloadResponse, ApiError> {
+        if self.workspace_repository.find_role(workspace_id, user_id).await?.is_none() {
+            return Err(ApiError::NotFound("워크스페이스를 찾을 수 없습니다".to_string()));
+        }
+
+        let members = self.assignment_repository.workload(workspace_id, Utc::now()).await?;
+        let unassigned_todos = self.assignment_repository.count_unassigned(workspace_id).await?;
+
+        Ok(WorkloadResponse {
+            workspace_id,
+            members: members.into_iter().map(MemberWorkloadResponse::from).collect(),
+            unassigned_todos,
+        })
+    }
+
+    /// 스스로 맡은 경우는 알리지 않는다. 알림 실패는 지정을 되돌리지 않는다.
+    async fn notify_assignees(&self, assigner_id: Uuid, todo: &Todo, assigned: &[Uuid]) {
+        let recipients: Vec<Uuid> = assigned.iter().copied().filter(|id| *id != assigner_id).collect();
+        if recipients.is_empty() {
+            return;
+        }
+
+        let assigner = match self.user_repository.find_by_id(assigner_id).await {
+            Ok(Some(user)) => user.username,
+            _ => "알 수 없는 사용자".to_string(),
+        };
+        let body = match todo.due_date {
+            Some(due_date) => format!("마감: {}", due_date.to_rfc3339()),
+            None => "마감일이 없는 할일입니다".to_string(),
+        };
+
+        for recipient in recipients {
+            let notification = Notification {
+                id: Uuid::new_v4(),
+                kind: NotificationKind::Assignment,
+                user_id: recipient,
+                todo_id: Some(todo.id),
+                title: format!("{}님이 '{}' 할일의 담당자로 회원님을 지정했습니다", assigner, todo.title),
+                body: body.clone(),
+                created_at: Utc::now(),
+            };
+            if let Err(err) = self.notifier.notify(&notification).await {
+                tracing::warn!(%err, todo_id = %todo.id, user_id = %recipient, "failed to notify assignee");
+            }
+        }
+    }
+
+    async fn find_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Todo, ApiError> {
+        self.todo_repository
+            .find_by_id(user_id, todo_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("할일을 찾을 수 없습니다".to_string()))
+    }
+
+    async fn assignees(&self, todo_id: Uuid) -> Result<AssigneeListResponse, ApiError> {
+        let assignees = self.assignment_repository.find_by_todo(todo_id).await?;
+
+        Ok(AssigneeListResponse {
+            todo_id,
+            assignees: assignees.into_iter().map(AssigneeResponse::from).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::user::User;
+    use crate::entities::workspace::WorkspaceRole;
+    use crate::notifications::tests::MockNotifier;
+    use crate::repositories::assignment_repository::tests::MockAssignmentRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::user_repository::tests::MockUserRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+
+    fn todo_repo_with_role(role: WorkspaceRole) -> MockTodoRepository {
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo.expect_find_role().returning(move |_, _| Ok(Some(role)));
+        todo_repo
+            .expect_find_by_id()
+            .returning(|user_id, id| Ok(Some(Todo::fixture(user_id, id))));
+        todo_repo
+    }
+
+    fn service(
+        assignment_repo: MockAssignmentRepository,
+        todo_repo: MockTodoRepository,
+        workspace_repo: MockWorkspaceRepository,
+        user_repo: MockUserRepository,
+        notifier: MockNotifier,
+    ) -> AssignmentService {
+        AssignmentService::new(
+            Arc::new(assignment_repo),
+            Arc::new(todo_repo),
+            Arc::new(workspace_repo),
+            Arc::new(user_repo),
+            Arc::new(notifier),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_assign_notifies_new_assignees_except_self() {
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+        let kim_id = Uuid::new_v4();
+
+        let mut assignment_repo = MockAssignmentRepository::new();
+        assignment_repo
+            .expect_assign()
+            .withf(move |_, user_ids, assigned_by| user_ids.len() == 2 && *assigned_by == user_id)
+            .times(1)
+            .returning(move |_, _, _| Ok(AssignOutcome::Assigned(vec![user_id, kim_id])));
+        assignment_repo.expect_find_by_todo().returning(|_| Ok(vec![]));
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_id().returning(move |id| {
+            Ok(Some(User {
+                id,
+                email: "lee@example.com".to_string(),
+                username: "lee".to_string(),
+                password_hash: "hash".to_string(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            }))
+        });
+
+        let mut notifier = MockNotifier::new();
+        notifier
+            .expect_notify()
+            .withf(move |n| n.kind == NotificationKind::Assignment && n.user_id == kim_id && n.todo_id == Some(todo_id))
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = service(
+            assignment_repo,
+            todo_repo_with_role(WorkspaceRole::Editor),
+            MockWorkspaceRepository::new(),
+            user_repo,
+            notifier,
+        );
+        let request = AssignTodoRequest { user_ids: vec![kim_id, user_id, kim_id] };
+
+        assert!(service.assign(user_id, todo_id, request).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_assign_rejects_non_members() {
+        let mut assignment_repo = MockAssignmentRepository::new();
+        assignment_repo
+            .expect_assign()
+            .returning(|_, user_ids, _| Ok(AssignOutcome::NotMembers(user_ids.to_vec())));
+
+        let mut notifier = MockNotifier::new();
+        notifier.expect_notify().never();
+
+        let service = service(
+            assignment_repo,
+            todo_repo_with_role(WorkspaceRole::Owner),
+            MockWorkspaceRepository::new(),
+            MockUserRepository::new(),
+            notifier,
+        );
+        let request = AssignTodoRequest { user_ids: vec![Uuid::new_v4()] };
+
+        let result = service.assign(Uuid::new_v4(), Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_viewer_cannot_assign() {
+        let mut assignment_repo = MockAssignmentRepository::new();
+        assignment_repo.expect_assign().never();
+
+        let service = service(
+            assignment_repo,
+            todo_repo_with_role(WorkspaceRole::Viewer),
+            MockWorkspaceRepository::new(),
+            MockUserRepository::new(),
+            MockNotifier::new(),
+        );
+        let request = AssignTodoRequest { user_ids: vec![Uuid::new_v4()] };
+
+        let result = service.assign(Uuid::new_v4(), Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_workload_requires_membership() {
+        let mut workspace_repo = MockWorkspaceRepository::new();
+        workspace_repo.expect_find_role().returning(|_, _| Ok(None));
+        let mut assignment_repo = MockAssignmentRepository::new();
+        assignment_repo.expect_workload().never();
+
+        let service = service(
+            assignment_repo,
+            MockTodoRepository::new(),
+            workspace_repo,
+            MockUserRepository::new(),
+            MockNotifier::new(),
+        );
+
+        let result = service.workload(Uuid::new_v4(), Uuid::new_v4()).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+}
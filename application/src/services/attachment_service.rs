@@ -0,0 +1,437 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+use bytes::Bytes;
+use chrono::{Duration, Utc};
+use futures_util::Stream;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use uuid::Uuid;
+use crate::repositories::attachment_repository::AttachmentRepository;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::services::workspace_service::authorize_todo_edit;
+use crate::dto::response::attachment_response::{AttachmentListResponse, AttachmentResponse, AttachmentUrlResponse};
+use crate::entities::attachment::{sanitize_file_name, Attachment, NewAttachment, MAX_ATTACHMENTS_PER_TODO};
+use crate::error::ApiError;
+use crate::storage::{spool, BlobBody, BlobStore};
+use crate::utils::content_sniff;
+use crate::utils::signed_url::UrlSigner;
+
+/// 첨부 파일 설정
+#[derive(Debug, Clone)]
+pub struct AttachmentConfig {
+    /// 파일 하나의 최대 크기 (바이트)
+    pub max_file_bytes: u64,
+    pub max_per_todo: i64,
+    /// 서명된 다운로드 URL의 유효 기간
+    pub url_ttl: Duration,
+    /// 업로드를 받아 두는 임시 디렉터리
+    pub spool_dir: PathBuf,
+    /// 지울 파일을 찾는 주기
+    pub cleanup_interval: StdDuration,
+    /// 한 번에 지우는 최대 파일 수
+    pub cleanup_batch_size: i64,
+}
+
+impl Default for AttachmentConfig {
+    fn default() -> Self {
+        Self {
+            max_file_bytes: 10 * 1024 * 1024,
+            max_per_todo: MAX_ATTACHMENTS_PER_TODO,
+            url_ttl: Duration::minutes(15),
+            spool_dir: std::env::temp_dir().join("tbm-uploads"),
+            cleanup_interval: StdDuration::from_secs(60),
+            cleanup_batch_size: 100,
+        }
+    }
+}
+
+/// 할일 첨부 파일. 내용은 [`BlobStore`]에, 메타데이터는 DB에 둔다.
+pub struct AttachmentService {
+    attachment_repository: Arc<dyn AttachmentRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    blob_store: Arc<dyn BlobStore>,
+    signer: UrlSigner,
+    config: AttachmentConfig,
+}
+
+impl AttachmentService {
+    pub fn new(
+        attachment_repository: Arc<dyn AttachmentRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        blob_store: Arc<dyn BlobStore>,
+        signer: UrlSigner,
+        config: AttachmentConfig,
+    ) -> Self {
+        Self {
+            attachment_repository,
+            todo_repository,
+            blob_store,
+            signer,
+            config,
+        }
+    }
+
+    pub fn max_file_bytes(&self) -> u64 {
+        self.config.max_file_bytes
+    }
+
+    /// 파일 업로드. 스트림을 임시 파일로 받아 크기 제한과 형식을 확인한 뒤 저장소에 올린다.
+    /// `declared_type`은 클라이언트가 밝힌 형식으로, 텍스트의 세부 형식을 고를 때만 쓴다.
+    pub async fn upload<S, E>(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        file_name: Option<&str>,
+        declared_type: Option<&str>,
+        stream: S,
+    ) -> Result<AttachmentResponse, ApiError>
+    where
+        S: Stream<Item = Result<Bytes, E>> + Unpin,
+        E: std::fmt::Display,
+    {
+        let file_name = file_name
+            .and_then(sanitize_file_name)
+            .ok_or_else(|| ApiError::Validation("파일 이름이 필요합니다".to_string()))?;
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+
+        let blob = spool(&self.config.spool_dir, stream, self.config.max_file_bytes).await?;
+        let truncated = blob.size > blob.head.len() as u64;
+        let content_type = content_sniff::sniff(&blob.head, truncated, declared_type).ok_or_else(|| {
+            ApiError::UnsupportedMediaType("이미지, PDF, 텍스트 파일만 첨부할 수 있습니다".to_string())
+        })?;
+
+        let id = Uuid::new_v4();
+        let storage_key = NewAttachment::storage_key(user_id, id);
+        self.blob_store.put(&storage_key, &blob).await?;
+
+        let created = self
+            .attachment_repository
+            .create(
+                NewAttachment {
+                    id,
+                    user_id,
+                    todo_id,
+                    file_name,
+                    content_type: content_type.to_string(),
+                    size_bytes: blob.size as i64,
+                    sha256: blob.sha256.clone(),
+                    storage_key: storage_key.clone(),
+                },
+                self.config.max_per_todo,
+            )
+            .await;
+
+        match created {
+            Ok(attachment) => Ok(AttachmentResponse::from(attachment)),
+            Err(err) => {
+                // 행이 없으니 정리 큐에도 들어가지 않는다. 여기서 지우지 못하면 파일이 남는다.
+                if let Err(cleanup_err) = self.blob_store.delete(&storage_key).await {
+                    tracing::warn!(%cleanup_err, storage_key, "failed to remove orphaned attachment blob");
+                }
+                Err(err)
+            }
+        }
+    }
+
+    /// 할일의 첨부 파일 목록
+    pub async fn list(&self, user_id: Uuid, todo_id: Uuid) -> Result<AttachmentListResponse, ApiError> {
+        self.find_todo(user_id, todo_id).await?;
+        let attachments = self.attachment_repository.find_by_todo(todo_id).await?;
+
+        Ok(AttachmentListResponse {
+            todo_id,
+            total_bytes: attachments.iter().map(|attachment| attachment.size_bytes).sum(),
+            attachments: attachments.into_iter().map(AttachmentResponse::from).collect(),
+        })
+    }
+
+    /// 인증 없이 내려받을 수 있는 만료 URL 발급
+    pub async fn download_url(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<AttachmentUrlResponse, ApiError> {
+        self.find_todo(user_id, todo_id).await?;
+        let attachment = self.find_attachment(todo_id, id).await?;
+
+        let expires_at = Utc::now() + self.config.url_ttl;
+        let expires = expires_at.timestamp();
+        let signature = self.signer.sign(&Self::signed_resource(attachment.id), expires);
+
+        Ok(AttachmentUrlResponse {
+            url: format!(
+                "/api/v1/attachments/{}/content?expires={}&signature={}",
+                attachment.id, expires, signature
+            ),
+            expires_at,
+        })
+    }
+
+    /// 서명된 URL로 내용 열기
+    pub async fn open_signed(&self, id: Uuid, expires: i64, signature: &str) -> Result<(Attachment, BlobBody), ApiError> {
+        self.signer.verify(&Self::signed_resource(id), expires, signature, Utc::now())?;
+
+        let attachment = self
+            .attachment_repository
+            .find(id)
+            .await?
+            .ok_or_else(Self::attachment_not_found)?;
+        let body = self.blob_store.get(&attachment.storage_key).await?.ok_or_else(|| {
+            tracing::error!(attachment_id = %attachment.id, "attachment blob is missing from storage");
+            Self::attachment_not_found()
+        })?;
+
+        Ok((attachment, body))
+    }
+
+    /// 첨부 파일 삭제. 저장소의 파일은 바로 지우되, 실패하면 정리 작업에 맡긴다.
+    pub async fn delete(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<(), ApiError> {
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+        let attachment = self.find_attachment(todo_id, id).await?;
+        if !self.attachment_repository.delete(todo_id, id).await? {
+            return Err(Self::attachment_not_found());
+        }
+
+        match self.blob_store.delete(&attachment.storage_key).await {
+            Ok(()) => self.attachment_repository.finish_blob_deletion(&attachment.storage_key).await?,
+            Err(err) => tracing::warn!(%err, storage_key = attachment.storage_key, "deferred attachment blob deletion"),
+        }
+
+        Ok(())
+    }
+
+    /// 정리 워커를 시작한다. 할일 영구 삭제 등으로 행이 사라진 파일을 저장소에서 지운다.
+    pub fn start_blob_cleaner(self: Arc<Self>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.config.cleanup_interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                ticker.tick().await;
+                if let Err(err) = self.clean_blobs().await {
+                    tracing::error!(%err, "failed to clean attachment blobs");
+                }
+            }
+        })
+    }
+
+    /// 정리 큐에서 지울 차례가 된 파일을 지우고 지운 개수를 반환한다.
+    /// 실패한 키는 큐에 남아 나중에 다시 시도한다.
+    pub async fn clean_blobs(&self) -> Result<usize, ApiError> {
+        let keys = self
+            .attachment_repository
+            .claim_blob_deletions(self.config.cleanup_batch_size)
+            .await?;
+
+        let mut removed = 0;
+        for key in keys {
+            match self.blob_store.delete(&key).await {
+                Ok(()) => {
+                    self.attachment_repository.finish_blob_deletion(&key).await?;
+                    removed += 1;
+                }
+                Err(err) => tracing::warn!(%err, storage_key = key, "failed to delete attachment blob"),
+            }
+        }
+        if removed > 0 {
+            tracing::info!(removed, "removed attachment blobs");
+        }
+
+        Ok(removed)
+    }
+
+    fn signed_resource(id: Uuid) -> String {
+        format!("attachments/{}", id)
+    }
+
+    async fn find_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<(), ApiError> {
+        self.todo_repository
+            .find_by_id(user_id, todo_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("할일을 찾을 수 없습니다".to_string()))?;
+        Ok(())
+    }
+
+    async fn find_attachment(&self, todo_id: Uuid, id: Uuid) -> Result<Attachment, ApiError> {
+        self.attachment_repository
+            .find_by_id(todo_id, id)
+            .await?
+            .ok_or_else(Self::attachment_not_found)
+    }
+
+    fn attachment_not_found() -> ApiError {
+        ApiError::NotFound("첨부 파일을 찾을 수 없습니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::{stream, StreamExt};
+    use mockall::predicate::eq;
+    use crate::entities::todo::Todo;
+    use crate::entities::workspace::WorkspaceRole;
+    use crate::repositories::attachment_repository::tests::MockAttachmentRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::storage::tests::MockBlobStore;
+
+    fn sample_attachment(user_id: Uuid, todo_id: Uuid) -> Attachment {
+        let id = Uuid::new_v4();
+        Attachment {
+            id,
+            user_id,
+            todo_id,
+            file_name: "receipt.png".to_string(),
+            content_type: "image/png".to_string(),
+            size_bytes: 12,
+            sha256: "0".repeat(64),
+            storage_key: NewAttachment::storage_key(user_id, id),
+            created_at: Utc::now(),
+        }
+    }
+
+    fn todo_repo_with(user_id: Uuid, todo_id: Uuid) -> MockTodoRepository {
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Editor)));
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(Todo::fixture(user_id, todo_id))));
+        todo_repo
+    }
+
+    fn service(attachment_repo: MockAttachmentRepository, todo_repo: MockTodoRepository, blob_store: MockBlobStore) -> AttachmentService {
+        AttachmentService::new(
+            Arc::new(attachment_repo),
+            Arc::new(todo_repo),
+            Arc::new(blob_store),
+            UrlSigner::new("test-secret"),
+            AttachmentConfig {
+                spool_dir: std::env::temp_dir().join(format!("attachment-test-{}", Uuid::new_v4())),
+                ..AttachmentConfig::default()
+            },
+        )
+    }
+
+    fn body(bytes: &'static [u8]) -> impl Stream<Item = Result<Bytes, std::io::Error>> + Unpin {
+        stream::iter(vec![Ok(Bytes::from_static(bytes))])
+    }
+
+    #[tokio::test]
+    async fn test_upload_rejects_unrecognized_content() {
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+        let mut blob_store = MockBlobStore::new();
+        blob_store.expect_put().never();
+
+        let service = service(MockAttachmentRepository::new(), todo_repo_with(user_id, todo_id), blob_store);
+        let result = service
+            .upload(user_id, todo_id, Some("receipt.png"), Some("image/png"), body(b"MZ\x90\x00\x03\x00\x00\x00"))
+            .await;
+
+        assert!(matches!(result, Err(ApiError::UnsupportedMediaType(_))));
+    }
+
+    #[tokio::test]
+    async fn test_upload_stores_sniffed_type_and_removes_blob_when_insert_fails() {
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+
+        let mut blob_store = MockBlobStore::new();
+        blob_store
+            .expect_put()
+            .withf(move |key, blob| key.starts_with(&format!("attachments/{}/", user_id)) && blob.size == 12)
+            .times(2)
+            .returning(|_, _| Ok(()));
+        blob_store.expect_delete().times(1).returning(|_| Ok(()));
+
+        let mut attachment_repo = MockAttachmentRepository::new();
+        let mut calls = 0;
+        attachment_repo.expect_create().times(2).returning(move |attachment, _| {
+            calls += 1;
+            if calls == 2 {
+                return Err(ApiError::Conflict("가득 찼습니다".to_string()));
+            }
+            assert_eq!(attachment.content_type, "image/png");
+            assert_eq!(attachment.file_name, "shot.png");
+            let mut created = sample_attachment(attachment.user_id, attachment.todo_id);
+            created.id = attachment.id;
+            created.content_type = attachment.content_type;
+            Ok(created)
+        });
+
+        let service = service(attachment_repo, todo_repo_with(user_id, todo_id), blob_store);
+        let png = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0d";
+        let created = service
+            .upload(user_id, todo_id, Some("C:\\shots\\shot.png"), Some("application/octet-stream"), body(png))
+            .await
+            .unwrap();
+        assert_eq!(created.content_type, "image/png");
+
+        let result = service.upload(user_id, todo_id, Some("shot.png"), None, body(png)).await;
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_signed_url_round_trip() {
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+        let attachment = sample_attachment(user_id, todo_id);
+        let id = attachment.id;
+
+        let mut attachment_repo = MockAttachmentRepository::new();
+        let found = attachment.clone();
+        attachment_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        attachment_repo.expect_find().with(eq(id)).returning(move |_| Ok(Some(attachment.clone())));
+
+        let mut blob_store = MockBlobStore::new();
+        blob_store.expect_get().times(1).returning(|_| {
+            Ok(Some(BlobBody {
+                size: Some(2),
+                stream: stream::iter(vec![Ok(Bytes::from_static(b"ok"))]).boxed(),
+            }))
+        });
+
+        let service = service(attachment_repo, todo_repo_with(user_id, todo_id), blob_store);
+        let issued = service.download_url(user_id, todo_id, id).await.unwrap();
+        let query = issued.url.split_once('?').unwrap().1;
+        let params: std::collections::HashMap<_, _> = query
+            .split('&')
+            .filter_map(|pair| pair.split_once('='))
+            .collect();
+        let expires: i64 = params["expires"].parse().unwrap();
+
+        let (opened, _) = service.open_signed(id, expires, params["signature"]).await.unwrap();
+        assert_eq!(opened.id, id);
+
+        let tampered = service.open_signed(id, expires + 60, params["signature"]).await;
+        assert!(matches!(tampered, Err(ApiError::Forbidden(_))));
+        let other = service.open_signed(Uuid::new_v4(), expires, params["signature"]).await;
+        assert!(matches!(other, Err(ApiError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_clean_blobs_keeps_failed_keys_queued() {
+        let mut attachment_repo = MockAttachmentRepository::new();
+        attachment_repo
+            .expect_claim_blob_deletions()
+            .returning(|_| Ok(vec!["attachments/a/1".to_string(), "attachments/a/2".to_string()]));
+        attachment_repo
+            .expect_finish_blob_deletion()
+            .withf(|key| key == "attachments/a/1")
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let mut blob_store = MockBlobStore::new();
+        blob_store.expect_delete().returning(|key| {
+            if key == "attachments/a/1" {
+                Ok(())
+            } else {
+                Err(crate::storage::StorageError::Backend("503".to_string()))
+            }
+        });
+
+        let service = service(attachment_repo, MockTodoRepository::new(), blob_store);
+        assert_eq!(service.clean_blobs().await.unwrap(), 1);
+    }
+}
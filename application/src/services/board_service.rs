@@ -0,0 +1,372 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::board_repository::BoardRepository;
+use crate::dto::request::board_request::{
+    BoardColumnRequest, CreateBoardRequest, MoveCardRequest, ReorderColumnsRequest, UpdateBoardRequest,
+};
+use crate::dto::response::board_response::{
+    BoardCardsResponse, BoardColumnCardsResponse, BoardColumnResponse, BoardListResponse, BoardResponse, MoveCardResponse,
+};
+use crate::dto::response::todo_response::TodoResponse;
+use crate::entities::board::{BoardColumnSpec, BoardWithColumns, CardMove, NewBoard, MAX_BOARD_COLUMNS};
+use crate::error::ApiError;
+use crate::events::{EventBus, TodoEvent};
+
+/// 칸반 보드. 상태 열로 카드를 옮기면 할일 상태도 상태 전이 규칙에 따라 바뀐다.
+pub struct BoardService {
+    board_repository: Arc<dyn BoardRepository>,
+    event_bus: EventBus,
+}
+
+impl BoardService {
+    pub fn new(board_repository: Arc<dyn BoardRepository>, event_bus: EventBus) -> Self {
+        Self {
+            board_repository,
+            event_bus,
+        }
+    }
+
+    /// 보드 목록 조회 (이름순)
+    pub async fn list_boards(&self, user_id: Uuid) -> Result<BoardListResponse, ApiError> {
+        let boards = self.board_repository.find_all(user_id).await?;
+
+        Ok(BoardListResponse {
+            boards: boards.into_iter().map(BoardResponse::from).collect(),
+        })
+    }
+
+    /// 보드 상세 조회
+    pub async fn get_board(&self, user_id: Uuid, id: Uuid) -> Result<BoardResponse, ApiError> {
+        Ok(BoardResponse::from(self.find_board(user_id, id).await?))
+    }
+
+    /// 보드 생성
+    pub async fn create_board(&self, user_id: Uuid, request: CreateBoardRequest) -> Result<BoardResponse, ApiError> {
+        request.validate()?;
+
+        let name = Self::board_name(&request.name)?;
+        let columns = if request.columns.is_empty() {
+            BoardColumnSpec::defaults()
+        } else {
+            request.columns.iter().map(Self::column).collect::<Result<Vec<_>, ApiError>>()?
+        };
+        if self.board_repository.find_by_name(user_id, &name).await?.is_some() {
+            return Err(Self::duplicate_name());
+        }
+
+        let board = self
+            .board_repository
+            .create(NewBoard { user_id, name, columns })
+            .await?;
+
+        Ok(BoardResponse::from(board))
+    }
+
+    /// 보드 이름 변경
+    pub async fn update_board(&self, user_id: Uuid, id: Uuid, request: UpdateBoardRequest) -> Result<BoardResponse, ApiError> {
+        request.validate()?;
+
+        let name = Self::board_name(&request.name)?;
+        if let Some(existing) = self.board_repository.find_by_name(user_id, &name).await? {
+            if existing.id != id {
+                return Err(Self::duplicate_name());
+            }
+        }
+
+        let board = self
+            .board_repository
+            .rename(user_id, id, &name)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(BoardResponse::from(board))
+    }
+
+    /// 보드 삭제 (할일에는 영향이 없다)
+    pub async fn delete_board(&self, user_id: Uuid, id: Uuid) -> Result<(), ApiError> {
+        if !self.board_repository.delete(user_id, id).await? {
+            return Err(Self::not_found());
+        }
+        Ok(())
+    }
+
+    /// 보드 맨 뒤에 열 추가
+    pub async fn add_column(&self, user_id: Uuid, board_id: Uuid, request: BoardColumnRequest) -> Result<BoardColumnResponse, ApiError> {
+        let column = Self::column(&request)?;
+
+        let board = self.find_board(user_id, board_id).await?;
+        if board.columns.len() >= MAX_BOARD_COLUMNS {
+            return Err(ApiError::Validation(format!(
+                "열은 최대 {}개까지 만들 수 있습니다",
+                MAX_BOARD_COLUMNS
+            )));
+        }
+
+        let column = self
+            .board_repository
+            .add_column(user_id, board_id, column)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(BoardColumnResponse::from(column))
+    }
+
+    /// 열 수정. WIP 제한을 낮춰도 이미 놓인 카드는 그대로 두고 새로 들어오는 카드만 막는다.
+    pub async fn update_column(&self, user_id: Uuid, board_id: Uuid, id: Uuid, request: BoardColumnRequest) -> Result<BoardColumnResponse, ApiError> {
+        let column = Self::column(&request)?;
+
+        let column = self
+            .board_repository
+            .update_column(user_id, board_id, id, column)
+            .await?
+            .ok_or_else(Self::column_not_found)?;
+
+        Ok(BoardColumnResponse::from(column))
+    }
+
+    /// 열 삭제. 보드에는 열이 하나 이상 있어야 한다.
+    pub async fn delete_column(&self, user_id: Uuid, board_id: Uuid, id: Uuid) -> Result<(), ApiError> {
+        let board = self.find_board(user_id, board_id).await?;
+        if !board.columns.iter().any(|column| column.id == id) {
+            return Err(Self::column_not_found());
+        }
+        if board.columns.len() == 1 {
+            return Err(ApiError::Validation("보드의 마지막 열은 삭제할 수 없습니다".to_string()));
+        }
+
+        if !self.board_repository.delete_column(user_id, board_id, id).await? {
+            return Err(Self::column_not_found());
+        }
+        Ok(())
+    }
+
+    /// 열 순서 변경
+    pub async fn reorder_columns(&self, user_id: Uuid, board_id: Uuid, request: ReorderColumnsRequest) -> Result<BoardResponse, ApiError> {
+        let board = self.find_board(user_id, board_id).await?;
+
+        let requested: HashSet<Uuid> = request.column_ids.iter().copied().collect();
+        let existing: HashSet<Uuid> = board.columns.iter().map(|column| column.id).collect();
+        if requested.len() != request.column_ids.len() || requested != existing {
+            return Err(ApiError::Validation("column_ids에는 보드의 열 ID를 모두 한 번씩 지정해야 합니다".to_string()));
+        }
+
+        let board = self
+            .board_repository
+            .reorder_columns(user_id, board_id, &request.column_ids)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(BoardResponse::from(board))
+    }
+
+    /// 열별 카드 조회 (열 순서, 열 안에서는 카드 순서대로)
+    pub async fn get_cards(&self, user_id: Uuid, board_id: Uuid) -> Result<BoardCardsResponse, ApiError> {
+        let board = self.find_board(user_id, board_id).await?;
+        let mut cards = self.board_repository.find_cards(user_id, board_id).await?;
+
+        let columns = board
+            .columns
+            .into_iter()
+            .map(|column| {
+                let (in_column, rest) = cards.drain(..).partition(|card| card.column_id == column.id);
+                cards = rest;
+                let cards: Vec<TodoResponse> = in_column.into_iter().map(|card| TodoResponse::from(card.todo)).collect();
+                BoardColumnCardsResponse {
+                    card_count: cards.len(),
+                    over_limit: column.wip_limit.is_some_and(|limit| cards.len() > limit as usize),
+                    column: BoardColumnResponse::from(column),
+                    cards,
+                }
+            })
+            .collect();
+
+        Ok(BoardCardsResponse { board_id, columns })
+    }
+
+    /// 카드를 다른 열이나 같은 열의 다른 자리로 옮긴다
+    pub async fn move_card(&self, user_id: Uuid, board_id: Uuid, todo_id: Uuid, request: MoveCardRequest) -> Result<MoveCardResponse, ApiError> {
+        if request.after_id == Some(todo_id) || request.before_id == Some(todo_id) {
+            return Err(ApiError::Validation("자기 자신을 기준으로 이동할 수 없습니다".to_string()));
+        }
+        if request.after_id.is_some() && request.after_id == request.before_id {
+            return Err(ApiError::Validation("after_id와 before_id는 서로 달라야 합니다".to_string()));
+        }
+
+        let board = self.find_board(user_id, board_id).await?;
+        if !board.columns.iter().any(|column| column.id == request.column_id) {
+            return Err(Self::column_not_found());
+        }
+
+        let card_move = CardMove {
+            todo_id,
+            column_id: request.column_id,
+            after_id: request.after_id,
+            before_id: request.before_id,
+        };
+        let moved = self
+            .board_repository
+            .move_card(user_id, board_id, card_move)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("할일을 찾을 수 없습니다".to_string()))?;
+
+        if let Some(transition) = &moved.transition {
+            self.event_bus.publish(TodoEvent::status_changed(user_id, transition));
+        }
+
+        Ok(MoveCardResponse {
+            board_id,
+            column_id: moved.card.column_id,
+            status_changed: moved.transition.is_some(),
+            todo: TodoResponse::from(moved.card.todo),
+        })
+    }
+
+    async fn find_board(&self, user_id: Uuid, id: Uuid) -> Result<BoardWithColumns, ApiError> {
+        self.board_repository
+            .find_by_id(user_id, id)
+            .await?
+            .ok_or_else(Self::not_found)
+    }
+
+    fn board_name(name: &str) -> Result<String, ApiError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ApiError::Validation("보드 이름을 입력해주세요".to_string()));
+        }
+        Ok(name.to_string())
+    }
+
+    fn column(request: &BoardColumnRequest) -> Result<BoardColumnSpec, ApiError> {
+        request.validate()?;
+
+        let name = request.name.trim();
+        if name.is_empty() {
+            return Err(ApiError::Validation("열 이름을 입력해주세요".to_string()));
+        }
+
+        Ok(BoardColumnSpec {
+            name: name.to_string(),
+            status: request.status,
+            wip_limit: request.wip_limit,
+        })
+    }
+
+    fn duplicate_name() -> ApiError {
+        ApiError::Conflict("이미 존재하는 보드 이름입니다".to_string())
+    }
+
+    fn not_found() -> ApiError {
+        ApiError::NotFound("보드를 찾을 수 없습니다".to_string())
+    }
+
+    fn column_not_found() -> ApiError {
+        ApiError::NotFound("열을 찾을 수 없습니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use crate::entities::board::{Board, BoardColumn};
+    use crate::entities::todo::TodoStatus;
+    use crate::repositories::board_repository::tests::MockBoardRepository;
+
+    fn sample_board(user_id: Uuid, statuses: &[Option<TodoStatus>]) -> BoardWithColumns {
+        let now = Utc::now();
+        let board = Board {
+            id: Uuid::new_v4(),
+            user_id,
+            name: "스프린트".to_string(),
+            created_at: now,
+            updated_at: now,
+        };
+        let columns = statuses
+            .iter()
+            .enumerate()
+            .map(|(position, status)| BoardColumn {
+                id: Uuid::new_v4(),
+                board_id: board.id,
+                position: position as i32,
+                name: format!("열 {}", position),
+                status: *status,
+                wip_limit: None,
+                created_at: now,
+                updated_at: now,
+            })
+            .collect();
+        BoardWithColumns { board, columns }
+    }
+
+    #[tokio::test]
+    async fn test_create_board_uses_default_status_columns() {
+        let user_id = Uuid::new_v4();
+        let mut board_repo = MockBoardRepository::new();
+        board_repo.expect_find_by_name().returning(|_, _| Ok(None));
+        board_repo
+            .expect_create()
+            .withf(|board| {
+                let statuses: Vec<_> = board.columns.iter().map(|column| column.status).collect();
+                board.name == "스프린트"
+                    && statuses == vec![Some(TodoStatus::Pending), Some(TodoStatus::InProgress), Some(TodoStatus::Completed)]
+            })
+            .returning(move |_| {
+                Ok(sample_board(user_id, &[Some(TodoStatus::Pending), Some(TodoStatus::InProgress), Some(TodoStatus::Completed)]))
+            });
+
+        let service = BoardService::new(Arc::new(board_repo), EventBus::default());
+        let request = CreateBoardRequest { name: "  스프린트 ".to_string(), columns: Vec::new() };
+        let board = service.create_board(user_id, request).await.unwrap();
+
+        assert_eq!(board.columns.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_delete_last_column_is_rejected() {
+        let user_id = Uuid::new_v4();
+        let board = sample_board(user_id, &[None]);
+        let (board_id, column_id) = (board.board.id, board.columns[0].id);
+        let mut board_repo = MockBoardRepository::new();
+        board_repo.expect_find_by_id().returning(move |_, _| Ok(Some(board.clone())));
+        board_repo.expect_delete_column().never();
+
+        let service = BoardService::new(Arc::new(board_repo), EventBus::default());
+        let result = service.delete_column(user_id, board_id, column_id).await;
+
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reorder_columns_requires_every_column_once() {
+        let user_id = Uuid::new_v4();
+        let board = sample_board(user_id, &[None, Some(TodoStatus::Pending)]);
+        let (board_id, first) = (board.board.id, board.columns[0].id);
+        let mut board_repo = MockBoardRepository::new();
+        board_repo.expect_find_by_id().returning(move |_, _| Ok(Some(board.clone())));
+        board_repo.expect_reorder_columns().never();
+
+        let service = BoardService::new(Arc::new(board_repo), EventBus::default());
+        let request = ReorderColumnsRequest { column_ids: vec![first, first] };
+        let result = service.reorder_columns(user_id, board_id, request).await;
+
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_move_card_to_unknown_column_is_not_found() {
+        let user_id = Uuid::new_v4();
+        let board = sample_board(user_id, &[Some(TodoStatus::Pending)]);
+        let board_id = board.board.id;
+        let mut board_repo = MockBoardRepository::new();
+        board_repo.expect_find_by_id().returning(move |_, _| Ok(Some(board.clone())));
+        board_repo.expect_move_card().never();
+
+        let service = BoardService::new(Arc::new(board_repo), EventBus::default());
+        let request = MoveCardRequest { column_id: Uuid::new_v4(), after_id: None, before_id: None };
+        let result = service.move_card(user_id, board_id, Uuid::new_v4(), request).await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+}
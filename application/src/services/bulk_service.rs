@@ -0,0 +1,204 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::bulk_repository::BulkTodoRepository;
+use crate::repositories::category_repository::CategoryRepository;
+use crate::dto::request::bulk_request::{BulkAction, BulkTodoRequest};
+use crate::dto::response::bulk_response::{BulkItemResponse, BulkTodoResponse};
+use crate::entities::bulk::{BulkItemOutcome, BulkMode, BulkOperation};
+use crate::error::ApiError;
+use crate::events::{EventBus, TodoEvent};
+
+pub struct BulkService {
+    bulk_repository: Arc<dyn BulkTodoRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    event_bus: EventBus,
+}
+
+impl BulkService {
+    pub fn new(
+        bulk_repository: Arc<dyn BulkTodoRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        event_bus: EventBus,
+    ) -> Self {
+        Self {
+            bulk_repository,
+            category_repository,
+            event_bus,
+        }
+    }
+
+    /// 여러 할일에 같은 작업을 적용하고 항목별 결과를 돌려준다
+    pub async fn execute(&self, user_id: Uuid, request: BulkTodoRequest) -> Result<BulkTodoResponse, ApiError> {
+        request.validate()?;
+
+        let mut seen = HashSet::with_capacity(request.ids.len());
+        if let Some(duplicate) = request.ids.iter().find(|id| !seen.insert(**id)) {
+            return Err(ApiError::Validation(format!("중복된 할일 ID가 있습니다: {}", duplicate)));
+        }
+
+        let operation = self.operation(user_id, &request).await?;
+        let mode = BulkMode {
+            atomic: request.atomic,
+            dry_run: request.dry_run,
+        };
+
+        let execution = self.bulk_repository.execute(user_id, &request.ids, &operation, mode).await?;
+
+        for transition in &execution.transitions {
+            self.event_bus.publish(TodoEvent::status_changed(user_id, transition));
+        }
+
+        let count = |outcome: BulkItemOutcome| execution.results.iter().filter(|r| r.outcome == outcome).count();
+        Ok(BulkTodoResponse {
+            action: request.action,
+            atomic: request.atomic,
+            dry_run: request.dry_run,
+            committed: execution.committed,
+            succeeded: count(BulkItemOutcome::Succeeded),
+            unchanged: count(BulkItemOutcome::Unchanged),
+            failed: count(BulkItemOutcome::Failed),
+            skipped: count(BulkItemOutcome::Skipped),
+            results: execution.results.into_iter().map(BulkItemResponse::from).collect(),
+        })
+    }
+
+    async fn operation(&self, user_id: Uuid, request: &BulkTodoRequest) -> Result<BulkOperation, ApiError> {
+        let operation = match request.action {
+            BulkAction::SetStatus => BulkOperation::SetStatus(
+                request
+                    .status
+                    .ok_or_else(|| ApiError::Validation("set_status에는 status가 필요합니다".to_string()))?,
+            ),
+            BulkAction::SetPriority => BulkOperation::SetPriority(
+                request
+                    .priority
+                    .ok_or_else(|| ApiError::Validation("set_priority에는 priority가 필요합니다".to_string()))?,
+            ),
+            BulkAction::AssignCategories => {
+                let mut category_ids = request.category_ids.clone().ok_or_else(|| {
+                    ApiError::Validation("assign_categories에는 category_ids가 필요합니다".to_string())
+                })?;
+                category_ids.sort();
+                category_ids.dedup();
+
+                let owned = self.category_repository.find_by_ids(user_id, &category_ids).await?;
+                if owned.len() != category_ids.len() {
+                    return Err(ApiError::NotFound("카테고리를 찾을 수 없습니다".to_string()));
+                }
+                BulkOperation::AssignCategories(category_ids)
+            }
+            BulkAction::Delete => BulkOperation::Delete,
+            BulkAction::Restore => BulkOperation::Restore,
+        };
+
+        Ok(operation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::bulk_repository::tests::MockBulkTodoRepository;
+    use crate::repositories::category_repository::tests::MockCategoryRepository;
+    use crate::entities::bulk::{BulkExecution, BulkItemResult};
+    use crate::entities::todo::{StatusTransition, TodoStatus};
+    use chrono::Utc;
+
+    fn request(ids: Vec<Uuid>, action: BulkAction) -> BulkTodoRequest {
+        BulkTodoRequest {
+            ids,
+            action,
+            status: None,
+            priority: None,
+            category_ids: None,
+            atomic: false,
+            dry_run: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_rejects_duplicate_ids_and_missing_arguments() {
+        let mut bulk_repo = MockBulkTodoRepository::new();
+        bulk_repo.expect_execute().never();
+        let service = BulkService::new(Arc::new(bulk_repo), Arc::new(MockCategoryRepository::new()), EventBus::default());
+
+        let id = Uuid::new_v4();
+        let result = service.execute(Uuid::new_v4(), request(vec![id, id], BulkAction::Delete)).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+
+        let result = service.execute(Uuid::new_v4(), request(vec![id], BulkAction::SetStatus)).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_assign_rejects_foreign_category() {
+        let mut bulk_repo = MockBulkTodoRepository::new();
+        let mut category_repo = MockCategoryRepository::new();
+        bulk_repo.expect_execute().never();
+        category_repo.expect_find_by_ids().returning(|_, _| Ok(vec![]));
+
+        let service = BulkService::new(Arc::new(bulk_repo), Arc::new(category_repo), EventBus::default());
+        let mut req = request(vec![Uuid::new_v4()], BulkAction::AssignCategories);
+        req.category_ids = Some(vec![Uuid::new_v4()]);
+
+        let result = service.execute(Uuid::new_v4(), req).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_counts_outcomes_and_publishes_committed_transitions() {
+        let user_id = Uuid::new_v4();
+        let done_id = Uuid::new_v4();
+        let failed_id = Uuid::new_v4();
+        let mut bulk_repo = MockBulkTodoRepository::new();
+        bulk_repo
+            .expect_execute()
+            .withf(|_, _, operation, mode| {
+                *operation == BulkOperation::SetStatus(TodoStatus::Completed) && !mode.atomic && !mode.dry_run
+            })
+            .times(1)
+            .returning(move |_, _, _, _| {
+                let now = Utc::now();
+                Ok(BulkExecution {
+                    results: vec![
+                        BulkItemResult { id: done_id, outcome: BulkItemOutcome::Succeeded, error: None, todo: None },
+                        BulkItemResult {
+                            id: failed_id,
+                            outcome: BulkItemOutcome::Failed,
+                            error: Some("할일을 찾을 수 없습니다".to_string()),
+                            todo: None,
+                        },
+                    ],
+                    committed: true,
+                    transitions: vec![StatusTransition {
+                        todo_id: done_id,
+                        from: TodoStatus::Pending,
+                        to: TodoStatus::Completed,
+                        started_at: None,
+                        completed_at: Some(now),
+                        occurred_at: now,
+                    }],
+                })
+            });
+
+        let event_bus = EventBus::default();
+        let mut events = event_bus.subscribe();
+        let service = BulkService::new(Arc::new(bulk_repo), Arc::new(MockCategoryRepository::new()), event_bus);
+        let mut req = request(vec![done_id, failed_id], BulkAction::SetStatus);
+        req.status = Some(TodoStatus::Completed);
+
+        let response = service.execute(user_id, req).await.unwrap();
+        assert!(response.committed);
+        assert_eq!((response.succeeded, response.failed), (1, 1));
+        assert_eq!(response.results[1].id, failed_id);
+
+        match events.try_recv().unwrap() {
+            TodoEvent::StatusChanged { todo_id, to, .. } => {
+                assert_eq!(todo_id, done_id);
+                assert_eq!(to, TodoStatus::Completed);
+            }
+        }
+    }
+}
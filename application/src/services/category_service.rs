@@ -0,0 +1,479 @@
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::category_repository::CategoryRepository;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::repositories::workspace_repository::WorkspaceRepository;
+use crate::services::workspace_service::{authorize_todo_edit, authorize_workspace_edit, target_workspace};
+use crate::dto::request::category_request::{AssignCategoriesRequest, CreateCategoryRequest, UpdateCategoryRequest};
+use crate::dto::response::category_response::{CategoryListResponse, CategoryResponse, TodoCategoriesResponse};
+use crate::entities::category::{Category, CategoryChanges, CategoryWithCount, NewCategory, DEFAULT_CATEGORY_COLOR};
+use crate::error::ApiError;
+
+/// 카테고리는 워크스페이스에 속하고, 같은 워크스페이스의 할일에만 지정할 수 있다
+pub struct CategoryService {
+    category_repository: Arc<dyn CategoryRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+}
+
+impl CategoryService {
+    pub fn new(
+        category_repository: Arc<dyn CategoryRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+    ) -> Self {
+        Self {
+            category_repository,
+            todo_repository,
+            workspace_repository,
+        }
+    }
+
+    /// 참여한 모든 워크스페이스의 카테고리 목록 조회 (이름순)
+    pub async fn list_categories(&self, user_id: Uuid) -> Result<CategoryListResponse, ApiError> {
+        let categories = self.category_repository.find_all(user_id).await?;
+
+        Ok(CategoryListResponse {
+            categories: categories.into_iter().map(CategoryResponse::from).collect(),
+        })
+    }
+
+    /// 카테고리 상세 조회
+    pub async fn get_category(&self, user_id: Uuid, id: Uuid) -> Result<CategoryResponse, ApiError> {
+        let category = self.find_category(user_id, id).await?;
+        Ok(CategoryResponse::from(category))
+    }
+
+    /// 카테고리 생성 (워크스페이스를 지정하지 않으면 개인 워크스페이스에 만든다)
+    pub async fn create_category(&self, user_id: Uuid, request: CreateCategoryRequest) -> Result<CategoryResponse, ApiError> {
+        request.validate()?;
+
+        let workspace_id = target_workspace(self.workspace_repository.as_ref(), user_id, request.workspace_id).await?;
+        let name = Self::normalize_name(&request.name)?;
+        if self.category_repository.find_by_name(workspace_id, &name).await?.is_some() {
+            return Err(Self::duplicate_name());
+        }
+
+        let new_category = NewCategory {
+            user_id,
+            workspace_id,
+            name,
+            color: request.color.unwrap_or_else(|| DEFAULT_CATEGORY_COLOR.to_string()),
+        };
+
+        let category = self.category_repository.create(new_category).await?;
+
+        Ok(CategoryResponse::from(category))
+    }
+
+    /// 카테고리 수정
+    pub async fn update_category(&self, user_id: Uuid, id: Uuid, request: UpdateCategoryRequest) -> Result<CategoryResponse, ApiError> {
+        request.validate()?;
+
+        let workspace_id = self.find_category(user_id, id).await?.category.workspace_id;
+        authorize_workspace_edit(self.workspace_repository.as_ref(), user_id, workspace_id).await?;
+
+        let name = Self::normalize_name(&request.name)?;
+        if let Some(existing) = self.category_repository.find_by_name(workspace_id, &name).await? {
+            if existing.id != id {
+                return Err(Self::duplicate_name());
+            }
+        }
+
+        let changes = CategoryChanges {
+            name,
+            color: request.color,
+        };
+
+        let category = self
+            .category_repository
+            .update(user_id, id, changes)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(CategoryResponse::from(category))
+    }
+
+    /// 카테고리 삭제 (할일 지정도 함께 해제된다)
+    pub async fn delete_category(&self, user_id: Uuid, id: Uuid) -> Result<(), ApiError> {
+        let workspace_id = self.find_category(user_id, id).await?.category.workspace_id;
+        authorize_workspace_edit(self.workspace_repository.as_ref(), user_id, workspace_id).await?;
+
+        if !self.category_repository.delete(user_id, id).await? {
+            return Err(Self::not_found());
+        }
+        Ok(())
+    }
+
+    /// 할일에 지정된 카테고리 조회
+    pub async fn list_todo_categories(&self, user_id: Uuid, todo_id: Uuid) -> Result<TodoCategoriesResponse, ApiError> {
+        self.ensure_todo(user_id, todo_id).await?;
+        self.todo_categories(todo_id).await
+    }
+
+    /// 할일에 카테고리 지정 (이미 지정된 카테고리는 무시)
+    pub async fn assign_categories(&self, user_id: Uuid, todo_id: Uuid, request: AssignCategoriesRequest) -> Result<TodoCategoriesResponse, ApiError> {
+        request.validate()?;
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+        let workspace_id = self.ensure_todo(user_id, todo_id).await?;
+
+        let mut category_ids = request.category_ids;
+        category_ids.sort();
+        category_ids.dedup();
+
+        let owned = self.category_repository.find_by_ids(user_id, &category_ids).await?;
+        if owned.len() != category_ids.len() || owned.iter().any(|category| category.workspace_id != workspace_id) {
+            return Err(Self::not_found());
+        }
+
+        self.category_repository.assign(todo_id, &category_ids).await?;
+        self.todo_categories(todo_id).await
+    }
+
+    /// 개인 워크스페이스에서 이름으로 카테고리를 찾고, 없는 이름은 기본 색으로 만든다 (이름 순서대로)
+    pub async fn find_or_create(&self, user_id: Uuid, names: &[String]) -> Result<Vec<Category>, ApiError> {
+        let workspace_id = self.workspace_repository.ensure_personal(user_id).await?;
+        let mut categories = Vec::with_capacity(names.len());
+        for name in names {
+            CreateCategoryRequest { workspace_id: None, name: name.clone(), color: None }.validate()?;
+
+            let name = Self::normalize_name(name)?;
+            let category = match self.category_repository.find_by_name(workspace_id, &name).await? {
+                Some(category) => category,
+                None => {
+                    let new_category = NewCategory {
+                        user_id,
+                        workspace_id,
+                        name,
+                        color: DEFAULT_CATEGORY_COLOR.to_string(),
+                    };
+                    self.category_repository.create(new_category).await?
+                }
+            };
+            categories.push(category);
+        }
+
+        Ok(categories)
+    }
+
+    /// 할일에서 카테고리 지정 해제
+    pub async fn unassign_category(&self, user_id: Uuid, todo_id: Uuid, category_id: Uuid) -> Result<(), ApiError> {
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+
+        if !self.category_repository.unassign(todo_id, category_id).await? {
+            return Err(ApiError::NotFound("할일에 지정되지 않은 카테고리입니다".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 할일이 속한 워크스페이스
+    async fn ensure_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Uuid, ApiError> {
+        let todo = self
+            .todo_repository
+            .find_by_id(user_id, todo_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("할일을 찾을 수 없습니다".to_string()))?;
+        Ok(todo.workspace_id)
+    }
+
+    async fn find_category(&self, user_id: Uuid, id: Uuid) -> Result<CategoryWithCount, ApiError> {
+        self.category_repository
+            .find_by_id(user_id, id)
+            .await?
+            .ok_or_else(Self::not_found)
+    }
+
+    async fn todo_categories(&self, todo_id: Uuid) -> Result<TodoCategoriesResponse, ApiError> {
+        let categories = self.category_repository.find_by_todo(todo_id).await?;
+
+        Ok(TodoCategoriesResponse {
+            todo_id,
+            categories: categories.into_iter().map(CategoryResponse::from).collect(),
+        })
+    }
+
+    fn normalize_name(name: &str) -> Result<String, ApiError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ApiError::Validation("카테고리 이름을 입력해주세요".to_string()));
+        }
+        Ok(name.to_string())
+    }
+
+    fn duplicate_name() -> ApiError {
+        ApiError::Conflict("이미 존재하는 카테고리 이름입니다".to_string())
+    }
+
+    fn not_found() -> ApiError {
+        ApiError::NotFound("카테고리를 찾을 수 없습니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::category_repository::tests::MockCategoryRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+    use crate::entities::category::{Category, CategoryWithCount};
+    use crate::entities::todo::Todo;
+    use crate::entities::workspace::WorkspaceRole;
+    use chrono::Utc;
+
+    const WORKSPACE_ID: Uuid = Uuid::from_u128(1);
+
+    fn sample_category(user_id: Uuid, name: &str) -> Category {
+        let now = Utc::now();
+        Category {
+            id: Uuid::new_v4(),
+            user_id,
+            workspace_id: WORKSPACE_ID,
+            name: name.to_string(),
+            color: DEFAULT_CATEGORY_COLOR.to_string(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn sample_todo(user_id: Uuid, id: Uuid) -> Todo {
+        Todo { workspace_id: WORKSPACE_ID, ..Todo::fixture(user_id, id) }
+    }
+
+    fn service(category_repo: MockCategoryRepository, todo_repo: MockTodoRepository, workspace_repo: MockWorkspaceRepository) -> CategoryService {
+        CategoryService::new(Arc::new(category_repo), Arc::new(todo_repo), Arc::new(workspace_repo))
+    }
+
+    fn workspaces_with_role(role: WorkspaceRole) -> MockWorkspaceRepository {
+        let mut workspace_repo = MockWorkspaceRepository::new();
+        workspace_repo
+            .expect_ensure_personal()
+            .returning(|_| Ok(WORKSPACE_ID));
+        workspace_repo
+            .expect_find_role()
+            .returning(move |_, _| Ok(Some(role)));
+        workspace_repo
+    }
+
+    fn editable_todos() -> MockTodoRepository {
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Editor)));
+        todo_repo
+            .expect_find_by_id()
+            .returning(|user_id, id| Ok(Some(sample_todo(user_id, id))));
+        todo_repo
+    }
+
+    #[tokio::test]
+    async fn test_create_category_with_default_color() {
+        let mut category_repo = MockCategoryRepository::new();
+        let user_id = Uuid::new_v4();
+
+        category_repo.expect_find_by_name().returning(|_, _| Ok(None));
+        category_repo
+            .expect_create()
+            .withf(|new_category| new_category.name == "업무" && new_category.color == DEFAULT_CATEGORY_COLOR)
+            .times(1)
+            .returning(|new_category| Ok(sample_category(new_category.user_id, &new_category.name)));
+
+        let request = CreateCategoryRequest {
+            workspace_id: None,
+            name: "  업무 ".to_string(),
+            color: None,
+        };
+
+        let response = service(category_repo, MockTodoRepository::new(), workspaces_with_role(WorkspaceRole::Owner))
+            .create_category(user_id, request)
+            .await
+            .unwrap();
+        assert_eq!(response.name, "업무");
+        assert_eq!(response.todo_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_category_duplicate_name() {
+        let mut category_repo = MockCategoryRepository::new();
+        category_repo
+            .expect_find_by_name()
+            .returning(|user_id, name| Ok(Some(sample_category(user_id, name))));
+
+        let request = CreateCategoryRequest {
+            workspace_id: None,
+            name: "업무".to_string(),
+            color: Some("#FF5722".to_string()),
+        };
+
+        let result = service(category_repo, MockTodoRepository::new(), workspaces_with_role(WorkspaceRole::Owner))
+            .create_category(Uuid::new_v4(), request)
+            .await;
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_category_invalid_color() {
+        let request = CreateCategoryRequest {
+            workspace_id: None,
+            name: "업무".to_string(),
+            color: Some("red".to_string()),
+        };
+
+        let result = service(MockCategoryRepository::new(), MockTodoRepository::new(), MockWorkspaceRepository::new())
+            .create_category(Uuid::new_v4(), request)
+            .await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_category_keeps_own_name() {
+        let mut category_repo = MockCategoryRepository::new();
+        let user_id = Uuid::new_v4();
+        let existing = sample_category(user_id, "업무");
+        let id = existing.id;
+
+        let found = CategoryWithCount { category: existing.clone(), todo_count: 3 };
+        category_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(found.clone())));
+        category_repo
+            .expect_find_by_name()
+            .returning(move |_, _| Ok(Some(existing.clone())));
+        category_repo
+            .expect_update()
+            .times(1)
+            .returning(|user_id, _, changes| {
+                let mut category = sample_category(user_id, &changes.name);
+                category.color = changes.color;
+                Ok(Some(CategoryWithCount { category, todo_count: 3 }))
+            });
+
+        let request = UpdateCategoryRequest {
+            name: "업무".to_string(),
+            color: "#FF5722".to_string(),
+        };
+
+        let response = service(category_repo, MockTodoRepository::new(), workspaces_with_role(WorkspaceRole::Editor))
+            .update_category(user_id, id, request)
+            .await
+            .unwrap();
+        assert_eq!(response.color, "#FF5722");
+        assert_eq!(response.todo_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_viewer_cannot_delete_category() {
+        let mut category_repo = MockCategoryRepository::new();
+        category_repo.expect_find_by_id().returning(|user_id, _| {
+            Ok(Some(CategoryWithCount {
+                category: sample_category(user_id, "업무"),
+                todo_count: 0,
+            }))
+        });
+        category_repo.expect_delete().never();
+
+        let result = service(category_repo, MockTodoRepository::new(), workspaces_with_role(WorkspaceRole::Viewer))
+            .delete_category(Uuid::new_v4(), Uuid::new_v4())
+            .await;
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_assign_categories_rejects_foreign_category() {
+        let mut category_repo = MockCategoryRepository::new();
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+
+        category_repo
+            .expect_find_by_ids()
+            .returning(|user_id, _| Ok(vec![sample_category(user_id, "업무")]));
+        category_repo.expect_assign().times(0);
+
+        let request = AssignCategoriesRequest {
+            category_ids: vec![Uuid::new_v4(), Uuid::new_v4()],
+        };
+
+        let result = service(category_repo, editable_todos(), MockWorkspaceRepository::new())
+            .assign_categories(user_id, todo_id, request)
+            .await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_assign_categories_rejects_other_workspace_category() {
+        let mut category_repo = MockCategoryRepository::new();
+        let category_id = Uuid::new_v4();
+
+        category_repo.expect_find_by_ids().returning(|user_id, _| {
+            let mut category = sample_category(user_id, "업무");
+            category.workspace_id = Uuid::new_v4();
+            Ok(vec![category])
+        });
+        category_repo.expect_assign().never();
+
+        let request = AssignCategoriesRequest {
+            category_ids: vec![category_id],
+        };
+
+        let result = service(category_repo, editable_todos(), MockWorkspaceRepository::new())
+            .assign_categories(Uuid::new_v4(), Uuid::new_v4(), request)
+            .await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_assign_categories_deduplicates() {
+        let mut category_repo = MockCategoryRepository::new();
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+        let category_id = Uuid::new_v4();
+
+        category_repo
+            .expect_find_by_ids()
+            .withf(move |_, ids| ids == [category_id])
+            .returning(|user_id, _| Ok(vec![sample_category(user_id, "업무")]));
+        category_repo
+            .expect_assign()
+            .withf(move |id, ids| *id == todo_id && ids == [category_id])
+            .times(1)
+            .returning(|_, _| Ok(()));
+        category_repo
+            .expect_find_by_todo()
+            .returning(move |_| {
+                Ok(vec![CategoryWithCount {
+                    category: sample_category(user_id, "업무"),
+                    todo_count: 1,
+                }])
+            });
+
+        let request = AssignCategoriesRequest {
+            category_ids: vec![category_id, category_id],
+        };
+
+        let response = service(category_repo, editable_todos(), MockWorkspaceRepository::new())
+            .assign_categories(user_id, todo_id, request)
+            .await
+            .unwrap();
+        assert_eq!(response.todo_id, todo_id);
+        assert_eq!(response.categories.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_unassign_category_of_other_users_todo() {
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo.expect_find_role().returning(|_, _| Ok(None));
+
+        let mut category_repo = MockCategoryRepository::new();
+        category_repo.expect_unassign().times(0);
+
+        let result = service(category_repo, todo_repo, MockWorkspaceRepository::new())
+            .unassign_category(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4())
+            .await;
+
+        if let Err(ApiError::NotFound(msg)) = result {
+            assert_eq!(msg, "할일을 찾을 수 없습니다");
+        } else {
+            panic!("Expected NotFound error");
+        }
+    }
+}
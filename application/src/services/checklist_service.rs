@@ -0,0 +1,345 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::checklist_repository::ChecklistRepository;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::services::todo_service::TodoService;
+use crate::services::workspace_service::authorize_todo_edit;
+use crate::dto::request::checklist_request::{CreateChecklistItemRequest, ReorderChecklistRequest, UpdateChecklistItemRequest};
+use crate::dto::request::todo_request::UpdateTodoStatusRequest;
+use crate::dto::response::checklist_response::{ChecklistItemResponse, ChecklistProgress, ChecklistResponse};
+use crate::entities::checklist::{ChecklistItemChanges, MAX_CHECKLIST_ITEMS};
+use crate::entities::todo::{Todo, TodoStatus, TransitionError};
+use crate::error::ApiError;
+
+pub struct ChecklistService {
+    checklist_repository: Arc<dyn ChecklistRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    todo_service: Arc<TodoService>,
+}
+
+impl ChecklistService {
+    pub fn new(
+        checklist_repository: Arc<dyn ChecklistRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        todo_service: Arc<TodoService>,
+    ) -> Self {
+        Self {
+            checklist_repository,
+            todo_repository,
+            todo_service,
+        }
+    }
+
+    /// 체크리스트 조회 (순서대로)
+    pub async fn list_items(&self, user_id: Uuid, todo_id: Uuid) -> Result<ChecklistResponse, ApiError> {
+        let todo = self.find_todo(user_id, todo_id).await?;
+        self.checklist(todo).await
+    }
+
+    /// 체크리스트 항목 추가 (마지막 순서)
+    pub async fn add_item(&self, user_id: Uuid, todo_id: Uuid, request: CreateChecklistItemRequest) -> Result<ChecklistResponse, ApiError> {
+        request.validate()?;
+
+        let title = Self::normalize_title(&request.title)?;
+        self.editable_todo(user_id, todo_id).await?;
+        if self.checklist_repository.create(todo_id, title).await?.is_none() {
+            return Err(ApiError::Validation(format!(
+                "체크리스트 항목은 최대 {}개까지 추가할 수 있습니다",
+                MAX_CHECKLIST_ITEMS
+            )));
+        }
+
+        self.refreshed_checklist(user_id, todo_id).await
+    }
+
+    /// 체크리스트 항목 수정 (제목 변경, 완료 여부 토글)
+    pub async fn update_item(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        item_id: Uuid,
+        request: UpdateChecklistItemRequest,
+    ) -> Result<ChecklistResponse, ApiError> {
+        request.validate()?;
+        if request.title.is_none() && request.is_done.is_none() {
+            return Err(ApiError::Validation("변경할 내용이 없습니다".to_string()));
+        }
+
+        let changes = ChecklistItemChanges {
+            title: request.title.as_deref().map(Self::normalize_title).transpose()?,
+            is_done: request.is_done,
+        };
+
+        self.editable_todo(user_id, todo_id).await?;
+        self.checklist_repository
+            .update(todo_id, item_id, changes)
+            .await?
+            .ok_or_else(Self::item_not_found)?;
+
+        self.refreshed_checklist(user_id, todo_id).await
+    }
+
+    /// 체크리스트 항목 삭제
+    pub async fn delete_item(&self, user_id: Uuid, todo_id: Uuid, item_id: Uuid) -> Result<ChecklistResponse, ApiError> {
+        self.editable_todo(user_id, todo_id).await?;
+        if !self.checklist_repository.delete(todo_id, item_id).await? {
+            return Err(Self::item_not_found());
+        }
+
+        self.refreshed_checklist(user_id, todo_id).await
+    }
+
+    /// 체크리스트 순서 변경 (모든 항목 ID를 새 순서대로 전달해야 한다)
+    pub async fn reorder_items(&self, user_id: Uuid, todo_id: Uuid, request: ReorderChecklistRequest) -> Result<ChecklistResponse, ApiError> {
+        request.validate()?;
+
+        let todo = self.editable_todo(user_id, todo_id).await?;
+        let current: HashSet<Uuid> = self
+            .checklist_repository
+            .find_by_todo(todo_id)
+            .await?
+            .into_iter()
+            .map(|item| item.id)
+            .collect();
+        let requested: HashSet<Uuid> = request.item_ids.iter().copied().collect();
+
+        if requested.len() != request.item_ids.len() || requested != current {
+            return Err(ApiError::Validation(
+                "item_ids에는 체크리스트의 모든 항목을 한 번씩 포함해야 합니다".to_string(),
+            ));
+        }
+
+        self.checklist_repository.reorder(todo_id, &request.item_ids).await?;
+        self.checklist(todo).await
+    }
+
+    async fn find_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Todo, ApiError> {
+        self.todo_repository
+            .find_by_id(user_id, todo_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("할일을 찾을 수 없습니다".to_string()))
+    }
+
+    /// 체크리스트를 바꿀 수 있는 할일 (owner 또는 editor)
+    async fn editable_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Todo, ApiError> {
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+        self.find_todo(user_id, todo_id).await
+    }
+
+    /// 항목 변경 후 갱신된 진행률을 다시 읽고, 필요하면 할일을 자동 완료한다
+    async fn refreshed_checklist(&self, user_id: Uuid, todo_id: Uuid) -> Result<ChecklistResponse, ApiError> {
+        let mut todo = self.find_todo(user_id, todo_id).await?;
+
+        if todo.checklist_auto_complete && todo.checklist_finished() && todo.status != TodoStatus::Completed {
+            // 상태 전이 규칙과 이벤트 발행을 그대로 따르도록 TodoService를 거친다
+            let request = UpdateTodoStatusRequest { status: TodoStatus::Completed };
+            match self.todo_service.change_status(user_id, todo_id, request).await {
+                Ok(status) => todo.status = status.status,
+                // 다른 요청이 먼저 상태를 바꾼 경우 현재 상태를 그대로 응답한다
+                Err(ApiError::Conflict(_)) => todo = self.find_todo(user_id, todo_id).await?,
+                // 선행 할일이 남아 있으면 완료하지 않고 체크리스트 변경만 반영한다
+                Err(ApiError::InvalidStatusTransition(TransitionError::Blocked { .. })) => {}
+                Err(err) => return Err(err),
+            }
+        }
+
+        self.checklist(todo).await
+    }
+
+    async fn checklist(&self, todo: Todo) -> Result<ChecklistResponse, ApiError> {
+        let items = self.checklist_repository.find_by_todo(todo.id).await?;
+
+        Ok(ChecklistResponse {
+            todo_id: todo.id,
+            items: items.into_iter().map(ChecklistItemResponse::from).collect(),
+            progress: ChecklistProgress::from(&todo),
+            todo_status: todo.status,
+        })
+    }
+
+    fn normalize_title(title: &str) -> Result<String, ApiError> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(ApiError::Validation("항목 제목을 입력해주세요".to_string()));
+        }
+        Ok(title.to_string())
+    }
+
+    fn item_not_found() -> ApiError {
+        ApiError::NotFound("체크리스트 항목을 찾을 수 없습니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::checklist_repository::tests::MockChecklistRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+    use crate::entities::workspace::WorkspaceRole;
+    use crate::entities::checklist::ChecklistItem;
+    use crate::events::EventBus;
+    use chrono::Utc;
+
+    fn sample_todo(user_id: Uuid, id: Uuid, done: i32, total: i32, auto_complete: bool) -> Todo {
+        Todo {
+            status: TodoStatus::InProgress,
+            started_at: Some(Utc::now()),
+            checklist_total: total,
+            checklist_done: done,
+            checklist_auto_complete: auto_complete,
+            ..Todo::fixture(user_id, id)
+        }
+    }
+
+    fn sample_item(todo_id: Uuid, position: i32) -> ChecklistItem {
+        let now = Utc::now();
+        ChecklistItem {
+            id: Uuid::new_v4(),
+            todo_id,
+            title: format!("항목 {}", position + 1),
+            is_done: true,
+            position,
+            done_at: Some(now),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn service(checklist_repo: MockChecklistRepository, mut todo_repo: MockTodoRepository) -> ChecklistService {
+        todo_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Editor)));
+        let todo_repo: Arc<dyn TodoRepository> = Arc::new(todo_repo);
+        let todo_service = Arc::new(TodoService::new(todo_repo.clone(), Arc::new(MockWorkspaceRepository::new()), EventBus::default()));
+        ChecklistService::new(Arc::new(checklist_repo), todo_repo, todo_service)
+    }
+
+    fn toggle_done() -> UpdateChecklistItemRequest {
+        UpdateChecklistItemRequest { title: None, is_done: Some(true) }
+    }
+
+    #[tokio::test]
+    async fn test_last_item_done_auto_completes_todo() {
+        let (user_id, todo_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(sample_todo(user_id, todo_id, 2, 2, true))));
+        todo_repo
+            .expect_update_status()
+            .times(1)
+            .withf(|_, transition| transition.from == TodoStatus::InProgress && transition.to == TodoStatus::Completed)
+            .returning(move |_, transition| {
+                let mut todo = sample_todo(user_id, todo_id, 2, 2, true);
+                todo.status = transition.to;
+                todo.completed_at = transition.completed_at;
+                Ok(Some(todo))
+            });
+
+        let mut checklist_repo = MockChecklistRepository::new();
+        checklist_repo
+            .expect_update()
+            .returning(move |_, _, _| Ok(Some(sample_item(todo_id, 1))));
+        checklist_repo
+            .expect_find_by_todo()
+            .returning(move |_| Ok(vec![sample_item(todo_id, 0), sample_item(todo_id, 1)]));
+
+        let response = service(checklist_repo, todo_repo)
+            .update_item(user_id, todo_id, Uuid::new_v4(), toggle_done())
+            .await
+            .unwrap();
+
+        assert_eq!(response.todo_status, TodoStatus::Completed);
+        assert_eq!(response.progress, ChecklistProgress { done: 2, total: 2 });
+        assert_eq!(response.items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_finished_checklist_without_auto_complete_keeps_status() {
+        let (user_id, todo_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(sample_todo(user_id, todo_id, 1, 1, false))));
+        todo_repo.expect_update_status().never();
+
+        let mut checklist_repo = MockChecklistRepository::new();
+        checklist_repo
+            .expect_update()
+            .returning(move |_, _, _| Ok(Some(sample_item(todo_id, 0))));
+        checklist_repo.expect_find_by_todo().returning(move |_| Ok(vec![sample_item(todo_id, 0)]));
+
+        let response = service(checklist_repo, todo_repo)
+            .update_item(user_id, todo_id, Uuid::new_v4(), toggle_done())
+            .await
+            .unwrap();
+
+        assert_eq!(response.todo_status, TodoStatus::InProgress);
+        assert_eq!(response.progress, ChecklistProgress { done: 1, total: 1 });
+    }
+
+    #[tokio::test]
+    async fn test_add_item_rejects_full_checklist() {
+        let (user_id, todo_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo.expect_find_by_id().returning(move |_, _| {
+            Ok(Some(sample_todo(user_id, todo_id, 0, MAX_CHECKLIST_ITEMS, false)))
+        });
+        let mut checklist_repo = MockChecklistRepository::new();
+        checklist_repo.expect_create().times(1).returning(|_, _| Ok(None));
+        checklist_repo.expect_find_by_todo().never();
+
+        let request = CreateChecklistItemRequest { title: "우유 사기".to_string() };
+        let result = service(checklist_repo, todo_repo).add_item(user_id, todo_id, request).await;
+
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_missing_item_is_not_found() {
+        let (user_id, todo_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(sample_todo(user_id, todo_id, 0, 0, false))));
+        let mut checklist_repo = MockChecklistRepository::new();
+        checklist_repo.expect_update().returning(|_, _, _| Ok(None));
+
+        let result = service(checklist_repo, todo_repo)
+            .update_item(user_id, todo_id, Uuid::new_v4(), toggle_done())
+            .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_reorder_requires_every_item_once() {
+        let (user_id, todo_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let items = vec![sample_item(todo_id, 0), sample_item(todo_id, 1)];
+        let (first, second) = (items[0].id, items[1].id);
+
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(sample_todo(user_id, todo_id, 2, 2, false))));
+        let mut checklist_repo = MockChecklistRepository::new();
+        checklist_repo.expect_find_by_todo().returning(move |_| Ok(items.clone()));
+        checklist_repo.expect_reorder().never();
+
+        let service = service(checklist_repo, todo_repo);
+
+        for item_ids in [vec![second], vec![second, first, second], vec![second, Uuid::new_v4()]] {
+            let result = service
+                .reorder_items(user_id, todo_id, ReorderChecklistRequest { item_ids })
+                .await;
+            assert!(matches!(result, Err(ApiError::Validation(_))));
+        }
+    }
+}
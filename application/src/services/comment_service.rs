@@ -0,0 +1,461 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use chrono::Utc;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::comment_repository::CommentRepository;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::repositories::user_repository::UserRepository;
+use crate::repositories::workspace_repository::WorkspaceRepository;
+use crate::dto::request::comment_request::{CreateCommentRequest, UpdateCommentRequest};
+use crate::dto::response::comment_response::{
+    CommentResponse, CommentRevisionListResponse, CommentRevisionResponse, CommentThreadResponse,
+};
+use crate::entities::comment::{Comment, NewComment, SavedComment, MAX_COMMENT_DEPTH, MAX_MENTIONS_PER_COMMENT};
+use crate::entities::todo::Todo;
+use crate::error::ApiError;
+use crate::notifications::{Notification, NotificationKind, Notifier};
+use crate::utils::markdown;
+
+/// 언급 알림 본문에 넣는 최대 글자 수
+const MENTION_EXCERPT_CHARS: usize = 200;
+
+/// 할일 댓글. 본문은 마크다운으로 받아 정제한 HTML을 함께 저장하고,
+/// 새로 언급된 사용자에게 알림을 보낸다. 할일이 있는 워크스페이스의 멤버만 언급할 수 있다.
+pub struct CommentService {
+    comment_repository: Arc<dyn CommentRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    notifier: Arc<dyn Notifier>,
+}
+
+impl CommentService {
+    pub fn new(
+        comment_repository: Arc<dyn CommentRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        notifier: Arc<dyn Notifier>,
+    ) -> Self {
+        Self {
+            comment_repository,
+            todo_repository,
+            workspace_repository,
+            user_repository,
+            notifier,
+        }
+    }
+
+    /// 할일의 댓글 스레드 조회
+    pub async fn list(&self, user_id: Uuid, todo_id: Uuid) -> Result<CommentThreadResponse, ApiError> {
+        self.find_todo(user_id, todo_id).await?;
+        let comments = self.comment_repository.find_by_todo(todo_id).await?;
+
+        Ok(CommentThreadResponse {
+            todo_id,
+            total: comments.iter().filter(|comment| !comment.is_deleted()).count(),
+            comments: build_thread(comments),
+        })
+    }
+
+    /// 댓글 또는 답글 작성
+    pub async fn create(&self, user_id: Uuid, todo_id: Uuid, request: CreateCommentRequest) -> Result<CommentResponse, ApiError> {
+        request.validate()?;
+        Self::validate_body(&request.body)?;
+        let todo = self.find_todo(user_id, todo_id).await?;
+
+        let depth = match request.parent_id {
+            Some(parent_id) => {
+                let parent = self
+                    .comment_repository
+                    .find_by_id(todo_id, parent_id)
+                    .await?
+                    .ok_or_else(|| ApiError::NotFound("답글을 달 댓글을 찾을 수 없습니다".to_string()))?;
+                if parent.is_deleted() {
+                    return Err(ApiError::Validation("삭제된 댓글에는 답글을 달 수 없습니다".to_string()));
+                }
+                if parent.depth >= MAX_COMMENT_DEPTH {
+                    return Err(ApiError::Validation(format!(
+                        "답글은 최대 {}단계까지 달 수 있습니다",
+                        MAX_COMMENT_DEPTH
+                    )));
+                }
+                parent.depth + 1
+            }
+            None => 0,
+        };
+
+        let rendered = markdown::render(&request.body);
+        let mentioned = self.resolve_mentions(user_id, &todo, &rendered.mentions).await?;
+        let saved = self
+            .comment_repository
+            .create(
+                NewComment {
+                    todo_id,
+                    user_id,
+                    parent_id: request.parent_id,
+                    depth,
+                    body: request.body,
+                    body_html: rendered.html,
+                },
+                &mentioned,
+            )
+            .await?;
+
+        self.notify_mentions(&todo, &saved).await;
+        Ok(CommentResponse::from(saved.comment))
+    }
+
+    /// 댓글 수정 (작성자만). 이전 본문은 수정 이력에 남는다.
+    pub async fn update(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        id: Uuid,
+        request: UpdateCommentRequest,
+    ) -> Result<CommentResponse, ApiError> {
+        request.validate()?;
+        Self::validate_body(&request.body)?;
+        let todo = self.find_todo(user_id, todo_id).await?;
+
+        let comment = self.find_comment(todo_id, id).await?;
+        if comment.user_id != user_id {
+            return Err(ApiError::Forbidden("작성자만 댓글을 수정할 수 있습니다".to_string()));
+        }
+        if comment.body == request.body {
+            return Ok(CommentResponse::from(comment));
+        }
+
+        let rendered = markdown::render(&request.body);
+        let mentioned = self.resolve_mentions(user_id, &todo, &rendered.mentions).await?;
+        let saved = self
+            .comment_repository
+            .update(todo_id, id, request.body, rendered.html, &mentioned)
+            .await?
+            .ok_or_else(Self::comment_not_found)?;
+
+        self.notify_mentions(&todo, &saved).await;
+        Ok(CommentResponse::from(saved.comment))
+    }
+
+    /// 댓글 삭제 (작성자 또는 할일 소유자). 답글은 그대로 남는다.
+    pub async fn delete(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<(), ApiError> {
+        let todo = self.find_todo(user_id, todo_id).await?;
+        let comment = self.find_comment(todo_id, id).await?;
+        if comment.user_id != user_id && todo.user_id != user_id {
+            return Err(ApiError::Forbidden("댓글을 삭제할 권한이 없습니다".to_string()));
+        }
+
+        if !self.comment_repository.soft_delete(todo_id, id).await? {
+            return Err(Self::comment_not_found());
+        }
+        Ok(())
+    }
+
+    /// 댓글의 수정 이력 조회
+    pub async fn revisions(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<CommentRevisionListResponse, ApiError> {
+        self.find_todo(user_id, todo_id).await?;
+        let comment = self.find_comment(todo_id, id).await?;
+        let revisions = self.comment_repository.find_revisions(comment.id).await?;
+
+        Ok(CommentRevisionListResponse {
+            comment_id: comment.id,
+            revisions: revisions.into_iter().map(CommentRevisionResponse::from).collect(),
+        })
+    }
+
+    fn validate_body(body: &str) -> Result<(), ApiError> {
+        if body.trim().is_empty() {
+            return Err(ApiError::Validation("댓글 내용을 입력해 주세요".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 언급한 사용자 이름을 ID로 바꾼다. 없는 사용자, 자기 자신, 할일의 워크스페이스 멤버가 아닌
+    /// 사용자는 건너뛴다 (알림에 할일 제목이 들어가므로).
+    async fn resolve_mentions(&self, author_id: Uuid, todo: &Todo, usernames: &[String]) -> Result<Vec<Uuid>, ApiError> {
+        let mut user_ids = Vec::new();
+        for username in usernames.iter().take(MAX_MENTIONS_PER_COMMENT) {
+            let Some(user) = self.user_repository.find_by_username(username).await? else {
+                continue;
+            };
+            if user.id == author_id || user_ids.contains(&user.id) {
+                continue;
+            }
+            if self.workspace_repository.find_role(todo.workspace_id, user.id).await?.is_some() {
+                user_ids.push(user.id);
+            }
+        }
+        Ok(user_ids)
+    }
+
+    /// 알림 실패는 댓글 저장을 되돌리지 않는다
+    async fn notify_mentions(&self, todo: &Todo, saved: &SavedComment) {
+        let comment = &saved.comment;
+        let author = comment.author_username.as_deref().unwrap_or("알 수 없는 사용자");
+        let mut excerpt: String = comment.body.chars().take(MENTION_EXCERPT_CHARS).collect();
+        if comment.body.chars().count() > MENTION_EXCERPT_CHARS {
+            excerpt.push('…');
+        }
+
+        for mention in &saved.new_mentions {
+            let notification = Notification {
+                id: mention.id,
+                kind: NotificationKind::Mention,
+                user_id: mention.user_id,
+                todo_id: Some(todo.id),
+                title: format!("{}님이 '{}' 댓글에서 회원님을 언급했습니다", author, todo.title),
+                body: excerpt.clone(),
+                created_at: Utc::now(),
+            };
+            if let Err(err) = self.notifier.notify(&notification).await {
+                tracing::warn!(%err, comment_id = %comment.id, user_id = %mention.user_id, "failed to notify mention");
+            }
+        }
+    }
+
+    async fn find_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Todo, ApiError> {
+        self.todo_repository
+            .find_by_id(user_id, todo_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("할일을 찾을 수 없습니다".to_string()))
+    }
+
+    /// 삭제된 댓글은 없는 것으로 본다
+    async fn find_comment(&self, todo_id: Uuid, id: Uuid) -> Result<Comment, ApiError> {
+        self.comment_repository
+            .find_by_id(todo_id, id)
+            .await?
+            .filter(|comment| !comment.is_deleted())
+            .ok_or_else(Self::comment_not_found)
+    }
+
+    fn comment_not_found() -> ApiError {
+        ApiError::NotFound("댓글을 찾을 수 없습니다".to_string())
+    }
+}
+
+/// 작성 순서로 정렬된 댓글을 트리로 묶는다. 남은 답글이 없는 삭제된 댓글은 뺀다.
+fn build_thread(comments: Vec<Comment>) -> Vec<CommentResponse> {
+    let mut children: HashMap<Option<Uuid>, Vec<Comment>> = HashMap::new();
+    for comment in comments {
+        children.entry(comment.parent_id).or_default().push(comment);
+    }
+
+    fn build(parent_id: Option<Uuid>, children: &mut HashMap<Option<Uuid>, Vec<Comment>>) -> Vec<CommentResponse> {
+        let Some(siblings) = children.remove(&parent_id) else {
+            return Vec::new();
+        };
+        siblings
+            .into_iter()
+            .filter_map(|comment| {
+                let replies = build(Some(comment.id), children);
+                if comment.is_deleted() && replies.is_empty() {
+                    return None;
+                }
+                let mut response = CommentResponse::from(comment);
+                response.replies = replies;
+                Some(response)
+            })
+            .collect()
+    }
+
+    build(None, &mut children)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration};
+    use crate::entities::comment::CommentMention;
+    use crate::entities::user::User;
+    use crate::entities::workspace::WorkspaceRole;
+    use crate::notifications::tests::MockNotifier;
+    use crate::notifications::NotifyError;
+    use crate::repositories::comment_repository::tests::MockCommentRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::user_repository::tests::MockUserRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+
+    fn sample_comment(todo_id: Uuid, user_id: Uuid, parent_id: Option<Uuid>, created_at: DateTime<Utc>) -> Comment {
+        Comment {
+            id: Uuid::new_v4(),
+            todo_id,
+            user_id,
+            author_username: Some("author".to_string()),
+            parent_id,
+            depth: if parent_id.is_some() { 1 } else { 0 },
+            body: "본문".to_string(),
+            body_html: "<p>본문</p>\n".to_string(),
+            edited_at: None,
+            deleted_at: None,
+            created_at,
+            updated_at: created_at,
+        }
+    }
+
+    fn sample_user(username: &str) -> User {
+        User {
+            id: Uuid::new_v4(),
+            email: format!("{}@example.com", username),
+            username: username.to_string(),
+            password_hash: "hash".to_string(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn todo_repo_with(user_id: Uuid, todo_id: Uuid) -> MockTodoRepository {
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(Todo::fixture(user_id, todo_id))));
+        todo_repo
+    }
+
+    fn service(
+        comment_repo: MockCommentRepository,
+        todo_repo: MockTodoRepository,
+        workspace_repo: MockWorkspaceRepository,
+        user_repo: MockUserRepository,
+        notifier: MockNotifier,
+    ) -> CommentService {
+        CommentService::new(
+            Arc::new(comment_repo),
+            Arc::new(todo_repo),
+            Arc::new(workspace_repo),
+            Arc::new(user_repo),
+            Arc::new(notifier),
+        )
+    }
+
+    #[test]
+    fn test_build_thread_keeps_deleted_comments_only_with_replies() {
+        let todo_id = Uuid::new_v4();
+        let user_id = Uuid::new_v4();
+        let start = Utc::now();
+
+        let mut root = sample_comment(todo_id, user_id, None, start);
+        root.deleted_at = Some(start);
+        let reply = sample_comment(todo_id, user_id, Some(root.id), start + Duration::seconds(1));
+        let mut lonely = sample_comment(todo_id, user_id, None, start + Duration::seconds(2));
+        lonely.deleted_at = Some(start);
+        let last = sample_comment(todo_id, user_id, None, start + Duration::seconds(3));
+        let (root_id, reply_id, last_id) = (root.id, reply.id, last.id);
+
+        let thread = build_thread(vec![root, reply, lonely, last]);
+
+        assert_eq!(thread.iter().map(|c| c.id).collect::<Vec<_>>(), vec![root_id, last_id]);
+        assert!(thread[0].deleted);
+        assert_eq!(thread[0].body, None);
+        assert_eq!(thread[0].replies.iter().map(|c| c.id).collect::<Vec<_>>(), vec![reply_id]);
+    }
+
+    #[tokio::test]
+    async fn test_create_notifies_resolved_mentions() {
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+        let kim = sample_user("kim_dev");
+        let kim_id = kim.id;
+        let outsider = sample_user("outsider");
+
+        let mut user_repo = MockUserRepository::new();
+        user_repo.expect_find_by_username().returning(move |username| {
+            Ok(match username {
+                "kim_dev" => Some(kim.clone()),
+                "outsider" => Some(outsider.clone()),
+                "me" => Some(User { id: user_id, ..sample_user("me") }),
+                _ => None,
+            })
+        });
+
+        let mut workspace_repo = MockWorkspaceRepository::new();
+        workspace_repo
+            .expect_find_role()
+            .returning(move |_, user_id| Ok((user_id == kim_id).then_some(WorkspaceRole::Viewer)));
+
+        let mut comment_repo = MockCommentRepository::new();
+        comment_repo
+            .expect_create()
+            .withf(move |comment, mentioned| {
+                comment.depth == 0 && comment.body_html.contains("<strong>") && mentioned == [kim_id]
+            })
+            .returning(move |comment, mentioned| {
+                let created = sample_comment(comment.todo_id, comment.user_id, None, Utc::now());
+                let new_mentions = mentioned
+                    .iter()
+                    .map(|user_id| CommentMention { id: Uuid::new_v4(), comment_id: created.id, user_id: *user_id })
+                    .collect();
+                Ok(SavedComment { comment: created, new_mentions })
+            });
+
+        let mut notifier = MockNotifier::new();
+        notifier
+            .expect_notify()
+            .withf(move |n| n.kind == NotificationKind::Mention && n.user_id == kim_id && n.todo_id == Some(todo_id))
+            .times(1)
+            .returning(|_| Err(NotifyError::Delivery("offline".to_string())));
+
+        let service = service(comment_repo, todo_repo_with(user_id, todo_id), workspace_repo, user_repo, notifier);
+        let request = CreateCommentRequest {
+            body: "**확인** 부탁해요 @kim_dev @me @ghost @outsider".to_string(),
+            parent_id: None,
+        };
+
+        assert!(service.create(user_id, todo_id, request).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_reply_beyond_max_depth() {
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+
+        let mut comment_repo = MockCommentRepository::new();
+        comment_repo.expect_find_by_id().returning(move |todo_id, _| {
+            let mut parent = sample_comment(todo_id, user_id, Some(Uuid::new_v4()), Utc::now());
+            parent.depth = MAX_COMMENT_DEPTH;
+            Ok(Some(parent))
+        });
+        comment_repo.expect_create().never();
+
+        let service = service(
+            comment_repo,
+            todo_repo_with(user_id, todo_id),
+            MockWorkspaceRepository::new(),
+            MockUserRepository::new(),
+            MockNotifier::new(),
+        );
+        let request = CreateCommentRequest {
+            body: "답글".to_string(),
+            parent_id: Some(Uuid::new_v4()),
+        };
+
+        let result = service.create(user_id, todo_id, request).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_is_limited_to_author() {
+        let user_id = Uuid::new_v4();
+        let todo_id = Uuid::new_v4();
+
+        let mut comment_repo = MockCommentRepository::new();
+        comment_repo
+            .expect_find_by_id()
+            .returning(move |todo_id, _| Ok(Some(sample_comment(todo_id, Uuid::new_v4(), None, Utc::now()))));
+        comment_repo.expect_update().never();
+
+        let service = service(
+            comment_repo,
+            todo_repo_with(user_id, todo_id),
+            MockWorkspaceRepository::new(),
+            MockUserRepository::new(),
+            MockNotifier::new(),
+        );
+        let request = UpdateCommentRequest { body: "수정".to_string() };
+
+        let result = service.update(user_id, todo_id, Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
+}
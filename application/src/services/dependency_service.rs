@@ -0,0 +1,178 @@
+use std::sync::Arc;
+use uuid::Uuid;
+use crate::repositories::dependency_repository::DependencyRepository;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::services::workspace_service::authorize_todo_edit;
+use crate::dto::request::dependency_request::AddDependencyRequest;
+use crate::dto::response::dependency_response::{DependencyListResponse, DependencyTodoResponse};
+use crate::entities::dependency::{LinkOutcome, MAX_BLOCKERS_PER_TODO};
+use crate::entities::todo::{Todo, TodoStatus};
+use crate::error::ApiError;
+
+pub struct DependencyService {
+    dependency_repository: Arc<dyn DependencyRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+}
+
+impl DependencyService {
+    pub fn new(dependency_repository: Arc<dyn DependencyRepository>, todo_repository: Arc<dyn TodoRepository>) -> Self {
+        Self {
+            dependency_repository,
+            todo_repository,
+        }
+    }
+
+    /// 할일의 선행 할일과 후속 할일 조회
+    pub async fn list_dependencies(&self, user_id: Uuid, todo_id: Uuid) -> Result<DependencyListResponse, ApiError> {
+        let todo = self.find_todo(user_id, todo_id).await?;
+        self.dependencies(todo).await
+    }
+
+    /// 선행 할일 연결 (`blocked_by_id`가 끝나야 이 할일을 끝낼 수 있다). 선행 할일은 같은 워크스페이스에 있어야 한다.
+    pub async fn add_dependency(&self, user_id: Uuid, todo_id: Uuid, request: AddDependencyRequest) -> Result<DependencyListResponse, ApiError> {
+        let blocked_by_id = request.blocked_by_id;
+        if blocked_by_id == todo_id {
+            return Err(ApiError::Validation("자기 자신을 선행 할일로 지정할 수 없습니다".to_string()));
+        }
+
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+        let todo = self.find_todo(user_id, todo_id).await?;
+        self.todo_repository
+            .find_by_id(user_id, blocked_by_id)
+            .await?
+            .filter(|blocker| blocker.workspace_id == todo.workspace_id)
+            .ok_or_else(|| ApiError::NotFound("선행 할일을 찾을 수 없습니다".to_string()))?;
+
+        let blockers = self.dependency_repository.find_blockers(todo_id).await?;
+        if blockers.len() >= MAX_BLOCKERS_PER_TODO {
+            return Err(ApiError::Validation(format!(
+                "선행 할일은 할일마다 최대 {}개까지 연결할 수 있습니다",
+                MAX_BLOCKERS_PER_TODO
+            )));
+        }
+
+        match self.dependency_repository.link(todo.workspace_id, todo_id, blocked_by_id).await? {
+            LinkOutcome::Linked => {}
+            LinkOutcome::AlreadyLinked => {
+                return Err(ApiError::Conflict("이미 연결된 선행 할일입니다".to_string()));
+            }
+            LinkOutcome::WouldCycle => {
+                return Err(ApiError::Conflict(
+                    "순환 의존 관계가 생기므로 연결할 수 없습니다".to_string(),
+                ));
+            }
+        }
+
+        self.dependencies(todo).await
+    }
+
+    /// 선행 할일 연결 해제
+    pub async fn remove_dependency(&self, user_id: Uuid, todo_id: Uuid, blocked_by_id: Uuid) -> Result<(), ApiError> {
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+        if !self.dependency_repository.unlink(todo_id, blocked_by_id).await? {
+            return Err(ApiError::NotFound("연결된 선행 할일을 찾을 수 없습니다".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn find_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Todo, ApiError> {
+        self.todo_repository
+            .find_by_id(user_id, todo_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("할일을 찾을 수 없습니다".to_string()))
+    }
+
+    async fn dependencies(&self, todo: Todo) -> Result<DependencyListResponse, ApiError> {
+        let blockers = self.dependency_repository.find_blockers(todo.id).await?;
+        let dependents = self.dependency_repository.find_dependents(todo.id).await?;
+        let open_blockers = blockers.iter().filter(|b| b.status != TodoStatus::Completed).count();
+
+        Ok(DependencyListResponse {
+            todo_id: todo.id,
+            blocked_by: blockers.into_iter().map(DependencyTodoResponse::from).collect(),
+            dependents: dependents.into_iter().map(DependencyTodoResponse::from).collect(),
+            open_blockers,
+            enforce_dependencies: todo.enforce_dependencies,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::dependency_repository::tests::MockDependencyRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::entities::workspace::WorkspaceRole;
+
+    const WORKSPACE_ID: Uuid = Uuid::from_u128(1);
+
+    fn sample_todo(user_id: Uuid, id: Uuid) -> Todo {
+        Todo { workspace_id: WORKSPACE_ID, enforce_dependencies: true, ..Todo::fixture(user_id, id) }
+    }
+
+    #[tokio::test]
+    async fn test_add_dependency_rejects_self() {
+        let mut dependency_repo = MockDependencyRepository::new();
+        dependency_repo.expect_link().never();
+
+        let service = DependencyService::new(Arc::new(dependency_repo), Arc::new(MockTodoRepository::new()));
+        let todo_id = Uuid::new_v4();
+        let result = service
+            .add_dependency(Uuid::new_v4(), todo_id, AddDependencyRequest { blocked_by_id: todo_id })
+            .await;
+
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_add_dependency_cycle_is_conflict() {
+        let mut todo_repo = MockTodoRepository::new();
+        let mut dependency_repo = MockDependencyRepository::new();
+
+        todo_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Editor)));
+        todo_repo
+            .expect_find_by_id()
+            .returning(|user_id, id| Ok(Some(sample_todo(user_id, id))));
+        dependency_repo.expect_find_blockers().returning(|_| Ok(vec![]));
+        dependency_repo
+            .expect_link()
+            .times(1)
+            .returning(|_, _, _| Ok(LinkOutcome::WouldCycle));
+
+        let service = DependencyService::new(Arc::new(dependency_repo), Arc::new(todo_repo));
+        let result = service
+            .add_dependency(Uuid::new_v4(), Uuid::new_v4(), AddDependencyRequest { blocked_by_id: Uuid::new_v4() })
+            .await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_add_dependency_rejects_other_workspace_blocker() {
+        let mut todo_repo = MockTodoRepository::new();
+        let mut dependency_repo = MockDependencyRepository::new();
+        let (todo_id, blocked_by_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        todo_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Owner)));
+        todo_repo.expect_find_by_id().returning(move |user_id, id| {
+            let mut todo = sample_todo(user_id, id);
+            if id == blocked_by_id {
+                todo.workspace_id = Uuid::new_v4();
+            }
+            Ok(Some(todo))
+        });
+        dependency_repo.expect_link().never();
+
+        let service = DependencyService::new(Arc::new(dependency_repo), Arc::new(todo_repo));
+        let result = service
+            .add_dependency(Uuid::new_v4(), todo_id, AddDependencyRequest { blocked_by_id })
+            .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+}
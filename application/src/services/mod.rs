@@ -2,7 +2,26 @@
 //!
 //! Contains business logic layer services.
 
+pub mod assignment_service;
+pub mod attachment_service;
+pub mod board_service;
+pub mod bulk_service;
+pub mod category_service;
+pub mod checklist_service;
+pub mod comment_service;
+pub mod dependency_service;
 pub mod health_service;
+pub mod quick_add_service;
+pub mod recurrence_service;
+pub mod reminder_service;
+pub mod share_link_service;
+pub mod smart_list_service;
+pub mod stats_service;
+pub mod template_service;
+pub mod time_tracking_service;
+pub mod todo_service;
 pub mod user_service;
+pub mod user_settings_service;
+pub mod workspace_service;
 
 pub use health_service::HealthService;
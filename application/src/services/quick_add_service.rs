@@ -0,0 +1,170 @@
+use std::sync::Arc;
+use chrono::Utc;
+use uuid::Uuid;
+use validator::Validate;
+use crate::services::category_service::CategoryService;
+use crate::services::recurrence_service::RecurrenceService;
+use crate::services::todo_service::TodoService;
+use crate::services::user_settings_service::UserSettingsService;
+use crate::dto::request::category_request::AssignCategoriesRequest;
+use crate::dto::request::quick_add_request::QuickAddRequest;
+use crate::dto::request::recurrence_request::SetRecurrenceRequest;
+use crate::dto::request::todo_request::CreateTodoRequest;
+use crate::dto::response::quick_add_response::{QuickAddParseResponse, QuickAddResponse, QuickAddTokenResponse};
+use crate::error::ApiError;
+use crate::utils::quick_add::{self, QuickAddContext};
+
+/// 한 줄 입력으로 할일 만들기
+///
+/// 해석은 `utils::quick_add`가 맡고, 여기서는 해석 결과로 할일, 카테고리 지정, 반복 일정을
+/// 기존 서비스를 통해 만든다.
+pub struct QuickAddService {
+    todo_service: Arc<TodoService>,
+    category_service: Arc<CategoryService>,
+    recurrence_service: Arc<RecurrenceService>,
+    settings_service: Arc<UserSettingsService>,
+}
+
+impl QuickAddService {
+    pub fn new(
+        todo_service: Arc<TodoService>,
+        category_service: Arc<CategoryService>,
+        recurrence_service: Arc<RecurrenceService>,
+        settings_service: Arc<UserSettingsService>,
+    ) -> Self {
+        Self {
+            todo_service,
+            category_service,
+            recurrence_service,
+            settings_service,
+        }
+    }
+
+    /// 입력을 해석해 할일 생성
+    pub async fn quick_add(&self, user_id: Uuid, request: QuickAddRequest) -> Result<QuickAddResponse, ApiError> {
+        request.validate()?;
+
+        let preferences = self
+            .settings_service
+            .time_preferences(user_id, request.timezone.as_deref(), None)
+            .await?;
+        let context = QuickAddContext {
+            now: Utc::now(),
+            timezone: preferences.timezone,
+            week_start: preferences.week_start.as_weekday(),
+        };
+        let parsed = quick_add::parse(&request.text, &context);
+
+        if parsed.title.is_empty() {
+            return Err(ApiError::Validation("제목으로 쓸 내용이 없습니다".to_string()));
+        }
+        let create_request = CreateTodoRequest {
+            workspace_id: None,
+            title: parsed.title.clone(),
+            description: None,
+            priority: parsed.priority.unwrap_or_default(),
+            due_date: parsed.due_date,
+            checklist_auto_complete: false,
+            enforce_dependencies: false,
+            estimate_minutes: None,
+        };
+        create_request.validate()?;
+        // 할일을 만들기 전에 카테고리 이름을 검사하고 준비해 둔다
+        let assign_request = if parsed.categories.is_empty() {
+            None
+        } else {
+            let categories = self.category_service.find_or_create(user_id, &parsed.categories).await?;
+            Some(AssignCategoriesRequest {
+                category_ids: categories.into_iter().map(|category| category.id).collect(),
+            })
+        };
+
+        let todo = self.todo_service.create_todo(user_id, create_request).await?;
+
+        let categories = match assign_request {
+            Some(assign_request) => {
+                self.category_service
+                    .assign_categories(user_id, todo.id, assign_request)
+                    .await?
+                    .categories
+            }
+            None => Vec::new(),
+        };
+
+        let (todo, recurrence) = match &parsed.recurrence {
+            Some(rule) => {
+                let recurrence_request = SetRecurrenceRequest {
+                    rrule: rule.to_string(),
+                    timezone: Some(context.timezone.name().to_string()),
+                };
+                let recurrence = self
+                    .recurrence_service
+                    .set_recurrence(user_id, todo.id, recurrence_request)
+                    .await?;
+                (self.todo_service.get_todo(user_id, todo.id).await?, Some(recurrence))
+            }
+            None => (todo, None),
+        };
+
+        Ok(QuickAddResponse {
+            todo,
+            categories,
+            recurrence,
+            parsed: QuickAddParseResponse {
+                title: parsed.title,
+                due_date: parsed.due_date,
+                all_day: parsed.all_day,
+                priority: parsed.priority,
+                categories: parsed.categories,
+                rrule: parsed.recurrence.map(|rule| rule.to_string()),
+                timezone: context.timezone.name().to_string(),
+                tokens: parsed.tokens.into_iter().map(QuickAddTokenResponse::from).collect(),
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::category_repository::tests::MockCategoryRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+    use crate::repositories::todo_series_repository::tests::MockTodoSeriesRepository;
+    use crate::repositories::user_settings_repository::tests::MockUserSettingsRepository;
+    use crate::events::EventBus;
+
+    fn service(todo_repo: MockTodoRepository, category_repo: MockCategoryRepository) -> QuickAddService {
+        let todo_repo = Arc::new(todo_repo);
+        let mut settings_repo = MockUserSettingsRepository::new();
+        settings_repo.expect_find().returning(|_| Ok(None));
+        let settings_service = Arc::new(UserSettingsService::new(Arc::new(settings_repo)));
+
+        QuickAddService::new(
+            Arc::new(TodoService::new(todo_repo.clone(), Arc::new(MockWorkspaceRepository::new()), EventBus::default())),
+            Arc::new(CategoryService::new(Arc::new(category_repo), todo_repo.clone(), Arc::new(MockWorkspaceRepository::new()))),
+            Arc::new(RecurrenceService::new(
+                Arc::new(MockTodoSeriesRepository::new()),
+                todo_repo,
+                settings_service.clone(),
+            )),
+            settings_service,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_quick_add_without_title_creates_nothing() {
+        let mut todo_repo = MockTodoRepository::new();
+        let mut category_repo = MockCategoryRepository::new();
+        todo_repo.expect_create().never();
+        category_repo.expect_create().never();
+
+        let request = QuickAddRequest {
+            text: "내일 오후 3시 !high #업무".to_string(),
+            timezone: Some("Asia/Seoul".to_string()),
+        };
+        let result = service(todo_repo, category_repo).quick_add(Uuid::new_v4(), request).await;
+
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+}
@@ -0,0 +1,400 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use chrono_tz::Tz;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::repositories::todo_series_repository::TodoSeriesRepository;
+use crate::services::user_settings_service::UserSettingsService;
+use crate::services::workspace_service::authorize_todo_edit;
+use crate::dto::request::recurrence_request::{EditScope, SetRecurrenceRequest, UpdateOccurrenceRequest};
+use crate::dto::response::recurrence_response::{OccurrenceUpdateResponse, RecurrenceResponse};
+use crate::dto::response::todo_response::TodoResponse;
+use crate::entities::todo::{Todo, TodoChanges};
+use crate::entities::todo_series::{NewTodoSeries, TodoSeries};
+use crate::error::ApiError;
+use crate::utils::recurrence::RecurrenceRule;
+use crate::utils::timezone::parse_timezone;
+
+const UPCOMING_PREVIEW: usize = 5;
+
+/// 반복 할일 관리
+///
+/// 다음 발생은 발생을 완료하는 저장소 트랜잭션 안에서 만들어진다
+/// (`todo_series_repository::create_next_occurrence`). 이 서비스는 규칙 지정과 수정을 맡는다.
+pub struct RecurrenceService {
+    series_repository: Arc<dyn TodoSeriesRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    settings_service: Arc<UserSettingsService>,
+}
+
+impl RecurrenceService {
+    pub fn new(
+        series_repository: Arc<dyn TodoSeriesRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        settings_service: Arc<UserSettingsService>,
+    ) -> Self {
+        Self {
+            series_repository,
+            todo_repository,
+            settings_service,
+        }
+    }
+
+    /// 할일의 반복 규칙과 이후 예정된 발생 조회
+    pub async fn get_recurrence(&self, user_id: Uuid, todo_id: Uuid) -> Result<RecurrenceResponse, ApiError> {
+        let todo = self.find_todo(user_id, todo_id).await?;
+        let series = self.find_series(&todo).await?.ok_or_else(Self::not_recurring)?;
+        Self::recurrence_response(&todo, &series)
+    }
+
+    /// 할일에 반복 규칙 지정. 이미 반복 중이면 이번 발생부터 새 규칙을 적용한다.
+    pub async fn set_recurrence(&self, user_id: Uuid, todo_id: Uuid, request: SetRecurrenceRequest) -> Result<RecurrenceResponse, ApiError> {
+        request.validate()?;
+
+        let rule = Self::parse_rule(&request.rrule)?;
+        let todo = self.editable_todo(user_id, todo_id).await?;
+        let dtstart = todo
+            .due_date
+            .ok_or_else(|| ApiError::Validation("반복 일정에는 마감일이 필요합니다".to_string()))?;
+
+        let timezone = match (request.timezone.as_deref(), self.find_series(&todo).await?) {
+            (Some(timezone), _) => parse_timezone(timezone)?,
+            (None, Some(series)) => parse_timezone(&series.timezone)?,
+            (None, None) => self.settings_service.time_preferences(user_id, None, None).await?.timezone,
+        };
+
+        let new_series = NewTodoSeries {
+            user_id: todo.user_id,
+            rrule: rule.to_string(),
+            timezone: timezone.name().to_string(),
+            dtstart,
+            title: todo.title.clone(),
+            description: todo.description.clone(),
+            priority: todo.priority,
+            checklist_auto_complete: todo.checklist_auto_complete,
+        };
+
+        let series = match (todo.series_id, todo.recurrence_index) {
+            (Some(series_id), Some(index)) => {
+                let changes = Self::unchanged(&todo);
+                self.series_repository.split(series_id, index, new_series, todo.id, changes).await?
+            }
+            _ => self.series_repository.start(new_series, todo.id).await?,
+        };
+
+        let todo = self.find_todo(user_id, todo_id).await?;
+        Self::recurrence_response(&todo, &series)
+    }
+
+    /// 반복 중지 (이 할일은 남기고 이후 발생을 더 만들지 않는다)
+    pub async fn stop_recurrence(&self, user_id: Uuid, todo_id: Uuid) -> Result<(), ApiError> {
+        let todo = self.editable_todo(user_id, todo_id).await?;
+        if !self.series_repository.detach(todo.user_id, todo_id).await? {
+            return Err(Self::not_recurring());
+        }
+        Ok(())
+    }
+
+    /// 반복 할일 수정 (`this`: 이번 발생만, `all_future`: 이번 발생부터 시리즈 전체)
+    pub async fn update_occurrence(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        request: UpdateOccurrenceRequest,
+    ) -> Result<OccurrenceUpdateResponse, ApiError> {
+        request.validate()?;
+
+        let todo = self.editable_todo(user_id, todo_id).await?;
+        let series = self.find_series(&todo).await?.ok_or_else(Self::not_recurring)?;
+        let index = todo.recurrence_index.ok_or_else(Self::not_recurring)?;
+
+        let changes = TodoChanges {
+            title: request.title,
+            description: request.description,
+            priority: request.priority,
+            due_date: request.due_date,
+            checklist_auto_complete: request.checklist_auto_complete,
+            enforce_dependencies: todo.enforce_dependencies,
+            estimate_minutes: todo.estimate_minutes,
+        };
+
+        let series = match request.scope {
+            EditScope::This => {
+                if request.rrule.is_some() || request.timezone.is_some() {
+                    return Err(ApiError::Validation(
+                        "반복 규칙과 시간대는 all_future 범위에서만 변경할 수 있습니다".to_string(),
+                    ));
+                }
+                // 시리즈 템플릿은 그대로 두므로 이후 발생에는 반영되지 않는다
+                self.todo_repository
+                    .update(user_id, todo_id, changes)
+                    .await?
+                    .ok_or_else(Self::todo_not_found)?;
+                series
+            }
+            EditScope::AllFuture => {
+                let dtstart = changes
+                    .due_date
+                    .ok_or_else(|| ApiError::Validation("반복 일정에는 마감일이 필요합니다".to_string()))?;
+                let rule = match request.rrule.as_deref() {
+                    Some(rrule) => Self::parse_rule(rrule)?,
+                    // 새 시리즈는 이번 발생부터 세므로 남은 COUNT만 이어받는다
+                    None => Self::stored_rule(&series)?
+                        .remaining_after(index as u32 - 1)
+                        .ok_or_else(|| ApiError::Validation("반복 일정이 이미 끝났습니다".to_string()))?,
+                };
+                let timezone = match request.timezone.as_deref() {
+                    Some(timezone) => parse_timezone(timezone)?,
+                    None => parse_timezone(&series.timezone)?,
+                };
+
+                let new_series = NewTodoSeries {
+                    user_id: todo.user_id,
+                    rrule: rule.to_string(),
+                    timezone: timezone.name().to_string(),
+                    dtstart,
+                    title: changes.title.clone(),
+                    description: changes.description.clone(),
+                    priority: changes.priority,
+                    checklist_auto_complete: changes.checklist_auto_complete,
+                };
+                self.series_repository.split(series.id, index, new_series, todo_id, changes).await?
+            }
+        };
+
+        let todo = self.find_todo(user_id, todo_id).await?;
+        Ok(OccurrenceUpdateResponse {
+            scope: request.scope,
+            recurrence: Self::recurrence_response(&todo, &series)?,
+            todo: TodoResponse::from(todo),
+        })
+    }
+
+    async fn find_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Todo, ApiError> {
+        self.todo_repository
+            .find_by_id(user_id, todo_id)
+            .await?
+            .ok_or_else(Self::todo_not_found)
+    }
+
+    /// 반복 규칙을 바꿀 수 있는 할일 (owner 또는 editor)
+    async fn editable_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Todo, ApiError> {
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+        self.find_todo(user_id, todo_id).await
+    }
+
+    async fn find_series(&self, todo: &Todo) -> Result<Option<TodoSeries>, ApiError> {
+        match todo.series_id {
+            Some(series_id) => self.series_repository.find_by_id(todo.user_id, series_id).await,
+            None => Ok(None),
+        }
+    }
+
+    fn recurrence_response(todo: &Todo, series: &TodoSeries) -> Result<RecurrenceResponse, ApiError> {
+        let rule = Self::stored_rule(series)?;
+        let timezone: Tz = parse_timezone(&series.timezone)?;
+        let index = todo.recurrence_index.unwrap_or(1);
+
+        Ok(RecurrenceResponse {
+            todo_id: todo.id,
+            series_id: series.id,
+            rrule: series.rrule.clone(),
+            timezone: series.timezone.clone(),
+            dtstart: series.dtstart,
+            recurrence_index: index,
+            upcoming: rule
+                .occurrences(series.dtstart, timezone)
+                .skip(index.max(1) as usize)
+                .take(UPCOMING_PREVIEW)
+                .collect(),
+        })
+    }
+
+    fn parse_rule(rrule: &str) -> Result<RecurrenceRule, ApiError> {
+        RecurrenceRule::from_str(rrule).map_err(ApiError::Validation)
+    }
+
+    fn stored_rule(series: &TodoSeries) -> Result<RecurrenceRule, ApiError> {
+        RecurrenceRule::from_str(&series.rrule)
+            .map_err(|e| ApiError::Internal(format!("저장된 반복 규칙을 해석할 수 없습니다: {}", e)))
+    }
+
+    fn unchanged(todo: &Todo) -> TodoChanges {
+        TodoChanges {
+            title: todo.title.clone(),
+            description: todo.description.clone(),
+            priority: todo.priority,
+            due_date: todo.due_date,
+            checklist_auto_complete: todo.checklist_auto_complete,
+            enforce_dependencies: todo.enforce_dependencies,
+            estimate_minutes: todo.estimate_minutes,
+        }
+    }
+
+    fn not_recurring() -> ApiError {
+        ApiError::NotFound("반복 일정이 없는 할일입니다".to_string())
+    }
+
+    fn todo_not_found() -> ApiError {
+        ApiError::NotFound("할일을 찾을 수 없습니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::todo_series_repository::tests::MockTodoSeriesRepository;
+    use crate::repositories::user_settings_repository::tests::MockUserSettingsRepository;
+    use crate::entities::workspace::WorkspaceRole;
+    use crate::entities::todo::{Priority, TodoStatus};
+    use chrono::{DateTime, Utc};
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn sample_series(user_id: Uuid, rrule: &str) -> TodoSeries {
+        let now = Utc::now();
+        TodoSeries {
+            id: Uuid::new_v4(),
+            user_id,
+            rrule: rrule.to_string(),
+            timezone: "Asia/Seoul".to_string(),
+            dtstart: utc("2025-07-14T00:00:00Z"),
+            title: "분리수거".to_string(),
+            description: None,
+            priority: Priority::Medium,
+            checklist_auto_complete: false,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn occurrence(series: &TodoSeries, index: i32, due_date: &str) -> Todo {
+        Todo {
+            title: series.title.clone(),
+            status: TodoStatus::Completed,
+            priority: series.priority,
+            due_date: Some(utc(due_date)),
+            completed_at: Some(Utc::now()),
+            series_id: Some(series.id),
+            recurrence_index: Some(index),
+            ..Todo::fixture(series.user_id, Uuid::new_v4())
+        }
+    }
+
+    fn service(series_repo: MockTodoSeriesRepository, mut todo_repo: MockTodoRepository) -> RecurrenceService {
+        todo_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Editor)));
+        let settings_service = Arc::new(UserSettingsService::new(Arc::new(MockUserSettingsRepository::new())));
+        RecurrenceService::new(Arc::new(series_repo), Arc::new(todo_repo), settings_service)
+    }
+
+    fn mocks_for(series: &TodoSeries, todo: &Todo) -> (MockTodoSeriesRepository, MockTodoRepository) {
+        let mut todo_repo = MockTodoRepository::new();
+        let found = todo.clone();
+        todo_repo.expect_find_by_id().returning(move |_, _| Ok(Some(found.clone())));
+
+        let mut series_repo = MockTodoSeriesRepository::new();
+        let found = series.clone();
+        series_repo.expect_find_by_id().returning(move |_, _| Ok(Some(found.clone())));
+
+        (series_repo, todo_repo)
+    }
+
+    fn edit(scope: EditScope, rrule: Option<&str>) -> UpdateOccurrenceRequest {
+        UpdateOccurrenceRequest {
+            scope,
+            title: "분리수거 (재활용)".to_string(),
+            description: None,
+            priority: Priority::High,
+            due_date: Some(utc("2025-07-29T00:00:00Z")),
+            checklist_auto_complete: false,
+            rrule: rrule.map(str::to_string),
+            timezone: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_edit_this_occurrence_keeps_series() {
+        let series = sample_series(Uuid::new_v4(), "FREQ=WEEKLY");
+        let todo = occurrence(&series, 3, "2025-07-28T00:00:00Z");
+        let (mut series_repo, mut todo_repo) = mocks_for(&series, &todo);
+        series_repo.expect_split().never();
+        todo_repo.expect_update().times(1).returning(|_, _, _| Ok(Some(occurrence(&sample_series(Uuid::new_v4(), "FREQ=WEEKLY"), 3, "2025-07-29T00:00:00Z"))));
+
+        let service = service(series_repo, todo_repo);
+        let response = service
+            .update_occurrence(todo.user_id, todo.id, edit(EditScope::This, None))
+            .await
+            .unwrap();
+        assert_eq!(response.recurrence.series_id, series.id);
+
+        let result = service
+            .update_occurrence(todo.user_id, todo.id, edit(EditScope::This, Some("FREQ=DAILY")))
+            .await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_edit_all_future_splits_with_remaining_count() {
+        let series = sample_series(Uuid::new_v4(), "FREQ=WEEKLY;COUNT=10");
+        let todo = occurrence(&series, 3, "2025-07-28T00:00:00Z");
+        let (mut series_repo, mut todo_repo) = mocks_for(&series, &todo);
+        todo_repo.expect_update().never();
+
+        let old_series_id = series.id;
+        series_repo
+            .expect_split()
+            .withf(move |old, from_index, new_series, _, changes| {
+                *old == old_series_id
+                    && *from_index == 3
+                    && new_series.rrule == "FREQ=WEEKLY;COUNT=8"
+                    && new_series.dtstart == utc("2025-07-29T00:00:00Z")
+                    && new_series.title == "분리수거 (재활용)"
+                    && changes.priority == Priority::High
+            })
+            .times(1)
+            .returning(|_, _, new_series, _, _| {
+                Ok(TodoSeries {
+                    rrule: new_series.rrule.clone(),
+                    dtstart: new_series.dtstart,
+                    ..sample_series(new_series.user_id, "FREQ=WEEKLY")
+                })
+            });
+
+        let response = service(series_repo, todo_repo)
+            .update_occurrence(todo.user_id, todo.id, edit(EditScope::AllFuture, None))
+            .await
+            .unwrap();
+
+        assert_eq!(response.scope, EditScope::AllFuture);
+        assert_eq!(response.recurrence.rrule, "FREQ=WEEKLY;COUNT=8");
+    }
+
+    #[tokio::test]
+    async fn test_set_recurrence_requires_due_date() {
+        let series = sample_series(Uuid::new_v4(), "FREQ=DAILY");
+        let mut todo = occurrence(&series, 1, "2025-07-14T00:00:00Z");
+        todo.series_id = None;
+        todo.recurrence_index = None;
+        todo.due_date = None;
+
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo.expect_find_by_id().returning(move |_, _| Ok(Some(todo.clone())));
+        let mut series_repo = MockTodoSeriesRepository::new();
+        series_repo.expect_start().never();
+
+        let service = service(series_repo, todo_repo);
+        let request = SetRecurrenceRequest { rrule: "FREQ=WEEKLY".to_string(), timezone: None };
+        let result = service.set_recurrence(Uuid::new_v4(), Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+
+        let request = SetRecurrenceRequest { rrule: "FREQ=YEARLY".to_string(), timezone: None };
+        let result = service.set_recurrence(Uuid::new_v4(), Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+}
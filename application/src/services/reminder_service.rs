@@ -0,0 +1,532 @@
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+use chrono::{DateTime, Duration, Utc};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::reminder_repository::ReminderRepository;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::services::workspace_service::authorize_todo_edit;
+use crate::dto::request::reminder_request::{CreateReminderRequest, SnoozeReminderRequest};
+use crate::dto::response::reminder_response::{ReminderListResponse, ReminderResponse};
+use crate::entities::reminder::{DueReminder, NewReminder, MAX_REMINDERS_PER_TODO};
+use crate::entities::todo::Todo;
+use crate::error::ApiError;
+use crate::notifications::{Notification, NotificationKind, Notifier};
+
+const DEFAULT_SNOOZE_MINUTES: u32 = 10;
+
+/// 알림 발송 워커 설정
+#[derive(Debug, Clone)]
+pub struct ReminderDispatchConfig {
+    /// 발송할 알림을 찾는 주기
+    pub poll_interval: StdDuration,
+    /// 한 번에 가져오는 최대 알림 수
+    pub batch_size: i64,
+    /// 가져간 알림의 임대 시간. 이 안에 발송 결과를 기록하지 못하면 다른 워커가 다시 가져간다.
+    pub lease: Duration,
+    /// 이 횟수만큼 실패하면 `failed`로 표시하고 재시도하지 않는다
+    pub max_attempts: i32,
+}
+
+impl Default for ReminderDispatchConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: StdDuration::from_secs(30),
+            batch_size: 100,
+            lease: Duration::minutes(5),
+            max_attempts: 5,
+        }
+    }
+}
+
+/// 한 번의 발송 주기 결과
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DispatchSummary {
+    pub delivered: usize,
+    pub failed: usize,
+}
+
+pub struct ReminderService {
+    reminder_repository: Arc<dyn ReminderRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    notifier: Arc<dyn Notifier>,
+    config: ReminderDispatchConfig,
+}
+
+impl ReminderService {
+    pub fn new(
+        reminder_repository: Arc<dyn ReminderRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        notifier: Arc<dyn Notifier>,
+        config: ReminderDispatchConfig,
+    ) -> Self {
+        Self {
+            reminder_repository,
+            todo_repository,
+            notifier,
+            config,
+        }
+    }
+
+    /// 할일의 알림 목록 (발송 예정 시각 순)
+    pub async fn list_reminders(&self, user_id: Uuid, todo_id: Uuid) -> Result<ReminderListResponse, ApiError> {
+        self.find_todo(user_id, todo_id).await?;
+        let reminders = self.reminder_repository.find_by_todo(todo_id).await?;
+
+        Ok(ReminderListResponse {
+            todo_id,
+            reminders: reminders.into_iter().map(ReminderResponse::from).collect(),
+        })
+    }
+
+    /// 알림 추가 (절대 시각 또는 마감 N분 전, owner, editor)
+    pub async fn add_reminder(&self, user_id: Uuid, todo_id: Uuid, request: CreateReminderRequest) -> Result<ReminderResponse, ApiError> {
+        request.validate()?;
+
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+        let todo = self.find_todo(user_id, todo_id).await?;
+        match (request.remind_at, request.offset_minutes) {
+            (Some(remind_at), None) => {
+                if remind_at <= Utc::now() {
+                    return Err(ApiError::Validation("알림 시각은 현재 이후여야 합니다".to_string()));
+                }
+            }
+            (None, Some(_)) => {
+                if todo.due_date.is_none() {
+                    return Err(ApiError::Validation(
+                        "마감일이 없는 할일에는 마감 기준 알림을 설정할 수 없습니다".to_string(),
+                    ));
+                }
+            }
+            _ => {
+                return Err(ApiError::Validation(
+                    "remind_at과 offset_minutes 중 하나만 지정해야 합니다".to_string(),
+                ));
+            }
+        }
+
+        let reminder = self
+            .reminder_repository
+            .create(NewReminder {
+                todo_id,
+                user_id,
+                remind_at: request.remind_at,
+                offset_minutes: request.offset_minutes,
+            })
+            .await?
+            .ok_or_else(|| {
+                ApiError::Validation(format!(
+                    "알림은 할일마다 최대 {}개까지 설정할 수 있습니다",
+                    MAX_REMINDERS_PER_TODO
+                ))
+            })?;
+
+        Ok(ReminderResponse::from(reminder))
+    }
+
+    /// 알림 삭제 (본인이 설정한 알림만)
+    pub async fn delete_reminder(&self, user_id: Uuid, todo_id: Uuid, reminder_id: Uuid) -> Result<(), ApiError> {
+        self.find_todo(user_id, todo_id).await?;
+        if !self.reminder_repository.delete(user_id, todo_id, reminder_id).await? {
+            return Err(Self::reminder_not_found());
+        }
+        Ok(())
+    }
+
+    /// 다시 알림: 지금부터 N분 뒤에 한 번 더 알린다 (본인이 설정한 알림만, 이미 발송된 알림도 가능)
+    pub async fn snooze_reminder(
+        &self,
+        user_id: Uuid,
+        todo_id: Uuid,
+        reminder_id: Uuid,
+        request: SnoozeReminderRequest,
+    ) -> Result<ReminderResponse, ApiError> {
+        request.validate()?;
+
+        self.find_todo(user_id, todo_id).await?;
+        let minutes = request.minutes.unwrap_or(DEFAULT_SNOOZE_MINUTES);
+        let until = Utc::now() + Duration::minutes(i64::from(minutes));
+
+        let reminder = self
+            .reminder_repository
+            .snooze(user_id, todo_id, reminder_id, until)
+            .await?
+            .ok_or_else(Self::reminder_not_found)?;
+
+        Ok(ReminderResponse::from(reminder))
+    }
+
+    /// 발송 시각이 지난 알림을 한 묶음 가져와 발송한다
+    pub async fn dispatch_due(&self, now: DateTime<Utc>) -> Result<DispatchSummary, ApiError> {
+        let lease_until = now + self.config.lease;
+        let due = self
+            .reminder_repository
+            .claim_due(now, lease_until, self.config.batch_size)
+            .await?;
+
+        let mut summary = DispatchSummary::default();
+        for reminder in due {
+            // 임대가 끝난 알림은 다른 워커가 이미 가져갔을 수 있으므로 남은 알림은 다음 주기로 넘긴다
+            if Utc::now() >= reminder.locked_until {
+                tracing::warn!(reminder_id = %reminder.id, "reminder lease expired before delivery; leaving the rest of the batch");
+                break;
+            }
+
+            match self.notifier.notify(&Self::notification(&reminder, now)).await {
+                Ok(()) => {
+                    if !self
+                        .reminder_repository
+                        .mark_sent(reminder.id, reminder.locked_until, Utc::now())
+                        .await?
+                    {
+                        tracing::warn!(reminder_id = %reminder.id, "reminder changed while being delivered; kept its new state");
+                    }
+                    summary.delivered += 1;
+                }
+                Err(err) => {
+                    tracing::warn!(reminder_id = %reminder.id, attempts = reminder.attempts, %err, "failed to deliver reminder");
+                    let retry_at = self.retry_at(&reminder, now);
+                    self.reminder_repository
+                        .mark_failed(reminder.id, reminder.locked_until, err.to_string(), retry_at)
+                        .await?;
+                    summary.failed += 1;
+                }
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// 알림 발송 워커를 시작한다. 여러 서버 인스턴스에서 동시에 실행해도 된다.
+    pub fn start_dispatcher(self: Arc<Self>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(self.config.poll_interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                ticker.tick().await;
+                match self.dispatch_due(Utc::now()).await {
+                    Ok(summary) if summary != DispatchSummary::default() => {
+                        tracing::info!(delivered = summary.delivered, failed = summary.failed, "dispatched reminders");
+                    }
+                    Ok(_) => {}
+                    Err(err) => tracing::error!(%err, "failed to dispatch reminders"),
+                }
+            }
+        })
+    }
+
+    /// 실패 횟수에 따라 1, 2, 4, ...분 뒤에 다시 시도한다. 한도를 넘으면 `None`.
+    fn retry_at(&self, reminder: &DueReminder, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        if reminder.attempts >= self.config.max_attempts {
+            return None;
+        }
+        let exponent = reminder.attempts.clamp(1, 10) - 1;
+        Some(now + Duration::minutes(1 << exponent))
+    }
+
+    fn notification(reminder: &DueReminder, now: DateTime<Utc>) -> Notification {
+        let body = match reminder.due_date {
+            Some(due_date) => format!("마감 시각: {}", due_date.to_rfc3339()),
+            None => "설정한 알림 시각이 되었습니다".to_string(),
+        };
+
+        Notification {
+            id: reminder.id,
+            kind: NotificationKind::Reminder,
+            user_id: reminder.user_id,
+            todo_id: Some(reminder.todo_id),
+            title: reminder.title.clone(),
+            body,
+            created_at: now,
+        }
+    }
+
+    async fn find_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Todo, ApiError> {
+        self.todo_repository
+            .find_by_id(user_id, todo_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("할일을 찾을 수 없습니다".to_string()))
+    }
+
+    fn reminder_not_found() -> ApiError {
+        ApiError::NotFound("알림을 찾을 수 없습니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::reminder_repository::tests::MockReminderRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::notifications::tests::MockNotifier;
+    use crate::notifications::NotifyError;
+    use crate::entities::reminder::{Reminder, ReminderStatus};
+    use crate::entities::workspace::WorkspaceRole;
+
+    fn sample_todo(user_id: Uuid, id: Uuid, due_date: Option<DateTime<Utc>>) -> Todo {
+        Todo { due_date, ..Todo::fixture(user_id, id) }
+    }
+
+    fn sample_reminder(reminder: &NewReminder, fire_at: Option<DateTime<Utc>>) -> Reminder {
+        let now = Utc::now();
+        Reminder {
+            id: Uuid::new_v4(),
+            todo_id: reminder.todo_id,
+            user_id: reminder.user_id,
+            remind_at: reminder.remind_at,
+            offset_minutes: reminder.offset_minutes,
+            snoozed_until: None,
+            status: ReminderStatus::Pending,
+            sent_at: None,
+            attempts: 0,
+            last_error: None,
+            fire_at,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn due_reminder(attempts: i32) -> DueReminder {
+        DueReminder {
+            id: Uuid::new_v4(),
+            todo_id: Uuid::new_v4(),
+            user_id: Uuid::new_v4(),
+            title: "보고서 제출".to_string(),
+            due_date: Some(Utc::now()),
+            fire_at: Utc::now(),
+            attempts,
+            locked_until: Utc::now() + Duration::minutes(5),
+        }
+    }
+
+    fn todo_repo_with_role(role: WorkspaceRole) -> MockTodoRepository {
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo.expect_find_role().returning(move |_, _| Ok(Some(role)));
+        todo_repo
+    }
+
+    fn service(reminder_repo: MockReminderRepository, todo_repo: MockTodoRepository, notifier: MockNotifier) -> ReminderService {
+        ReminderService::new(
+            Arc::new(reminder_repo),
+            Arc::new(todo_repo),
+            Arc::new(notifier),
+            ReminderDispatchConfig::default(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_add_offset_reminder() {
+        let (user_id, todo_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let due_date = Utc::now() + Duration::days(1);
+
+        let mut todo_repo = todo_repo_with_role(WorkspaceRole::Editor);
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(sample_todo(user_id, todo_id, Some(due_date)))));
+
+        let mut reminder_repo = MockReminderRepository::new();
+        reminder_repo
+            .expect_create()
+            .withf(move |reminder| reminder.todo_id == todo_id && reminder.offset_minutes == Some(30) && reminder.remind_at.is_none())
+            .returning(move |reminder| Ok(Some(sample_reminder(&reminder, Some(due_date - Duration::minutes(30))))));
+
+        let request = CreateReminderRequest { remind_at: None, offset_minutes: Some(30) };
+        let response = service(reminder_repo, todo_repo, MockNotifier::new())
+            .add_reminder(user_id, todo_id, request)
+            .await
+            .unwrap();
+
+        assert_eq!(response.fire_at, Some(due_date - Duration::minutes(30)));
+        assert_eq!(response.status, ReminderStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_add_reminder_rejects_full_todo() {
+        let (user_id, todo_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mut todo_repo = todo_repo_with_role(WorkspaceRole::Editor);
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(sample_todo(user_id, todo_id, None))));
+        let mut reminder_repo = MockReminderRepository::new();
+        reminder_repo.expect_create().times(1).returning(|_| Ok(None));
+
+        let request = CreateReminderRequest { remind_at: Some(Utc::now() + Duration::hours(1)), offset_minutes: None };
+        let result = service(reminder_repo, todo_repo, MockNotifier::new())
+            .add_reminder(user_id, todo_id, request)
+            .await;
+
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_add_reminder_requires_exactly_one_schedule() {
+        let (user_id, todo_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let due_date = Utc::now() + Duration::days(1);
+
+        let mut todo_repo = todo_repo_with_role(WorkspaceRole::Editor);
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(sample_todo(user_id, todo_id, Some(due_date)))));
+        let mut reminder_repo = MockReminderRepository::new();
+        reminder_repo.expect_create().never();
+
+        let service = service(reminder_repo, todo_repo, MockNotifier::new());
+        for request in [
+            CreateReminderRequest { remind_at: None, offset_minutes: None },
+            CreateReminderRequest { remind_at: Some(due_date), offset_minutes: Some(10) },
+            CreateReminderRequest { remind_at: Some(Utc::now() - Duration::minutes(1)), offset_minutes: None },
+        ] {
+            let result = service.add_reminder(user_id, todo_id, request).await;
+            assert!(matches!(result, Err(ApiError::Validation(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_offset_reminder_requires_due_date() {
+        let (user_id, todo_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let mut todo_repo = todo_repo_with_role(WorkspaceRole::Editor);
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(sample_todo(user_id, todo_id, None))));
+        let mut reminder_repo = MockReminderRepository::new();
+        reminder_repo.expect_create().never();
+
+        let request = CreateReminderRequest { remind_at: None, offset_minutes: Some(30) };
+        let result = service(reminder_repo, todo_repo, MockNotifier::new())
+            .add_reminder(user_id, todo_id, request)
+            .await;
+
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_viewer_cannot_add_reminder() {
+        let mut reminder_repo = MockReminderRepository::new();
+        reminder_repo.expect_create().never();
+
+        let request = CreateReminderRequest { remind_at: Some(Utc::now() + Duration::hours(1)), offset_minutes: None };
+        let result = service(reminder_repo, todo_repo_with_role(WorkspaceRole::Viewer), MockNotifier::new())
+            .add_reminder(Uuid::new_v4(), Uuid::new_v4(), request)
+            .await;
+
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_reminder_is_scoped_to_its_owner() {
+        let (user_id, todo_id, reminder_id) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(sample_todo(user_id, todo_id, None))));
+        let mut reminder_repo = MockReminderRepository::new();
+        reminder_repo
+            .expect_delete()
+            .withf(move |owner, todo, id| *owner == user_id && *todo == todo_id && *id == reminder_id)
+            .times(1)
+            .returning(|_, _, _| Ok(false));
+
+        let result = service(reminder_repo, todo_repo, MockNotifier::new())
+            .delete_reminder(user_id, todo_id, reminder_id)
+            .await;
+
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_marks_delivered_reminders_sent() {
+        let reminder = due_reminder(1);
+        let (reminder_id, claimed_until) = (reminder.id, reminder.locked_until);
+
+        let mut reminder_repo = MockReminderRepository::new();
+        reminder_repo
+            .expect_claim_due()
+            .times(1)
+            .returning(move |_, _, _| Ok(vec![reminder.clone()]));
+        reminder_repo
+            .expect_mark_sent()
+            .times(1)
+            .withf(move |id, locked_until, _| *id == reminder_id && *locked_until == claimed_until)
+            .returning(|_, _, _| Ok(true));
+        reminder_repo.expect_mark_failed().never();
+
+        let mut notifier = MockNotifier::new();
+        notifier
+            .expect_notify()
+            .times(1)
+            .withf(move |notification| notification.id == reminder_id && notification.kind == NotificationKind::Reminder)
+            .returning(|_| Ok(()));
+
+        let summary = service(reminder_repo, MockTodoRepository::new(), notifier)
+            .dispatch_due(Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(summary, DispatchSummary { delivered: 1, failed: 0 });
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_stops_once_lease_expires() {
+        let expired = DueReminder { locked_until: Utc::now() - Duration::seconds(1), ..due_reminder(1) };
+
+        let mut reminder_repo = MockReminderRepository::new();
+        reminder_repo
+            .expect_claim_due()
+            .returning(move |_, _, _| Ok(vec![expired.clone(), due_reminder(1)]));
+        reminder_repo.expect_mark_sent().never();
+        reminder_repo.expect_mark_failed().never();
+
+        let mut notifier = MockNotifier::new();
+        notifier.expect_notify().never();
+
+        let summary = service(reminder_repo, MockTodoRepository::new(), notifier)
+            .dispatch_due(Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(summary, DispatchSummary::default());
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_failure_backs_off_then_gives_up() {
+        let now = Utc::now();
+        let retrying = due_reminder(2);
+        let exhausted = due_reminder(ReminderDispatchConfig::default().max_attempts);
+        let (retrying_id, exhausted_id) = (retrying.id, exhausted.id);
+
+        let mut reminder_repo = MockReminderRepository::new();
+        reminder_repo
+            .expect_claim_due()
+            .returning(move |_, _, _| Ok(vec![retrying.clone(), exhausted.clone()]));
+        reminder_repo
+            .expect_mark_failed()
+            .times(1)
+            .withf(move |id, _, _, retry_at| *id == retrying_id && *retry_at == Some(now + Duration::minutes(2)))
+            .returning(|_, _, _, _| Ok(true));
+        reminder_repo
+            .expect_mark_failed()
+            .times(1)
+            .withf(move |id, _, _, retry_at| *id == exhausted_id && retry_at.is_none())
+            .returning(|_, _, _, _| Ok(true));
+        reminder_repo.expect_mark_sent().never();
+
+        let mut notifier = MockNotifier::new();
+        notifier
+            .expect_notify()
+            .times(2)
+            .returning(|_| Err(NotifyError::Delivery("connection refused".to_string())));
+
+        let summary = service(reminder_repo, MockTodoRepository::new(), notifier)
+            .dispatch_due(now)
+            .await
+            .unwrap();
+
+        assert_eq!(summary, DispatchSummary { delivered: 0, failed: 2 });
+    }
+}
@@ -0,0 +1,480 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use bcrypt::{hash, verify, DEFAULT_COST};
+use chrono::Utc;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::share_link_repository::ShareLinkRepository;
+use crate::repositories::todo_query::{TodoFilter, TodoQuery, TodoSort};
+use crate::repositories::todo_repository::TodoRepository;
+use crate::repositories::workspace_repository::WorkspaceRepository;
+use crate::services::workspace_service::{authorize_todo_edit, authorize_workspace_edit};
+use crate::dto::request::share_link_request::CreateShareLinkRequest;
+use crate::dto::response::share_link_response::{
+    ShareLinkCreatedResponse, ShareLinkListResponse, ShareLinkResponse, SharedContentResponse, SharedTodoResponse,
+};
+use crate::entities::share_link::{
+    NewShareLink, ShareFilter, ShareLink, MAX_ACTIVE_SHARE_LINKS_PER_WORKSPACE, MAX_SHARED_TODOS,
+};
+use crate::entities::todo::Todo;
+use crate::error::ApiError;
+use crate::utils::collections::unique;
+use crate::utils::token::{generate_token, hash_token};
+
+const MIN_SHARE_PASSWORD_BYTES: usize = 4;
+/// bcrypt는 앞의 72바이트만 사용한다
+const MAX_SHARE_PASSWORD_BYTES: usize = 72;
+
+/// 계정 없이 볼 수 있는 읽기 전용 공유 링크
+///
+/// 링크는 만든 멤버의 권한으로 조회한다. 만든 멤버가 워크스페이스를 떠나거나 viewer가 되면
+/// 링크도 더 이상 열리지 않는다.
+pub struct ShareLinkService {
+    share_link_repository: Arc<dyn ShareLinkRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    /// 공유 페이지 주소. 뒤에 `/{token}`을 붙인다.
+    base_url: String,
+}
+
+impl ShareLinkService {
+    pub fn new(
+        share_link_repository: Arc<dyn ShareLinkRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        base_url: String,
+    ) -> Self {
+        Self {
+            share_link_repository,
+            todo_repository,
+            workspace_repository,
+            base_url,
+        }
+    }
+
+    /// 공유 링크 생성 (owner, editor). 토큰은 응답으로 한 번만 돌려준다.
+    pub async fn create(&self, user_id: Uuid, request: CreateShareLinkRequest) -> Result<ShareLinkCreatedResponse, ApiError> {
+        request.validate()?;
+        if let Some(password) = request.password.as_deref() {
+            Self::validate_password(password)?;
+        }
+        let now = Utc::now();
+        if request.expires_at.is_some_and(|expires_at| expires_at <= now) {
+            return Err(ApiError::Validation("만료 시각은 현재 이후여야 합니다".to_string()));
+        }
+
+        let (workspace_id, filter) = match (request.todo_id, request.workspace_id) {
+            (Some(todo_id), None) => {
+                let has_filter = !request.statuses.is_empty()
+                    || !request.priorities.is_empty()
+                    || !request.category_ids.is_empty()
+                    || request.search.is_some()
+                    || request.sort.is_some();
+                if has_filter {
+                    return Err(ApiError::Validation("할일 공유에는 목록 조건을 지정할 수 없습니다".to_string()));
+                }
+                authorize_todo_edit(self.todo_repository.as_ref(), user_id, todo_id).await?;
+                let todo = self
+                    .todo_repository
+                    .find_by_id(user_id, todo_id)
+                    .await?
+                    .ok_or_else(|| ApiError::NotFound("할일을 찾을 수 없습니다".to_string()))?;
+                (todo.workspace_id, ShareFilter::default())
+            }
+            (None, Some(workspace_id)) => {
+                authorize_workspace_edit(self.workspace_repository.as_ref(), user_id, workspace_id).await?;
+                if let Some(sort) = request.sort.as_deref() {
+                    TodoSort::from_str(sort).map_err(ApiError::Validation)?;
+                }
+                let filter = ShareFilter {
+                    statuses: unique(request.statuses),
+                    priorities: unique(request.priorities),
+                    category_ids: unique(request.category_ids),
+                    search: request.search,
+                    sort: request.sort,
+                };
+                (workspace_id, filter)
+            }
+            _ => {
+                return Err(ApiError::Validation(
+                    "todo_id와 workspace_id 중 하나만 지정해 주세요".to_string(),
+                ));
+            }
+        };
+
+        if self.share_link_repository.count_active(workspace_id, now).await? >= MAX_ACTIVE_SHARE_LINKS_PER_WORKSPACE {
+            return Err(ApiError::Validation(format!(
+                "유효한 공유 링크는 워크스페이스마다 최대 {}개까지 만들 수 있습니다",
+                MAX_ACTIVE_SHARE_LINKS_PER_WORKSPACE
+            )));
+        }
+
+        let password_hash = match request.password.as_deref() {
+            Some(password) => Some(hash(password, DEFAULT_COST)?),
+            None => None,
+        };
+        let token = generate_token();
+        let link = self
+            .share_link_repository
+            .create(NewShareLink {
+                workspace_id,
+                created_by: user_id,
+                todo_id: request.todo_id,
+                title: request.title,
+                filter,
+                token_hash: hash_token(&token),
+                password_hash,
+                expires_at: request.expires_at,
+            })
+            .await?;
+
+        Ok(ShareLinkCreatedResponse {
+            link: ShareLinkResponse::new(link, now),
+            url: format!("{}/{}", self.base_url.trim_end_matches('/'), token),
+            token,
+        })
+    }
+
+    /// 워크스페이스의 공유 링크 목록 (owner, editor)
+    pub async fn list(&self, user_id: Uuid, workspace_id: Uuid) -> Result<ShareLinkListResponse, ApiError> {
+        authorize_workspace_edit(self.workspace_repository.as_ref(), user_id, workspace_id).await?;
+        let links = self.share_link_repository.find_by_workspace(workspace_id).await?;
+        let now = Utc::now();
+
+        Ok(ShareLinkListResponse {
+            links: links.into_iter().map(|link| ShareLinkResponse::new(link, now)).collect(),
+        })
+    }
+
+    /// 공유 링크 회수 (owner, editor). 회수한 링크는 다시 열 수 없다.
+    pub async fn revoke(&self, user_id: Uuid, id: Uuid) -> Result<(), ApiError> {
+        let link = self
+            .share_link_repository
+            .find_by_id(id)
+            .await?
+            .ok_or_else(Self::link_not_found)?;
+        authorize_workspace_edit(self.workspace_repository.as_ref(), user_id, link.workspace_id)
+            .await
+            .map_err(|err| match err {
+                ApiError::NotFound(_) => Self::link_not_found(),
+                err => err,
+            })?;
+
+        if !self.share_link_repository.revoke(id, Utc::now()).await? {
+            return Err(ApiError::Conflict("이미 회수된 공유 링크입니다".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 토큰으로 공유된 할일 조회 (인증 없음)
+    ///
+    /// 없는 토큰, 회수되거나 만료된 링크는 모두 같은 404로 응답해 어떤 토큰이 있었는지 드러내지 않는다.
+    pub async fn view(&self, token: &str, password: Option<&str>) -> Result<SharedContentResponse, ApiError> {
+        let now = Utc::now();
+        let link = self
+            .share_link_repository
+            .find_by_token(&hash_token(token))
+            .await?
+            .filter(|link| link.is_active(now))
+            .ok_or_else(Self::link_not_found)?;
+
+        if let Some(password_hash) = link.password_hash.as_deref() {
+            let Some(password) = password else {
+                return Err(ApiError::Unauthorized("비밀번호가 필요한 공유 링크입니다".to_string()));
+            };
+            if !verify(password, password_hash)? {
+                return Err(ApiError::Unauthorized("비밀번호가 올바르지 않습니다".to_string()));
+            }
+        }
+
+        let creator_can_edit = self
+            .workspace_repository
+            .find_role(link.workspace_id, link.created_by)
+            .await?
+            .is_some_and(|role| role.can_edit());
+        if !creator_can_edit {
+            return Err(Self::link_not_found());
+        }
+
+        let (todos, truncated) = self.shared_todos(&link).await?;
+        if let Err(err) = self.share_link_repository.record_view(link.id, now).await {
+            tracing::warn!(%err, share_link_id = %link.id, "failed to record share link view");
+        }
+
+        Ok(SharedContentResponse {
+            kind: link.kind(),
+            title: link.title,
+            todos: todos.into_iter().map(SharedTodoResponse::from).collect(),
+            truncated,
+            expires_at: link.expires_at,
+        })
+    }
+
+    /// 링크를 만든 멤버의 권한으로 조회한다. 휴지통의 할일은 빠진다.
+    async fn shared_todos(&self, link: &ShareLink) -> Result<(Vec<Todo>, bool), ApiError> {
+        if let Some(todo_id) = link.todo_id {
+            let todo = self
+                .todo_repository
+                .find_by_id(link.created_by, todo_id)
+                .await?
+                .ok_or_else(Self::link_not_found)?;
+            return Ok((vec![todo], false));
+        }
+
+        let sort = match link.filter.sort.as_deref() {
+            Some(sort) => TodoSort::from_str(sort).unwrap_or_default(),
+            None => TodoSort::default(),
+        };
+        let query = TodoQuery {
+            filter: TodoFilter {
+                statuses: link.filter.statuses.clone(),
+                priorities: link.filter.priorities.clone(),
+                category_ids: link.filter.category_ids.clone(),
+                search: link.filter.search.clone(),
+                workspace_id: Some(link.workspace_id),
+                ..TodoFilter::default()
+            },
+            sort,
+            // 잘렸는지 알기 위해 한 건 더 조회한다
+            limit: MAX_SHARED_TODOS as i64 + 1,
+            offset: 0,
+            keyset: None,
+        };
+
+        let mut todos = self.todo_repository.find_all(link.created_by, &query).await?;
+        let truncated = todos.len() > MAX_SHARED_TODOS;
+        todos.truncate(MAX_SHARED_TODOS);
+        Ok((todos, truncated))
+    }
+
+    /// 비밀번호는 `X-Share-Password` 헤더로 받으므로 헤더에 그대로 담을 수 있는 문자만 허용한다.
+    /// 앞뒤 공백은 헤더에서 잘려 나가므로 공백도 받지 않는다.
+    fn validate_password(password: &str) -> Result<(), ApiError> {
+        if !(MIN_SHARE_PASSWORD_BYTES..=MAX_SHARE_PASSWORD_BYTES).contains(&password.len()) {
+            return Err(ApiError::Validation(format!(
+                "비밀번호는 {}-{}바이트 사이여야 합니다",
+                MIN_SHARE_PASSWORD_BYTES, MAX_SHARE_PASSWORD_BYTES
+            )));
+        }
+        if !password.bytes().all(|byte| byte.is_ascii_graphic()) {
+            return Err(ApiError::Validation(
+                "비밀번호에는 공백을 제외한 영문, 숫자, 기호만 쓸 수 있습니다".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    fn link_not_found() -> ApiError {
+        ApiError::NotFound("공유 링크를 찾을 수 없거나 만료되었습니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, Duration};
+    use crate::entities::todo::{Priority, TodoStatus};
+    use crate::entities::workspace::WorkspaceRole;
+    use crate::repositories::share_link_repository::tests::MockShareLinkRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+
+    fn sample_todo(user_id: Uuid, id: Uuid) -> Todo {
+        Todo {
+            title: "발표 자료".to_string(),
+            description: Some("초안 공유".to_string()),
+            status: TodoStatus::InProgress,
+            priority: Priority::High,
+            started_at: Some(Utc::now()),
+            checklist_total: 3,
+            checklist_done: 1,
+            ..Todo::fixture(user_id, id)
+        }
+    }
+
+    fn sample_link(todo_id: Option<Uuid>, password_hash: Option<String>, expires_at: Option<DateTime<Utc>>) -> ShareLink {
+        ShareLink {
+            id: Uuid::new_v4(),
+            workspace_id: Uuid::new_v4(),
+            created_by: Uuid::new_v4(),
+            todo_id,
+            title: None,
+            filter: ShareFilter::default(),
+            password_hash,
+            expires_at,
+            revoked_at: None,
+            view_count: 0,
+            last_viewed_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    fn creator_is(role: WorkspaceRole) -> MockWorkspaceRepository {
+        let mut workspace_repo = MockWorkspaceRepository::new();
+        workspace_repo.expect_find_role().returning(move |_, _| Ok(Some(role)));
+        workspace_repo
+    }
+
+    fn service(
+        share_link_repo: MockShareLinkRepository,
+        todo_repo: MockTodoRepository,
+        workspace_repo: MockWorkspaceRepository,
+    ) -> ShareLinkService {
+        ShareLinkService::new(
+            Arc::new(share_link_repo),
+            Arc::new(todo_repo),
+            Arc::new(workspace_repo),
+            "https://todo.example.com/shared/".to_string(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_stores_only_token_hash() {
+        let workspace_id = Uuid::new_v4();
+
+        let mut share_link_repo = MockShareLinkRepository::new();
+        share_link_repo.expect_count_active().returning(|_, _| Ok(0));
+        share_link_repo
+            .expect_create()
+            .withf(move |link| {
+                link.workspace_id == workspace_id
+                    && link.token_hash.len() == 64
+                    && link.password_hash.as_deref().is_some_and(|hash| hash != "secret-pw")
+                    && link.filter.statuses == vec![TodoStatus::Pending]
+            })
+            .times(1)
+            .returning(|link| {
+                Ok(ShareLink {
+                    workspace_id: link.workspace_id,
+                    password_hash: link.password_hash,
+                    filter: link.filter,
+                    ..sample_link(None, None, None)
+                })
+            });
+
+        let service = service(share_link_repo, MockTodoRepository::new(), creator_is(WorkspaceRole::Editor));
+        let request = CreateShareLinkRequest {
+            workspace_id: Some(workspace_id),
+            statuses: vec![TodoStatus::Pending, TodoStatus::Pending],
+            password: Some("secret-pw".to_string()),
+            ..Default::default()
+        };
+
+        let response = service.create(Uuid::new_v4(), request).await.unwrap();
+        assert_eq!(response.url, format!("https://todo.example.com/shared/{}", response.token));
+        assert!(response.link.has_password);
+    }
+
+    #[tokio::test]
+    async fn test_create_requires_exactly_one_target() {
+        let mut share_link_repo = MockShareLinkRepository::new();
+        share_link_repo.expect_create().never();
+
+        let service = service(share_link_repo, MockTodoRepository::new(), MockWorkspaceRepository::new());
+        let request = CreateShareLinkRequest {
+            todo_id: Some(Uuid::new_v4()),
+            workspace_id: Some(Uuid::new_v4()),
+            ..Default::default()
+        };
+
+        let result = service.create(Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_rejects_passwords_that_cannot_be_sent_in_a_header() {
+        let mut share_link_repo = MockShareLinkRepository::new();
+        share_link_repo.expect_create().never();
+
+        let service = service(share_link_repo, MockTodoRepository::new(), MockWorkspaceRepository::new());
+        for password in ["비밀번호1234", "open sesame", &"x".repeat(73)] {
+            let request = CreateShareLinkRequest {
+                workspace_id: Some(Uuid::new_v4()),
+                password: Some(password.to_string()),
+                ..Default::default()
+            };
+
+            let result = service.create(Uuid::new_v4(), request).await;
+            assert!(matches!(result, Err(ApiError::Validation(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_viewer_cannot_create_link() {
+        let mut share_link_repo = MockShareLinkRepository::new();
+        share_link_repo.expect_create().never();
+
+        let service = service(share_link_repo, MockTodoRepository::new(), creator_is(WorkspaceRole::Viewer));
+        let request = CreateShareLinkRequest {
+            workspace_id: Some(Uuid::new_v4()),
+            ..Default::default()
+        };
+
+        let result = service.create(Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_view_hides_expired_and_revoked_links() {
+        let expired = sample_link(Some(Uuid::new_v4()), None, Some(Utc::now() - Duration::minutes(1)));
+        let mut revoked = sample_link(Some(Uuid::new_v4()), None, None);
+        revoked.revoked_at = Some(Utc::now());
+
+        for link in [expired, revoked] {
+            let mut share_link_repo = MockShareLinkRepository::new();
+            share_link_repo
+                .expect_find_by_token()
+                .returning(move |_| Ok(Some(link.clone())));
+            share_link_repo.expect_record_view().never();
+
+            let service = service(share_link_repo, MockTodoRepository::new(), MockWorkspaceRepository::new());
+            let result = service.view(&generate_token(), None).await;
+            assert!(matches!(result, Err(ApiError::NotFound(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_view_checks_password() {
+        let password_hash = hash("secret-pw", 4).unwrap();
+        let link = sample_link(Some(Uuid::new_v4()), Some(password_hash), None);
+
+        let mut share_link_repo = MockShareLinkRepository::new();
+        share_link_repo
+            .expect_find_by_token()
+            .returning(move |_| Ok(Some(link.clone())));
+        share_link_repo.expect_record_view().times(1).returning(|_, _| Ok(()));
+
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo
+            .expect_find_by_id()
+            .returning(|user_id, id| Ok(Some(sample_todo(user_id, id))));
+
+        let service = service(share_link_repo, todo_repo, creator_is(WorkspaceRole::Owner));
+        let token = generate_token();
+
+        assert!(matches!(service.view(&token, None).await, Err(ApiError::Unauthorized(_))));
+        assert!(matches!(service.view(&token, Some("wrong")).await, Err(ApiError::Unauthorized(_))));
+
+        let shared = service.view(&token, Some("secret-pw")).await.unwrap();
+        assert_eq!(shared.todos.len(), 1);
+        assert_eq!(shared.todos[0].title, "발표 자료");
+        assert_eq!(shared.todos[0].checklist.done, 1);
+    }
+
+    #[tokio::test]
+    async fn test_view_stops_when_creator_loses_edit_access() {
+        let link = sample_link(Some(Uuid::new_v4()), None, None);
+
+        let mut share_link_repo = MockShareLinkRepository::new();
+        share_link_repo
+            .expect_find_by_token()
+            .returning(move |_| Ok(Some(link.clone())));
+
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo.expect_find_by_id().never();
+
+        let service = service(share_link_repo, todo_repo, creator_is(WorkspaceRole::Viewer));
+        let result = service.view(&generate_token(), None).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+}
@@ -0,0 +1,431 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc, Weekday};
+use chrono_tz::Tz;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::category_repository::CategoryRepository;
+use crate::repositories::smart_list_repository::SmartListRepository;
+use crate::repositories::todo_query::TodoSort;
+use crate::services::todo_service::TodoService;
+use crate::services::user_settings_service::UserSettingsService;
+use crate::dto::request::smart_list_request::{SmartListRequest, SmartListTodosQuery};
+use crate::dto::request::todo_request::TodoListQuery;
+use crate::dto::response::smart_list_response::{SmartListListResponse, SmartListResponse};
+use crate::dto::response::todo_response::TodoListResponse;
+use crate::entities::smart_list::{
+    BuiltinSmartList, DueWindow, NewSmartList, SmartListChanges, SmartListFilter, MAX_SMART_LISTS_PER_USER,
+};
+use crate::error::ApiError;
+use crate::utils::collections::unique;
+use crate::utils::timezone::{local_day_start, TimeWindows};
+
+/// 경로의 스마트 목록 ID
+enum SmartListId {
+    Builtin(BuiltinSmartList),
+    Saved(Uuid),
+}
+
+/// 저장된 조건 모음. 조회할 때마다 `GET /todos`와 같은 조건으로 바꿔 평가한다.
+pub struct SmartListService {
+    smart_list_repository: Arc<dyn SmartListRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    todo_service: Arc<TodoService>,
+    settings_service: Arc<UserSettingsService>,
+}
+
+impl SmartListService {
+    pub fn new(
+        smart_list_repository: Arc<dyn SmartListRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        todo_service: Arc<TodoService>,
+        settings_service: Arc<UserSettingsService>,
+    ) -> Self {
+        Self {
+            smart_list_repository,
+            category_repository,
+            todo_service,
+            settings_service,
+        }
+    }
+
+    /// 기본 목록과 저장된 목록 조회
+    pub async fn list_smart_lists(&self, user_id: Uuid) -> Result<SmartListListResponse, ApiError> {
+        let saved = self.smart_list_repository.find_all(user_id).await?;
+
+        let smart_lists = BuiltinSmartList::ALL
+            .into_iter()
+            .map(SmartListResponse::from)
+            .chain(saved.into_iter().map(SmartListResponse::from))
+            .collect();
+
+        Ok(SmartListListResponse { smart_lists })
+    }
+
+    /// 스마트 목록 상세 조회
+    pub async fn get_smart_list(&self, user_id: Uuid, id: &str) -> Result<SmartListResponse, ApiError> {
+        match Self::parse_id(id)? {
+            SmartListId::Builtin(builtin) => Ok(SmartListResponse::from(builtin)),
+            SmartListId::Saved(id) => {
+                let smart_list = self
+                    .smart_list_repository
+                    .find_by_id(user_id, id)
+                    .await?
+                    .ok_or_else(Self::not_found)?;
+                Ok(SmartListResponse::from(smart_list))
+            }
+        }
+    }
+
+    /// 스마트 목록 생성
+    pub async fn create_smart_list(&self, user_id: Uuid, request: SmartListRequest) -> Result<SmartListResponse, ApiError> {
+        let (name, filter) = self.prepare(user_id, request).await?;
+
+        if self.smart_list_repository.find_by_name(user_id, &name).await?.is_some() {
+            return Err(Self::duplicate_name());
+        }
+        if self.smart_list_repository.count(user_id).await? >= MAX_SMART_LISTS_PER_USER {
+            return Err(ApiError::Validation(format!(
+                "스마트 목록은 최대 {}개까지 만들 수 있습니다",
+                MAX_SMART_LISTS_PER_USER
+            )));
+        }
+
+        let smart_list = self
+            .smart_list_repository
+            .create(NewSmartList { user_id, name, filter })
+            .await?;
+
+        Ok(SmartListResponse::from(smart_list))
+    }
+
+    /// 스마트 목록 수정 (모든 조건을 교체한다)
+    pub async fn update_smart_list(&self, user_id: Uuid, id: &str, request: SmartListRequest) -> Result<SmartListResponse, ApiError> {
+        let id = Self::saved_id(id)?;
+        let (name, filter) = self.prepare(user_id, request).await?;
+
+        if let Some(existing) = self.smart_list_repository.find_by_name(user_id, &name).await? {
+            if existing.id != id {
+                return Err(Self::duplicate_name());
+            }
+        }
+
+        let smart_list = self
+            .smart_list_repository
+            .update(user_id, id, SmartListChanges { name, filter })
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(SmartListResponse::from(smart_list))
+    }
+
+    /// 스마트 목록 삭제 (할일에는 영향이 없다)
+    pub async fn delete_smart_list(&self, user_id: Uuid, id: &str) -> Result<(), ApiError> {
+        let id = Self::saved_id(id)?;
+        if !self.smart_list_repository.delete(user_id, id).await? {
+            return Err(Self::not_found());
+        }
+        Ok(())
+    }
+
+    /// 스마트 목록의 조건으로 할일 조회
+    pub async fn list_todos(&self, user_id: Uuid, id: &str, query: SmartListTodosQuery) -> Result<TodoListResponse, ApiError> {
+        query.validate()?;
+
+        let filter = match Self::parse_id(id)? {
+            SmartListId::Builtin(builtin) => builtin.filter(),
+            SmartListId::Saved(id) => {
+                self.smart_list_repository
+                    .find_by_id(user_id, id)
+                    .await?
+                    .ok_or_else(Self::not_found)?
+                    .filter
+            }
+        };
+
+        let preferences = self
+            .settings_service
+            .time_preferences(user_id, query.timezone.as_deref(), None)
+            .await?;
+        let list_query = Self::todo_query(
+            filter,
+            query,
+            Utc::now(),
+            preferences.timezone,
+            preferences.week_start.as_weekday(),
+        );
+
+        self.todo_service.list_todos(user_id, list_query).await
+    }
+
+    /// 저장 전 검증. 이름 앞뒤 공백을 제거하고 중복 조건을 정리한다.
+    async fn prepare(&self, user_id: Uuid, request: SmartListRequest) -> Result<(String, SmartListFilter), ApiError> {
+        request.validate()?;
+
+        let name = request.name.trim().to_string();
+        if name.is_empty() {
+            return Err(ApiError::Validation("스마트 목록 이름을 입력해주세요".to_string()));
+        }
+        if let Some(sort) = request.sort.as_deref() {
+            TodoSort::from_str(sort).map_err(ApiError::Validation)?;
+        }
+
+        let mut category_ids = request.category_ids;
+        category_ids.sort();
+        category_ids.dedup();
+
+        if !category_ids.is_empty() {
+            let owned = self.category_repository.find_by_ids(user_id, &category_ids).await?;
+            if owned.len() != category_ids.len() {
+                return Err(ApiError::NotFound("카테고리를 찾을 수 없습니다".to_string()));
+            }
+        }
+
+        let filter = SmartListFilter {
+            statuses: unique(request.statuses),
+            priorities: unique(request.priorities),
+            due_window: request.due_window,
+            category_ids,
+            search: request.search.map(|search| search.trim().to_string()).filter(|search| !search.is_empty()),
+            sort: request.sort,
+        };
+
+        Ok((name, filter))
+    }
+
+    /// 스마트 목록 조건을 `GET /todos` 조회 조건으로 변환
+    ///
+    /// 마감 구간은 `now`와 사용자 시간대 기준의 `[due_after, due_before)`로 바꾼다.
+    fn todo_query(filter: SmartListFilter, query: SmartListTodosQuery, now: DateTime<Utc>, tz: Tz, week_start: Weekday) -> TodoListQuery {
+        let join = |values: Vec<String>| (!values.is_empty()).then(|| values.join(","));
+
+        let mut list_query = TodoListQuery {
+            status: join(filter.statuses.iter().map(|status| status.as_str().to_string()).collect()),
+            priority: join(filter.priorities.iter().map(|priority| priority.as_str().to_string()).collect()),
+            category_ids: join(filter.category_ids.iter().map(Uuid::to_string).collect()),
+            search: filter.search,
+            sort: query.sort.or(filter.sort),
+            page: query.page,
+            limit: query.limit,
+            after: query.after,
+            before: query.before,
+            ..TodoListQuery::default()
+        };
+
+        let windows = TimeWindows::at(now, tz, week_start);
+        let days_from_today = |days: i64| local_day_start(tz, now.with_timezone(&tz).date_naive() + Duration::days(days));
+        let range = match filter.due_window {
+            None => None,
+            Some(DueWindow::Overdue) => {
+                list_query.overdue = Some(true);
+                None
+            }
+            Some(DueWindow::NoDueDate) => {
+                list_query.has_due_date = Some(false);
+                None
+            }
+            Some(DueWindow::Today) => Some((windows.today_start, windows.tomorrow_start)),
+            Some(DueWindow::Tomorrow) => Some((windows.tomorrow_start, days_from_today(2))),
+            Some(DueWindow::ThisWeek) => Some((windows.week_start, windows.next_week_start)),
+            Some(DueWindow::Next7Days) => Some((windows.tomorrow_start, days_from_today(8))),
+        };
+        if let Some((start, end)) = range {
+            list_query.due_after = Some(start);
+            list_query.due_before = Some(end);
+        }
+
+        list_query
+    }
+
+    fn parse_id(id: &str) -> Result<SmartListId, ApiError> {
+        if let Some(builtin) = BuiltinSmartList::from_id(id) {
+            return Ok(SmartListId::Builtin(builtin));
+        }
+        Uuid::parse_str(id).map(SmartListId::Saved).map_err(|_| Self::not_found())
+    }
+
+    fn saved_id(id: &str) -> Result<Uuid, ApiError> {
+        match Self::parse_id(id)? {
+            SmartListId::Builtin(_) => Err(ApiError::BadRequest(
+                "기본 스마트 목록은 수정하거나 삭제할 수 없습니다".to_string(),
+            )),
+            SmartListId::Saved(id) => Ok(id),
+        }
+    }
+
+    fn duplicate_name() -> ApiError {
+        ApiError::Conflict("이미 존재하는 스마트 목록 이름입니다".to_string())
+    }
+
+    fn not_found() -> ApiError {
+        ApiError::NotFound("스마트 목록을 찾을 수 없습니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::smart_list::SmartList;
+    use crate::entities::todo::{Priority, TodoStatus};
+    use crate::repositories::category_repository::tests::MockCategoryRepository;
+    use crate::repositories::smart_list_repository::tests::MockSmartListRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+    use crate::repositories::user_settings_repository::tests::MockUserSettingsRepository;
+    use crate::events::EventBus;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn service(smart_list_repo: MockSmartListRepository) -> SmartListService {
+        SmartListService::new(
+            Arc::new(smart_list_repo),
+            Arc::new(MockCategoryRepository::new()),
+            Arc::new(TodoService::new(Arc::new(MockTodoRepository::new()), Arc::new(MockWorkspaceRepository::new()), EventBus::default())),
+            Arc::new(UserSettingsService::new(Arc::new(MockUserSettingsRepository::new()))),
+        )
+    }
+
+    fn request(name: &str) -> SmartListRequest {
+        SmartListRequest {
+            name: name.to_string(),
+            statuses: vec![TodoStatus::Pending, TodoStatus::Pending],
+            priorities: vec![Priority::High],
+            due_window: Some(DueWindow::ThisWeek),
+            category_ids: Vec::new(),
+            search: Some("  ".to_string()),
+            sort: Some("-priority,due_date".to_string()),
+        }
+    }
+
+    fn sample_smart_list(user_id: Uuid, name: &str) -> SmartList {
+        let now = Utc::now();
+        SmartList {
+            id: Uuid::new_v4(),
+            user_id,
+            name: name.to_string(),
+            filter: SmartListFilter::default(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_smart_list_normalizes_filter() {
+        let mut smart_list_repo = MockSmartListRepository::new();
+        smart_list_repo.expect_find_by_name().returning(|_, _| Ok(None));
+        smart_list_repo.expect_count().returning(|_| Ok(0));
+        smart_list_repo
+            .expect_create()
+            .withf(|new| {
+                new.name == "중요"
+                    && new.filter.statuses == vec![TodoStatus::Pending]
+                    && new.filter.search.is_none()
+            })
+            .returning(|new| {
+                Ok(SmartList {
+                    filter: new.filter,
+                    ..sample_smart_list(new.user_id, &new.name)
+                })
+            });
+
+        let response = service(smart_list_repo)
+            .create_smart_list(Uuid::new_v4(), request(" 중요 "))
+            .await
+            .unwrap();
+
+        assert!(!response.builtin);
+        assert_eq!(response.due_window, Some(DueWindow::ThisWeek));
+    }
+
+    #[tokio::test]
+    async fn test_create_smart_list_duplicate_name() {
+        let mut smart_list_repo = MockSmartListRepository::new();
+        smart_list_repo
+            .expect_find_by_name()
+            .returning(|user_id, name| Ok(Some(sample_smart_list(user_id, name))));
+        smart_list_repo.expect_create().never();
+
+        let result = service(smart_list_repo).create_smart_list(Uuid::new_v4(), request("중요")).await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_smart_list_invalid_sort() {
+        let mut invalid = request("중요");
+        invalid.sort = Some("colour".to_string());
+
+        let result = service(MockSmartListRepository::new()).create_smart_list(Uuid::new_v4(), invalid).await;
+
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_builtin_smart_list_cannot_be_modified() {
+        let service = service(MockSmartListRepository::new());
+        let user_id = Uuid::new_v4();
+
+        let updated = service.update_smart_list(user_id, "today", request("오늘")).await;
+        let deleted = service.delete_smart_list(user_id, "overdue").await;
+        let unknown = service.get_smart_list(user_id, "someday").await;
+
+        assert!(matches!(updated, Err(ApiError::BadRequest(_))));
+        assert!(matches!(deleted, Err(ApiError::BadRequest(_))));
+        assert!(matches!(unknown, Err(ApiError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_todo_query_uses_local_due_windows() {
+        // 서울 기준 2025-07-23(수) 10:00
+        let now = utc("2025-07-23T01:00:00Z");
+        let tz = chrono_tz::Asia::Seoul;
+        let filter = SmartListFilter {
+            statuses: vec![TodoStatus::Pending, TodoStatus::InProgress],
+            category_ids: vec![Uuid::nil()],
+            due_window: Some(DueWindow::Tomorrow),
+            sort: Some("due_date".to_string()),
+            ..SmartListFilter::default()
+        };
+
+        let query = SmartListService::todo_query(filter, SmartListTodosQuery::default(), now, tz, Weekday::Mon);
+
+        assert_eq!(query.status.as_deref(), Some("pending,in_progress"));
+        assert_eq!(query.priority, None);
+        assert_eq!(query.category_ids, Some(Uuid::nil().to_string()));
+        assert_eq!(query.due_after, Some(utc("2025-07-23T15:00:00Z")));
+        assert_eq!(query.due_before, Some(utc("2025-07-24T15:00:00Z")));
+        assert_eq!(query.sort.as_deref(), Some("due_date"));
+    }
+
+    #[test]
+    fn test_todo_query_overrides_sort_and_maps_flags() {
+        let now = utc("2025-07-23T01:00:00Z");
+        let overrides = SmartListTodosQuery {
+            sort: Some("-created_at".to_string()),
+            limit: Some(5),
+            ..SmartListTodosQuery::default()
+        };
+
+        let query = SmartListService::todo_query(
+            BuiltinSmartList::Overdue.filter(),
+            overrides,
+            now,
+            chrono_tz::UTC,
+            Weekday::Mon,
+        );
+
+        assert_eq!(query.overdue, Some(true));
+        assert_eq!(query.due_after, None);
+        assert_eq!(query.sort.as_deref(), Some("-created_at"));
+        assert_eq!(query.limit, Some(5));
+
+        let filter = SmartListFilter {
+            due_window: Some(DueWindow::NoDueDate),
+            ..SmartListFilter::default()
+        };
+        let query = SmartListService::todo_query(filter, SmartListTodosQuery::default(), now, chrono_tz::UTC, Weekday::Mon);
+        assert_eq!(query.has_due_date, Some(false));
+    }
+}
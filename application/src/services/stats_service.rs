@@ -0,0 +1,159 @@
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::services::user_settings_service::UserSettingsService;
+use crate::dto::request::todo_request::TodoStatsQuery;
+use crate::dto::response::todo_response::TodoStatsResponse;
+use crate::error::ApiError;
+use crate::utils::timezone::TimeWindows;
+
+pub struct StatsService {
+    todo_repository: Arc<dyn TodoRepository>,
+    settings_service: Arc<UserSettingsService>,
+}
+
+impl StatsService {
+    pub fn new(todo_repository: Arc<dyn TodoRepository>, settings_service: Arc<UserSettingsService>) -> Self {
+        Self {
+            todo_repository,
+            settings_service,
+        }
+    }
+
+    /// 할일 통계 조회 ("오늘", "이번 주"는 사용자 시간대 기준)
+    pub async fn todo_stats(&self, user_id: Uuid, query: TodoStatsQuery) -> Result<TodoStatsResponse, ApiError> {
+        self.todo_stats_at(user_id, query, Utc::now()).await
+    }
+
+    async fn todo_stats_at(&self, user_id: Uuid, query: TodoStatsQuery, now: DateTime<Utc>) -> Result<TodoStatsResponse, ApiError> {
+        let preferences = self
+            .settings_service
+            .time_preferences(user_id, query.timezone.as_deref(), query.week_start)
+            .await?;
+        let windows = TimeWindows::at(now, preferences.timezone, preferences.week_start.as_weekday());
+
+        let stats = self
+            .todo_repository
+            .stats(user_id, now, &windows, query.include_archived.unwrap_or(false))
+            .await?;
+        let total_todos = stats.pending_todos + stats.in_progress_todos + stats.completed_todos;
+
+        Ok(TodoStatsResponse {
+            total_todos,
+            completed_todos: stats.completed_todos,
+            pending_todos: stats.pending_todos,
+            in_progress_todos: stats.in_progress_todos,
+            completion_rate: completion_rate(stats.completed_todos, total_todos),
+            overdue_todos: stats.overdue_todos,
+            today_completed: stats.today_completed,
+            this_week_completed: stats.this_week_completed,
+            timezone: preferences.timezone.name().to_string(),
+            week_start: preferences.week_start,
+        })
+    }
+}
+
+/// 완료율(%)을 소수점 첫째 자리까지 반올림한다
+fn completion_rate(completed: i64, total: i64) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    (completed as f64 * 1000.0 / total as f64).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::user_settings_repository::tests::MockUserSettingsRepository;
+    use crate::entities::todo::TodoStats;
+    use crate::entities::user_settings::{UserSettings, WeekStart};
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn service(todo_repo: MockTodoRepository, settings_repo: MockUserSettingsRepository) -> StatsService {
+        let settings_service = Arc::new(UserSettingsService::new(Arc::new(settings_repo)));
+        StatsService::new(Arc::new(todo_repo), settings_service)
+    }
+
+    #[tokio::test]
+    async fn test_stats_use_saved_timezone() {
+        let mut todo_repo = MockTodoRepository::new();
+        let mut settings_repo = MockUserSettingsRepository::new();
+
+        settings_repo.expect_find().returning(|user_id| {
+            Ok(Some(UserSettings {
+                user_id,
+                timezone: "Asia/Seoul".to_string(),
+                week_start: WeekStart::Sunday,
+                auto_archive_days: None,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            }))
+        });
+        todo_repo
+            .expect_stats()
+            .withf(|_, _, windows, include_archived| {
+                // 서울 2025-07-10(목) 05:00 → 오늘은 7/10, 이번 주는 7/6(일)부터
+                windows.today_start == utc("2025-07-09T15:00:00Z")
+                    && windows.week_start == utc("2025-07-05T15:00:00Z")
+                    && !include_archived
+            })
+            .times(1)
+            .returning(|_, _, _, _| {
+                Ok(TodoStats {
+                    pending_todos: 25,
+                    in_progress_todos: 5,
+                    completed_todos: 120,
+                    overdue_todos: 3,
+                    today_completed: 5,
+                    this_week_completed: 25,
+                })
+            });
+
+        let response = service(todo_repo, settings_repo)
+            .todo_stats_at(Uuid::new_v4(), TodoStatsQuery::default(), utc("2025-07-09T20:00:00Z"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.total_todos, 150);
+        assert_eq!(response.completion_rate, 80.0);
+        assert_eq!(response.timezone, "Asia/Seoul");
+        assert_eq!(response.week_start, WeekStart::Sunday);
+    }
+
+    #[tokio::test]
+    async fn test_stats_query_overrides_skip_settings_lookup() {
+        let mut todo_repo = MockTodoRepository::new();
+        let mut settings_repo = MockUserSettingsRepository::new();
+
+        settings_repo.expect_find().times(0);
+        todo_repo
+            .expect_stats()
+            .withf(|_, _, _, include_archived| *include_archived)
+            .returning(|_, _, _, _| Ok(TodoStats::default()));
+
+        let query = TodoStatsQuery {
+            timezone: Some("America/New_York".to_string()),
+            week_start: Some(WeekStart::Monday),
+            include_archived: Some(true),
+        };
+
+        let response = service(todo_repo, settings_repo)
+            .todo_stats(Uuid::new_v4(), query)
+            .await
+            .unwrap();
+        assert_eq!(response.total_todos, 0);
+        assert_eq!(response.completion_rate, 0.0);
+    }
+
+    #[test]
+    fn test_completion_rate_rounding() {
+        assert_eq!(completion_rate(1, 3), 33.3);
+        assert_eq!(completion_rate(2, 3), 66.7);
+        assert_eq!(completion_rate(0, 0), 0.0);
+    }
+}
@@ -0,0 +1,435 @@
+use std::sync::Arc;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::category_repository::CategoryRepository;
+use crate::repositories::template_repository::TemplateRepository;
+use crate::repositories::workspace_repository::WorkspaceRepository;
+use crate::services::user_settings_service::UserSettingsService;
+use crate::services::workspace_service::target_workspace;
+use crate::dto::request::template_request::{InstantiateTemplateRequest, TemplateItemRequest, TemplateRequest};
+use crate::dto::response::template_response::{InstantiateTemplateResponse, TemplateListResponse, TemplateResponse};
+use crate::dto::response::todo_response::TodoResponse;
+use crate::entities::template::{NewTemplate, NewTemplateItem, RelativeDue, TemplateChanges, TemplateTodo};
+use crate::error::ApiError;
+
+/// 반복해서 만드는 할일 묶음. 인스턴스화하면 시작일을 기준으로 마감을 계산해 실제 할일을 만든다.
+pub struct TemplateService {
+    template_repository: Arc<dyn TemplateRepository>,
+    category_repository: Arc<dyn CategoryRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    settings_service: Arc<UserSettingsService>,
+}
+
+impl TemplateService {
+    pub fn new(
+        template_repository: Arc<dyn TemplateRepository>,
+        category_repository: Arc<dyn CategoryRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        settings_service: Arc<UserSettingsService>,
+    ) -> Self {
+        Self {
+            template_repository,
+            category_repository,
+            workspace_repository,
+            settings_service,
+        }
+    }
+
+    /// 템플릿 목록 조회 (이름순)
+    pub async fn list_templates(&self, user_id: Uuid) -> Result<TemplateListResponse, ApiError> {
+        let templates = self.template_repository.find_all(user_id).await?;
+
+        Ok(TemplateListResponse {
+            templates: templates.into_iter().map(TemplateResponse::from).collect(),
+        })
+    }
+
+    /// 템플릿 상세 조회
+    pub async fn get_template(&self, user_id: Uuid, id: Uuid) -> Result<TemplateResponse, ApiError> {
+        let template = self
+            .template_repository
+            .find_by_id(user_id, id)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(TemplateResponse::from(template))
+    }
+
+    /// 템플릿 생성
+    pub async fn create_template(&self, user_id: Uuid, request: TemplateRequest) -> Result<TemplateResponse, ApiError> {
+        let (name, items) = self.prepare(user_id, &request).await?;
+
+        if self.template_repository.find_by_name(user_id, &name).await?.is_some() {
+            return Err(Self::duplicate_name());
+        }
+
+        let template = self
+            .template_repository
+            .create(NewTemplate {
+                user_id,
+                name,
+                description: request.description,
+                items,
+            })
+            .await?;
+
+        Ok(TemplateResponse::from(template))
+    }
+
+    /// 템플릿 수정 (항목 전체를 교체한다)
+    pub async fn update_template(&self, user_id: Uuid, id: Uuid, request: TemplateRequest) -> Result<TemplateResponse, ApiError> {
+        let (name, items) = self.prepare(user_id, &request).await?;
+
+        if let Some(existing) = self.template_repository.find_by_name(user_id, &name).await? {
+            if existing.id != id {
+                return Err(Self::duplicate_name());
+            }
+        }
+
+        let changes = TemplateChanges {
+            name,
+            description: request.description,
+            items,
+        };
+        let template = self
+            .template_repository
+            .update(user_id, id, changes)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(TemplateResponse::from(template))
+    }
+
+    /// 템플릿 삭제 (이미 만든 할일에는 영향이 없다)
+    pub async fn delete_template(&self, user_id: Uuid, id: Uuid) -> Result<(), ApiError> {
+        if !self.template_repository.delete(user_id, id).await? {
+            return Err(Self::not_found());
+        }
+        Ok(())
+    }
+
+    /// 시작일을 기준으로 템플릿의 할일을 모두 만든다
+    pub async fn instantiate(&self, user_id: Uuid, id: Uuid, request: InstantiateTemplateRequest) -> Result<InstantiateTemplateResponse, ApiError> {
+        request.validate()?;
+
+        let template = self
+            .template_repository
+            .find_by_id(user_id, id)
+            .await?
+            .ok_or_else(Self::not_found)?;
+        let workspace_id = target_workspace(self.workspace_repository.as_ref(), user_id, request.workspace_id).await?;
+
+        // 할일에는 같은 워크스페이스의 카테고리만 붙일 수 있으므로 다른 곳의 카테고리는 조용히 빼지 않고 거절한다
+        let mut category_ids: Vec<Uuid> = template
+            .items
+            .iter()
+            .flat_map(|item| item.category_ids.iter().copied())
+            .collect();
+        category_ids.sort();
+        category_ids.dedup();
+        if !category_ids.is_empty() {
+            let categories = self.category_repository.find_by_ids(user_id, &category_ids).await?;
+            if categories.iter().any(|category| category.workspace_id != workspace_id) {
+                return Err(ApiError::Validation(
+                    "템플릿에 할일을 만들 워크스페이스에 없는 카테고리가 있습니다".to_string(),
+                ));
+            }
+        }
+        let preferences = self
+            .settings_service
+            .time_preferences(user_id, request.timezone.as_deref(), None)
+            .await?;
+        let tz = preferences.timezone;
+
+        let todos = template
+            .items
+            .iter()
+            .map(|item| TemplateTodo::from_item(item, request.start_date, tz))
+            .collect();
+        let created = self.template_repository.instantiate(user_id, workspace_id, todos).await?;
+
+        Ok(InstantiateTemplateResponse {
+            template_id: template.template.id,
+            start_date: request.start_date,
+            timezone: tz.name().to_string(),
+            todos: created.into_iter().map(TodoResponse::from).collect(),
+        })
+    }
+
+    /// 저장 전 검증. 이름 앞뒤 공백을 제거하고 항목별 카테고리 중복을 정리한다.
+    async fn prepare(&self, user_id: Uuid, request: &TemplateRequest) -> Result<(String, Vec<NewTemplateItem>), ApiError> {
+        request.validate()?;
+
+        let name = request.name.trim().to_string();
+        if name.is_empty() {
+            return Err(ApiError::Validation("템플릿 이름을 입력해주세요".to_string()));
+        }
+
+        let items = request
+            .items
+            .iter()
+            .map(Self::item)
+            .collect::<Result<Vec<NewTemplateItem>, ApiError>>()?;
+
+        let mut category_ids: Vec<Uuid> = items.iter().flat_map(|item| item.category_ids.iter().copied()).collect();
+        category_ids.sort();
+        category_ids.dedup();
+        if !category_ids.is_empty() {
+            let owned = self.category_repository.find_by_ids(user_id, &category_ids).await?;
+            if owned.len() != category_ids.len() {
+                return Err(ApiError::NotFound("카테고리를 찾을 수 없습니다".to_string()));
+            }
+        }
+
+        Ok((name, items))
+    }
+
+    fn item(request: &TemplateItemRequest) -> Result<NewTemplateItem, ApiError> {
+        request.validate()?;
+
+        if request.due_time.is_some() && request.due_offset_days.is_none() {
+            return Err(ApiError::Validation("due_time에는 due_offset_days가 필요합니다".to_string()));
+        }
+        let checklist = request
+            .checklist
+            .iter()
+            .map(|title| {
+                let title = title.trim();
+                match title.chars().count() {
+                    1..=255 => Ok(title.to_string()),
+                    _ => Err(ApiError::Validation("체크리스트 항목 제목은 1-255자 사이여야 합니다".to_string())),
+                }
+            })
+            .collect::<Result<Vec<String>, ApiError>>()?;
+
+        let mut category_ids = request.category_ids.clone();
+        category_ids.sort();
+        category_ids.dedup();
+
+        Ok(NewTemplateItem {
+            title: request.title.clone(),
+            description: request.description.clone(),
+            priority: request.priority,
+            due: RelativeDue {
+                due_offset_days: request.due_offset_days,
+                due_time: request.due_time,
+            },
+            category_ids,
+            checklist,
+        })
+    }
+
+    fn duplicate_name() -> ApiError {
+        ApiError::Conflict("이미 존재하는 템플릿 이름입니다".to_string())
+    }
+
+    fn not_found() -> ApiError {
+        ApiError::NotFound("템플릿을 찾을 수 없습니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+    use crate::entities::category::Category;
+    use crate::entities::template::{TemplateItem, TemplateWithItems, TodoTemplate};
+    use crate::entities::todo::Priority;
+    use crate::entities::workspace::WorkspaceRole;
+    use crate::repositories::category_repository::tests::MockCategoryRepository;
+    use crate::repositories::template_repository::tests::MockTemplateRepository;
+    use crate::repositories::user_settings_repository::tests::MockUserSettingsRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+
+    fn service(template_repo: MockTemplateRepository) -> TemplateService {
+        let mut workspace_repo = MockWorkspaceRepository::new();
+        workspace_repo.expect_ensure_personal().returning(|_| Ok(Uuid::new_v4()));
+        service_with(template_repo, MockCategoryRepository::new(), workspace_repo)
+    }
+
+    fn service_with(
+        template_repo: MockTemplateRepository,
+        category_repo: MockCategoryRepository,
+        workspace_repo: MockWorkspaceRepository,
+    ) -> TemplateService {
+        let mut settings_repo = MockUserSettingsRepository::new();
+        settings_repo.expect_find().returning(|_| Ok(None));
+
+        TemplateService::new(
+            Arc::new(template_repo),
+            Arc::new(category_repo),
+            Arc::new(workspace_repo),
+            Arc::new(UserSettingsService::new(Arc::new(settings_repo))),
+        )
+    }
+
+    fn item_request(title: &str) -> TemplateItemRequest {
+        TemplateItemRequest {
+            title: title.to_string(),
+            description: None,
+            priority: Priority::Medium,
+            due_offset_days: None,
+            due_time: None,
+            category_ids: Vec::new(),
+            checklist: Vec::new(),
+        }
+    }
+
+    fn sample_template(user_id: Uuid, name: &str) -> TodoTemplate {
+        let now = Utc::now();
+        TodoTemplate {
+            id: Uuid::new_v4(),
+            user_id,
+            name: name.to_string(),
+            description: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn sample_item(template_id: Uuid, position: i32, due: RelativeDue) -> TemplateItem {
+        TemplateItem {
+            id: Uuid::new_v4(),
+            template_id,
+            position,
+            title: format!("항목 {}", position),
+            description: None,
+            priority: Priority::High,
+            due,
+            category_ids: Vec::new(),
+            checklist: vec!["확인".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_item_request_accepts_short_time() {
+        let item: TemplateItemRequest = serde_json::from_str(
+            r#"{"title": "계정 발급", "due_offset_days": 1, "due_time": "09:30"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(item.due_time, NaiveTime::from_hms_opt(9, 30, 0));
+    }
+
+    #[tokio::test]
+    async fn test_create_template_rejects_time_without_offset() {
+        let mut template_repo = MockTemplateRepository::new();
+        template_repo.expect_create().never();
+
+        let mut item = item_request("계정 발급");
+        item.due_time = NaiveTime::from_hms_opt(9, 0, 0);
+        let request = TemplateRequest {
+            name: "온보딩".to_string(),
+            description: None,
+            items: vec![item],
+        };
+
+        let result = service(template_repo).create_template(Uuid::new_v4(), request).await;
+
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_template_duplicate_name() {
+        let mut template_repo = MockTemplateRepository::new();
+        template_repo
+            .expect_find_by_name()
+            .returning(|user_id, name| Ok(Some(sample_template(user_id, name))));
+        template_repo.expect_create().never();
+
+        let request = TemplateRequest {
+            name: " 온보딩 ".to_string(),
+            description: None,
+            items: vec![item_request("계정 발급")],
+        };
+
+        let result = service(template_repo).create_template(Uuid::new_v4(), request).await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_instantiate_resolves_due_dates_in_timezone() {
+        let user_id = Uuid::new_v4();
+        let template = sample_template(user_id, "릴리스");
+        let template_id = template.id;
+        let items = vec![
+            sample_item(template_id, 0, RelativeDue { due_offset_days: Some(-1), due_time: NaiveTime::from_hms_opt(9, 0, 0) }),
+            sample_item(template_id, 1, RelativeDue { due_offset_days: Some(0), due_time: None }),
+            sample_item(template_id, 2, RelativeDue::default()),
+        ];
+
+        let mut template_repo = MockTemplateRepository::new();
+        template_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(TemplateWithItems { template: template.clone(), items: items.clone() })));
+        template_repo
+            .expect_instantiate()
+            .withf(|_, _, todos| {
+                let utc = |s: &str| Some(s.parse::<DateTime<Utc>>().unwrap());
+                todos.len() == 3
+                    && todos[0].due_date == utc("2025-07-30T00:00:00Z")
+                    && todos[1].due_date == utc("2025-07-31T14:59:00Z")
+                    && todos[2].due_date.is_none()
+                    && todos[0].checklist == vec!["확인".to_string()]
+            })
+            .returning(|_, _, _| Ok(Vec::new()));
+
+        let request = InstantiateTemplateRequest {
+            start_date: NaiveDate::from_ymd_opt(2025, 7, 31).unwrap(),
+            timezone: Some("Asia/Seoul".to_string()),
+            workspace_id: None,
+        };
+        let response = service(template_repo).instantiate(user_id, template_id, request).await.unwrap();
+
+        assert_eq!(response.template_id, template_id);
+        assert_eq!(response.timezone, "Asia/Seoul");
+    }
+
+    #[tokio::test]
+    async fn test_instantiate_rejects_categories_from_another_workspace() {
+        let user_id = Uuid::new_v4();
+        let (team_id, other_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let template = sample_template(user_id, "릴리스");
+        let template_id = template.id;
+        let mut item = sample_item(template_id, 0, RelativeDue::default());
+        let category_id = Uuid::new_v4();
+        item.category_ids = vec![category_id];
+
+        let mut template_repo = MockTemplateRepository::new();
+        template_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(TemplateWithItems { template: template.clone(), items: vec![item.clone()] })));
+        template_repo.expect_instantiate().never();
+
+        let mut workspace_repo = MockWorkspaceRepository::new();
+        workspace_repo
+            .expect_find_role()
+            .withf(move |workspace_id, _| *workspace_id == team_id)
+            .returning(|_, _| Ok(Some(WorkspaceRole::Editor)));
+
+        let mut category_repo = MockCategoryRepository::new();
+        category_repo.expect_find_by_ids().returning(move |user_id, _| {
+            Ok(vec![Category {
+                id: category_id,
+                user_id,
+                workspace_id: other_id,
+                name: "개인".to_string(),
+                color: "#2196F3".to_string(),
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+            }])
+        });
+
+        let request = InstantiateTemplateRequest {
+            start_date: NaiveDate::from_ymd_opt(2025, 7, 31).unwrap(),
+            timezone: None,
+            workspace_id: Some(team_id),
+        };
+        let result = service_with(template_repo, category_repo, workspace_repo)
+            .instantiate(user_id, template_id, request)
+            .await;
+
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+}
@@ -0,0 +1,346 @@
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::time_entry_repository::TimeEntryRepository;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::services::user_settings_service::UserSettingsService;
+use crate::dto::request::time_entry_request::{TimeEntryRequest, TimeReportQuery};
+use crate::dto::response::time_entry_response::{
+    round_minutes, TimeEntryListResponse, TimeEntryResponse, TimeReportResponse, TimeReportRowResponse, TimerResponse,
+};
+use crate::entities::time_entry::{NewTimeEntry, TimeEntryChanges, TimeEntrySource, MAX_MANUAL_ENTRY_HOURS};
+use crate::entities::todo::Todo;
+use crate::error::ApiError;
+use crate::utils::time_report;
+use crate::utils::timezone::local_day_start;
+
+/// 보고서 조회 구간의 최대 일수
+const MAX_REPORT_DAYS: i64 = 366;
+
+/// 할일별 시간 기록. 타이머는 사용자마다 하나만 실행할 수 있다.
+pub struct TimeTrackingService {
+    time_entry_repository: Arc<dyn TimeEntryRepository>,
+    todo_repository: Arc<dyn TodoRepository>,
+    settings_service: Arc<UserSettingsService>,
+}
+
+impl TimeTrackingService {
+    pub fn new(
+        time_entry_repository: Arc<dyn TimeEntryRepository>,
+        todo_repository: Arc<dyn TodoRepository>,
+        settings_service: Arc<UserSettingsService>,
+    ) -> Self {
+        Self {
+            time_entry_repository,
+            todo_repository,
+            settings_service,
+        }
+    }
+
+    /// 실행 중인 타이머 조회
+    pub async fn current_timer(&self, user_id: Uuid) -> Result<TimerResponse, ApiError> {
+        let running = self.time_entry_repository.find_running(user_id).await?;
+
+        Ok(TimerResponse {
+            running: running.map(TimeEntryResponse::from),
+        })
+    }
+
+    /// 할일의 타이머 시작 (다른 타이머가 실행 중이면 `Conflict`)
+    pub async fn start_timer(&self, user_id: Uuid, todo_id: Uuid) -> Result<TimeEntryResponse, ApiError> {
+        self.find_todo(user_id, todo_id).await?;
+
+        if let Some(running) = self.time_entry_repository.find_running(user_id).await? {
+            return Err(ApiError::Conflict(format!(
+                "이미 실행 중인 타이머가 있습니다 (할일 {})",
+                running.todo_id
+            )));
+        }
+
+        let entry = self
+            .time_entry_repository
+            .create(NewTimeEntry {
+                user_id,
+                todo_id,
+                started_at: Utc::now(),
+                ended_at: None,
+                source: TimeEntrySource::Timer,
+                note: None,
+            })
+            .await?;
+
+        Ok(TimeEntryResponse::from(entry))
+    }
+
+    /// 실행 중인 타이머 정지
+    pub async fn stop_timer(&self, user_id: Uuid) -> Result<TimeEntryResponse, ApiError> {
+        let entry = self
+            .time_entry_repository
+            .stop_running(user_id, Utc::now())
+            .await?
+            .ok_or_else(|| ApiError::NotFound("실행 중인 타이머가 없습니다".to_string()))?;
+
+        Ok(TimeEntryResponse::from(entry))
+    }
+
+    /// 할일의 시간 기록과 합계 조회
+    pub async fn list_entries(&self, user_id: Uuid, todo_id: Uuid) -> Result<TimeEntryListResponse, ApiError> {
+        let todo = self.find_todo(user_id, todo_id).await?;
+        let entries = self.time_entry_repository.find_by_todo(user_id, todo_id).await?;
+
+        let now = Utc::now();
+        let tracked_seconds = entries.iter().map(|entry| entry.duration_seconds(now)).sum();
+
+        Ok(TimeEntryListResponse {
+            todo_id,
+            entries: entries.into_iter().map(TimeEntryResponse::from).collect(),
+            tracked_minutes: round_minutes(tracked_seconds),
+            estimate_minutes: todo.estimate_minutes,
+        })
+    }
+
+    /// 시간 직접 기록
+    pub async fn add_entry(&self, user_id: Uuid, todo_id: Uuid, request: TimeEntryRequest) -> Result<TimeEntryResponse, ApiError> {
+        request.validate()?;
+        Self::validate_range(request.started_at, request.ended_at, Utc::now())?;
+        self.find_todo(user_id, todo_id).await?;
+
+        let entry = self
+            .time_entry_repository
+            .create(NewTimeEntry {
+                user_id,
+                todo_id,
+                started_at: request.started_at,
+                ended_at: Some(request.ended_at),
+                source: TimeEntrySource::Manual,
+                note: request.note,
+            })
+            .await?;
+
+        Ok(TimeEntryResponse::from(entry))
+    }
+
+    /// 시간 기록 수정 (실행 중인 타이머는 먼저 정지해야 한다)
+    pub async fn update_entry(&self, user_id: Uuid, todo_id: Uuid, id: Uuid, request: TimeEntryRequest) -> Result<TimeEntryResponse, ApiError> {
+        request.validate()?;
+        Self::validate_range(request.started_at, request.ended_at, Utc::now())?;
+
+        let entry = self
+            .time_entry_repository
+            .find_by_id(user_id, todo_id, id)
+            .await?
+            .ok_or_else(Self::entry_not_found)?;
+        if entry.is_running() {
+            return Err(ApiError::BadRequest("실행 중인 타이머는 정지한 뒤 수정할 수 있습니다".to_string()));
+        }
+
+        let changes = TimeEntryChanges {
+            started_at: request.started_at,
+            ended_at: request.ended_at,
+            note: request.note,
+        };
+        let entry = self
+            .time_entry_repository
+            .update(user_id, todo_id, id, changes)
+            .await?
+            .ok_or_else(Self::entry_not_found)?;
+
+        Ok(TimeEntryResponse::from(entry))
+    }
+
+    /// 시간 기록 삭제 (실행 중인 타이머는 기록 없이 취소된다)
+    pub async fn delete_entry(&self, user_id: Uuid, todo_id: Uuid, id: Uuid) -> Result<(), ApiError> {
+        if !self.time_entry_repository.delete(user_id, todo_id, id).await? {
+            return Err(Self::entry_not_found());
+        }
+        Ok(())
+    }
+
+    /// 기간별 기록 시간과 예상 시간 보고서
+    pub async fn report(&self, user_id: Uuid, query: TimeReportQuery) -> Result<TimeReportResponse, ApiError> {
+        query.validate()?;
+        if query.from > query.to {
+            return Err(ApiError::Validation("from은 to보다 이전이어야 합니다".to_string()));
+        }
+        if (query.to - query.from).num_days() >= MAX_REPORT_DAYS {
+            return Err(ApiError::Validation(format!(
+                "보고서 기간은 최대 {}일입니다",
+                MAX_REPORT_DAYS
+            )));
+        }
+
+        let tz = self
+            .settings_service
+            .time_preferences(user_id, query.timezone.as_deref(), None)
+            .await?
+            .timezone;
+        let from = local_day_start(tz, query.from);
+        let to = local_day_start(tz, query.to + Duration::days(1));
+        let group = query.group_by.unwrap_or_default();
+
+        let now = Utc::now();
+        let tracked = self.time_entry_repository.find_tracked(user_id, from, to).await?;
+        let rows = time_report::aggregate(&tracked, group, from, to, now, tz);
+        let total_seconds = time_report::total_seconds(&tracked, from, to, now);
+
+        Ok(TimeReportResponse {
+            from: query.from,
+            to: query.to,
+            timezone: tz.name().to_string(),
+            group_by: group,
+            total_minutes: round_minutes(total_seconds),
+            rows: rows.into_iter().map(TimeReportRowResponse::from).collect(),
+        })
+    }
+
+    fn validate_range(started_at: DateTime<Utc>, ended_at: DateTime<Utc>, now: DateTime<Utc>) -> Result<(), ApiError> {
+        if ended_at <= started_at {
+            return Err(ApiError::Validation("종료 시각은 시작 시각보다 뒤여야 합니다".to_string()));
+        }
+        if ended_at > now {
+            return Err(ApiError::Validation("아직 지나지 않은 시간은 기록할 수 없습니다".to_string()));
+        }
+        if ended_at - started_at > Duration::hours(MAX_MANUAL_ENTRY_HOURS) {
+            return Err(ApiError::Validation(format!(
+                "한 번에 기록할 수 있는 시간은 최대 {}시간입니다",
+                MAX_MANUAL_ENTRY_HOURS
+            )));
+        }
+        Ok(())
+    }
+
+    async fn find_todo(&self, user_id: Uuid, todo_id: Uuid) -> Result<Todo, ApiError> {
+        self.todo_repository
+            .find_by_id(user_id, todo_id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("할일을 찾을 수 없습니다".to_string()))
+    }
+
+    fn entry_not_found() -> ApiError {
+        ApiError::NotFound("시간 기록을 찾을 수 없습니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use crate::entities::time_entry::{TimeEntry, TimeReportGroup};
+    use crate::entities::todo::TodoStatus;
+    use crate::repositories::time_entry_repository::tests::MockTimeEntryRepository;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::user_settings_repository::tests::MockUserSettingsRepository;
+
+    fn sample_todo(user_id: Uuid, id: Uuid) -> Todo {
+        Todo { status: TodoStatus::InProgress, estimate_minutes: Some(90), ..Todo::fixture(user_id, id) }
+    }
+
+    fn sample_entry(user_id: Uuid, todo_id: Uuid, ended_at: Option<DateTime<Utc>>) -> TimeEntry {
+        let now = Utc::now();
+        TimeEntry {
+            id: Uuid::new_v4(),
+            user_id,
+            todo_id,
+            started_at: now - Duration::minutes(30),
+            ended_at,
+            source: TimeEntrySource::Timer,
+            note: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    fn service(time_entry_repo: MockTimeEntryRepository, todo_repo: MockTodoRepository) -> TimeTrackingService {
+        let mut settings_repo = MockUserSettingsRepository::new();
+        settings_repo.expect_find().returning(|_| Ok(None));
+
+        TimeTrackingService::new(
+            Arc::new(time_entry_repo),
+            Arc::new(todo_repo),
+            Arc::new(UserSettingsService::new(Arc::new(settings_repo))),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_start_timer_rejects_second_running_timer() {
+        let user_id = Uuid::new_v4();
+        let mut todo_repo = MockTodoRepository::new();
+        let mut time_entry_repo = MockTimeEntryRepository::new();
+        todo_repo
+            .expect_find_by_id()
+            .returning(|user_id, id| Ok(Some(sample_todo(user_id, id))));
+        time_entry_repo
+            .expect_find_running()
+            .returning(|user_id| Ok(Some(sample_entry(user_id, Uuid::new_v4(), None))));
+        time_entry_repo.expect_create().never();
+
+        let result = service(time_entry_repo, todo_repo).start_timer(user_id, Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_add_entry_validates_range() {
+        let now = Utc::now();
+        let service = service(MockTimeEntryRepository::new(), MockTodoRepository::new());
+        let request = |started_at, ended_at| TimeEntryRequest { started_at, ended_at, note: None };
+        let (user_id, todo_id) = (Uuid::new_v4(), Uuid::new_v4());
+
+        let reversed = service.add_entry(user_id, todo_id, request(now - Duration::hours(1), now - Duration::hours(2))).await;
+        let future = service.add_entry(user_id, todo_id, request(now, now + Duration::hours(1))).await;
+        let too_long = service.add_entry(user_id, todo_id, request(now - Duration::hours(30), now - Duration::hours(1))).await;
+
+        assert!(matches!(reversed, Err(ApiError::Validation(_))));
+        assert!(matches!(future, Err(ApiError::Validation(_))));
+        assert!(matches!(too_long, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_update_entry_rejects_running_timer() {
+        let now = Utc::now();
+        let mut time_entry_repo = MockTimeEntryRepository::new();
+        time_entry_repo
+            .expect_find_by_id()
+            .returning(|user_id, todo_id, _| Ok(Some(sample_entry(user_id, todo_id, None))));
+        time_entry_repo.expect_update().never();
+
+        let request = TimeEntryRequest {
+            started_at: now - Duration::hours(1),
+            ended_at: now - Duration::minutes(10),
+            note: None,
+        };
+        let result = service(time_entry_repo, MockTodoRepository::new())
+            .update_entry(Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), request)
+            .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_report_uses_local_day_range() {
+        let mut time_entry_repo = MockTimeEntryRepository::new();
+        time_entry_repo
+            .expect_find_tracked()
+            .withf(|_, from, to| {
+                *from == "2025-07-20T15:00:00Z".parse::<DateTime<Utc>>().unwrap()
+                    && *to == "2025-07-27T15:00:00Z".parse::<DateTime<Utc>>().unwrap()
+            })
+            .returning(|_, _, _| Ok(Vec::new()));
+
+        let query = TimeReportQuery {
+            from: NaiveDate::from_ymd_opt(2025, 7, 21).unwrap(),
+            to: NaiveDate::from_ymd_opt(2025, 7, 27).unwrap(),
+            group_by: Some(TimeReportGroup::Category),
+            timezone: Some("Asia/Seoul".to_string()),
+        };
+        let response = service(time_entry_repo, MockTodoRepository::new())
+            .report(Uuid::new_v4(), query)
+            .await
+            .unwrap();
+
+        assert_eq!(response.group_by, TimeReportGroup::Category);
+        assert_eq!(response.total_minutes, 0);
+        assert!(response.rows.is_empty());
+    }
+}
@@ -0,0 +1,1069 @@
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::todo_query::{TodoFilter, TodoQuery, TodoSearch, TodoSort};
+use crate::utils::highlight::{escape_html, highlight};
+use crate::utils::pagination::{CursorPage, Keyset};
+use crate::repositories::todo_repository::TodoRepository;
+use crate::repositories::workspace_repository::WorkspaceRepository;
+use crate::services::workspace_service::{authorize_todo_edit, target_workspace};
+use crate::dto::request::todo_request::{
+    CreateTodoRequest, MoveTodoRequest, TodoListQuery, TodoSearchQuery, TrashListQuery, UpdateTodoRequest,
+    UpdateTodoStatusRequest,
+};
+use crate::dto::response::todo_response::{
+    PaginationInfo, SearchField, SearchHighlight, TodoListResponse, TodoResponse, TodoSearchResponse,
+    TodoSearchResult, TodoStatusResponse, TrashListResponse,
+};
+use crate::entities::todo::{NewTodo, TodoChanges, TodoSearchHit, TodoStatus, TransitionError};
+use crate::error::ApiError;
+use crate::events::{EventBus, TodoEvent};
+
+const DEFAULT_PAGE: u32 = 1;
+const DEFAULT_LIMIT: u32 = 20;
+const DEFAULT_SEARCH_LIMIT: u32 = 20;
+const TITLE_SNIPPET_CHARS: usize = 255;
+const SNIPPET_CHARS: usize = 120;
+/// 재배치 워커가 한 번에 처리하는 최대 워크스페이스 수
+const REBALANCE_BATCH_WORKSPACES: i64 = 100;
+/// 휴지통 비우기 한 번에 영구 삭제하는 최대 할일 수
+const PURGE_BATCH_SIZE: i64 = 500;
+/// 자동 보관 한 번에 보관하는 최대 할일 수
+const AUTO_ARCHIVE_BATCH_SIZE: i64 = 500;
+
+/// 할일은 워크스페이스에 속한다. 멤버는 누구나 조회할 수 있고, 만들고 고치려면 owner 또는 editor여야 한다.
+pub struct TodoService {
+    todo_repository: Arc<dyn TodoRepository>,
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    event_bus: EventBus,
+}
+
+impl TodoService {
+    pub fn new(
+        todo_repository: Arc<dyn TodoRepository>,
+        workspace_repository: Arc<dyn WorkspaceRepository>,
+        event_bus: EventBus,
+    ) -> Self {
+        Self {
+            todo_repository,
+            workspace_repository,
+            event_bus,
+        }
+    }
+
+    /// 할일 생성 (워크스페이스를 지정하지 않으면 개인 워크스페이스에 만든다)
+    pub async fn create_todo(&self, user_id: Uuid, request: CreateTodoRequest) -> Result<TodoResponse, ApiError> {
+        request.validate()?;
+
+        let workspace_id = target_workspace(self.workspace_repository.as_ref(), user_id, request.workspace_id).await?;
+
+        let new_todo = NewTodo {
+            user_id,
+            workspace_id,
+            title: request.title,
+            description: request.description,
+            priority: request.priority,
+            due_date: request.due_date,
+            checklist_auto_complete: request.checklist_auto_complete,
+            enforce_dependencies: request.enforce_dependencies,
+            estimate_minutes: request.estimate_minutes,
+        };
+
+        let todo = self.todo_repository.create(new_todo).await?;
+
+        Ok(TodoResponse::from(todo))
+    }
+
+    /// 할일 상세 조회
+    pub async fn get_todo(&self, user_id: Uuid, id: Uuid) -> Result<TodoResponse, ApiError> {
+        let todo = self
+            .todo_repository
+            .find_by_id(user_id, id)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(TodoResponse::from(todo))
+    }
+
+    /// 할일 목록 조회 (필터링 + 페이지네이션)
+    pub async fn list_todos(&self, user_id: Uuid, query: TodoListQuery) -> Result<TodoListResponse, ApiError> {
+        query.validate()?;
+
+        let page = query.page.unwrap_or(DEFAULT_PAGE);
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+        let sort = Self::build_sort(query.sort.as_deref())?;
+
+        let keyset = Keyset::from_params(
+            query.after.as_deref(),
+            query.before.as_deref(),
+            &sort.signature(),
+            sort.keyset_columns().len(),
+        )?;
+        if keyset.is_some() && query.page.is_some() {
+            return Err(ApiError::Validation("page와 커서(after/before)는 함께 사용할 수 없습니다".to_string()));
+        }
+
+        // 다음 페이지 존재 여부를 알기 위해 한 건 더 조회한다
+        let todo_query = TodoQuery {
+            filter: Self::build_filter(&query)?,
+            sort,
+            limit: limit as i64 + 1,
+            offset: (page as i64 - 1) * limit as i64,
+            keyset,
+        };
+
+        let rows = self.todo_repository.find_all(user_id, &todo_query).await?;
+        let todo_page = CursorPage::from_rows(rows, limit, todo_query.keyset.as_ref(), |todo| {
+            todo_query.sort.cursor_for(todo)
+        });
+
+        // 커서 조회는 대량 목록을 위한 것이므로 전체 개수를 세지 않는다
+        let pagination = match todo_query.keyset {
+            Some(_) => None,
+            None => {
+                let total = self.todo_repository.count(user_id, &todo_query.filter).await?;
+                Some(PaginationInfo::new(total, page, limit))
+            }
+        };
+
+        Ok(TodoListResponse {
+            todos: todo_page.items.into_iter().map(TodoResponse::from).collect(),
+            pagination,
+            cursor: todo_page.info,
+        })
+    }
+
+    /// 나에게 맡겨진 할일 목록 (멤버인 모든 워크스페이스)
+    pub async fn list_assigned_todos(&self, user_id: Uuid, query: TodoListQuery) -> Result<TodoListResponse, ApiError> {
+        let query = TodoListQuery {
+            assignee_id: Some(user_id),
+            ..query
+        };
+        self.list_todos(user_id, query).await
+    }
+
+    /// 할일 검색 (관련도 순, 일치 부분 강조)
+    pub async fn search_todos(&self, user_id: Uuid, query: TodoSearchQuery) -> Result<TodoSearchResponse, ApiError> {
+        query.validate()?;
+
+        let search = TodoSearch::new(&query.q, query.limit.unwrap_or(DEFAULT_SEARCH_LIMIT) as i64);
+        if search.terms.is_empty() {
+            return Err(ApiError::Validation("검색어를 입력해주세요".to_string()));
+        }
+
+        let hits = self.todo_repository.search(user_id, &search).await?;
+        let results = hits
+            .into_iter()
+            .map(|hit| Self::search_result(hit, &search.terms))
+            .collect();
+
+        Ok(TodoSearchResponse { query: search.query, results })
+    }
+
+    /// 할일 수정
+    pub async fn update_todo(&self, user_id: Uuid, id: Uuid, request: UpdateTodoRequest) -> Result<TodoResponse, ApiError> {
+        request.validate()?;
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, id).await?;
+
+        let changes = TodoChanges {
+            title: request.title,
+            description: request.description,
+            priority: request.priority,
+            due_date: request.due_date,
+            checklist_auto_complete: request.checklist_auto_complete,
+            enforce_dependencies: request.enforce_dependencies,
+            estimate_minutes: request.estimate_minutes,
+        };
+
+        let todo = self
+            .todo_repository
+            .update(user_id, id, changes)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(TodoResponse::from(todo))
+    }
+
+    /// 할일 상태 변경 (상태 전이 규칙 적용)
+    pub async fn change_status(&self, user_id: Uuid, id: Uuid, request: UpdateTodoStatusRequest) -> Result<TodoStatusResponse, ApiError> {
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, id).await?;
+        let todo = self
+            .todo_repository
+            .find_by_id(user_id, id)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        let transition = todo.transition_to(request.status, chrono::Utc::now())?;
+        if transition.to == TodoStatus::Completed && todo.enforce_dependencies {
+            let open_blockers = self.todo_repository.count_open_blockers(id).await?;
+            if open_blockers > 0 {
+                return Err(TransitionError::Blocked { from: todo.status, open_blockers }.into());
+            }
+        }
+
+        // 조회 이후 다른 요청이 상태를 바꿨다면 반영되지 않는다
+        let updated = self
+            .todo_repository
+            .update_status(user_id, &transition)
+            .await?
+            .ok_or_else(|| ApiError::Conflict("할일 상태가 이미 변경되었습니다. 다시 시도해주세요".to_string()))?;
+
+        self.event_bus.publish(TodoEvent::status_changed(user_id, &transition));
+
+        Ok(TodoStatusResponse::from(updated))
+    }
+
+    /// 할일 순서 이동 (`after_id` 바로 뒤 또는 `before_id` 바로 앞)
+    pub async fn move_todo(&self, user_id: Uuid, id: Uuid, request: MoveTodoRequest) -> Result<TodoResponse, ApiError> {
+        let (after_id, before_id) = (request.after_id, request.before_id);
+        if after_id.is_none() && before_id.is_none() {
+            return Err(ApiError::Validation("after_id 또는 before_id를 지정해야 합니다".to_string()));
+        }
+        if after_id == Some(id) || before_id == Some(id) {
+            return Err(ApiError::Validation("자기 자신을 기준으로 이동할 수 없습니다".to_string()));
+        }
+        if after_id.is_some() && after_id == before_id {
+            return Err(ApiError::Validation("after_id와 before_id는 서로 달라야 합니다".to_string()));
+        }
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, id).await?;
+
+        let todo = self
+            .todo_repository
+            .move_to(user_id, id, after_id, before_id)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(TodoResponse::from(todo))
+    }
+
+    /// 정렬 키 재배치 워커를 시작한다. 키가 없거나 너무 길어진 워크스페이스의 목록을 주기적으로 정리한다.
+    pub fn start_rebalancer(self: Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                ticker.tick().await;
+                if let Err(err) = self.rebalance_positions().await {
+                    tracing::error!(%err, "failed to rebalance todo positions");
+                }
+            }
+        })
+    }
+
+    /// 재배치가 필요한 워크스페이스의 정렬 키를 다시 매기고 처리한 워크스페이스 수를 반환한다
+    pub async fn rebalance_positions(&self) -> Result<usize, ApiError> {
+        let workspace_ids = self
+            .todo_repository
+            .find_workspaces_needing_rebalance(REBALANCE_BATCH_WORKSPACES)
+            .await?;
+
+        for workspace_id in &workspace_ids {
+            self.todo_repository.rebalance_positions(*workspace_id).await?;
+        }
+        if !workspace_ids.is_empty() {
+            tracing::info!(workspaces = workspace_ids.len(), "rebalanced todo positions");
+        }
+
+        Ok(workspace_ids.len())
+    }
+
+    /// 할일 삭제 (휴지통으로 이동)
+    pub async fn delete_todo(&self, user_id: Uuid, id: Uuid) -> Result<(), ApiError> {
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, id).await?;
+        if !self.todo_repository.delete(user_id, id).await? {
+            return Err(Self::not_found());
+        }
+
+        Ok(())
+    }
+
+    /// 할일 보관 (목록과 통계에서 기본적으로 제외된다)
+    pub async fn archive_todo(&self, user_id: Uuid, id: Uuid) -> Result<TodoResponse, ApiError> {
+        self.set_archived(user_id, id, true).await
+    }
+
+    /// 할일 보관 해제
+    pub async fn unarchive_todo(&self, user_id: Uuid, id: Uuid) -> Result<TodoResponse, ApiError> {
+        self.set_archived(user_id, id, false).await
+    }
+
+    async fn set_archived(&self, user_id: Uuid, id: Uuid, archived: bool) -> Result<TodoResponse, ApiError> {
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, id).await?;
+        let todo = self
+            .todo_repository
+            .set_archived(user_id, id, archived)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(TodoResponse::from(todo))
+    }
+
+    /// 자동 보관 워커를 시작한다
+    pub fn start_auto_archiver(self: Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                ticker.tick().await;
+                if let Err(err) = self.auto_archive(chrono::Utc::now()).await {
+                    tracing::error!(%err, "failed to auto-archive completed todos");
+                }
+            }
+        })
+    }
+
+    /// 사용자 설정에 따라 완료 후 오래된 할일을 모두 보관하고 보관한 개수를 반환한다
+    pub async fn auto_archive(&self, now: chrono::DateTime<chrono::Utc>) -> Result<u64, ApiError> {
+        let mut archived = 0;
+        loop {
+            let batch = self.todo_repository.auto_archive(now, AUTO_ARCHIVE_BATCH_SIZE).await?;
+            archived += batch;
+            if batch < AUTO_ARCHIVE_BATCH_SIZE as u64 {
+                break;
+            }
+        }
+        if archived > 0 {
+            tracing::info!(archived, "auto-archived completed todos");
+        }
+
+        Ok(archived)
+    }
+
+    /// 휴지통 목록 조회 (최근 삭제 순)
+    pub async fn list_trash(&self, user_id: Uuid, query: TrashListQuery) -> Result<TrashListResponse, ApiError> {
+        query.validate()?;
+
+        let page = query.page.unwrap_or(DEFAULT_PAGE);
+        let limit = query.limit.unwrap_or(DEFAULT_LIMIT);
+        let offset = (page as i64 - 1) * limit as i64;
+
+        let todos = self.todo_repository.find_trashed(user_id, limit as i64, offset).await?;
+        let total = self.todo_repository.count_trashed(user_id).await?;
+
+        Ok(TrashListResponse {
+            todos: todos.into_iter().map(TodoResponse::from).collect(),
+            pagination: PaginationInfo::new(total, page, limit),
+        })
+    }
+
+    /// 휴지통의 할일 복원
+    pub async fn restore_todo(&self, user_id: Uuid, id: Uuid) -> Result<TodoResponse, ApiError> {
+        authorize_todo_edit(self.todo_repository.as_ref(), user_id, id).await?;
+        let todo = self
+            .todo_repository
+            .restore(user_id, id)
+            .await?
+            .ok_or_else(|| ApiError::NotFound("휴지통에서 할일을 찾을 수 없습니다".to_string()))?;
+
+        Ok(TodoResponse::from(todo))
+    }
+
+    /// 보관 기간 워커를 시작한다. 휴지통에서 `retention`보다 오래된 할일을 주기적으로 영구 삭제한다.
+    pub fn start_trash_purger(self: Arc<Self>, retention: chrono::Duration, interval: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+
+            loop {
+                ticker.tick().await;
+                if let Err(err) = self.purge_trash(chrono::Utc::now() - retention).await {
+                    tracing::error!(%err, "failed to purge trashed todos");
+                }
+            }
+        })
+    }
+
+    /// `deleted_before` 이전에 휴지통으로 옮긴 할일을 모두 영구 삭제하고 삭제한 개수를 반환한다
+    pub async fn purge_trash(&self, deleted_before: chrono::DateTime<chrono::Utc>) -> Result<u64, ApiError> {
+        let mut purged = 0;
+        loop {
+            let deleted = self.todo_repository.purge_trashed(deleted_before, PURGE_BATCH_SIZE).await?;
+            purged += deleted;
+            if deleted < PURGE_BATCH_SIZE as u64 {
+                break;
+            }
+        }
+        if purged > 0 {
+            tracing::info!(purged, "purged trashed todos");
+        }
+
+        Ok(purged)
+    }
+
+    /// 쿼리 파라미터를 타입이 있는 필터로 변환한다
+    fn build_filter(query: &TodoListQuery) -> Result<TodoFilter, ApiError> {
+        if let (Some(after), Some(before)) = (query.due_after, query.due_before) {
+            if after > before {
+                return Err(ApiError::Validation("due_after는 due_before보다 이전이어야 합니다".to_string()));
+            }
+        }
+        if let (Some(after), Some(before)) = (query.created_after, query.created_before) {
+            if after > before {
+                return Err(ApiError::Validation("created_after는 created_before보다 이전이어야 합니다".to_string()));
+            }
+        }
+
+        Ok(TodoFilter {
+            statuses: parse_list(query.status.as_deref())?,
+            priorities: parse_list(query.priority.as_deref())?,
+            search: query.search.clone(),
+            due_before: query.due_before,
+            due_after: query.due_after,
+            overdue: query.overdue.unwrap_or(false),
+            has_due_date: query.has_due_date,
+            category_ids: parse_list(query.category_ids.as_deref())?,
+            created_after: query.created_after,
+            created_before: query.created_before,
+            include_archived: query.include_archived.unwrap_or(false),
+            workspace_id: query.workspace_id,
+            assignee_id: query.assignee_id,
+        })
+    }
+
+    fn build_sort(sort: Option<&str>) -> Result<TodoSort, ApiError> {
+        match sort {
+            Some(sort) => TodoSort::from_str(sort).map_err(ApiError::Validation),
+            None => Ok(TodoSort::default()),
+        }
+    }
+
+    /// 제목은 항상 포함하고, 설명과 카테고리명은 검색어가 일치한 경우에만 스니펫을 만든다
+    fn search_result(hit: TodoSearchHit, terms: &[String]) -> TodoSearchResult {
+        let mut highlights = vec![SearchHighlight {
+            field: SearchField::Title,
+            snippet: highlight(&hit.todo.title, terms, TITLE_SNIPPET_CHARS)
+                .unwrap_or_else(|| escape_html(&hit.todo.title)),
+        }];
+
+        if let Some(snippet) = hit.todo.description.as_deref().and_then(|d| highlight(d, terms, SNIPPET_CHARS)) {
+            highlights.push(SearchHighlight { field: SearchField::Description, snippet });
+        }
+        for name in &hit.category_names {
+            if let Some(snippet) = highlight(name, terms, SNIPPET_CHARS) {
+                highlights.push(SearchHighlight { field: SearchField::Category, snippet });
+            }
+        }
+
+        TodoSearchResult {
+            todo: TodoResponse::from(hit.todo),
+            score: hit.score,
+            highlights,
+        }
+    }
+
+    fn not_found() -> ApiError {
+        ApiError::NotFound("할일을 찾을 수 없습니다".to_string())
+    }
+}
+
+/// 쉼표로 구분된 값 목록을 파싱한다 (중복 제거, 빈 항목 무시)
+fn parse_list<T>(value: Option<&str>) -> Result<Vec<T>, ApiError>
+where
+    T: FromStr + PartialEq,
+    T::Err: std::fmt::Display,
+{
+    let mut items = Vec::new();
+    for part in value.unwrap_or_default().split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let item = T::from_str(part).map_err(|e| ApiError::Validation(e.to_string()))?;
+        if !items.contains(&item) {
+            items.push(item);
+        }
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+    use crate::utils::pagination::PageDirection;
+    use crate::entities::todo::{Priority, Todo, TodoStatus};
+    use crate::entities::workspace::WorkspaceRole;
+    use chrono::Utc;
+
+    fn personal_workspaces() -> Arc<MockWorkspaceRepository> {
+        let mut mock_repo = MockWorkspaceRepository::new();
+        mock_repo
+            .expect_ensure_personal()
+            .returning(|_| Ok(Uuid::new_v4()));
+        Arc::new(mock_repo)
+    }
+
+    /// 요청한 사용자가 할일의 워크스페이스에서 editor라고 가정한다
+    fn allow_edit(mock_repo: &mut MockTodoRepository) {
+        mock_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Editor)));
+    }
+
+    fn sample_todo(user_id: Uuid) -> Todo {
+        Todo {
+            title: "장보기".to_string(),
+            description: Some("우유, 빵, 계란 구매".to_string()),
+            ..Todo::fixture(user_id, Uuid::new_v4())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_create_todo_success() {
+        let mut mock_repo = MockTodoRepository::new();
+        let user_id = Uuid::new_v4();
+
+        mock_repo
+            .expect_create()
+            .withf(move |new_todo| new_todo.user_id == user_id && new_todo.priority == Priority::High)
+            .times(1)
+            .returning(|new_todo| {
+                let mut todo = sample_todo(new_todo.user_id);
+                todo.title = new_todo.title;
+                todo.priority = new_todo.priority;
+                Ok(todo)
+            });
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let request = CreateTodoRequest {
+            workspace_id: None,
+            title: "회의 준비".to_string(),
+            description: None,
+            priority: Priority::High,
+            due_date: None,
+            checklist_auto_complete: false,
+            enforce_dependencies: false,
+            estimate_minutes: None,
+        };
+
+        let response = service.create_todo(user_id, request).await.unwrap();
+        assert_eq!(response.title, "회의 준비");
+        assert_eq!(response.status, TodoStatus::Pending);
+        assert_eq!(response.priority, Priority::High);
+    }
+
+    #[tokio::test]
+    async fn test_create_todo_empty_title() {
+        let mock_repo = MockTodoRepository::new();
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+
+        let request = CreateTodoRequest {
+            workspace_id: None,
+            title: "".to_string(),
+            description: None,
+            priority: Priority::Medium,
+            due_date: None,
+            checklist_auto_complete: false,
+            enforce_dependencies: false,
+            estimate_minutes: None,
+        };
+
+        let result = service.create_todo(Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_get_todo_of_other_user_is_not_found() {
+        let mut mock_repo = MockTodoRepository::new();
+
+        mock_repo
+            .expect_find_by_id()
+            .times(1)
+            .returning(|_, _| Ok(None));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let result = service.get_todo(Uuid::new_v4(), Uuid::new_v4()).await;
+
+        if let Err(ApiError::NotFound(msg)) = result {
+            assert_eq!(msg, "할일을 찾을 수 없습니다");
+        } else {
+            panic!("Expected NotFound error");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_todos_pagination() {
+        let mut mock_repo = MockTodoRepository::new();
+        let user_id = Uuid::new_v4();
+
+        mock_repo
+            .expect_find_all()
+            .withf(|_, query| {
+                query.limit == 11
+                    && query.offset == 10
+                    && query.keyset.is_none()
+                    && query.filter.statuses == vec![TodoStatus::Pending, TodoStatus::InProgress]
+                    && query.sort == TodoSort::from_str("-priority,due_date").unwrap()
+            })
+            .times(1)
+            .returning(move |user_id, _| Ok(vec![sample_todo(user_id)]));
+        mock_repo
+            .expect_count()
+            .times(1)
+            .returning(|_, _| Ok(25));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let query = TodoListQuery {
+            status: Some("pending,in_progress".to_string()),
+            page: Some(2),
+            limit: Some(10),
+            sort: Some("-priority,due_date".to_string()),
+            ..Default::default()
+        };
+
+        let response = service.list_todos(user_id, query).await.unwrap();
+        assert_eq!(response.todos.len(), 1);
+        let pagination = response.pagination.unwrap();
+        assert_eq!(pagination.total, 25);
+        assert_eq!(pagination.total_pages, 3);
+        assert!(pagination.has_next);
+        assert!(pagination.has_prev);
+    }
+
+    #[tokio::test]
+    async fn test_list_todos_with_cursor_skips_count() {
+        let mut mock_repo = MockTodoRepository::new();
+        let user_id = Uuid::new_v4();
+        let sort = TodoSort::default();
+        let after = sort.cursor_for(&sample_todo(user_id)).encode();
+
+        mock_repo
+            .expect_find_all()
+            .withf(|_, query| {
+                query.limit == 3
+                    && query.keyset.as_ref().map(|k| k.direction) == Some(PageDirection::Forward)
+            })
+            .times(1)
+            .returning(|user_id, _| Ok((0..3).map(|_| sample_todo(user_id)).collect()));
+        mock_repo.expect_count().times(0);
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let query = TodoListQuery {
+            limit: Some(2),
+            after: Some(after),
+            ..Default::default()
+        };
+
+        let response = service.list_todos(user_id, query).await.unwrap();
+        assert_eq!(response.todos.len(), 2);
+        assert!(response.pagination.is_none());
+        assert!(response.cursor.has_next);
+        assert!(response.cursor.has_prev);
+        assert!(response.cursor.next_cursor.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_list_todos_rejects_mismatched_cursor() {
+        let service = TodoService::new(Arc::new(MockTodoRepository::new()), personal_workspaces(), EventBus::default());
+        let cursor = TodoSort::default().cursor_for(&sample_todo(Uuid::new_v4())).encode();
+
+        let invalid_queries = vec![
+            TodoListQuery { after: Some(cursor.clone()), sort: Some("title".to_string()), ..Default::default() },
+            TodoListQuery { before: Some(cursor.clone()), page: Some(2), ..Default::default() },
+            TodoListQuery { after: Some("garbage".to_string()), ..Default::default() },
+        ];
+
+        for query in invalid_queries {
+            let result = service.list_todos(Uuid::new_v4(), query).await;
+            assert!(matches!(result, Err(ApiError::Validation(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_list_todos_limit_too_large() {
+        let mock_repo = MockTodoRepository::new();
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+
+        let query = TodoListQuery {
+            limit: Some(101),
+            ..Default::default()
+        };
+
+        let result = service.list_todos(Uuid::new_v4(), query).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_change_status_records_transition_and_publishes_event() {
+        let mut mock_repo = MockTodoRepository::new();
+        allow_edit(&mut mock_repo);
+        let user_id = Uuid::new_v4();
+        let todo = sample_todo(user_id);
+        let todo_id = todo.id;
+
+        mock_repo
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_, _| Ok(Some(todo.clone())));
+        mock_repo
+            .expect_update_status()
+            .withf(|_, transition| {
+                transition.from == TodoStatus::Pending
+                    && transition.to == TodoStatus::InProgress
+                    && transition.started_at.is_some()
+            })
+            .times(1)
+            .returning(move |user_id, transition| {
+                let mut updated = sample_todo(user_id);
+                updated.id = transition.todo_id;
+                updated.status = transition.to;
+                updated.started_at = transition.started_at;
+                Ok(Some(updated))
+            });
+
+        let event_bus = EventBus::default();
+        let mut events = event_bus.subscribe();
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), event_bus);
+
+        let request = UpdateTodoStatusRequest { status: TodoStatus::InProgress };
+        let response = service.change_status(user_id, todo_id, request).await.unwrap();
+        assert_eq!(response.status, TodoStatus::InProgress);
+        assert!(response.started_at.is_some());
+
+        match events.try_recv().unwrap() {
+            TodoEvent::StatusChanged { todo_id: id, from, to, .. } => {
+                assert_eq!(id, todo_id);
+                assert_eq!(from, TodoStatus::Pending);
+                assert_eq!(to, TodoStatus::InProgress);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_change_status_rejects_invalid_transition() {
+        let mut mock_repo = MockTodoRepository::new();
+        allow_edit(&mut mock_repo);
+        let user_id = Uuid::new_v4();
+        let mut todo = sample_todo(user_id);
+        todo.status = TodoStatus::Completed;
+        todo.completed_at = Some(Utc::now());
+
+        mock_repo
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_, _| Ok(Some(todo.clone())));
+        mock_repo.expect_update_status().never();
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let request = UpdateTodoStatusRequest { status: TodoStatus::InProgress };
+        let result = service.change_status(user_id, Uuid::new_v4(), request).await;
+
+        assert!(matches!(result, Err(ApiError::InvalidStatusTransition(_))));
+    }
+
+    #[tokio::test]
+    async fn test_change_status_blocked_by_open_dependencies() {
+        let mut mock_repo = MockTodoRepository::new();
+        allow_edit(&mut mock_repo);
+        let user_id = Uuid::new_v4();
+        let mut todo = sample_todo(user_id);
+        todo.enforce_dependencies = true;
+
+        mock_repo
+            .expect_find_by_id()
+            .times(1)
+            .returning(move |_, _| Ok(Some(todo.clone())));
+        mock_repo.expect_count_open_blockers().times(1).returning(|_| Ok(2));
+        mock_repo.expect_update_status().never();
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let request = UpdateTodoStatusRequest { status: TodoStatus::Completed };
+        let result = service.change_status(user_id, Uuid::new_v4(), request).await;
+
+        assert!(matches!(
+            result,
+            Err(ApiError::InvalidStatusTransition(TransitionError::Blocked { open_blockers: 2, .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_change_status_concurrent_update_is_conflict() {
+        let mut mock_repo = MockTodoRepository::new();
+        allow_edit(&mut mock_repo);
+        let user_id = Uuid::new_v4();
+        let todo = sample_todo(user_id);
+
+        mock_repo
+            .expect_find_by_id()
+            .returning(move |_, _| Ok(Some(todo.clone())));
+        mock_repo
+            .expect_update_status()
+            .returning(|_, _| Ok(None));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let request = UpdateTodoStatusRequest { status: TodoStatus::Completed };
+        let result = service.change_status(user_id, Uuid::new_v4(), request).await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_todos_rich_filters() {
+        let mut mock_repo = MockTodoRepository::new();
+        let category_id = Uuid::new_v4();
+
+        mock_repo
+            .expect_find_all()
+            .withf(move |_, query| {
+                query.filter.priorities == vec![Priority::High]
+                    && query.filter.overdue
+                    && query.filter.has_due_date == Some(true)
+                    && query.filter.category_ids == vec![category_id]
+                    && query.sort == TodoSort::default()
+            })
+            .times(1)
+            .returning(|_, _| Ok(vec![]));
+        mock_repo.expect_count().returning(|_, _| Ok(0));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let query = TodoListQuery {
+            priority: Some("high, high".to_string()),
+            overdue: Some(true),
+            has_due_date: Some(true),
+            category_ids: Some(category_id.to_string()),
+            ..Default::default()
+        };
+
+        let response = service.list_todos(Uuid::new_v4(), query).await.unwrap();
+        assert_eq!(response.pagination.unwrap().total_pages, 0);
+        assert!(!response.cursor.has_next);
+    }
+
+    #[tokio::test]
+    async fn test_list_todos_invalid_filters() {
+        let service = TodoService::new(Arc::new(MockTodoRepository::new()), personal_workspaces(), EventBus::default());
+
+        let invalid_queries = vec![
+            TodoListQuery { status: Some("pending,done".to_string()), ..Default::default() },
+            TodoListQuery { category_ids: Some("not-a-uuid".to_string()), ..Default::default() },
+            TodoListQuery { sort: Some("-password_hash".to_string()), ..Default::default() },
+            TodoListQuery {
+                due_after: Some(Utc::now()),
+                due_before: Some(Utc::now() - chrono::Duration::days(1)),
+                ..Default::default()
+            },
+        ];
+
+        for query in invalid_queries {
+            let result = service.list_todos(Uuid::new_v4(), query).await;
+            assert!(matches!(result, Err(ApiError::Validation(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_todos_highlights_matches() {
+        let mut mock_repo = MockTodoRepository::new();
+
+        mock_repo
+            .expect_search()
+            .withf(|_, search| search.terms == vec!["회의", "준비"] && search.limit == 20)
+            .times(1)
+            .returning(|user_id, _| {
+                let mut todo = sample_todo(user_id);
+                todo.title = "주간 회의 준비".to_string();
+                todo.description = Some("<발표> 자료 준비".to_string());
+                Ok(vec![TodoSearchHit {
+                    todo,
+                    category_names: vec!["업무".to_string(), "회의실".to_string()],
+                    score: 3.5,
+                }])
+            });
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let query = TodoSearchQuery { q: " 회의  준비 ".to_string(), limit: None };
+
+        let response = service.search_todos(Uuid::new_v4(), query).await.unwrap();
+        assert_eq!(response.query, "회의 준비");
+
+        let highlights = &response.results[0].highlights;
+        assert_eq!(highlights.len(), 3);
+        assert_eq!(highlights[0].snippet, "주간 <mark>회의</mark> <mark>준비</mark>");
+        assert_eq!(highlights[1].field, SearchField::Description);
+        assert_eq!(highlights[1].snippet, "&lt;발표&gt; 자료 <mark>준비</mark>");
+        assert_eq!(highlights[2].field, SearchField::Category);
+        assert_eq!(highlights[2].snippet, "<mark>회의</mark>실");
+    }
+
+    #[tokio::test]
+    async fn test_search_todos_blank_query() {
+        let service = TodoService::new(Arc::new(MockTodoRepository::new()), personal_workspaces(), EventBus::default());
+        let query = TodoSearchQuery { q: "   ".to_string(), limit: None };
+
+        let result = service.search_todos(Uuid::new_v4(), query).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_delete_todo_not_found() {
+        let mut mock_repo = MockTodoRepository::new();
+        allow_edit(&mut mock_repo);
+
+        mock_repo
+            .expect_delete()
+            .times(1)
+            .returning(|_, _| Ok(false));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let result = service.delete_todo(Uuid::new_v4(), Uuid::new_v4()).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_viewer_cannot_modify_shared_todo() {
+        let mut mock_repo = MockTodoRepository::new();
+        mock_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Viewer)));
+        mock_repo.expect_delete().never();
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let result = service.delete_todo(Uuid::new_v4(), Uuid::new_v4()).await;
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_create_todo_in_workspace_requires_editor() {
+        let mut workspace_repo = MockWorkspaceRepository::new();
+        workspace_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Viewer)));
+        let mut mock_repo = MockTodoRepository::new();
+        mock_repo.expect_create().never();
+
+        let service = TodoService::new(Arc::new(mock_repo), Arc::new(workspace_repo), EventBus::default());
+        let request = CreateTodoRequest {
+            workspace_id: Some(Uuid::new_v4()),
+            title: "회의 준비".to_string(),
+            description: None,
+            priority: Priority::High,
+            due_date: None,
+            checklist_auto_complete: false,
+            enforce_dependencies: false,
+            estimate_minutes: None,
+        };
+
+        let result = service.create_todo(Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_move_todo_rejects_invalid_neighbours() {
+        let mut mock_repo = MockTodoRepository::new();
+        mock_repo.expect_move_to().never();
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+
+        let (user_id, id, other) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        for (after_id, before_id) in [(None, None), (Some(id), None), (None, Some(id)), (Some(other), Some(other))] {
+            let result = service.move_todo(user_id, id, MoveTodoRequest { after_id, before_id }).await;
+            assert!(matches!(result, Err(ApiError::Validation(_))));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_move_todo_returns_new_position() {
+        let mut mock_repo = MockTodoRepository::new();
+        allow_edit(&mut mock_repo);
+        let user_id = Uuid::new_v4();
+        let after_id = Uuid::new_v4();
+
+        mock_repo
+            .expect_move_to()
+            .withf(move |_, _, after, before| *after == Some(after_id) && before.is_none())
+            .times(1)
+            .returning(move |_, id, _, _| {
+                let mut todo = sample_todo(user_id);
+                todo.id = id;
+                todo.position = Some("Vl".to_string());
+                Ok(Some(todo))
+            });
+        mock_repo.expect_move_to().returning(|_, _, _, _| Ok(None));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let request = MoveTodoRequest { after_id: Some(after_id), before_id: None };
+        let response = service.move_todo(user_id, Uuid::new_v4(), request).await.unwrap();
+        assert_eq!(response.position.as_deref(), Some("Vl"));
+
+        let request = MoveTodoRequest { after_id: None, before_id: Some(Uuid::new_v4()) };
+        let result = service.move_todo(user_id, Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_rebalance_positions_processes_each_workspace() {
+        let mut mock_repo = MockTodoRepository::new();
+        let workspace_ids = vec![Uuid::new_v4(), Uuid::new_v4()];
+        let expected = workspace_ids.clone();
+
+        mock_repo
+            .expect_find_workspaces_needing_rebalance()
+            .returning(move |_| Ok(workspace_ids.clone()));
+        mock_repo
+            .expect_rebalance_positions()
+            .times(2)
+            .withf(move |workspace_id| expected.contains(workspace_id))
+            .returning(|_| Ok(()));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        assert_eq!(service.rebalance_positions().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_restore_todo_not_in_trash() {
+        let mut mock_repo = MockTodoRepository::new();
+        allow_edit(&mut mock_repo);
+        mock_repo.expect_restore().returning(|_, _| Ok(None));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let result = service.restore_todo(Uuid::new_v4(), Uuid::new_v4()).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_purge_trash_deletes_in_batches() {
+        let mut mock_repo = MockTodoRepository::new();
+        let cutoff = Utc::now();
+        let mut batches = vec![PURGE_BATCH_SIZE as u64, 3].into_iter();
+
+        mock_repo
+            .expect_purge_trashed()
+            .times(2)
+            .withf(move |deleted_before, limit| *deleted_before == cutoff && *limit == PURGE_BATCH_SIZE)
+            .returning(move |_, _| Ok(batches.next().unwrap()));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        assert_eq!(service.purge_trash(cutoff).await.unwrap(), PURGE_BATCH_SIZE as u64 + 3);
+    }
+
+    #[tokio::test]
+    async fn test_archive_missing_todo() {
+        let mut mock_repo = MockTodoRepository::new();
+        allow_edit(&mut mock_repo);
+        mock_repo
+            .expect_set_archived()
+            .withf(|_, _, archived| *archived)
+            .returning(|_, _, _| Ok(None));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        let result = service.archive_todo(Uuid::new_v4(), Uuid::new_v4()).await;
+        assert!(matches!(result, Err(ApiError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn test_list_todos_excludes_archived_by_default() {
+        let mut mock_repo = MockTodoRepository::new();
+        mock_repo
+            .expect_find_all()
+            .withf(|_, query| !query.filter.include_archived)
+            .returning(|_, _| Ok(vec![]));
+        mock_repo
+            .expect_count()
+            .withf(|_, filter| !filter.include_archived)
+            .returning(|_, _| Ok(0));
+
+        let service = TodoService::new(Arc::new(mock_repo), personal_workspaces(), EventBus::default());
+        service.list_todos(Uuid::new_v4(), TodoListQuery::default()).await.unwrap();
+    }
+}
@@ -0,0 +1,146 @@
+use std::sync::Arc;
+use chrono_tz::Tz;
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::user_settings_repository::UserSettingsRepository;
+use crate::dto::request::user_settings_request::UpdateUserSettingsRequest;
+use crate::dto::response::user_settings_response::UserSettingsResponse;
+use crate::entities::user_settings::{UserSettingsChanges, WeekStart, DEFAULT_TIMEZONE};
+use crate::error::ApiError;
+use crate::utils::timezone::parse_timezone;
+
+/// 날짜 기준 계산에 쓰이는 사용자 시간대와 주 시작 요일
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimePreferences {
+    pub timezone: Tz,
+    pub week_start: WeekStart,
+}
+
+pub struct UserSettingsService {
+    settings_repository: Arc<dyn UserSettingsRepository>,
+}
+
+impl UserSettingsService {
+    pub fn new(settings_repository: Arc<dyn UserSettingsRepository>) -> Self {
+        Self { settings_repository }
+    }
+
+    /// 사용자 설정 조회 (저장된 설정이 없으면 기본값)
+    pub async fn get_settings(&self, user_id: Uuid) -> Result<UserSettingsResponse, ApiError> {
+        let response = match self.settings_repository.find(user_id).await? {
+            Some(settings) => UserSettingsResponse::from(settings),
+            None => UserSettingsResponse {
+                timezone: DEFAULT_TIMEZONE.to_string(),
+                week_start: WeekStart::default(),
+                auto_archive_days: None,
+            },
+        };
+
+        Ok(response)
+    }
+
+    /// 사용자 설정 변경
+    pub async fn update_settings(&self, user_id: Uuid, request: UpdateUserSettingsRequest) -> Result<UserSettingsResponse, ApiError> {
+        request.validate()?;
+
+        let timezone = parse_timezone(request.timezone.trim())?;
+        let changes = UserSettingsChanges {
+            timezone: timezone.name().to_string(),
+            week_start: request.week_start,
+            auto_archive_days: request.auto_archive_days.map(|days| days as i32),
+        };
+
+        let settings = self.settings_repository.upsert(user_id, changes).await?;
+
+        Ok(UserSettingsResponse::from(settings))
+    }
+
+    /// 저장된 설정에 요청별 지정값을 덮어쓴 시간 기준
+    pub async fn time_preferences(
+        &self,
+        user_id: Uuid,
+        timezone: Option<&str>,
+        week_start: Option<WeekStart>,
+    ) -> Result<TimePreferences, ApiError> {
+        let (timezone, week_start) = match (timezone, week_start) {
+            (Some(timezone), Some(week_start)) => (timezone.to_string(), week_start),
+            _ => {
+                let saved = self.get_settings(user_id).await?;
+                (
+                    timezone.map(str::to_string).unwrap_or(saved.timezone),
+                    week_start.unwrap_or(saved.week_start),
+                )
+            }
+        };
+
+        Ok(TimePreferences {
+            timezone: parse_timezone(&timezone)?,
+            week_start,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::user_settings_repository::tests::MockUserSettingsRepository;
+    use crate::entities::user_settings::UserSettings;
+    use chrono::Utc;
+
+    fn saved_settings(user_id: Uuid, timezone: &str, week_start: WeekStart) -> UserSettings {
+        UserSettings {
+            user_id,
+            timezone: timezone.to_string(),
+            week_start,
+            auto_archive_days: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_settings_defaults() {
+        let mut mock_repo = MockUserSettingsRepository::new();
+        mock_repo.expect_find().returning(|_| Ok(None));
+
+        let service = UserSettingsService::new(Arc::new(mock_repo));
+        let response = service.get_settings(Uuid::new_v4()).await.unwrap();
+
+        assert_eq!(response.timezone, "UTC");
+        assert_eq!(response.week_start, WeekStart::Monday);
+    }
+
+    #[tokio::test]
+    async fn test_update_settings_rejects_unknown_timezone() {
+        let mut mock_repo = MockUserSettingsRepository::new();
+        mock_repo.expect_upsert().times(0);
+
+        let service = UserSettingsService::new(Arc::new(mock_repo));
+        let request = UpdateUserSettingsRequest {
+            timezone: "Seoul".to_string(),
+            week_start: WeekStart::Sunday,
+            auto_archive_days: None,
+        };
+
+        let result = service.update_settings(Uuid::new_v4(), request).await;
+        assert!(matches!(result, Err(ApiError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_time_preferences_overrides_saved_settings() {
+        let mut mock_repo = MockUserSettingsRepository::new();
+        mock_repo
+            .expect_find()
+            .times(1)
+            .returning(|user_id| Ok(Some(saved_settings(user_id, "Asia/Seoul", WeekStart::Sunday))));
+
+        let service = UserSettingsService::new(Arc::new(mock_repo));
+        let preferences = service
+            .time_preferences(Uuid::new_v4(), None, Some(WeekStart::Monday))
+            .await
+            .unwrap();
+
+        assert_eq!(preferences.timezone, chrono_tz::Asia::Seoul);
+        assert_eq!(preferences.week_start, WeekStart::Monday);
+    }
+}
@@ -0,0 +1,518 @@
+use std::sync::Arc;
+use chrono::{DateTime, Duration, Utc};
+use uuid::Uuid;
+use validator::Validate;
+use crate::repositories::todo_repository::TodoRepository;
+use crate::repositories::workspace_repository::WorkspaceRepository;
+use crate::dto::request::workspace_request::{
+    CreateInvitationRequest, CreateWorkspaceRequest, InvitationTokenRequest, UpdateMemberRoleRequest,
+    UpdateWorkspaceRequest,
+};
+use crate::dto::response::workspace_response::{
+    InvitationListResponse, InvitationResponse, WorkspaceListResponse, WorkspaceMemberListResponse,
+    WorkspaceMemberResponse, WorkspaceResponse,
+};
+use crate::entities::workspace::{
+    NewInvitation, NewWorkspace, WorkspaceInvitation, WorkspaceRole, WorkspaceWithRole, MAX_WORKSPACE_MEMBERS,
+};
+use crate::error::ApiError;
+use crate::notifications::{Email, Mailer};
+use crate::utils::token::{generate_token, hash_token};
+
+/// 할일을 수정할 수 있는지 확인한다. 볼 수 없는 할일이면 404, viewer면 403.
+pub(crate) async fn authorize_todo_edit(todo_repository: &dyn TodoRepository, user_id: Uuid, todo_id: Uuid) -> Result<(), ApiError> {
+    match todo_repository.find_role(user_id, todo_id).await? {
+        Some(role) => require_editor(role),
+        None => Err(ApiError::NotFound("할일을 찾을 수 없습니다".to_string())),
+    }
+}
+
+/// 워크스페이스에 쓸 수 있는지 확인한다. 멤버가 아니면 404, viewer면 403.
+pub(crate) async fn authorize_workspace_edit(
+    workspace_repository: &dyn WorkspaceRepository,
+    user_id: Uuid,
+    workspace_id: Uuid,
+) -> Result<(), ApiError> {
+    match workspace_repository.find_role(workspace_id, user_id).await? {
+        Some(role) => require_editor(role),
+        None => Err(ApiError::NotFound("워크스페이스를 찾을 수 없습니다".to_string())),
+    }
+}
+
+/// 새 할일이나 카테고리를 둘 워크스페이스. 지정하지 않으면 개인 워크스페이스.
+pub(crate) async fn target_workspace(
+    workspace_repository: &dyn WorkspaceRepository,
+    user_id: Uuid,
+    workspace_id: Option<Uuid>,
+) -> Result<Uuid, ApiError> {
+    match workspace_id {
+        Some(workspace_id) => {
+            authorize_workspace_edit(workspace_repository, user_id, workspace_id).await?;
+            Ok(workspace_id)
+        }
+        None => workspace_repository.ensure_personal(user_id).await,
+    }
+}
+
+/// owner 또는 editor만 통과한다
+pub(crate) fn require_editor(role: WorkspaceRole) -> Result<(), ApiError> {
+    if role.can_edit() {
+        Ok(())
+    } else {
+        Err(ApiError::Forbidden("이 워크스페이스에서는 조회만 할 수 있습니다".to_string()))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct InvitationConfig {
+    /// 초대 유효 기간
+    pub ttl: Duration,
+    /// 메일 본문에 넣는 초대 페이지 주소. `?token=`을 붙여 보낸다.
+    pub accept_url: String,
+}
+
+/// 워크스페이스, 멤버 역할, 이메일 초대
+pub struct WorkspaceService {
+    workspace_repository: Arc<dyn WorkspaceRepository>,
+    mailer: Arc<dyn Mailer>,
+    config: InvitationConfig,
+}
+
+impl WorkspaceService {
+    pub fn new(workspace_repository: Arc<dyn WorkspaceRepository>, mailer: Arc<dyn Mailer>, config: InvitationConfig) -> Self {
+        Self {
+            workspace_repository,
+            mailer,
+            config,
+        }
+    }
+
+    /// 참여한 워크스페이스 목록 (개인 워크스페이스가 먼저)
+    pub async fn list(&self, user_id: Uuid) -> Result<WorkspaceListResponse, ApiError> {
+        self.workspace_repository.ensure_personal(user_id).await?;
+        let workspaces = self.workspace_repository.find_all(user_id).await?;
+
+        Ok(WorkspaceListResponse {
+            workspaces: workspaces.into_iter().map(WorkspaceResponse::from).collect(),
+        })
+    }
+
+    /// 워크스페이스 상세 조회
+    pub async fn get(&self, user_id: Uuid, id: Uuid) -> Result<WorkspaceResponse, ApiError> {
+        let workspace = self.find_workspace(user_id, id).await?;
+        Ok(WorkspaceResponse::from(workspace))
+    }
+
+    /// 워크스페이스 생성 (만든 사용자가 소유자가 된다)
+    pub async fn create(&self, user_id: Uuid, request: CreateWorkspaceRequest) -> Result<WorkspaceResponse, ApiError> {
+        request.validate()?;
+
+        let new_workspace = NewWorkspace {
+            name: Self::normalize_name(&request.name)?,
+            created_by: user_id,
+        };
+        let workspace = self.workspace_repository.create(new_workspace).await?;
+
+        Ok(WorkspaceResponse::from(workspace))
+    }
+
+    /// 워크스페이스 이름 변경 (소유자)
+    pub async fn rename(&self, user_id: Uuid, id: Uuid, request: UpdateWorkspaceRequest) -> Result<WorkspaceResponse, ApiError> {
+        request.validate()?;
+
+        let name = Self::normalize_name(&request.name)?;
+        let mut workspace = self.find_owned(user_id, id).await?;
+        workspace.workspace = self
+            .workspace_repository
+            .rename(id, &name)
+            .await?
+            .ok_or_else(Self::not_found)?;
+
+        Ok(WorkspaceResponse::from(workspace))
+    }
+
+    /// 워크스페이스 삭제 (소유자). 할일과 카테고리도 함께 삭제된다.
+    pub async fn delete(&self, user_id: Uuid, id: Uuid) -> Result<(), ApiError> {
+        let workspace = self.find_owned(user_id, id).await?;
+        if workspace.workspace.is_personal {
+            return Err(ApiError::BadRequest("개인 워크스페이스는 삭제할 수 없습니다".to_string()));
+        }
+
+        if !self.workspace_repository.delete(id).await? {
+            return Err(Self::not_found());
+        }
+        Ok(())
+    }
+
+    /// 멤버 목록 (멤버라면 누구나)
+    pub async fn list_members(&self, user_id: Uuid, id: Uuid) -> Result<WorkspaceMemberListResponse, ApiError> {
+        self.find_workspace(user_id, id).await?;
+        let members = self.workspace_repository.find_members(id).await?;
+
+        Ok(WorkspaceMemberListResponse {
+            workspace_id: id,
+            members: members.into_iter().map(WorkspaceMemberResponse::from).collect(),
+        })
+    }
+
+    /// 멤버 역할 변경 (소유자). 마지막 소유자는 다른 역할로 바꿀 수 없다.
+    pub async fn update_member_role(
+        &self,
+        user_id: Uuid,
+        id: Uuid,
+        member_id: Uuid,
+        request: UpdateMemberRoleRequest,
+    ) -> Result<WorkspaceMemberResponse, ApiError> {
+        self.find_owned(user_id, id).await?;
+
+        let member = self
+            .workspace_repository
+            .update_member_role(id, member_id, request.role)
+            .await?
+            .ok_or_else(Self::member_not_found)?;
+
+        Ok(WorkspaceMemberResponse::from(member))
+    }
+
+    /// 멤버 내보내기 (소유자) 또는 스스로 나가기. 마지막 소유자는 나갈 수 없다.
+    pub async fn remove_member(&self, user_id: Uuid, id: Uuid, member_id: Uuid) -> Result<(), ApiError> {
+        if member_id == user_id {
+            self.find_workspace(user_id, id).await?;
+        } else {
+            self.find_owned(user_id, id).await?;
+        }
+
+        if !self.workspace_repository.remove_member(id, member_id).await? {
+            return Err(Self::member_not_found());
+        }
+        Ok(())
+    }
+
+    /// 이메일로 초대 (소유자). 같은 주소로 대기 중인 초대는 새 초대로 바뀐다.
+    pub async fn invite(&self, user_id: Uuid, id: Uuid, request: CreateInvitationRequest) -> Result<InvitationResponse, ApiError> {
+        request.validate()?;
+
+        let workspace = self.find_owned(user_id, id).await?;
+        if workspace.workspace.is_personal {
+            return Err(ApiError::BadRequest("개인 워크스페이스에는 멤버를 초대할 수 없습니다".to_string()));
+        }
+        if request.role == WorkspaceRole::Owner {
+            return Err(ApiError::Validation(
+                "초대할 때는 editor 또는 viewer 역할만 지정할 수 있습니다".to_string(),
+            ));
+        }
+        if workspace.member_count >= MAX_WORKSPACE_MEMBERS {
+            return Err(ApiError::Conflict(format!(
+                "워크스페이스 멤버는 최대 {}명까지 둘 수 있습니다",
+                MAX_WORKSPACE_MEMBERS
+            )));
+        }
+
+        let token = generate_token();
+        let new_invitation = NewInvitation {
+            workspace_id: id,
+            email: request.email.trim().to_lowercase(),
+            role: request.role,
+            token_hash: hash_token(&token),
+            invited_by: user_id,
+            expires_at: Utc::now() + self.config.ttl,
+        };
+        let invitation = self.workspace_repository.create_invitation(new_invitation).await?;
+
+        // 토큰은 저장하지 않으므로 메일을 보내지 못하면 초대를 다시 보내야 한다
+        if let Err(err) = self.mailer.send(&self.invitation_email(&invitation, &token)).await {
+            tracing::error!(invitation_id = %invitation.id, %err, "failed to send invitation email");
+            return Err(ApiError::Internal("초대 메일을 보내지 못했습니다. 다시 시도해주세요".to_string()));
+        }
+
+        Ok(InvitationResponse::from(invitation))
+    }
+
+    /// 대기 중인 초대 목록 (소유자)
+    pub async fn list_invitations(&self, user_id: Uuid, id: Uuid) -> Result<InvitationListResponse, ApiError> {
+        self.find_owned(user_id, id).await?;
+        let invitations = self.workspace_repository.find_pending_invitations(id).await?;
+
+        Ok(InvitationListResponse {
+            invitations: invitations.into_iter().map(InvitationResponse::from).collect(),
+        })
+    }
+
+    /// 초대 취소 (소유자)
+    pub async fn revoke_invitation(&self, user_id: Uuid, id: Uuid, invitation_id: Uuid) -> Result<(), ApiError> {
+        self.find_owned(user_id, id).await?;
+
+        if !self.workspace_repository.revoke_invitation(id, invitation_id).await? {
+            return Err(Self::invitation_not_found());
+        }
+        Ok(())
+    }
+
+    /// 초대 수락. 초대받은 이메일로 로그인한 사용자만 수락할 수 있다.
+    pub async fn accept_invitation(&self, user_id: Uuid, email: &str, request: InvitationTokenRequest) -> Result<WorkspaceResponse, ApiError> {
+        request.validate()?;
+
+        let now = Utc::now();
+        let invitation = self.find_open_invitation(&request.token, now).await?;
+        if !invitation.email.eq_ignore_ascii_case(email.trim()) {
+            return Err(ApiError::Forbidden("초대받은 이메일로 로그인해야 수락할 수 있습니다".to_string()));
+        }
+
+        self.workspace_repository
+            .accept_invitation(invitation.id, user_id, now)
+            .await?
+            .ok_or_else(Self::invitation_closed)?;
+
+        self.get(user_id, invitation.workspace_id).await
+    }
+
+    /// 초대 거절. 메일을 받은 사람이면 로그인하지 않아도 거절할 수 있다.
+    pub async fn decline_invitation(&self, request: InvitationTokenRequest) -> Result<(), ApiError> {
+        request.validate()?;
+
+        let now = Utc::now();
+        let invitation = self.find_open_invitation(&request.token, now).await?;
+        if !self.workspace_repository.decline_invitation(invitation.id, now).await? {
+            return Err(Self::invitation_closed());
+        }
+        Ok(())
+    }
+
+    async fn find_workspace(&self, user_id: Uuid, id: Uuid) -> Result<WorkspaceWithRole, ApiError> {
+        self.workspace_repository
+            .find_by_id(user_id, id)
+            .await?
+            .ok_or_else(Self::not_found)
+    }
+
+    /// 소유자가 아니면 403
+    async fn find_owned(&self, user_id: Uuid, id: Uuid) -> Result<WorkspaceWithRole, ApiError> {
+        let workspace = self.find_workspace(user_id, id).await?;
+        if !workspace.role.is_owner() {
+            return Err(ApiError::Forbidden("워크스페이스 소유자만 할 수 있습니다".to_string()));
+        }
+        Ok(workspace)
+    }
+
+    async fn find_open_invitation(&self, token: &str, now: DateTime<Utc>) -> Result<WorkspaceInvitation, ApiError> {
+        let invitation = self
+            .workspace_repository
+            .find_invitation_by_token(&hash_token(token))
+            .await?
+            .ok_or_else(Self::invitation_not_found)?;
+        if !invitation.is_open(now) {
+            return Err(Self::invitation_closed());
+        }
+        Ok(invitation)
+    }
+
+    fn invitation_email(&self, invitation: &WorkspaceInvitation, token: &str) -> Email {
+        Email {
+            to: invitation.email.clone(),
+            subject: format!("'{}' 워크스페이스에 초대되었습니다", invitation.workspace_name),
+            body: format!(
+                "'{}' 워크스페이스에 {} 역할로 초대되었습니다.\n\n아래 링크에서 초대를 수락하거나 거절할 수 있습니다.\n{}?token={}\n\n이 초대는 {}까지 유효합니다.",
+                invitation.workspace_name,
+                invitation.role.as_str(),
+                self.config.accept_url,
+                token,
+                invitation.expires_at.format("%Y-%m-%d %H:%M UTC"),
+            ),
+        }
+    }
+
+    fn normalize_name(name: &str) -> Result<String, ApiError> {
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(ApiError::Validation("워크스페이스 이름을 입력해주세요".to_string()));
+        }
+        Ok(name.to_string())
+    }
+
+    fn not_found() -> ApiError {
+        ApiError::NotFound("워크스페이스를 찾을 수 없습니다".to_string())
+    }
+
+    fn member_not_found() -> ApiError {
+        ApiError::NotFound("워크스페이스 멤버를 찾을 수 없습니다".to_string())
+    }
+
+    fn invitation_not_found() -> ApiError {
+        ApiError::NotFound("초대를 찾을 수 없습니다".to_string())
+    }
+
+    fn invitation_closed() -> ApiError {
+        ApiError::Conflict("이미 응답했거나 만료된 초대입니다".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entities::workspace::{InvitationStatus, Workspace, WorkspaceMember};
+    use crate::notifications::tests::MockMailer;
+    use crate::repositories::todo_repository::tests::MockTodoRepository;
+    use crate::repositories::workspace_repository::tests::MockWorkspaceRepository;
+
+    fn config() -> InvitationConfig {
+        InvitationConfig {
+            ttl: Duration::days(7),
+            accept_url: "https://todo.example.com/invitations".to_string(),
+        }
+    }
+
+    fn workspace(id: Uuid, role: WorkspaceRole, is_personal: bool) -> WorkspaceWithRole {
+        let now = Utc::now();
+        WorkspaceWithRole {
+            workspace: Workspace {
+                id,
+                name: "디자인팀".to_string(),
+                is_personal,
+                created_by: Uuid::new_v4(),
+                created_at: now,
+                updated_at: now,
+            },
+            role,
+            member_count: 2,
+        }
+    }
+
+    fn invitation(workspace_id: Uuid, email: &str, expires_at: DateTime<Utc>) -> WorkspaceInvitation {
+        WorkspaceInvitation {
+            id: Uuid::new_v4(),
+            workspace_id,
+            workspace_name: "디자인팀".to_string(),
+            email: email.to_string(),
+            role: WorkspaceRole::Editor,
+            invited_by: Uuid::new_v4(),
+            status: InvitationStatus::Pending,
+            expires_at,
+            responded_at: None,
+            created_at: Utc::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_authorize_todo_edit_rejects_viewer() {
+        let mut todo_repo = MockTodoRepository::new();
+        todo_repo
+            .expect_find_role()
+            .returning(|_, _| Ok(Some(WorkspaceRole::Viewer)));
+
+        let result = authorize_todo_edit(&todo_repo, Uuid::new_v4(), Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(ApiError::Forbidden(_))));
+    }
+
+    #[tokio::test]
+    async fn test_invite_stores_token_hash_and_mails_token() {
+        let (user_id, workspace_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut repo = MockWorkspaceRepository::new();
+        repo.expect_find_by_id()
+            .returning(move |_, id| Ok(Some(workspace(id, WorkspaceRole::Owner, false))));
+        repo.expect_create_invitation()
+            .withf(|new| new.email == "friend@example.com" && new.token_hash.len() == 64)
+            .returning(|new| Ok(invitation(new.workspace_id, &new.email, new.expires_at)));
+
+        let mut mailer = MockMailer::new();
+        mailer
+            .expect_send()
+            .withf(|email| {
+                let token = email.body.split("?token=").nth(1).and_then(|rest| rest.lines().next()).unwrap_or("");
+                email.to == "friend@example.com" && token.len() == 64
+            })
+            .times(1)
+            .returning(|_| Ok(()));
+
+        let service = WorkspaceService::new(Arc::new(repo), Arc::new(mailer), config());
+        let request = CreateInvitationRequest {
+            email: "Friend@Example.com".to_string(),
+            role: WorkspaceRole::Editor,
+        };
+
+        let response = service.invite(user_id, workspace_id, request).await.unwrap();
+
+        assert_eq!(response.workspace_id, workspace_id);
+        assert_eq!(response.status, InvitationStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn test_editor_cannot_invite_and_personal_workspace_cannot_be_shared() {
+        let (team_id, personal_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let mut repo = MockWorkspaceRepository::new();
+        repo.expect_find_by_id().returning(move |_, id| {
+            Ok(Some(match id == team_id {
+                true => workspace(id, WorkspaceRole::Editor, false),
+                false => workspace(id, WorkspaceRole::Owner, true),
+            }))
+        });
+        repo.expect_create_invitation().never();
+
+        let service = WorkspaceService::new(Arc::new(repo), Arc::new(MockMailer::new()), config());
+        let request = CreateInvitationRequest {
+            email: "friend@example.com".to_string(),
+            role: WorkspaceRole::Viewer,
+        };
+
+        let as_editor = service.invite(Uuid::new_v4(), team_id, request.clone()).await;
+        let into_personal = service.invite(Uuid::new_v4(), personal_id, request).await;
+
+        assert!(matches!(as_editor, Err(ApiError::Forbidden(_))));
+        assert!(matches!(into_personal, Err(ApiError::BadRequest(_))));
+    }
+
+    #[tokio::test]
+    async fn test_accept_requires_matching_email_and_open_invitation() {
+        let (user_id, workspace_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let token = generate_token();
+        let expected_hash = hash_token(&token);
+
+        let mut repo = MockWorkspaceRepository::new();
+        repo.expect_find_invitation_by_token()
+            .withf(move |hash| hash == expected_hash)
+            .returning(move |_| Ok(Some(invitation(workspace_id, "friend@example.com", Utc::now() + Duration::days(1)))));
+        repo.expect_accept_invitation()
+            .times(1)
+            .returning(move |_, user_id, _| {
+                Ok(Some(WorkspaceMember {
+                    workspace_id,
+                    user_id,
+                    username: Some("friend".to_string()),
+                    email: Some("friend@example.com".to_string()),
+                    role: WorkspaceRole::Editor,
+                    created_at: Utc::now(),
+                }))
+            });
+        repo.expect_find_by_id()
+            .returning(|_, id| Ok(Some(workspace(id, WorkspaceRole::Editor, false))));
+
+        let service = WorkspaceService::new(Arc::new(repo), Arc::new(MockMailer::new()), config());
+
+        let wrong_account = service
+            .accept_invitation(user_id, "someone@example.com", InvitationTokenRequest { token: token.clone() })
+            .await;
+        let accepted = service
+            .accept_invitation(user_id, "Friend@example.com", InvitationTokenRequest { token })
+            .await
+            .unwrap();
+
+        assert!(matches!(wrong_account, Err(ApiError::Forbidden(_))));
+        assert_eq!(accepted.id, workspace_id);
+        assert_eq!(accepted.role, WorkspaceRole::Editor);
+    }
+
+    #[tokio::test]
+    async fn test_expired_invitation_cannot_be_declined() {
+        let mut repo = MockWorkspaceRepository::new();
+        repo.expect_find_invitation_by_token()
+            .returning(|_| Ok(Some(invitation(Uuid::new_v4(), "friend@example.com", Utc::now() - Duration::hours(1)))));
+        repo.expect_decline_invitation().never();
+
+        let service = WorkspaceService::new(Arc::new(repo), Arc::new(MockMailer::new()), config());
+        let result = service
+            .decline_invitation(InvitationTokenRequest { token: generate_token() })
+            .await;
+
+        assert!(matches!(result, Err(ApiError::Conflict(_))));
+    }
+}
@@ -0,0 +1,99 @@
+use std::io;
+use std::path::PathBuf;
+use async_trait::async_trait;
+use futures_util::StreamExt;
+use tokio_util::io::ReaderStream;
+use uuid::Uuid;
+use super::{validate_key, BlobBody, BlobStore, SpooledBlob, StorageError};
+
+/// `root` 디렉터리 아래에 키를 상대 경로로 삼아 저장한다
+#[derive(Debug, Clone)]
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path(&self, key: &str) -> Result<PathBuf, StorageError> {
+        validate_key(key)?;
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    async fn put(&self, key: &str, blob: &SpooledBlob) -> Result<(), StorageError> {
+        let path = self.path(key)?;
+        let dir = path.parent().unwrap_or(&self.root);
+        tokio::fs::create_dir_all(dir).await?;
+
+        // 같은 파일 시스템이면 하드 링크로 복사 없이 옮기고, 아니면 복사한다.
+        // 어느 쪽이든 임시 이름으로 만든 뒤 바꿔서 읽는 쪽이 덜 쓴 파일을 보지 않게 한다.
+        let staging = dir.join(format!(".{}.tmp", Uuid::new_v4()));
+        if tokio::fs::hard_link(&blob.path, &staging).await.is_err() {
+            tokio::fs::copy(&blob.path, &staging).await?;
+        }
+        if let Err(err) = tokio::fs::rename(&staging, &path).await {
+            let _ = tokio::fs::remove_file(&staging).await;
+            return Err(err.into());
+        }
+
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> Result<Option<BlobBody>, StorageError> {
+        let path = self.path(key)?;
+        let file = match tokio::fs::File::open(&path).await {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+        let size = file.metadata().await?.len();
+
+        Ok(Some(BlobBody {
+            size: Some(size),
+            stream: ReaderStream::new(file).boxed(),
+        }))
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), StorageError> {
+        let path = self.path(key)?;
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => Ok(()),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytes::Bytes;
+    use futures_util::stream;
+    use crate::storage::spool;
+
+    #[tokio::test]
+    async fn test_put_get_delete_round_trip() {
+        let root = std::env::temp_dir().join(format!("blob-test-{}", Uuid::new_v4()));
+        let store = LocalBlobStore::new(&root);
+        let chunks = stream::iter(vec![Ok::<_, io::Error>(Bytes::from_static(b"receipt"))]);
+        let blob = spool(&root.join("spool"), chunks, 1024).await.unwrap();
+
+        store.put("attachments/a1/b2", &blob).await.unwrap();
+        drop(blob);
+
+        let body = store.get("attachments/a1/b2").await.unwrap().unwrap();
+        assert_eq!(body.size, Some(7));
+        let content: Vec<Bytes> = body.stream.map(Result::unwrap).collect().await;
+        assert_eq!(content.concat(), b"receipt");
+
+        store.delete("attachments/a1/b2").await.unwrap();
+        store.delete("attachments/a1/b2").await.unwrap();
+        assert!(store.get("attachments/a1/b2").await.unwrap().is_none());
+        assert!(store.get("../outside").await.is_err());
+    }
+}
@@ -0,0 +1,194 @@
+//! Storage module
+//!
+//! Stores attachment contents through a pluggable [`BlobStore`].
+//! The local filesystem backend is the default; an S3-compatible backend
+//! (AWS S3, MinIO, ...) can be selected in `main.rs` via configuration.
+
+pub mod local;
+pub mod s3;
+
+use std::io;
+use std::path::{Path, PathBuf};
+use async_trait::async_trait;
+use bytes::Bytes;
+use futures_util::stream::BoxStream;
+use futures_util::{Stream, StreamExt};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+use tokio::io::AsyncWriteExt;
+use uuid::Uuid;
+
+pub use local::LocalBlobStore;
+pub use s3::{S3BlobStore, S3Config};
+
+/// 형식 판별에 쓰는 앞부분 길이
+pub const SNIFF_LEN: usize = 512;
+
+#[derive(Error, Debug)]
+pub enum StorageError {
+    #[error("파일 저장소 입출력 오류: {0}")]
+    Io(#[from] io::Error),
+    #[error("파일 저장소 요청 실패: {0}")]
+    Backend(String),
+    #[error("잘못된 저장소 키입니다: {0}")]
+    InvalidKey(String),
+}
+
+/// 업로드를 임시 파일로 받는 중 생긴 오류
+#[derive(Error, Debug)]
+pub enum SpoolError {
+    #[error("파일은 최대 {limit}바이트까지 올릴 수 있습니다")]
+    TooLarge { limit: u64 },
+    #[error("빈 파일은 올릴 수 없습니다")]
+    Empty,
+    #[error("업로드를 읽지 못했습니다: {0}")]
+    Read(String),
+    #[error("임시 파일 오류: {0}")]
+    Io(#[from] io::Error),
+}
+
+/// 저장소에서 읽은 내용
+pub struct BlobBody {
+    pub size: Option<u64>,
+    pub stream: BoxStream<'static, io::Result<Bytes>>,
+}
+
+/// 업로드를 끝까지 받아 둔 임시 파일. 크기와 SHA-256을 알고 있어 저장소에 그대로 넘길 수 있다.
+/// 값이 사라지면 임시 파일도 지운다.
+#[derive(Debug)]
+pub struct SpooledBlob {
+    pub path: PathBuf,
+    pub size: u64,
+    /// 소문자 16진수
+    pub sha256: String,
+    /// 앞부분 최대 [`SNIFF_LEN`]바이트 (형식 판별용)
+    pub head: Vec<u8>,
+}
+
+impl Drop for SpooledBlob {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// 스트림을 `dir` 아래 임시 파일로 받는다. 메모리에는 조각 하나만 올라가며,
+/// `max_bytes`를 넘는 순간 중단하고 임시 파일을 지운다.
+pub async fn spool<S, E>(dir: &Path, mut stream: S, max_bytes: u64) -> Result<SpooledBlob, SpoolError>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+    E: std::fmt::Display,
+{
+    tokio::fs::create_dir_all(dir).await?;
+    let path = dir.join(format!("upload-{}.part", Uuid::new_v4()));
+    let mut file = tokio::fs::File::create(&path).await?;
+    // 중간에 실패해도 Drop에서 임시 파일을 지운다
+    let mut blob = SpooledBlob {
+        path,
+        size: 0,
+        sha256: String::new(),
+        head: Vec::new(),
+    };
+
+    let mut hasher = Sha256::new();
+    while let Some(chunk) = stream.next().await {
+        let chunk = chunk.map_err(|err| SpoolError::Read(err.to_string()))?;
+        blob.size += chunk.len() as u64;
+        if blob.size > max_bytes {
+            return Err(SpoolError::TooLarge { limit: max_bytes });
+        }
+        if blob.head.len() < SNIFF_LEN {
+            let take = (SNIFF_LEN - blob.head.len()).min(chunk.len());
+            blob.head.extend_from_slice(&chunk[..take]);
+        }
+        hasher.update(&chunk);
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+    file.sync_all().await?;
+
+    if blob.size == 0 {
+        return Err(SpoolError::Empty);
+    }
+    blob.sha256 = hex::encode(hasher.finalize());
+
+    Ok(blob)
+}
+
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// 임시 파일의 내용을 `key`에 저장한다 (같은 키가 있으면 덮어쓴다)
+    async fn put(&self, key: &str, blob: &SpooledBlob) -> Result<(), StorageError>;
+    /// 없으면 `None`
+    async fn get(&self, key: &str) -> Result<Option<BlobBody>, StorageError>;
+    /// 없는 키를 지워도 성공으로 본다
+    async fn delete(&self, key: &str) -> Result<(), StorageError>;
+}
+
+/// 저장소 키는 내부에서 만든 `a-z`, `0-9`, `-`, `/` 조합만 쓴다. 경로 이탈과 URL 인코딩 문제를 막는다.
+pub(crate) fn validate_key(key: &str) -> Result<(), StorageError> {
+    let valid = !key.is_empty()
+        && key.split('/').all(|segment| {
+            !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        });
+    if valid {
+        Ok(())
+    } else {
+        Err(StorageError::InvalidKey(key.to_string()))
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use futures_util::stream;
+    use mockall::mock;
+
+    mock! {
+        pub BlobStore {}
+
+        #[async_trait]
+        impl BlobStore for BlobStore {
+            async fn put(&self, key: &str, blob: &SpooledBlob) -> Result<(), StorageError>;
+            async fn get(&self, key: &str) -> Result<Option<BlobBody>, StorageError>;
+            async fn delete(&self, key: &str) -> Result<(), StorageError>;
+        }
+    }
+
+    fn chunks(parts: &[&'static [u8]]) -> impl Stream<Item = Result<Bytes, io::Error>> + Unpin {
+        stream::iter(parts.iter().map(|part| Ok(Bytes::from_static(part))).collect::<Vec<_>>())
+    }
+
+    #[tokio::test]
+    async fn test_spool_hashes_and_keeps_head() {
+        let dir = std::env::temp_dir().join(format!("spool-test-{}", Uuid::new_v4()));
+        let blob = spool(&dir, chunks(&[b"hello ", b"world"]), 100).await.unwrap();
+
+        assert_eq!(blob.size, 11);
+        assert_eq!(blob.head, b"hello world");
+        assert_eq!(blob.sha256, "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9");
+        assert_eq!(std::fs::read(&blob.path).unwrap(), b"hello world");
+
+        let path = blob.path.clone();
+        drop(blob);
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_spool_stops_at_limit_and_removes_file() {
+        let dir = std::env::temp_dir().join(format!("spool-test-{}", Uuid::new_v4()));
+
+        let result = spool(&dir, chunks(&[b"12345", b"67890"]), 8).await;
+        assert!(matches!(result, Err(SpoolError::TooLarge { limit: 8 })));
+        assert!(matches!(spool(&dir, chunks(&[]), 8).await, Err(SpoolError::Empty)));
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn test_validate_key() {
+        assert!(validate_key("attachments/2f1c/9a7e-11").is_ok());
+        assert!(validate_key("../etc/passwd").is_err());
+        assert!(validate_key("a//b").is_err());
+        assert!(validate_key("A/b").is_err());
+        assert!(validate_key("").is_err());
+    }
+}
@@ -0,0 +1,25 @@
+//! 컬렉션 도우미
+//!
+//! 요청으로 받은 필터 값 목록 등을 저장하기 전에 정리한다.
+
+/// 순서를 유지하며 중복 제거
+pub fn unique<T: PartialEq>(values: Vec<T>) -> Vec<T> {
+    let mut unique = Vec::with_capacity(values.len());
+    for value in values {
+        if !unique.contains(&value) {
+            unique.push(value);
+        }
+    }
+    unique
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unique_keeps_first_occurrence_order() {
+        assert_eq!(unique(vec![3, 1, 3, 2, 1]), vec![3, 1, 2]);
+        assert_eq!(unique(Vec::<i32>::new()), Vec::<i32>::new());
+    }
+}
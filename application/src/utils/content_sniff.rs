@@ -0,0 +1,104 @@
+//! 업로드 파일의 앞부분으로 실제 형식을 판별한다.
+//!
+//! 클라이언트가 보낸 Content-Type은 믿지 않는다. 허용한 형식(이미지, PDF, 텍스트)의
+//! 시그니처와 맞지 않으면 받지 않는다.
+
+/// 판별할 수 있는 형식
+pub const ALLOWED_CONTENT_TYPES: &[&str] = &[
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "image/heic",
+    "application/pdf",
+    "text/plain",
+    "text/csv",
+    "text/markdown",
+];
+
+/// 텍스트로 판별되면 클라이언트가 밝힌 형식 중 이것들은 그대로 쓴다
+const TEXT_SUBTYPES: &[&str] = &["text/plain", "text/csv", "text/markdown"];
+
+/// `head`는 파일의 앞부분, `truncated`는 파일이 `head`보다 긴지 여부.
+/// 허용하지 않는 형식이면 `None`.
+pub fn sniff(head: &[u8], truncated: bool, declared: Option<&str>) -> Option<&'static str> {
+    if let Some(content_type) = sniff_binary(head) {
+        return Some(content_type);
+    }
+    if !is_text(head, truncated) {
+        return None;
+    }
+
+    let declared = declared.map(|value| value.split(';').next().unwrap_or_default().trim().to_ascii_lowercase());
+    let subtype = TEXT_SUBTYPES
+        .iter()
+        .find(|subtype| declared.as_deref() == Some(**subtype))
+        .copied();
+    Some(subtype.unwrap_or("text/plain"))
+}
+
+/// 브라우저에서 바로 열어도 안전한 형식 (그 밖에는 내려받기로 응답한다)
+pub fn is_inline_safe(content_type: &str) -> bool {
+    content_type.starts_with("image/") || content_type == "application/pdf"
+}
+
+fn sniff_binary(head: &[u8]) -> Option<&'static str> {
+    let content_type = if head.starts_with(b"\x89PNG\r\n\x1a\n") {
+        "image/png"
+    } else if head.starts_with(&[0xFF, 0xD8, 0xFF]) {
+        "image/jpeg"
+    } else if head.starts_with(b"GIF87a") || head.starts_with(b"GIF89a") {
+        "image/gif"
+    } else if head.len() >= 12 && &head[..4] == b"RIFF" && &head[8..12] == b"WEBP" {
+        "image/webp"
+    } else if head.len() >= 12 && &head[4..8] == b"ftyp" && matches!(&head[8..12], b"heic" | b"heix" | b"mif1" | b"msf1") {
+        "image/heic"
+    } else if head.starts_with(b"%PDF-") {
+        "application/pdf"
+    } else {
+        return None;
+    };
+
+    Some(content_type)
+}
+
+/// UTF-8 텍스트인지 여부. 잘린 앞부분이면 마지막 글자가 잘린 것은 허용한다.
+fn is_text(head: &[u8], truncated: bool) -> bool {
+    let text = match std::str::from_utf8(head) {
+        Ok(text) => text,
+        // 잘린 글자는 최대 3바이트
+        Err(err) if truncated && err.error_len().is_none() && head.len() - err.valid_up_to() < 4 => {
+            std::str::from_utf8(&head[..err.valid_up_to()]).unwrap_or_default()
+        }
+        Err(_) => return false,
+    };
+
+    !text.chars().any(|c| c.is_control() && !matches!(c, '\t' | '\n' | '\r' | '\u{c}'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sniff_uses_signature_over_declared_type() {
+        assert_eq!(sniff(b"\x89PNG\r\n\x1a\n....", false, Some("text/plain")), Some("image/png"));
+        assert_eq!(sniff(b"%PDF-1.7\n", false, None), Some("application/pdf"));
+        assert_eq!(sniff(b"RIFF\x00\x00\x00\x00WEBPVP8 ", false, None), Some("image/webp"));
+        assert_eq!(sniff(b"\x00\x00\x00\x18ftypheic", false, None), Some("image/heic"));
+        assert_eq!(sniff(b"MZ\x90\x00\x03\x00", false, Some("image/png")), None);
+    }
+
+    #[test]
+    fn test_sniff_text() {
+        assert_eq!(sniff("영수증 합계: 12,000원\n".as_bytes(), false, None), Some("text/plain"));
+        assert_eq!(sniff(b"a,b\n1,2\n", false, Some("text/csv; charset=utf-8")), Some("text/csv"));
+        // 텍스트로 보이더라도 HTML로는 받지 않는다
+        assert_eq!(sniff(b"<html></html>", false, Some("text/html")), Some("text/plain"));
+
+        let cut = &"가나".as_bytes()[..4];
+        assert_eq!(sniff(cut, true, None), Some("text/plain"));
+        assert_eq!(sniff(cut, false, None), None);
+        assert_eq!(sniff(b"abc\x00def", false, None), None);
+    }
+}
@@ -0,0 +1,169 @@
+//! Fractional indexing for manual ordering
+//!
+//! 정렬 키는 base62 숫자(`0-9A-Za-z`)로 쓴 0과 1 사이의 소수부다. 두 키 사이에는 항상
+//! 새 키를 만들 수 있으므로, 항목 하나를 옮길 때 그 항목의 키만 바꾸면 된다.
+//! 바이트 순서로 비교하므로 DB 컬럼은 `COLLATE "C"`여야 한다.
+//!
+//! 같은 자리에 여러 번 끼워 넣으면 키가 길어지므로, 길어진 키는 [`spaced_keys`]로 재배치한다.
+
+const DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const BASE: usize = 62;
+
+fn digit_value(c: u8) -> Option<usize> {
+    DIGITS.iter().position(|&d| d == c)
+}
+
+/// 키 형식 검증: 비어 있지 않고, base62 숫자만 쓰며, `0`으로 끝나지 않아야 한다
+/// (`V0`과 `V`는 같은 값이므로 끝자리 0을 허용하면 두 키 사이에 끼워 넣을 수 없다).
+pub fn validate_key(key: &str) -> Result<(), String> {
+    if key.is_empty() {
+        return Err("정렬 키가 비어 있습니다".to_string());
+    }
+    if !key.bytes().all(|c| digit_value(c).is_some()) {
+        return Err(format!("정렬 키에 허용되지 않는 문자가 있습니다: {}", key));
+    }
+    if key.ends_with('0') {
+        return Err(format!("정렬 키는 0으로 끝날 수 없습니다: {}", key));
+    }
+    Ok(())
+}
+
+/// `lower`와 `upper` 사이에 정렬되는 새 키. `None`은 목록의 맨 앞/맨 뒤를 뜻한다.
+pub fn key_between(lower: Option<&str>, upper: Option<&str>) -> Result<String, String> {
+    for key in [lower, upper].into_iter().flatten() {
+        validate_key(key)?;
+    }
+    if let (Some(lower), Some(upper)) = (lower, upper) {
+        if lower >= upper {
+            return Err(format!("정렬 키 순서가 올바르지 않습니다: {} >= {}", lower, upper));
+        }
+    }
+
+    Ok(midpoint(lower.unwrap_or("").as_bytes(), upper.map(str::as_bytes)))
+}
+
+/// `a < b`인 두 소수부 사이의 가장 짧은 키 (`b`가 `None`이면 1)
+fn midpoint(a: &[u8], b: Option<&[u8]>) -> String {
+    if let Some(b) = b {
+        // 공통 접두사(짧은 쪽은 0으로 채워 비교)는 그대로 두고 나머지에서 중간값을 찾는다
+        let common = b
+            .iter()
+            .enumerate()
+            .take_while(|&(i, &digit)| a.get(i).copied().unwrap_or(DIGITS[0]) == digit)
+            .count();
+        if common > 0 {
+            let rest = midpoint(a.get(common..).unwrap_or(&[]), Some(&b[common..]));
+            return format!("{}{}", String::from_utf8_lossy(&b[..common]), rest);
+        }
+    }
+
+    let digit_a = a.first().and_then(|&c| digit_value(c)).unwrap_or(0);
+    let digit_b = b.and_then(|b| b.first()).and_then(|&c| digit_value(c)).unwrap_or(BASE);
+
+    if digit_b - digit_a > 1 {
+        char::from(DIGITS[(digit_a + digit_b).div_ceil(2)]).to_string()
+    } else if let Some(b) = b.filter(|b| b.len() > 1) {
+        // b의 첫 자리만 남기면 a보다 크고 b보다 작다
+        char::from(b[0]).to_string()
+    } else {
+        format!("{}{}", char::from(DIGITS[digit_a]), midpoint(a.get(1..).unwrap_or(&[]), None))
+    }
+}
+
+/// 같은 길이의 키 `count`개를 고르게 나눠 만든다 (재배치용)
+pub fn spaced_keys(count: usize) -> Vec<String> {
+    let mut width = 1;
+    let mut span = BASE;
+    while span <= count {
+        width += 1;
+        span *= BASE;
+    }
+
+    (1..=count)
+        .map(|i| {
+            let mut value = i * span / (count + 1);
+            let mut digits = vec![DIGITS[0]; width];
+            for slot in digits.iter_mut().rev() {
+                *slot = DIGITS[value % BASE];
+                value /= BASE;
+            }
+            let key = String::from_utf8_lossy(&digits).into_owned();
+            key.trim_end_matches('0').to_string()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn between(lower: Option<&str>, upper: Option<&str>) -> String {
+        let key = key_between(lower, upper).unwrap();
+        validate_key(&key).unwrap();
+        if let Some(lower) = lower {
+            assert!(lower < key.as_str(), "{} < {}", lower, key);
+        }
+        if let Some(upper) = upper {
+            assert!(key.as_str() < upper, "{} < {}", key, upper);
+        }
+        key
+    }
+
+    #[test]
+    fn test_first_key_is_middle_digit() {
+        assert_eq!(between(None, None), "V");
+        assert_eq!(between(Some("V"), None), "l");
+        assert_eq!(between(None, Some("V")), "G");
+    }
+
+    #[test]
+    fn test_adjacent_digits_extend_key() {
+        assert_eq!(between(Some("a"), Some("b")), "aV");
+        assert_eq!(between(Some("a"), Some("b1")), "b");
+        assert_eq!(between(Some("az"), Some("b")), "azV");
+        assert_eq!(between(Some("z"), None), "zV");
+        assert_eq!(between(None, Some("01")), "00V");
+    }
+
+    #[test]
+    fn test_repeated_inserts_stay_ordered() {
+        // 맨 앞, 맨 뒤, 같은 자리 사이에 반복해서 끼워 넣어도 순서가 유지된다
+        let mut keys = vec![between(None, None)];
+        for i in 0..300 {
+            match i % 3 {
+                0 => keys.insert(0, between(None, Some(&keys[0]))),
+                1 => keys.push(between(keys.last().map(String::as_str), None)),
+                _ => {
+                    let key = between(Some(&keys[0]), Some(&keys[1]));
+                    keys.insert(1, key);
+                }
+            }
+        }
+
+        let mut sorted = keys.clone();
+        sorted.sort();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn test_rejects_invalid_keys() {
+        assert!(key_between(Some("b"), Some("a")).is_err());
+        assert!(key_between(Some("a"), Some("a")).is_err());
+        assert!(key_between(Some("a0"), None).is_err());
+        assert!(key_between(None, Some("a-b")).is_err());
+        assert!(key_between(Some(""), None).is_err());
+    }
+
+    #[test]
+    fn test_spaced_keys_are_short_and_ordered() {
+        assert!(spaced_keys(0).is_empty());
+        assert_eq!(spaced_keys(1), vec!["V"]);
+
+        for count in [2, 61, 62, 500, 5000] {
+            let keys = spaced_keys(count);
+            assert_eq!(keys.len(), count);
+            assert!(keys.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(keys.iter().all(|key| validate_key(key).is_ok() && key.len() <= 3));
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Search result highlighting
+//!
+//! 검색어와 일치하는 부분을 `<mark>`로 감싼 HTML 스니펫을 만든다.
+//! 원문은 항상 HTML 이스케이프되며, 위치 계산은 바이트가 아닌 문자 단위로 한다.
+
+pub const MARK_OPEN: &str = "<mark>";
+pub const MARK_CLOSE: &str = "</mark>";
+const ELLIPSIS: char = '…';
+
+/// HTML 특수 문자를 이스케이프한다
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        push_escaped(&mut escaped, c);
+    }
+    escaped
+}
+
+/// `text`에서 `terms`(대소문자 무시)와 일치하는 부분을 강조한 스니펫을 만든다.
+///
+/// 일치하는 부분이 없으면 `None`. 원문이 `max_chars`보다 길면 첫 일치 위치 주변만 잘라
+/// 앞뒤에 `…`을 붙인다.
+pub fn highlight(text: &str, terms: &[String], max_chars: usize) -> Option<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let folded: Vec<char> = chars.iter().map(|&c| fold(c)).collect();
+
+    let mut marked = vec![false; chars.len()];
+    for term in terms {
+        let needle: Vec<char> = term.chars().map(fold).collect();
+        if needle.is_empty() || needle.len() > folded.len() {
+            continue;
+        }
+        let mut start = 0;
+        while start + needle.len() <= folded.len() {
+            if folded[start..start + needle.len()] == needle[..] {
+                marked[start..start + needle.len()].iter_mut().for_each(|m| *m = true);
+                start += needle.len();
+            } else {
+                start += 1;
+            }
+        }
+    }
+
+    let first = marked.iter().position(|&m| m)?;
+    let (start, end) = window(chars.len(), first, max_chars);
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push(ELLIPSIS);
+    }
+    let mut open = false;
+    for index in start..end {
+        if marked[index] != open {
+            snippet.push_str(if open { MARK_CLOSE } else { MARK_OPEN });
+            open = marked[index];
+        }
+        push_escaped(&mut snippet, chars[index]);
+    }
+    if open {
+        snippet.push_str(MARK_CLOSE);
+    }
+    if end < chars.len() {
+        snippet.push(ELLIPSIS);
+    }
+
+    Some(snippet)
+}
+
+/// 첫 일치 위치가 앞쪽 1/3 지점에 오도록 `[start, end)` 범위를 정한다
+fn window(len: usize, first: usize, max_chars: usize) -> (usize, usize) {
+    if len <= max_chars {
+        return (0, len);
+    }
+    let start = first.saturating_sub(max_chars / 3).min(len - max_chars);
+    (start, start + max_chars)
+}
+
+/// 대소문자 비교용 문자. 소문자 변환 결과가 여러 글자인 경우 첫 글자만 써서 위치를 유지한다.
+fn fold(c: char) -> char {
+    c.to_lowercase().next().unwrap_or(c)
+}
+
+fn push_escaped(out: &mut String, c: char) {
+    match c {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        '"' => out.push_str("&quot;"),
+        '\'' => out.push_str("&#39;"),
+        _ => out.push(c),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn terms(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn test_highlight_korean_terms() {
+        let snippet = highlight("주간 회의 준비물 챙기기", &terms(&["회의", "준비"]), 100).unwrap();
+        assert_eq!(snippet, "주간 <mark>회의</mark> <mark>준비</mark>물 챙기기");
+    }
+
+    #[test]
+    fn test_highlight_is_case_insensitive_and_merges_adjacent() {
+        let snippet = highlight("Weekly SYNC meeting", &terms(&["sync", " meet"]), 100).unwrap();
+        assert_eq!(snippet, "Weekly <mark>SYNC meet</mark>ing");
+    }
+
+    #[test]
+    fn test_highlight_escapes_html() {
+        let snippet = highlight("<b>장보기</b> & 정리", &terms(&["장보기"]), 100).unwrap();
+        assert_eq!(snippet, "&lt;b&gt;<mark>장보기</mark>&lt;/b&gt; &amp; 정리");
+    }
+
+    #[test]
+    fn test_highlight_windows_long_text() {
+        let text = format!("{}장보기{}", "가".repeat(50), "나".repeat(50));
+        let snippet = highlight(&text, &terms(&["장보기"]), 12).unwrap();
+
+        assert_eq!(snippet, "…가가가가<mark>장보기</mark>나나나나나…");
+    }
+
+    #[test]
+    fn test_highlight_without_match() {
+        assert!(highlight("장보기", &terms(&["회의"]), 100).is_none());
+        assert!(highlight("", &terms(&["회의"]), 100).is_none());
+    }
+}
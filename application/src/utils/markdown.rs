@@ -0,0 +1,108 @@
+//! 댓글용 마크다운 렌더링
+//!
+//! CommonMark(+ 표, 취소선)를 HTML로 바꾼 뒤 ammonia로 허용 목록 밖의 태그와 속성을 걷어낸다.
+//! 원문에 쓴 HTML은 실행하지 않고 글자 그대로 보여 준다.
+
+use std::sync::LazyLock;
+use ammonia::Builder;
+use pulldown_cmark::{html, Event, Options, Parser, Tag, TagEnd};
+use regex::Regex;
+use crate::utils::validation::is_valid_username;
+
+/// `@username` 후보. 이메일 주소(`a@b.com`)의 `@`는 앞 글자로 걸러낸다.
+static MENTION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:^|[^A-Za-z0-9_.@])@([A-Za-z0-9_]+)").expect("Invalid mention regex"));
+
+static SANITIZER: LazyLock<Builder<'static>> = LazyLock::new(|| {
+    let mut builder = Builder::default();
+    // 외부 이미지는 열람 추적에 쓰일 수 있어 허용하지 않는다
+    builder
+        .rm_tags(std::iter::once("img"))
+        .link_rel(Some("noopener noreferrer nofollow"));
+    builder
+});
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedMarkdown {
+    /// 정제한 HTML
+    pub html: String,
+    /// 본문에서 언급한 사용자 이름 (처음 나온 순서, 중복 제거). 코드 안의 `@`는 제외한다.
+    pub mentions: Vec<String>,
+}
+
+pub fn render(source: &str) -> RenderedMarkdown {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+
+    let mut mentions = Vec::new();
+    let mut in_code_block = false;
+    // 파서가 `_` 등에서 텍스트를 나누므로 이어진 텍스트를 모아 한 번에 찾는다
+    let mut text_run = String::new();
+
+    let events: Vec<Event> = Parser::new_ext(source, options)
+        .map(|event| match event {
+            // 원문 HTML은 글자로 취급한다
+            Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(raw),
+            other => other,
+        })
+        .inspect(|event| match event {
+            Event::Text(text) if !in_code_block => text_run.push_str(text),
+            _ => {
+                collect_mentions(&text_run, &mut mentions);
+                text_run.clear();
+                match event {
+                    Event::Start(Tag::CodeBlock(_)) => in_code_block = true,
+                    Event::End(TagEnd::CodeBlock) => in_code_block = false,
+                    _ => {}
+                }
+            }
+        })
+        .collect();
+    collect_mentions(&text_run, &mut mentions);
+
+    let mut unsafe_html = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, events.into_iter());
+
+    RenderedMarkdown {
+        html: SANITIZER.clean(&unsafe_html).to_string(),
+        mentions,
+    }
+}
+
+fn collect_mentions(text: &str, mentions: &mut Vec<String>) {
+    for captures in MENTION.captures_iter(text) {
+        let username = &captures[1];
+        if is_valid_username(username) && !mentions.iter().any(|known| known == username) {
+            mentions.push(username.to_string());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_sanitizes_html() {
+        let rendered = render("**굵게** [링크](https://example.com) <script>alert(1)</script>");
+        assert_eq!(
+            rendered.html,
+            "<p><strong>굵게</strong> <a href=\"https://example.com\" rel=\"noopener noreferrer nofollow\">링크</a> \
+             &lt;script&gt;alert(1)&lt;/script&gt;</p>\n"
+        );
+
+        let rendered = render("[x](javascript:alert(1)) ![추적](https://t.example/p.gif)");
+        assert!(!rendered.html.contains("javascript"));
+        assert!(!rendered.html.contains("<img"));
+    }
+
+    #[test]
+    fn test_render_collects_mentions() {
+        let rendered = render("@kim_dev 확인 부탁드려요, cc @lee_pm @kim_dev\n\nmail me@example.com `@in_code`\n\n```\n@in_block\n```");
+        assert_eq!(rendered.mentions, vec!["kim_dev", "lee_pm"]);
+        assert!(rendered.html.contains("@kim_dev"));
+
+        assert_eq!(render("@ab @snake_case_name").mentions, vec!["snake_case_name"]);
+    }
+}
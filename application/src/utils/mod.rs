@@ -4,6 +4,18 @@
 
 pub mod validation;
 pub mod jwt;
+pub mod pagination;
+pub mod highlight;
+pub mod timezone;
+pub mod recurrence;
+pub mod fractional_index;
+pub mod quick_add;
+pub mod collections;
+pub mod time_report;
+pub mod content_sniff;
+pub mod signed_url;
+pub mod markdown;
+pub mod token;
 
 // Future utility functions will be added here
 // For example: password_utils.rs, etc.
@@ -0,0 +1,427 @@
+//! Keyset (cursor) pagination
+//!
+//! 커서는 정렬 키 값과 마지막 tiebreaker(id)를 담은 불투명 문자열이다.
+//! 목록 엔드포인트는 정렬 컬럼을 `KeysetColumn`으로 선언하고
+//! `push_keyset_condition` / `push_keyset_order_by`로 SQL을 만든 뒤,
+//! `limit + 1`개를 조회해 `CursorPage::from_rows`로 페이지를 구성한다.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::{Postgres, QueryBuilder};
+use utoipa::ToSchema;
+use uuid::Uuid;
+use crate::error::ApiError;
+
+/// 커서 문자열의 최대 길이 (디코딩 전 방어)
+const MAX_CURSOR_LENGTH: usize = 1024;
+
+/// 커서에 저장되는 정렬 키 값
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CursorValue {
+    #[serde(rename = "n")]
+    Null,
+    #[serde(rename = "i")]
+    Int(i64),
+    #[serde(rename = "s")]
+    Text(String),
+    #[serde(rename = "t")]
+    Timestamp(DateTime<Utc>),
+    #[serde(rename = "u")]
+    Uuid(Uuid),
+}
+
+impl CursorValue {
+    fn is_null(&self) -> bool {
+        matches!(self, CursorValue::Null)
+    }
+
+    fn push_bind(&self, builder: &mut QueryBuilder<'_, Postgres>) {
+        match self {
+            CursorValue::Null => builder.push("NULL"),
+            CursorValue::Int(value) => builder.push_bind(*value),
+            CursorValue::Text(value) => builder.push_bind(value.clone()),
+            CursorValue::Timestamp(value) => builder.push_bind(*value),
+            CursorValue::Uuid(value) => builder.push_bind(*value),
+        };
+    }
+}
+
+impl<T: Into<CursorValue>> From<Option<T>> for CursorValue {
+    fn from(value: Option<T>) -> Self {
+        value.map(Into::into).unwrap_or(CursorValue::Null)
+    }
+}
+
+impl From<i64> for CursorValue {
+    fn from(value: i64) -> Self {
+        CursorValue::Int(value)
+    }
+}
+
+impl From<String> for CursorValue {
+    fn from(value: String) -> Self {
+        CursorValue::Text(value)
+    }
+}
+
+impl From<DateTime<Utc>> for CursorValue {
+    fn from(value: DateTime<Utc>) -> Self {
+        CursorValue::Timestamp(value)
+    }
+}
+
+impl From<Uuid> for CursorValue {
+    fn from(value: Uuid) -> Self {
+        CursorValue::Uuid(value)
+    }
+}
+
+/// 정렬 기준 한 행의 위치
+///
+/// `sort`는 커서를 만든 정렬 조건의 서명이다. 정렬 조건이 바뀐 요청에
+/// 이전 커서를 재사용하면 잘못된 위치에서 이어지므로 거부한다.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cursor {
+    #[serde(rename = "s")]
+    pub sort: String,
+    #[serde(rename = "v")]
+    pub values: Vec<CursorValue>,
+}
+
+impl Cursor {
+    pub fn new(sort: impl Into<String>, values: Vec<CursorValue>) -> Self {
+        Self { sort: sort.into(), values }
+    }
+
+    pub fn encode(&self) -> String {
+        let json = serde_json::to_vec(self).expect("cursor is always serializable");
+        URL_SAFE_NO_PAD.encode(json)
+    }
+
+    pub fn decode(encoded: &str) -> Result<Self, ApiError> {
+        if encoded.is_empty() || encoded.len() > MAX_CURSOR_LENGTH {
+            return Err(invalid_cursor());
+        }
+        let bytes = URL_SAFE_NO_PAD.decode(encoded).map_err(|_| invalid_cursor())?;
+        serde_json::from_slice(&bytes).map_err(|_| invalid_cursor())
+    }
+}
+
+fn invalid_cursor() -> ApiError {
+    ApiError::Validation("유효하지 않은 커서입니다".to_string())
+}
+
+/// 커서 기준 조회 방향
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PageDirection {
+    /// 커서 다음 항목 (`after`)
+    Forward,
+    /// 커서 이전 항목 (`before`)
+    Backward,
+}
+
+/// 커서 위치와 조회 방향
+#[derive(Debug, Clone, PartialEq)]
+pub struct Keyset {
+    pub cursor: Cursor,
+    pub direction: PageDirection,
+}
+
+impl Keyset {
+    /// `after` / `before` 쿼리 파라미터를 해석한다. 둘 다 없으면 `None`.
+    ///
+    /// 커서는 `sort` 서명과 키 개수(`key_count`)가 현재 정렬과 일치해야 한다.
+    pub fn from_params(
+        after: Option<&str>,
+        before: Option<&str>,
+        sort: &str,
+        key_count: usize,
+    ) -> Result<Option<Self>, ApiError> {
+        let (encoded, direction) = match (after, before) {
+            (Some(_), Some(_)) => {
+                return Err(ApiError::Validation("after와 before는 함께 사용할 수 없습니다".to_string()))
+            }
+            (Some(after), None) => (after, PageDirection::Forward),
+            (None, Some(before)) => (before, PageDirection::Backward),
+            (None, None) => return Ok(None),
+        };
+
+        let cursor = Cursor::decode(encoded)?;
+        if cursor.sort != sort || cursor.values.len() != key_count {
+            return Err(ApiError::Validation("커서가 현재 정렬 조건과 일치하지 않습니다".to_string()));
+        }
+
+        Ok(Some(Self { cursor, direction }))
+    }
+}
+
+/// keyset 정렬에 참여하는 컬럼. 마지막 컬럼은 유일하고 NULL이 아니어야 한다 (보통 id).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KeysetColumn {
+    pub expr: &'static str,
+    pub descending: bool,
+    /// NULL 허용 컬럼은 방향과 상관없이 항상 마지막(`NULLS LAST`)에 정렬된다.
+    pub nullable: bool,
+}
+
+impl KeysetColumn {
+    pub fn new(expr: &'static str, descending: bool, nullable: bool) -> Self {
+        Self { expr, descending, nullable }
+    }
+}
+
+/// `ORDER BY` 절을 추가한다. `Backward`이면 순서를 뒤집어 조회한다 (결과는 `CursorPage`가 되돌린다).
+pub fn push_keyset_order_by(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    columns: &[KeysetColumn],
+    direction: PageDirection,
+) {
+    let reversed = direction == PageDirection::Backward;
+
+    builder.push(" ORDER BY ");
+    for (index, column) in columns.iter().enumerate() {
+        if index > 0 {
+            builder.push(", ");
+        }
+        let descending = column.descending != reversed;
+        builder.push(column.expr);
+        builder.push(if descending { " DESC" } else { " ASC" });
+        if column.nullable {
+            builder.push(if reversed { " NULLS FIRST" } else { " NULLS LAST" });
+        }
+    }
+}
+
+/// 커서 위치 이후(또는 이전) 행만 남기는 조건을 `AND (...)`로 추가한다.
+///
+/// 정렬 방향이 섞여 있고 NULL이 있을 수 있으므로 행 비교(`(a, b) > (x, y)`) 대신
+/// `k1 > v1 OR (k1 = v1 AND k2 > v2) OR ...` 형태로 펼친다.
+pub fn push_keyset_condition(
+    builder: &mut QueryBuilder<'_, Postgres>,
+    columns: &[KeysetColumn],
+    keyset: &Keyset,
+) {
+    let values = &keyset.cursor.values;
+    debug_assert_eq!(columns.len(), values.len());
+
+    let mut branches = 0;
+    builder.push(" AND (");
+    for (index, (column, value)) in columns.iter().zip(values).enumerate() {
+        let forward = keyset.direction == PageDirection::Forward;
+
+        // NULLS LAST: 앞으로 가면 NULL 값 뒤에는 NULL만, 뒤로 가면 NULL이 아닌 모든 값이 앞에 있다.
+        let strictly: Option<Strictly> = match (value.is_null(), forward) {
+            (true, true) => None,
+            (true, false) => Some(Strictly::NotNull),
+            (false, true) if column.nullable => Some(Strictly::CompareOrNull),
+            (false, _) => Some(Strictly::Compare),
+        };
+
+        if let Some(strictly) = strictly {
+            if branches > 0 {
+                builder.push(" OR ");
+            }
+            branches += 1;
+
+            builder.push("(");
+            push_equal_prefix(builder, &columns[..index], &values[..index]);
+
+            match strictly {
+                Strictly::NotNull => {
+                    builder.push(column.expr).push(" IS NOT NULL");
+                }
+                Strictly::Compare | Strictly::CompareOrNull => {
+                    let greater = column.descending != forward;
+                    if strictly == Strictly::CompareOrNull {
+                        builder.push("(");
+                    }
+                    builder.push(column.expr).push(if greater { " > " } else { " < " });
+                    value.push_bind(builder);
+                    if strictly == Strictly::CompareOrNull {
+                        builder.push(" OR ").push(column.expr).push(" IS NULL)");
+                    }
+                }
+            }
+            builder.push(")");
+        }
+    }
+
+    if branches == 0 {
+        builder.push("FALSE");
+    }
+    builder.push(")");
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Strictly {
+    Compare,
+    CompareOrNull,
+    NotNull,
+}
+
+fn push_equal_prefix(builder: &mut QueryBuilder<'_, Postgres>, columns: &[KeysetColumn], values: &[CursorValue]) {
+    for (column, value) in columns.iter().zip(values) {
+        builder.push(column.expr);
+        if value.is_null() {
+            builder.push(" IS NULL AND ");
+        } else {
+            builder.push(" = ");
+            value.push_bind(builder);
+            builder.push(" AND ");
+        }
+    }
+}
+
+/// 커서 페이지네이션 응답 정보
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CursorInfo {
+    pub limit: u32,
+    pub has_next: bool,
+    pub has_prev: bool,
+    /// 다음 페이지 조회 시 `after`로 전달
+    pub next_cursor: Option<String>,
+    /// 이전 페이지 조회 시 `before`로 전달
+    pub prev_cursor: Option<String>,
+}
+
+/// 한 페이지 분량의 항목과 커서 정보
+#[derive(Debug, Clone, PartialEq)]
+pub struct CursorPage<T> {
+    pub items: Vec<T>,
+    pub info: CursorInfo,
+}
+
+impl<T> CursorPage<T> {
+    /// `limit + 1`개까지 조회한 행으로 페이지를 만든다.
+    ///
+    /// `keyset`이 `None`이면 첫 페이지로 간주한다. `cursor_of`는 행의 정렬 키로 커서를 만든다.
+    pub fn from_rows(
+        mut rows: Vec<T>,
+        limit: u32,
+        keyset: Option<&Keyset>,
+        cursor_of: impl Fn(&T) -> Cursor,
+    ) -> Self {
+        let has_more = rows.len() > limit as usize;
+        rows.truncate(limit as usize);
+
+        let direction = keyset.map(|k| k.direction).unwrap_or(PageDirection::Forward);
+        let (has_next, has_prev) = match direction {
+            PageDirection::Forward => (has_more, keyset.is_some()),
+            PageDirection::Backward => {
+                rows.reverse();
+                (true, has_more)
+            }
+        };
+
+        let next_cursor = has_next.then(|| rows.last().map(|row| cursor_of(row).encode())).flatten();
+        let prev_cursor = has_prev.then(|| rows.first().map(|row| cursor_of(row).encode())).flatten();
+
+        Self {
+            info: CursorInfo {
+                limit,
+                has_next: next_cursor.is_some(),
+                has_prev: prev_cursor.is_some(),
+                next_cursor,
+                prev_cursor,
+            },
+            items: rows,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn columns() -> Vec<KeysetColumn> {
+        vec![
+            KeysetColumn::new("due_date", false, true),
+            KeysetColumn::new("id", false, false),
+        ]
+    }
+
+    fn keyset(values: Vec<CursorValue>, direction: PageDirection) -> Keyset {
+        Keyset { cursor: Cursor::new("due_date", values), direction }
+    }
+
+    fn condition_sql(keyset: &Keyset) -> String {
+        let mut builder = QueryBuilder::<Postgres>::new("");
+        push_keyset_condition(&mut builder, &columns(), keyset);
+        builder.sql().to_string()
+    }
+
+    #[test]
+    fn test_cursor_roundtrip() {
+        let cursor = Cursor::new(
+            "-priority",
+            vec![CursorValue::Int(3), CursorValue::Null, CursorValue::Uuid(Uuid::new_v4())],
+        );
+        let encoded = cursor.encode();
+
+        assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+        assert_eq!(Cursor::decode(&encoded).unwrap(), cursor);
+        assert!(Cursor::decode("not a cursor").is_err());
+        assert!(Cursor::decode("").is_err());
+    }
+
+    #[test]
+    fn test_keyset_from_params() {
+        let encoded = Cursor::new("title", vec!["a".to_string().into(), Uuid::new_v4().into()]).encode();
+
+        let keyset = Keyset::from_params(None, Some(&encoded), "title", 2).unwrap().unwrap();
+        assert_eq!(keyset.direction, PageDirection::Backward);
+
+        assert!(Keyset::from_params(None, None, "title", 2).unwrap().is_none());
+        assert!(Keyset::from_params(Some(&encoded), Some(&encoded), "title", 2).is_err());
+        assert!(Keyset::from_params(Some(&encoded), None, "-title", 2).is_err());
+        assert!(Keyset::from_params(Some(&encoded), None, "title", 3).is_err());
+    }
+
+    #[test]
+    fn test_forward_condition_with_nullable_key() {
+        let sql = condition_sql(&keyset(
+            vec![CursorValue::Timestamp(Utc::now()), CursorValue::Uuid(Uuid::new_v4())],
+            PageDirection::Forward,
+        ));
+        assert_eq!(sql, " AND (((due_date > $1 OR due_date IS NULL)) OR (due_date = $2 AND id > $3))");
+
+        let sql = condition_sql(&keyset(
+            vec![CursorValue::Null, CursorValue::Uuid(Uuid::new_v4())],
+            PageDirection::Forward,
+        ));
+        assert_eq!(sql, " AND ((due_date IS NULL AND id > $1))");
+    }
+
+    #[test]
+    fn test_backward_condition_and_order() {
+        let sql = condition_sql(&keyset(
+            vec![CursorValue::Null, CursorValue::Uuid(Uuid::new_v4())],
+            PageDirection::Backward,
+        ));
+        assert_eq!(sql, " AND ((due_date IS NOT NULL) OR (due_date IS NULL AND id < $1))");
+
+        let mut builder = QueryBuilder::<Postgres>::new("");
+        push_keyset_order_by(&mut builder, &columns(), PageDirection::Backward);
+        assert_eq!(builder.sql(), " ORDER BY due_date DESC NULLS FIRST, id DESC");
+    }
+
+    #[test]
+    fn test_cursor_page_from_rows() {
+        let cursor_of = |n: &i64| Cursor::new("n", vec![CursorValue::Int(*n)]);
+
+        let first = CursorPage::from_rows(vec![1, 2, 3], 2, None, cursor_of);
+        assert_eq!(first.items, vec![1, 2]);
+        assert!(first.info.has_next);
+        assert!(!first.info.has_prev);
+        assert_eq!(first.info.next_cursor, Some(cursor_of(&2).encode()));
+
+        let backward = keyset(vec![CursorValue::Int(4)], PageDirection::Backward);
+        let page = CursorPage::from_rows(vec![3, 2], 2, Some(&backward), cursor_of);
+        assert_eq!(page.items, vec![2, 3]);
+        assert!(page.info.has_next);
+        assert!(!page.info.has_prev);
+        assert_eq!(page.info.next_cursor, Some(cursor_of(&3).encode()));
+    }
+}
@@ -0,0 +1,704 @@
+//! Natural-language quick add
+//!
+//! "내일 오후 3시 회의 준비 !high #업무", "pay rent every month on the 1st" 같은 한 줄 입력에서
+//! 마감 시각, 우선순위, 카테고리, 반복 규칙을 뽑아내고 나머지를 제목으로 쓴다.
+//!
+//! - 날짜와 시각은 사용자 시간대 기준으로 해석한다. 시각 없이 날짜만 있으면 그날 23:59를 마감으로 본다.
+//! - 오전/오후 표시가 없는 1~7시는 오후로 본다 ("3시 회의" → 15:00).
+//! - 시각만 있으면 오늘, 이미 지났으면 내일로 잡는다.
+//! - 반복만 있으면 지금 이후의 첫 발생을 마감으로 잡는다.
+//! - 인식하지 못한 부분은 그대로 제목에 남는다. 날짜·시각·반복은 처음 인식한 하나만 쓴다.
+
+use std::sync::LazyLock;
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveTime, Utc, Weekday};
+use chrono_tz::Tz;
+use regex::{Captures, Regex};
+use crate::entities::todo::Priority;
+use crate::utils::recurrence::{ByDay, Frequency, RecurrenceRule};
+use crate::utils::timezone::local_datetime;
+
+/// 시각 없이 날짜만 지정했을 때의 마감 시각
+const ALL_DAY_DUE_TIME: (u32, u32) = (23, 59);
+const MAX_RELATIVE_AMOUNT: u32 = 366;
+
+static CATEGORY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"#([^\s#!,]+)").expect("Invalid category regex"));
+static PRIORITY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)!(high|medium|low|urgent|높음|보통|낮음|긴급|[123])").expect("Invalid priority regex"));
+
+// 반복
+static KO_DAILY: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"매일(?:마다)?").expect("Invalid regex"));
+static KO_WEEKDAYS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"평일마다|매\s*평일").expect("Invalid regex"));
+static KO_WEEKLY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"매주(?:\s*([월화수목금토일]요일(?:(?:\s*,\s*|\s+)[월화수목금토일]요일)*|[월화수목금토일]{2,}(?:요일)?))?(?:마다|에)?")
+        .expect("Invalid regex")
+});
+static KO_WEEKDAY_EVERY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"([월화수목금토일])요일마다").expect("Invalid regex"));
+static KO_MONTHLY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?:매월|매달)(?:\s*(?:([0-9]{1,2})일|(말일)))?(?:마다|에)?").expect("Invalid regex"));
+static KO_INTERVAL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"([0-9]{1,3})\s*(일|주|개월|달)\s*마다").expect("Invalid regex"));
+static EN_EVERY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)(?:every|each)\s+(?:([0-9]{1,3})\s+|(other)\s+)?(day|week|month)s?(?:\s+on\s+(?:the\s+)?(?:([0-9]{1,2})(?:st|nd|rd|th)?|(last)\s+day))?",
+    )
+    .expect("Invalid regex")
+});
+static EN_EVERY_WEEKDAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:every|each)\s+(weekday|[a-z]+(?:(?:\s*,\s*|\s+and\s+|\s*,\s*and\s+)[a-z]+)*)").expect("Invalid regex")
+});
+static EN_PERIODIC: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(daily|weekly|monthly)(?:\s+on\s+(?:the\s+)?(?:([0-9]{1,2})(?:st|nd|rd|th)?|(last)\s+day))?")
+        .expect("Invalid regex")
+});
+
+// 날짜
+static ISO_DATE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"([0-9]{4})-([0-9]{1,2})-([0-9]{1,2})").expect("Invalid regex"));
+static KO_AFTER: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"([0-9]{1,3})\s*(일|주|개월|달)\s*(?:후|뒤)(?:에|까지)?").expect("Invalid regex")
+});
+static KO_RELATIVE_DAY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(오늘|내일|모레|글피)(?:까지|에|부터)?").expect("Invalid regex"));
+static KO_WEEKDAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:(이번|다음|다다음)\s*주\s*)?([월화수목금토일])요일(?:까지|에|부터)?").expect("Invalid regex")
+});
+static KO_DATE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:([0-9]{1,2})월\s*)?([0-9]{1,2})일(?:까지|에|부터)?").expect("Invalid regex")
+});
+static EN_RELATIVE_DAY: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?:(?:by|due)\s+)?(today|tomorrow|tmrw|tmr)").expect("Invalid regex"));
+static EN_IN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)in\s+([0-9]{1,3})\s+(day|week|month)s?").expect("Invalid regex"));
+static EN_WEEKDAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:(?:on|by|due)\s+)?(?:(this|next)\s+)?([a-z]+)").expect("Invalid regex")
+});
+static EN_MONTH_DAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:(?:on|by|due)\s+)?([a-z]+)\.?\s+([0-9]{1,2})(?:st|nd|rd|th)?").expect("Invalid regex")
+});
+static EN_DAY_MONTH: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:(?:on|by|due)\s+)?([0-9]{1,2})(?:st|nd|rd|th)?\s+([a-z]+)").expect("Invalid regex")
+});
+static SLASH_DATE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?:(?:on|by|due)\s+)?([0-9]{1,2})/([0-9]{1,2})").expect("Invalid regex"));
+
+// 시각
+static KO_TIME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:(오전|오후|아침|낮|저녁|밤|새벽)\s*)?([0-9]{1,2})시(?:\s*([0-9]{1,2})분|\s*(반))?(?:까지|에|부터)?")
+        .expect("Invalid regex")
+});
+static KO_NOON: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"정오(?:까지|에)?").expect("Invalid regex"));
+static EN_MERIDIEM_TIME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)(?:(?:at|by)\s+)?([0-9]{1,2})(?::([0-9]{2}))?\s*([ap])\.?m\.?").expect("Invalid regex")
+});
+static CLOCK_TIME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?:(?:at|by)\s+)?([0-9]{1,2}):([0-9]{2})").expect("Invalid regex"));
+static EN_NOON: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)(?:(?:at|by)\s+)?(?:noon|midday)").expect("Invalid regex"));
+static EN_AT_HOUR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)at\s+([0-9]{1,2})").expect("Invalid regex"));
+
+/// 인식한 부분의 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenKind {
+    Date,
+    Time,
+    Priority,
+    Category,
+    Recurrence,
+}
+
+/// 입력에서 인식한 부분. `start`/`end`는 문자(유니코드 스칼라) 단위 위치이며 `end`는 포함하지 않는다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuickAddToken {
+    pub kind: TokenKind,
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// 해석 기준
+#[derive(Debug, Clone, Copy)]
+pub struct QuickAddContext {
+    pub now: DateTime<Utc>,
+    pub timezone: Tz,
+    /// "이번 주", "다음 주"의 기준
+    pub week_start: Weekday,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuickAdd {
+    /// 인식한 부분을 뺀 나머지 (비어 있을 수 있다)
+    pub title: String,
+    pub due_date: Option<DateTime<Utc>>,
+    /// 시각 없이 날짜만 지정됨
+    pub all_day: bool,
+    pub priority: Option<Priority>,
+    /// 입력 순서대로, 중복 제거
+    pub categories: Vec<String>,
+    pub recurrence: Option<RecurrenceRule>,
+    /// 입력 순서대로
+    pub tokens: Vec<QuickAddToken>,
+}
+
+/// 한 줄 입력을 해석한다
+pub fn parse(input: &str, context: &QuickAddContext) -> QuickAdd {
+    let today = context.now.with_timezone(&context.timezone).date_naive();
+    let mut scanner = Scanner::new(input);
+
+    let mut categories: Vec<String> = Vec::new();
+    for name in scanner.take_all(TokenKind::Category, &CATEGORY, |c| Some(c[1].to_string())) {
+        if !categories.contains(&name) {
+            categories.push(name);
+        }
+    }
+
+    let priority = scanner.take(TokenKind::Priority, &PRIORITY, |c| {
+        Some(match c[1].to_lowercase().as_str() {
+            "high" | "urgent" | "높음" | "긴급" | "1" => Priority::High,
+            "medium" | "보통" | "2" => Priority::Medium,
+            _ => Priority::Low,
+        })
+    });
+
+    let recurrence = take_recurrence(&mut scanner);
+    let date = take_date(&mut scanner, today, context.week_start);
+    let time = take_time(&mut scanner);
+
+    let due_date = resolve_due(date, time, recurrence.as_ref(), context);
+
+    QuickAdd {
+        title: scanner.remainder(),
+        due_date,
+        all_day: due_date.is_some() && time.is_none(),
+        priority,
+        categories,
+        recurrence,
+        tokens: scanner.tokens(),
+    }
+}
+
+fn take_recurrence(scanner: &mut Scanner) -> Option<RecurrenceRule> {
+    let kind = TokenKind::Recurrence;
+
+    scanner
+        .take(kind, &KO_WEEKDAYS, |_| Some(weekly(&WORKDAYS)))
+        .or_else(|| scanner.take(kind, &KO_DAILY, |_| Some(rule(Frequency::Daily, 1))))
+        .or_else(|| {
+            scanner.take(kind, &KO_WEEKLY, |c| match c.get(1) {
+                Some(days) => Some(weekly(&days.as_str().replace("요일", "").chars().filter_map(ko_weekday).collect::<Vec<_>>())),
+                None => Some(rule(Frequency::Weekly, 1)),
+            })
+        })
+        .or_else(|| scanner.take(kind, &KO_WEEKDAY_EVERY, |c| Some(weekly(&[ko_weekday(c[1].chars().next()?)?]))))
+        .or_else(|| {
+            scanner.take(kind, &KO_MONTHLY, |c| {
+                let month_day = match (c.get(1), c.get(2)) {
+                    (Some(day), _) => Some(day.as_str().parse().ok()?),
+                    (None, Some(_)) => Some(-1),
+                    (None, None) => None,
+                };
+                monthly(1, month_day)
+            })
+        })
+        .or_else(|| {
+            scanner.take(kind, &KO_INTERVAL, |c| {
+                let interval = c[1].parse().ok()?;
+                match &c[2] {
+                    "일" => interval_rule(Frequency::Daily, interval),
+                    "주" => interval_rule(Frequency::Weekly, interval),
+                    _ => interval_rule(Frequency::Monthly, interval),
+                }
+            })
+        })
+        .or_else(|| {
+            scanner.take(kind, &EN_EVERY_WEEKDAY, |c| {
+                if c[1].eq_ignore_ascii_case("weekday") {
+                    return Some(weekly(&WORKDAYS));
+                }
+                let days = c[1]
+                    .split(|ch: char| ch == ',' || ch.is_whitespace())
+                    .filter(|word| !word.is_empty() && !word.eq_ignore_ascii_case("and"))
+                    .map(en_weekday)
+                    .collect::<Option<Vec<_>>>()?;
+                Some(weekly(&days))
+            })
+        })
+        .or_else(|| {
+            scanner.take(kind, &EN_EVERY, |c| {
+                let interval = match (c.get(1), c.get(2)) {
+                    (Some(n), _) => n.as_str().parse().ok()?,
+                    (None, Some(_)) => 2,
+                    (None, None) => 1,
+                };
+                en_periodic(&c[3], interval, c.get(4).map(|m| m.as_str()), c.get(5).is_some())
+            })
+        })
+        .or_else(|| {
+            scanner.take(kind, &EN_PERIODIC, |c| {
+                let unit = match c[1].to_lowercase().as_str() {
+                    "daily" => "day",
+                    "weekly" => "week",
+                    _ => "month",
+                };
+                en_periodic(unit, 1, c.get(2).map(|m| m.as_str()), c.get(3).is_some())
+            })
+        })
+}
+
+fn take_date(scanner: &mut Scanner, today: NaiveDate, week_start: Weekday) -> Option<NaiveDate> {
+    let kind = TokenKind::Date;
+
+    scanner
+        .take(kind, &ISO_DATE, |c| NaiveDate::from_ymd_opt(c[1].parse().ok()?, c[2].parse().ok()?, c[3].parse().ok()?))
+        .or_else(|| scanner.take(kind, &KO_AFTER, |c| after(today, c[1].parse().ok()?, &c[2])))
+        .or_else(|| {
+            scanner.take(kind, &KO_RELATIVE_DAY, |c| {
+                let days = match &c[1] {
+                    "오늘" => 0,
+                    "내일" => 1,
+                    "모레" => 2,
+                    _ => 3,
+                };
+                Some(today + Duration::days(days))
+            })
+        })
+        .or_else(|| {
+            scanner.take(kind, &KO_WEEKDAY, |c| {
+                let weekday = ko_weekday(c[2].chars().next()?)?;
+                match c.get(1).map(|m| m.as_str()) {
+                    None => Some(upcoming(today, weekday)),
+                    Some("이번") => Some(in_week(today, week_start, weekday, 0)),
+                    Some("다음") => Some(in_week(today, week_start, weekday, 1)),
+                    _ => Some(in_week(today, week_start, weekday, 2)),
+                }
+            })
+        })
+        .or_else(|| {
+            scanner.take(kind, &KO_DATE, |c| {
+                let day = c[2].parse().ok()?;
+                match c.get(1) {
+                    Some(month) => month_day(today, month.as_str().parse().ok()?, day),
+                    None => day_of_month(today, day),
+                }
+            })
+        })
+        .or_else(|| {
+            scanner.take(kind, &EN_RELATIVE_DAY, |c| match c[1].to_lowercase().as_str() {
+                "today" => Some(today),
+                _ => Some(today + Duration::days(1)),
+            })
+        })
+        .or_else(|| {
+            scanner.take(kind, &EN_IN, |c| {
+                let unit = match c[2].to_lowercase().as_str() {
+                    "day" => "일",
+                    "week" => "주",
+                    _ => "개월",
+                };
+                after(today, c[1].parse().ok()?, unit)
+            })
+        })
+        .or_else(|| {
+            scanner.take(kind, &EN_WEEKDAY, |c| {
+                let weekday = en_weekday(&c[2])?;
+                match c.get(1).map(|m| m.as_str().to_lowercase()).as_deref() {
+                    Some("next") => Some(in_week(today, week_start, weekday, 1)),
+                    _ => Some(upcoming(today, weekday)),
+                }
+            })
+        })
+        .or_else(|| scanner.take(kind, &EN_MONTH_DAY, |c| month_day(today, en_month(&c[1])?, c[2].parse().ok()?)))
+        .or_else(|| scanner.take(kind, &EN_DAY_MONTH, |c| month_day(today, en_month(&c[2])?, c[1].parse().ok()?)))
+        .or_else(|| scanner.take(kind, &SLASH_DATE, |c| month_day(today, c[1].parse().ok()?, c[2].parse().ok()?)))
+}
+
+fn take_time(scanner: &mut Scanner) -> Option<NaiveTime> {
+    let kind = TokenKind::Time;
+
+    scanner
+        .take(kind, &KO_TIME, |c| {
+            let hour: u32 = c[2].parse().ok()?;
+            let minute = match (c.get(3), c.get(4)) {
+                (Some(minute), _) => minute.as_str().parse().ok()?,
+                (None, Some(_)) => 30,
+                (None, None) => 0,
+            };
+            let pm = c.get(1).map(|m| matches!(m.as_str(), "오후" | "낮" | "저녁" | "밤"));
+            clock(hour, minute, pm)
+        })
+        .or_else(|| scanner.take(kind, &KO_NOON, |_| NaiveTime::from_hms_opt(12, 0, 0)))
+        .or_else(|| {
+            scanner.take(kind, &EN_MERIDIEM_TIME, |c| {
+                let minute = c.get(2).map_or(Some(0), |m| m.as_str().parse().ok())?;
+                clock(c[1].parse().ok()?, minute, Some(c[3].eq_ignore_ascii_case("p")))
+            })
+        })
+        .or_else(|| scanner.take(kind, &CLOCK_TIME, |c| NaiveTime::from_hms_opt(c[1].parse().ok()?, c[2].parse().ok()?, 0)))
+        .or_else(|| scanner.take(kind, &EN_NOON, |_| NaiveTime::from_hms_opt(12, 0, 0)))
+        .or_else(|| scanner.take(kind, &EN_AT_HOUR, |c| clock(c[1].parse().ok()?, 0, None)))
+}
+
+fn resolve_due(
+    date: Option<NaiveDate>,
+    time: Option<NaiveTime>,
+    recurrence: Option<&RecurrenceRule>,
+    context: &QuickAddContext,
+) -> Option<DateTime<Utc>> {
+    let tz = context.timezone;
+    let today = context.now.with_timezone(&tz).date_naive();
+    let at = time.unwrap_or_else(|| {
+        NaiveTime::from_hms_opt(ALL_DAY_DUE_TIME.0, ALL_DAY_DUE_TIME.1, 0).expect("valid all-day due time")
+    });
+
+    match (date, recurrence) {
+        (Some(date), _) => Some(local_datetime(tz, date.and_time(at))),
+        (None, Some(rule)) => {
+            // 첫 발생은 항상 dtstart이므로, 요일이나 날짜가 정해진 규칙은 어제를 기준으로 잡고
+            // 그 다음 발생부터 찾는다
+            let anchored = !rule.by_day.is_empty() || !rule.by_month_day.is_empty();
+            let (start, skip) = if anchored { (today - Duration::days(1), 1) } else { (today, 0) };
+            let dtstart = local_datetime(tz, start.and_time(at));
+            rule.occurrences(dtstart, tz)
+                .skip(skip)
+                .find(|occurrence| *occurrence >= context.now)
+        }
+        (None, None) => {
+            let time = time?;
+            let due = local_datetime(tz, today.and_time(time));
+            if due >= context.now {
+                Some(due)
+            } else {
+                Some(local_datetime(tz, (today + Duration::days(1)).and_time(time)))
+            }
+        }
+    }
+}
+
+/// `pm`이 `None`이면 24시간제로 보되, 1~7시는 오후로 본다
+fn clock(hour: u32, minute: u32, pm: Option<bool>) -> Option<NaiveTime> {
+    let hour = match pm {
+        Some(_) if !(1..=12).contains(&hour) => return None,
+        Some(true) if hour < 12 => hour + 12,
+        Some(false) if hour == 12 => 0,
+        Some(_) => hour,
+        None if (1..=7).contains(&hour) => hour + 12,
+        None => hour,
+    };
+    NaiveTime::from_hms_opt(hour, minute, 0)
+}
+
+/// 오늘 또는 그 이후의 첫 해당 요일
+fn upcoming(today: NaiveDate, weekday: Weekday) -> NaiveDate {
+    let days = (7 + weekday.num_days_from_monday() - today.weekday().num_days_from_monday()) % 7;
+    today + Duration::days(days as i64)
+}
+
+/// `weeks_ahead`주 뒤 주의 해당 요일 (주의 시작 요일 기준)
+fn in_week(today: NaiveDate, week_start: Weekday, weekday: Weekday, weeks_ahead: i64) -> NaiveDate {
+    let into_week = (7 + today.weekday().num_days_from_monday() - week_start.num_days_from_monday()) % 7;
+    let offset = (7 + weekday.num_days_from_monday() - week_start.num_days_from_monday()) % 7;
+    today - Duration::days(into_week as i64) + Duration::days(offset as i64) + Duration::weeks(weeks_ahead)
+}
+
+/// 연도 없는 월/일. 올해 날짜가 이미 지났으면 내년으로 본다.
+fn month_day(today: NaiveDate, month: u32, day: u32) -> Option<NaiveDate> {
+    match NaiveDate::from_ymd_opt(today.year(), month, day) {
+        Some(date) if date >= today => Some(date),
+        _ => NaiveDate::from_ymd_opt(today.year() + 1, month, day),
+    }
+}
+
+/// 월 없는 일. 이번 달 날짜가 이미 지났으면 다음 달로 본다.
+fn day_of_month(today: NaiveDate, day: u32) -> Option<NaiveDate> {
+    match today.with_day(day) {
+        Some(date) if date >= today => Some(date),
+        _ => today.with_day(1)?.checked_add_months(Months::new(1))?.with_day(day),
+    }
+}
+
+fn after(today: NaiveDate, amount: u32, unit: &str) -> Option<NaiveDate> {
+    if amount > MAX_RELATIVE_AMOUNT {
+        return None;
+    }
+    match unit {
+        "일" => Some(today + Duration::days(amount as i64)),
+        "주" => Some(today + Duration::weeks(amount as i64)),
+        _ => today.checked_add_months(Months::new(amount)),
+    }
+}
+
+const WORKDAYS: [Weekday; 5] = [Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri];
+
+fn rule(frequency: Frequency, interval: u32) -> RecurrenceRule {
+    RecurrenceRule {
+        frequency,
+        interval,
+        by_day: Vec::new(),
+        by_month_day: Vec::new(),
+        week_start: Weekday::Mon,
+        count: None,
+        until: None,
+    }
+}
+
+fn interval_rule(frequency: Frequency, interval: u32) -> Option<RecurrenceRule> {
+    (1..=MAX_RELATIVE_AMOUNT).contains(&interval).then(|| rule(frequency, interval))
+}
+
+fn weekly(days: &[Weekday]) -> RecurrenceRule {
+    let mut rule = rule(Frequency::Weekly, 1);
+    for weekday in days {
+        let by_day = ByDay { ordinal: None, weekday: *weekday };
+        if !rule.by_day.contains(&by_day) {
+            rule.by_day.push(by_day);
+        }
+    }
+    rule
+}
+
+fn monthly(interval: u32, month_day: Option<i8>) -> Option<RecurrenceRule> {
+    let mut rule = interval_rule(Frequency::Monthly, interval)?;
+    if let Some(day) = month_day {
+        if day == 0 || day > 31 {
+            return None;
+        }
+        rule.by_month_day.push(day);
+    }
+    Some(rule)
+}
+
+fn en_periodic(unit: &str, interval: u32, month_day: Option<&str>, last_day: bool) -> Option<RecurrenceRule> {
+    match unit.to_lowercase().as_str() {
+        "day" => interval_rule(Frequency::Daily, interval),
+        "week" => interval_rule(Frequency::Weekly, interval),
+        _ => {
+            let day = match (month_day, last_day) {
+                (Some(day), _) => Some(day.parse().ok()?),
+                (None, true) => Some(-1),
+                (None, false) => None,
+            };
+            monthly(interval, day)
+        }
+    }
+}
+
+fn ko_weekday(c: char) -> Option<Weekday> {
+    match c {
+        '월' => Some(Weekday::Mon),
+        '화' => Some(Weekday::Tue),
+        '수' => Some(Weekday::Wed),
+        '목' => Some(Weekday::Thu),
+        '금' => Some(Weekday::Fri),
+        '토' => Some(Weekday::Sat),
+        '일' => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn en_weekday(word: &str) -> Option<Weekday> {
+    match word.to_lowercase().as_str() {
+        "mon" | "monday" => Some(Weekday::Mon),
+        "tue" | "tues" | "tuesday" => Some(Weekday::Tue),
+        "wed" | "wednesday" => Some(Weekday::Wed),
+        "thu" | "thur" | "thurs" | "thursday" => Some(Weekday::Thu),
+        "fri" | "friday" => Some(Weekday::Fri),
+        "sat" | "saturday" => Some(Weekday::Sat),
+        "sun" | "sunday" => Some(Weekday::Sun),
+        _ => None,
+    }
+}
+
+fn en_month(word: &str) -> Option<u32> {
+    let month = match word.to_lowercase().as_str() {
+        "jan" | "january" => 1,
+        "feb" | "february" => 2,
+        "mar" | "march" => 3,
+        "apr" | "april" => 4,
+        "may" => 5,
+        "jun" | "june" => 6,
+        "jul" | "july" => 7,
+        "aug" | "august" => 8,
+        "sep" | "sept" | "september" => 9,
+        "oct" | "october" => 10,
+        "nov" | "november" => 11,
+        "dec" | "december" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+/// 입력에서 이미 인식한 부분을 기억하며 새 일치를 찾는다
+struct Scanner<'a> {
+    input: &'a str,
+    /// (종류, 바이트 시작, 바이트 끝)
+    taken: Vec<(TokenKind, usize, usize)>,
+}
+
+impl<'a> Scanner<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, taken: Vec::new() }
+    }
+
+    /// 단어 경계에 있고 이미 인식한 부분과 겹치지 않는 일치 중 `f`가 값을 돌려주는 첫 일치를 차지한다
+    fn take<T>(&mut self, kind: TokenKind, regex: &Regex, mut f: impl FnMut(&Captures) -> Option<T>) -> Option<T> {
+        for captures in regex.captures_iter(self.input) {
+            let whole = captures.get(0).expect("group 0 always matches");
+            if !self.is_free(whole.start(), whole.end()) {
+                continue;
+            }
+            if let Some(value) = f(&captures) {
+                self.taken.push((kind, whole.start(), whole.end()));
+                return Some(value);
+            }
+        }
+        None
+    }
+
+    fn take_all<T>(&mut self, kind: TokenKind, regex: &Regex, mut f: impl FnMut(&Captures) -> Option<T>) -> Vec<T> {
+        let mut values = Vec::new();
+        while let Some(value) = self.take(kind, regex, &mut f) {
+            values.push(value);
+        }
+        values
+    }
+
+    fn is_free(&self, start: usize, end: usize) -> bool {
+        let before = self.input[..start].chars().next_back();
+        let after = self.input[end..].chars().next();
+        let boundary = |c: Option<char>| c.is_none_or(|c| c.is_whitespace() || ",.!?()".contains(c));
+
+        boundary(before)
+            && boundary(after)
+            && self.taken.iter().all(|(_, s, e)| end <= *s || start >= *e)
+    }
+
+    /// 인식하지 못한 나머지 (공백 정리)
+    fn remainder(&self) -> String {
+        let mut taken = self.taken.clone();
+        taken.sort_by_key(|(_, start, _)| *start);
+
+        let mut rest = String::with_capacity(self.input.len());
+        let mut cursor = 0;
+        for (_, start, end) in taken {
+            rest.push_str(&self.input[cursor..start]);
+            rest.push(' ');
+            cursor = end;
+        }
+        rest.push_str(&self.input[cursor..]);
+
+        rest.split_whitespace().collect::<Vec<_>>().join(" ")
+    }
+
+    fn tokens(&self) -> Vec<QuickAddToken> {
+        let mut tokens: Vec<QuickAddToken> = self
+            .taken
+            .iter()
+            .map(|(kind, start, end)| QuickAddToken {
+                kind: *kind,
+                text: self.input[*start..*end].to_string(),
+                start: self.input[..*start].chars().count(),
+                end: self.input[..*end].chars().count(),
+            })
+            .collect();
+        tokens.sort_by_key(|token| token.start);
+        tokens
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    /// 서울 2025-07-10(목) 10:00
+    fn seoul() -> QuickAddContext {
+        QuickAddContext {
+            now: utc("2025-07-10T01:00:00Z"),
+            timezone: chrono_tz::Asia::Seoul,
+            week_start: Weekday::Mon,
+        }
+    }
+
+    #[test]
+    fn test_korean_date_time_priority_and_category() {
+        let parsed = parse("내일 오후 3시 회의 준비 !high #업무", &seoul());
+
+        assert_eq!(parsed.title, "회의 준비");
+        assert_eq!(parsed.due_date, Some(utc("2025-07-11T06:00:00Z")));
+        assert!(!parsed.all_day);
+        assert_eq!(parsed.priority, Some(Priority::High));
+        assert_eq!(parsed.categories, vec!["업무".to_string()]);
+        assert!(parsed.recurrence.is_none());
+
+        let kinds: Vec<TokenKind> = parsed.tokens.iter().map(|t| t.kind).collect();
+        assert_eq!(kinds, vec![TokenKind::Date, TokenKind::Time, TokenKind::Priority, TokenKind::Category]);
+        assert_eq!((parsed.tokens[1].start, parsed.tokens[1].end), (3, 8));
+        assert_eq!(parsed.tokens[1].text, "오후 3시");
+    }
+
+    #[test]
+    fn test_english_monthly_recurrence_without_date() {
+        let parsed = parse("pay rent every month on the 1st", &seoul());
+
+        assert_eq!(parsed.title, "pay rent");
+        assert_eq!(parsed.recurrence.as_ref().map(|r| r.to_string()).as_deref(), Some("FREQ=MONTHLY;BYMONTHDAY=1"));
+        // 다음 1일 23:59 (서울)
+        assert_eq!(parsed.due_date, Some(utc("2025-08-01T14:59:00Z")));
+        assert!(parsed.all_day);
+    }
+
+    #[test]
+    fn test_weekly_recurrence_picks_next_matching_day() {
+        let parsed = parse("매주 월요일 9시 반 주간 보고", &seoul());
+
+        assert_eq!(parsed.title, "주간 보고");
+        assert_eq!(parsed.recurrence.as_ref().map(|r| r.to_string()).as_deref(), Some("FREQ=WEEKLY;BYDAY=MO"));
+        assert_eq!(parsed.due_date, Some(utc("2025-07-14T00:30:00Z")));
+
+        let parsed = parse("standup every weekday at 9:15am", &seoul());
+        assert_eq!(parsed.title, "standup");
+        // 오늘(목) 09:15는 이미 지났으므로 금요일
+        assert_eq!(parsed.due_date, Some(utc("2025-07-11T00:15:00Z")));
+    }
+
+    #[test]
+    fn test_time_only_rolls_to_tomorrow_when_past() {
+        let parsed = parse("call mom at 9am", &seoul());
+        assert_eq!(parsed.title, "call mom");
+        assert_eq!(parsed.due_date, Some(utc("2025-07-11T00:00:00Z")));
+
+        let parsed = parse("3시 치과", &seoul());
+        assert_eq!(parsed.title, "치과");
+        assert_eq!(parsed.due_date, Some(utc("2025-07-10T06:00:00Z")));
+    }
+
+    #[test]
+    fn test_dates_relative_to_user_week_and_month() {
+        let context = seoul();
+
+        let parsed = parse("다음 주 금요일까지 보고서 제출", &context);
+        assert_eq!(parsed.title, "보고서 제출");
+        assert_eq!(parsed.due_date, Some(utc("2025-07-18T14:59:00Z")));
+
+        // 5일은 이미 지났으므로 다음 달
+        let parsed = parse("5일 관리비 납부", &context);
+        assert_eq!(parsed.due_date, Some(utc("2025-08-05T14:59:00Z")));
+
+        let parsed = parse("Submit report by jul 25", &context);
+        assert_eq!(parsed.title, "Submit report");
+        assert_eq!(parsed.due_date, Some(utc("2025-07-25T14:59:00Z")));
+    }
+
+    #[test]
+    fn test_unrecognized_text_stays_in_title() {
+        let parsed = parse("Monkey business #a #a !unknown 25시", &seoul());
+
+        assert_eq!(parsed.title, "Monkey business !unknown 25시");
+        assert_eq!(parsed.categories, vec!["a".to_string()]);
+        assert!(parsed.due_date.is_none());
+        assert!(parsed.priority.is_none());
+    }
+}
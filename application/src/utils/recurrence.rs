@@ -0,0 +1,587 @@
+//! RFC 5545 recurrence rules
+//!
+//! 할일 반복에 필요한 RRULE 부분집합을 지원한다:
+//! `FREQ`(DAILY, WEEKLY, MONTHLY), `INTERVAL`, `BYDAY`, `BYMONTHDAY`, `WKST`, `COUNT`, `UNTIL`.
+//!
+//! 발생 시각은 반복 일정의 시간대 기준 벽시계 시각으로 계산한다. 따라서 서울 시간
+//! 월요일 09:00 일정은 일광 절약 시간과 무관하게 매번 현지 월요일 09:00에 발생한다.
+
+use std::collections::{BTreeSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+
+const MAX_INTERVAL: u32 = 1000;
+const MAX_COUNT: u32 = 1000;
+/// 일치하는 날짜가 없는 기간을 이만큼 연속으로 만나면 더 이상 발생하지 않는 규칙으로 본다
+const MAX_EMPTY_PERIODS: u32 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+impl Frequency {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+        }
+    }
+}
+
+/// `BYDAY` 항목. `ordinal`은 MONTHLY에서만 쓰며 `-1FR`은 그달의 마지막 금요일이다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ByDay {
+    pub ordinal: Option<i8>,
+    pub weekday: Weekday,
+}
+
+/// `UNTIL` 값. 날짜만 지정하면 일정 시간대 기준으로 그날까지 포함한다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Until {
+    Date(NaiveDate),
+    DateTime(DateTime<Utc>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<ByDay>,
+    pub by_month_day: Vec<i8>,
+    pub week_start: Weekday,
+    pub count: Option<u32>,
+    pub until: Option<Until>,
+}
+
+impl RecurrenceRule {
+    /// `dtstart`부터 시작하는 발생 시각. 첫 발생은 항상 `dtstart`이다.
+    pub fn occurrences(&self, dtstart: DateTime<Utc>, tz: Tz) -> Occurrences<'_> {
+        Occurrences {
+            rule: self,
+            tz,
+            dtstart,
+            local_start: dtstart.with_timezone(&tz).naive_local(),
+            period: 0,
+            empty_periods: 0,
+            pending: VecDeque::new(),
+            emitted: 0,
+            finished: false,
+        }
+    }
+
+    /// `index`번째(1부터) 발생 시각. 규칙이 그 전에 끝나면 `None`.
+    pub fn nth_occurrence(&self, dtstart: DateTime<Utc>, tz: Tz, index: u32) -> Option<DateTime<Utc>> {
+        if index == 0 {
+            return None;
+        }
+        self.occurrences(dtstart, tz).nth(index as usize - 1)
+    }
+
+    /// 앞의 `skipped`개 발생을 떼어낸 나머지 일정의 규칙 (`COUNT`만 줄어든다)
+    pub fn remaining_after(&self, skipped: u32) -> Option<Self> {
+        let count = match self.count {
+            Some(count) if count <= skipped => return None,
+            Some(count) => Some(count - skipped),
+            None => None,
+        };
+        Some(Self { count, ..self.clone() })
+    }
+
+    fn period_dates(&self, start: NaiveDate, period: u32) -> Option<Vec<NaiveDate>> {
+        let step = period.checked_mul(self.interval)? as i64;
+
+        let dates = match self.frequency {
+            Frequency::Daily => {
+                let date = start.checked_add_signed(Duration::days(step))?;
+                vec![date].into_iter().filter(|d| self.matches_day(*d)).collect()
+            }
+            Frequency::Weekly => {
+                let offset = (7 + start.weekday().num_days_from_monday() - self.week_start.num_days_from_monday()) % 7;
+                let first = start.checked_sub_signed(Duration::days(offset as i64))?
+                    .checked_add_signed(Duration::weeks(step))?;
+                (0..7)
+                    .filter_map(|day| first.checked_add_signed(Duration::days(day)))
+                    .filter(|d| match self.by_day.is_empty() {
+                        true => d.weekday() == start.weekday(),
+                        false => self.by_day.iter().any(|b| b.weekday == d.weekday()),
+                    })
+                    .collect()
+            }
+            Frequency::Monthly => {
+                let months = start.year() as i64 * 12 + start.month0() as i64 + step;
+                let first = NaiveDate::from_ymd_opt(i32::try_from(months.div_euclid(12)).ok()?, months.rem_euclid(12) as u32 + 1, 1)?;
+                self.monthly_dates(first, start.day())
+            }
+        };
+
+        Some(dates)
+    }
+
+    fn monthly_dates(&self, first: NaiveDate, start_day: u32) -> Vec<NaiveDate> {
+        let days_in_month = days_in_month(first);
+        let day = |d: u32| first.with_day(d);
+
+        let mut dates = BTreeSet::new();
+        if !self.by_day.is_empty() {
+            for by_day in &self.by_day {
+                let matching: Vec<NaiveDate> = (1..=days_in_month)
+                    .filter_map(day)
+                    .filter(|d| d.weekday() == by_day.weekday)
+                    .collect();
+                match by_day.ordinal {
+                    None => dates.extend(matching),
+                    Some(n) if n > 0 => dates.extend(matching.get(n as usize - 1)),
+                    Some(n) => dates.extend(matching.len().checked_sub(n.unsigned_abs() as usize).map(|i| matching[i])),
+                }
+            }
+            if !self.by_month_day.is_empty() {
+                dates.retain(|d| self.matches_month_day(*d, days_in_month));
+            }
+        } else if !self.by_month_day.is_empty() {
+            dates.extend(
+                (1..=days_in_month)
+                    .filter_map(day)
+                    .filter(|d| self.matches_month_day(*d, days_in_month)),
+            );
+        } else {
+            // 31일 같은 날짜가 없는 달은 건너뛴다 (RFC 5545)
+            dates.extend(day(start_day));
+        }
+
+        dates.into_iter().collect()
+    }
+
+    /// DAILY의 BYDAY/BYMONTHDAY 필터
+    fn matches_day(&self, date: NaiveDate) -> bool {
+        let weekday_ok = self.by_day.is_empty() || self.by_day.iter().any(|b| b.weekday == date.weekday());
+        let month_day_ok = self.by_month_day.is_empty() || self.matches_month_day(date, days_in_month(date));
+        weekday_ok && month_day_ok
+    }
+
+    fn matches_month_day(&self, date: NaiveDate, days_in_month: u32) -> bool {
+        self.by_month_day.iter().any(|&d| {
+            let resolved = if d > 0 { d as i64 } else { days_in_month as i64 + 1 + d as i64 };
+            resolved == date.day() as i64
+        })
+    }
+
+    fn is_after_until(&self, occurrence: DateTime<Utc>, tz: Tz) -> bool {
+        match self.until {
+            Some(Until::Date(date)) => occurrence.with_timezone(&tz).date_naive() > date,
+            Some(Until::DateTime(until)) => occurrence > until,
+            None => false,
+        }
+    }
+}
+
+pub struct Occurrences<'a> {
+    rule: &'a RecurrenceRule,
+    tz: Tz,
+    dtstart: DateTime<Utc>,
+    local_start: NaiveDateTime,
+    period: u32,
+    empty_periods: u32,
+    pending: VecDeque<DateTime<Utc>>,
+    emitted: u32,
+    finished: bool,
+}
+
+impl Occurrences<'_> {
+    fn next_candidate(&mut self) -> Option<DateTime<Utc>> {
+        if self.emitted == 0 {
+            return Some(self.dtstart);
+        }
+
+        while self.pending.is_empty() {
+            if self.empty_periods >= MAX_EMPTY_PERIODS {
+                return None;
+            }
+            let dates = self.rule.period_dates(self.local_start.date(), self.period)?;
+            self.period += 1;
+
+            let time = self.local_start.time();
+            self.pending.extend(
+                dates
+                    .into_iter()
+                    .filter_map(|date| resolve_local(self.tz, date.and_time(time)))
+                    .filter(|at| *at > self.dtstart),
+            );
+            self.empty_periods = if self.pending.is_empty() { self.empty_periods + 1 } else { 0 };
+        }
+
+        self.pending.pop_front()
+    }
+}
+
+impl Iterator for Occurrences<'_> {
+    type Item = DateTime<Utc>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished || self.rule.count.is_some_and(|count| self.emitted >= count) {
+            return None;
+        }
+
+        match self.next_candidate() {
+            Some(at) if !self.rule.is_after_until(at, self.tz) => {
+                self.emitted += 1;
+                Some(at)
+            }
+            _ => {
+                self.finished = true;
+                None
+            }
+        }
+    }
+}
+
+/// 현지 시각을 UTC로 바꾼다. 일광 절약 시간 전환으로 없는 시각이면 한 시간 뒤를 쓴다.
+fn resolve_local(tz: Tz, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| tz.from_local_datetime(&(local + Duration::hours(1))).earliest())
+        .map(|at| at.with_timezone(&Utc))
+}
+
+fn days_in_month(date: NaiveDate) -> u32 {
+    let (year, month) = match date.month() {
+        12 => (date.year() + 1, 1),
+        month => (date.year(), month + 1),
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|next| next.pred_opt())
+        .map(|last| last.day())
+        .unwrap_or(28)
+}
+
+fn parse_weekday(value: &str) -> Result<Weekday, String> {
+    match value {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        _ => Err(format!("알 수 없는 요일입니다: {}", value)),
+    }
+}
+
+fn weekday_code(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn parse_number<T: FromStr>(key: &str, value: &str) -> Result<T, String> {
+    value.parse().map_err(|_| format!("{} 값이 올바르지 않습니다: {}", key, value))
+}
+
+fn parse_until(value: &str) -> Result<Until, String> {
+    let invalid = || format!("UNTIL 값이 올바르지 않습니다: {}", value);
+
+    if let Some(datetime) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(datetime, "%Y%m%dT%H%M%S").map_err(|_| invalid())?;
+        return Ok(Until::DateTime(Utc.from_utc_datetime(&naive)));
+    }
+    if value.contains('T') {
+        return Err("UNTIL 시각은 UTC(`Z` 접미사)로 지정해야 합니다".to_string());
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d").map(Until::Date).map_err(|_| invalid())
+}
+
+fn parse_by_day(value: &str, frequency: Frequency) -> Result<ByDay, String> {
+    if !value.is_ascii() {
+        return Err(format!("알 수 없는 요일입니다: {}", value));
+    }
+    let split = value.len().saturating_sub(2);
+    let (ordinal, weekday) = value.split_at(split);
+    let weekday = parse_weekday(weekday)?;
+
+    if ordinal.is_empty() {
+        return Ok(ByDay { ordinal: None, weekday });
+    }
+    if frequency != Frequency::Monthly {
+        return Err("BYDAY의 순번(예: 1MO, -1FR)은 MONTHLY에서만 사용할 수 있습니다".to_string());
+    }
+    let ordinal: i8 = parse_number("BYDAY", ordinal.strip_prefix('+').unwrap_or(ordinal))?;
+    if ordinal == 0 || ordinal.abs() > 5 {
+        return Err(format!("BYDAY 순번은 -5~5 사이여야 합니다: {}", value));
+    }
+    Ok(ByDay { ordinal: Some(ordinal), weekday })
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let body = match s.get(..6) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("RRULE:") => &s[6..],
+            _ => s,
+        };
+
+        let mut parts: Vec<(String, String)> = Vec::new();
+        for part in body.split(';').map(str::trim).filter(|p| !p.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| format!("RRULE 형식이 올바르지 않습니다: {}", part))?;
+            let key = key.trim().to_ascii_uppercase();
+            if parts.iter().any(|(existing, _)| *existing == key) {
+                return Err(format!("RRULE 속성이 중복되었습니다: {}", key));
+            }
+            parts.push((key, value.trim().to_ascii_uppercase()));
+        }
+        let value_of = |key: &str| parts.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str());
+
+        let frequency = match value_of("FREQ") {
+            Some("DAILY") => Frequency::Daily,
+            Some("WEEKLY") => Frequency::Weekly,
+            Some("MONTHLY") => Frequency::Monthly,
+            Some(other) => return Err(format!("지원하지 않는 반복 주기입니다: {} (DAILY, WEEKLY, MONTHLY)", other)),
+            None => return Err("RRULE에 FREQ가 필요합니다".to_string()),
+        };
+
+        let mut rule = Self {
+            frequency,
+            interval: 1,
+            by_day: Vec::new(),
+            by_month_day: Vec::new(),
+            week_start: Weekday::Mon,
+            count: None,
+            until: None,
+        };
+
+        for (key, value) in &parts {
+            match key.as_str() {
+                "FREQ" => {}
+                "INTERVAL" => {
+                    rule.interval = parse_number(key, value)?;
+                    if rule.interval == 0 || rule.interval > MAX_INTERVAL {
+                        return Err(format!("INTERVAL은 1-{} 사이여야 합니다", MAX_INTERVAL));
+                    }
+                }
+                "COUNT" => {
+                    let count = parse_number(key, value)?;
+                    if count == 0 || count > MAX_COUNT {
+                        return Err(format!("COUNT는 1-{} 사이여야 합니다", MAX_COUNT));
+                    }
+                    rule.count = Some(count);
+                }
+                "UNTIL" => rule.until = Some(parse_until(value)?),
+                "WKST" => rule.week_start = parse_weekday(value)?,
+                "BYDAY" => {
+                    for day in value.split(',') {
+                        let by_day = parse_by_day(day.trim(), frequency)?;
+                        if !rule.by_day.contains(&by_day) {
+                            rule.by_day.push(by_day);
+                        }
+                    }
+                }
+                "BYMONTHDAY" => {
+                    if frequency == Frequency::Weekly {
+                        return Err("BYMONTHDAY는 WEEKLY와 함께 사용할 수 없습니다".to_string());
+                    }
+                    for day in value.split(',') {
+                        let day: i8 = parse_number(key, day.trim())?;
+                        if day == 0 || day.abs() > 31 {
+                            return Err("BYMONTHDAY는 1~31 또는 -31~-1 사이여야 합니다".to_string());
+                        }
+                        if !rule.by_month_day.contains(&day) {
+                            rule.by_month_day.push(day);
+                        }
+                    }
+                }
+                other => return Err(format!("지원하지 않는 RRULE 속성입니다: {}", other)),
+            }
+        }
+
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err("COUNT와 UNTIL은 함께 사용할 수 없습니다".to_string());
+        }
+
+        Ok(rule)
+    }
+}
+
+/// 정규화된 RRULE 문자열 (기본값인 `INTERVAL=1`, `WKST=MO`는 생략)
+impl fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "FREQ={}", self.frequency.as_str())?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<String> = self
+                .by_day
+                .iter()
+                .map(|b| format!("{}{}", b.ordinal.map(|n| n.to_string()).unwrap_or_default(), weekday_code(b.weekday)))
+                .collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        if !self.by_month_day.is_empty() {
+            let days: Vec<String> = self.by_month_day.iter().map(|d| d.to_string()).collect();
+            write!(f, ";BYMONTHDAY={}", days.join(","))?;
+        }
+        if self.week_start != Weekday::Mon {
+            write!(f, ";WKST={}", weekday_code(self.week_start))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        match self.until {
+            Some(Until::Date(date)) => write!(f, ";UNTIL={}", date.format("%Y%m%d")),
+            Some(Until::DateTime(at)) => write!(f, ";UNTIL={}", at.format("%Y%m%dT%H%M%SZ")),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn rule(s: &str) -> RecurrenceRule {
+        RecurrenceRule::from_str(s).unwrap()
+    }
+
+    fn take(rule: &RecurrenceRule, dtstart: &str, tz: Tz, n: usize) -> Vec<DateTime<Utc>> {
+        rule.occurrences(utc(dtstart), tz).take(n).collect()
+    }
+
+    #[test]
+    fn test_weekly_byday_in_local_time() {
+        // 서울 월요일 09:00 (= UTC 00:00), 월/수/금
+        let occurrences = take(&rule("FREQ=WEEKLY;BYDAY=MO,WE,FR"), "2025-07-14T00:00:00Z", chrono_tz::Asia::Seoul, 4);
+
+        assert_eq!(occurrences, vec![
+            utc("2025-07-14T00:00:00Z"),
+            utc("2025-07-16T00:00:00Z"),
+            utc("2025-07-18T00:00:00Z"),
+            utc("2025-07-21T00:00:00Z"),
+        ]);
+    }
+
+    #[test]
+    fn test_weekly_uses_local_weekday_not_utc() {
+        // 서울 월요일 08:00은 UTC로 일요일 23:00
+        let occurrences = take(&rule("FREQ=WEEKLY;BYDAY=MO"), "2025-07-13T23:00:00Z", chrono_tz::Asia::Seoul, 2);
+        assert_eq!(occurrences[1], utc("2025-07-20T23:00:00Z"));
+    }
+
+    #[test]
+    fn test_biweekly_with_interval() {
+        let occurrences = take(&rule("FREQ=WEEKLY;INTERVAL=2;BYDAY=TU"), "2025-07-01T10:00:00Z", Tz::UTC, 3);
+        assert_eq!(occurrences, vec![
+            utc("2025-07-01T10:00:00Z"),
+            utc("2025-07-15T10:00:00Z"),
+            utc("2025-07-29T10:00:00Z"),
+        ]);
+    }
+
+    #[test]
+    fn test_daily_weekdays_only() {
+        // 2025-07-18은 금요일
+        let occurrences = take(&rule("FREQ=DAILY;BYDAY=MO,TU,WE,TH,FR"), "2025-07-18T09:00:00Z", Tz::UTC, 2);
+        assert_eq!(occurrences[1], utc("2025-07-21T09:00:00Z"));
+    }
+
+    #[test]
+    fn test_monthly_skips_missing_days() {
+        let occurrences = take(&rule("FREQ=MONTHLY"), "2025-01-31T09:00:00Z", Tz::UTC, 3);
+        assert_eq!(occurrences, vec![
+            utc("2025-01-31T09:00:00Z"),
+            utc("2025-03-31T09:00:00Z"),
+            utc("2025-05-31T09:00:00Z"),
+        ]);
+    }
+
+    #[test]
+    fn test_monthly_last_friday_and_last_day() {
+        let last_friday = take(&rule("FREQ=MONTHLY;BYDAY=-1FR"), "2025-07-25T09:00:00Z", Tz::UTC, 3);
+        assert_eq!(last_friday[1], utc("2025-08-29T09:00:00Z"));
+        assert_eq!(last_friday[2], utc("2025-09-26T09:00:00Z"));
+
+        let last_day = take(&rule("FREQ=MONTHLY;BYMONTHDAY=-1"), "2025-01-31T09:00:00Z", Tz::UTC, 2);
+        assert_eq!(last_day[1], utc("2025-02-28T09:00:00Z"));
+    }
+
+    #[test]
+    fn test_count_and_until_end_the_series() {
+        let counted: Vec<_> = rule("FREQ=DAILY;COUNT=3").occurrences(utc("2025-07-01T09:00:00Z"), Tz::UTC).collect();
+        assert_eq!(counted.len(), 3);
+
+        let until: Vec<_> = rule("FREQ=WEEKLY;UNTIL=20250722T000000Z")
+            .occurrences(utc("2025-07-01T09:00:00Z"), Tz::UTC)
+            .collect();
+        assert_eq!(until.last(), Some(&utc("2025-07-15T09:00:00Z")));
+
+        let until_date: Vec<_> = rule("FREQ=WEEKLY;UNTIL=20250722")
+            .occurrences(utc("2025-07-01T09:00:00Z"), Tz::UTC)
+            .collect();
+        assert_eq!(until_date.last(), Some(&utc("2025-07-22T09:00:00Z")));
+    }
+
+    #[test]
+    fn test_keeps_wall_clock_time_across_dst() {
+        // 뉴욕 09:00, 서머타임 시작(2025-03-09) 전후
+        let occurrences = take(&rule("FREQ=WEEKLY"), "2025-03-03T14:00:00Z", chrono_tz::America::New_York, 2);
+        assert_eq!(occurrences[1], utc("2025-03-10T13:00:00Z"));
+    }
+
+    #[test]
+    fn test_nth_occurrence_and_remaining_after() {
+        let weekly = rule("FREQ=WEEKLY;COUNT=3");
+        let dtstart = utc("2025-07-01T09:00:00Z");
+
+        assert_eq!(weekly.nth_occurrence(dtstart, Tz::UTC, 3), Some(utc("2025-07-15T09:00:00Z")));
+        assert_eq!(weekly.nth_occurrence(dtstart, Tz::UTC, 4), None);
+        assert_eq!(weekly.remaining_after(1).unwrap().count, Some(2));
+        assert!(weekly.remaining_after(3).is_none());
+    }
+
+    #[test]
+    fn test_impossible_rule_terminates() {
+        let never = rule("FREQ=MONTHLY;BYMONTHDAY=31;BYDAY=1MO");
+        assert_eq!(never.occurrences(utc("2025-07-01T09:00:00Z"), Tz::UTC).count(), 1);
+    }
+
+    #[test]
+    fn test_parse_normalizes() {
+        let parsed = rule("RRULE:freq=monthly;byday=+1mo,-1fr;interval=1;count=12");
+        assert_eq!(parsed.to_string(), "FREQ=MONTHLY;BYDAY=1MO,-1FR;COUNT=12");
+        assert_eq!(rule(&parsed.to_string()), parsed);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_rules() {
+        for invalid in [
+            "",
+            "FREQ=YEARLY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;COUNT=2;UNTIL=20250101",
+            "FREQ=WEEKLY;BYDAY=1MO",
+            "FREQ=WEEKLY;BYMONTHDAY=1",
+            "FREQ=DAILY;BYHOUR=9",
+            "FREQ=DAILY;FREQ=WEEKLY",
+            "FREQ=DAILY;UNTIL=20250101T000000",
+        ] {
+            assert!(RecurrenceRule::from_str(invalid).is_err(), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,229 @@
+//! Time tracking reports
+//!
+//! 기록을 조회 구간에 맞춰 자른 뒤 사용자 시간대의 날짜, 카테고리, 할일별로 합산한다.
+//! 자정을 넘긴 기록은 날짜별로 나누고, 실행 중인 타이머는 지금까지의 시간을 센다.
+
+use std::collections::HashMap;
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Tz;
+use uuid::Uuid;
+use crate::entities::time_entry::{TimeReportGroup, TrackedTime};
+use crate::utils::timezone::local_day_start;
+
+/// 카테고리가 없는 할일을 모으는 행의 키
+pub const UNCATEGORIZED_KEY: &str = "uncategorized";
+const UNCATEGORIZED_LABEL: &str = "미분류";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReportRow {
+    /// 날짜(`YYYY-MM-DD`), 카테고리 ID 또는 할일 ID
+    pub key: String,
+    pub label: String,
+    pub tracked_seconds: i64,
+    /// 행에 포함된 할일의 예상 시간 합계. 날짜별 보고서와 예상 시간이 없는 경우는 `None`.
+    pub estimate_minutes: Option<i64>,
+    pub todo_count: usize,
+}
+
+#[derive(Default)]
+struct Bucket {
+    label: String,
+    seconds: i64,
+    estimates: HashMap<Uuid, Option<i32>>,
+}
+
+/// `[from, to)` 구간의 기록을 `group` 기준으로 합산한다.
+/// 날짜별은 날짜순, 나머지는 기록된 시간이 긴 순서로 반환한다.
+pub fn aggregate(
+    entries: &[TrackedTime],
+    group: TimeReportGroup,
+    from: DateTime<Utc>,
+    to: DateTime<Utc>,
+    now: DateTime<Utc>,
+    tz: Tz,
+) -> Vec<ReportRow> {
+    let mut buckets: HashMap<String, Bucket> = HashMap::new();
+    let mut add = |key: String, label: &str, entry: &TrackedTime, seconds: i64| {
+        let bucket = buckets.entry(key).or_insert_with(|| Bucket {
+            label: label.to_string(),
+            ..Bucket::default()
+        });
+        bucket.seconds += seconds;
+        bucket.estimates.insert(entry.todo_id, entry.estimate_minutes);
+    };
+
+    for entry in entries {
+        let Some((start, end)) = clip(entry, from, to, now) else {
+            continue;
+        };
+        let seconds = (end - start).num_seconds();
+
+        match group {
+            TimeReportGroup::Day => {
+                for (date, seconds) in split_by_day(start, end, tz) {
+                    let key = date.to_string();
+                    add(key.clone(), &key, entry, seconds);
+                }
+            }
+            TimeReportGroup::Category if entry.category_ids.is_empty() => {
+                add(UNCATEGORIZED_KEY.to_string(), UNCATEGORIZED_LABEL, entry, seconds);
+            }
+            TimeReportGroup::Category => {
+                for (id, name) in entry.category_ids.iter().zip(&entry.category_names) {
+                    add(id.to_string(), name, entry, seconds);
+                }
+            }
+            TimeReportGroup::Todo => add(entry.todo_id.to_string(), &entry.title, entry, seconds),
+        }
+    }
+
+    let mut rows: Vec<ReportRow> = buckets
+        .into_iter()
+        .map(|(key, bucket)| {
+            let estimates: Vec<i64> = bucket.estimates.values().flatten().map(|&minutes| minutes as i64).collect();
+            ReportRow {
+                key,
+                label: bucket.label,
+                tracked_seconds: bucket.seconds,
+                estimate_minutes: match group {
+                    TimeReportGroup::Day => None,
+                    _ if estimates.is_empty() => None,
+                    _ => Some(estimates.iter().sum()),
+                },
+                todo_count: bucket.estimates.len(),
+            }
+        })
+        .collect();
+
+    match group {
+        TimeReportGroup::Day => rows.sort_by(|a, b| a.key.cmp(&b.key)),
+        _ => rows.sort_by(|a, b| b.tracked_seconds.cmp(&a.tracked_seconds).then_with(|| a.label.cmp(&b.label))),
+    }
+    rows
+}
+
+/// `[from, to)` 구간에 기록된 전체 시간 (초). 카테고리별 행의 합과 달리 중복해서 세지 않는다.
+pub fn total_seconds(entries: &[TrackedTime], from: DateTime<Utc>, to: DateTime<Utc>, now: DateTime<Utc>) -> i64 {
+    entries
+        .iter()
+        .filter_map(|entry| clip(entry, from, to, now))
+        .map(|(start, end)| (end - start).num_seconds())
+        .sum()
+}
+
+/// 기록 중 `[from, to)`에 속하는 부분
+fn clip(entry: &TrackedTime, from: DateTime<Utc>, to: DateTime<Utc>, now: DateTime<Utc>) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+    let start = entry.started_at.max(from);
+    let end = entry.ended_at.unwrap_or(now).min(to);
+    (end > start).then_some((start, end))
+}
+
+/// `[start, end)`를 사용자 시간대의 날짜 경계로 나눈다
+fn split_by_day(start: DateTime<Utc>, end: DateTime<Utc>, tz: Tz) -> Vec<(chrono::NaiveDate, i64)> {
+    let mut parts = Vec::new();
+    let mut cursor = start;
+    while cursor < end {
+        let date = cursor.with_timezone(&tz).date_naive();
+        let next_day = local_day_start(tz, date + Duration::days(1));
+        let part_end = next_day.min(end);
+        parts.push((date, (part_end - cursor).num_seconds()));
+        cursor = part_end;
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    fn tracked(todo_id: Uuid, start: &str, end: Option<&str>, estimate: Option<i32>, categories: &[(Uuid, &str)]) -> TrackedTime {
+        TrackedTime {
+            todo_id,
+            title: "보고서 작성".to_string(),
+            estimate_minutes: estimate,
+            started_at: utc(start),
+            ended_at: end.map(utc),
+            category_ids: categories.iter().map(|(id, _)| *id).collect(),
+            category_names: categories.iter().map(|(_, name)| name.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn test_day_report_splits_at_local_midnight() {
+        // 서울 23:00 ~ 다음 날 01:30
+        let entries = vec![tracked(Uuid::new_v4(), "2025-07-23T14:00:00Z", Some("2025-07-23T16:30:00Z"), Some(60), &[])];
+
+        let rows = aggregate(
+            &entries,
+            TimeReportGroup::Day,
+            utc("2025-07-20T15:00:00Z"),
+            utc("2025-07-27T15:00:00Z"),
+            utc("2025-07-25T00:00:00Z"),
+            chrono_tz::Asia::Seoul,
+        );
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!((rows[0].key.as_str(), rows[0].tracked_seconds), ("2025-07-23", 3600));
+        assert_eq!((rows[1].key.as_str(), rows[1].tracked_seconds), ("2025-07-24", 5400));
+        assert_eq!(rows[0].estimate_minutes, None);
+    }
+
+    #[test]
+    fn test_report_clips_to_range_and_counts_running_timer() {
+        let todo_id = Uuid::new_v4();
+        let entries = vec![
+            tracked(todo_id, "2025-07-19T23:00:00Z", Some("2025-07-20T01:00:00Z"), Some(90), &[]),
+            tracked(todo_id, "2025-07-21T09:00:00Z", None, Some(90), &[]),
+        ];
+
+        let rows = aggregate(
+            &entries,
+            TimeReportGroup::Todo,
+            utc("2025-07-20T00:00:00Z"),
+            utc("2025-07-27T00:00:00Z"),
+            utc("2025-07-21T09:30:00Z"),
+            chrono_tz::UTC,
+        );
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].key, todo_id.to_string());
+        assert_eq!(rows[0].tracked_seconds, 3600 + 1800);
+        assert_eq!(rows[0].estimate_minutes, Some(90));
+        assert_eq!(rows[0].todo_count, 1);
+    }
+
+    #[test]
+    fn test_category_report_counts_each_category_and_sums_estimates_once_per_todo() {
+        let (work, client) = (Uuid::new_v4(), Uuid::new_v4());
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let entries = vec![
+            tracked(first, "2025-07-21T09:00:00Z", Some("2025-07-21T10:00:00Z"), Some(120), &[(work, "업무"), (client, "고객사")]),
+            tracked(first, "2025-07-22T09:00:00Z", Some("2025-07-22T09:30:00Z"), Some(120), &[(work, "업무"), (client, "고객사")]),
+            tracked(second, "2025-07-22T11:00:00Z", Some("2025-07-22T11:15:00Z"), None, &[(work, "업무")]),
+            tracked(Uuid::new_v4(), "2025-07-22T12:00:00Z", Some("2025-07-22T12:10:00Z"), None, &[]),
+        ];
+
+        let rows = aggregate(
+            &entries,
+            TimeReportGroup::Category,
+            utc("2025-07-20T00:00:00Z"),
+            utc("2025-07-27T00:00:00Z"),
+            utc("2025-07-25T00:00:00Z"),
+            chrono_tz::UTC,
+        );
+
+        assert_eq!(rows.iter().map(|row| row.label.as_str()).collect::<Vec<_>>(), vec!["업무", "고객사", "미분류"]);
+        assert_eq!(rows[0].tracked_seconds, 6300);
+        assert_eq!((rows[0].estimate_minutes, rows[0].todo_count), (Some(120), 2));
+        assert_eq!(rows[1].tracked_seconds, 5400);
+        assert_eq!((rows[2].key.as_str(), rows[2].estimate_minutes), (UNCATEGORIZED_KEY, None));
+        assert_eq!(
+            total_seconds(&entries, utc("2025-07-20T00:00:00Z"), utc("2025-07-27T00:00:00Z"), utc("2025-07-25T00:00:00Z")),
+            3600 + 1800 + 900 + 600
+        );
+    }
+}
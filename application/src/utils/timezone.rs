@@ -0,0 +1,120 @@
+//! Timezone-aware time windows
+//!
+//! "오늘", "이번 주" 같은 구간은 서버 UTC가 아닌 사용자 시간대의 자정을 기준으로
+//! 계산한 뒤 UTC 시각으로 변환해 쿼리에 사용한다.
+
+use std::str::FromStr;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use crate::error::ApiError;
+
+/// IANA 시간대 이름을 해석한다 (`Asia/Seoul`, `UTC` 등)
+pub fn parse_timezone(name: &str) -> Result<Tz, ApiError> {
+    Tz::from_str(name).map_err(|_| ApiError::Validation(format!("알 수 없는 시간대입니다: {}", name)))
+}
+
+/// 해당 날짜가 시작되는 UTC 시각
+///
+/// 일광 절약 시간 전환으로 자정이 존재하지 않는 날은 그날의 첫 유효 시각을,
+/// 자정이 두 번 있는 날은 이른 쪽을 사용한다.
+pub fn local_day_start(tz: Tz, date: NaiveDate) -> DateTime<Utc> {
+    let midnight = date.and_hms_opt(0, 0, 0).expect("midnight is always valid");
+
+    (0..=24 * 60)
+        .step_by(15)
+        .find_map(|minutes| tz.from_local_datetime(&(midnight + Duration::minutes(minutes))).earliest())
+        .map(|local| local.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&midnight))
+}
+
+/// 현지 시각을 UTC로 바꾼다. 일광 절약 시간 전환으로 없는 시각이면 한 시간 뒤로 민다.
+pub fn local_datetime(tz: Tz, local: NaiveDateTime) -> DateTime<Utc> {
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| tz.from_local_datetime(&(local + Duration::hours(1))).earliest())
+        .map(|at| at.with_timezone(&Utc))
+        .unwrap_or_else(|| Utc.from_utc_datetime(&local))
+}
+
+/// 사용자 시간대 기준의 오늘/이번 주 구간 (`[start, end)`, UTC)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeWindows {
+    pub today_start: DateTime<Utc>,
+    pub tomorrow_start: DateTime<Utc>,
+    pub week_start: DateTime<Utc>,
+    pub next_week_start: DateTime<Utc>,
+}
+
+impl TimeWindows {
+    pub fn at(now: DateTime<Utc>, tz: Tz, week_starts_on: Weekday) -> Self {
+        let today = now.with_timezone(&tz).date_naive();
+        let days_into_week = (7 + today.weekday().num_days_from_monday()
+            - week_starts_on.num_days_from_monday())
+            % 7;
+        let first_day_of_week = today - Duration::days(days_into_week as i64);
+
+        Self {
+            today_start: local_day_start(tz, today),
+            tomorrow_start: local_day_start(tz, today + Duration::days(1)),
+            week_start: local_day_start(tz, first_day_of_week),
+            next_week_start: local_day_start(tz, first_day_of_week + Duration::days(7)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utc(s: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(s).unwrap().with_timezone(&Utc)
+    }
+
+    #[test]
+    fn test_today_in_seoul_differs_from_utc() {
+        // UTC 2025-07-09 20:00 = 서울 2025-07-10 05:00 (목요일)
+        let windows = TimeWindows::at(utc("2025-07-09T20:00:00Z"), chrono_tz::Asia::Seoul, Weekday::Mon);
+
+        assert_eq!(windows.today_start, utc("2025-07-09T15:00:00Z"));
+        assert_eq!(windows.tomorrow_start, utc("2025-07-10T15:00:00Z"));
+        assert_eq!(windows.week_start, utc("2025-07-06T15:00:00Z"));
+        assert_eq!(windows.next_week_start, utc("2025-07-13T15:00:00Z"));
+    }
+
+    #[test]
+    fn test_week_start_is_configurable() {
+        // 2025-07-13은 일요일
+        let now = utc("2025-07-13T12:00:00Z");
+
+        let sunday = TimeWindows::at(now, Tz::UTC, Weekday::Sun);
+        assert_eq!(sunday.week_start, utc("2025-07-13T00:00:00Z"));
+
+        let monday = TimeWindows::at(now, Tz::UTC, Weekday::Mon);
+        assert_eq!(monday.week_start, utc("2025-07-07T00:00:00Z"));
+
+        let saturday = TimeWindows::at(now, Tz::UTC, Weekday::Sat);
+        assert_eq!(saturday.week_start, utc("2025-07-12T00:00:00Z"));
+    }
+
+    #[test]
+    fn test_dst_day_is_23_hours() {
+        // 2025-03-09 미국 동부 서머타임 시작일
+        let windows = TimeWindows::at(utc("2025-03-09T18:00:00Z"), chrono_tz::America::New_York, Weekday::Mon);
+
+        assert_eq!(windows.today_start, utc("2025-03-09T05:00:00Z"));
+        assert_eq!(windows.tomorrow_start - windows.today_start, Duration::hours(23));
+    }
+
+    #[test]
+    fn test_missing_midnight_uses_first_valid_time() {
+        // 2018-11-04 상파울루는 자정에 서머타임이 시작되어 00:00이 존재하지 않는다
+        let start = local_day_start(chrono_tz::America::Sao_Paulo, NaiveDate::from_ymd_opt(2018, 11, 4).unwrap());
+        assert_eq!(start, utc("2018-11-04T03:00:00Z"));
+    }
+
+    #[test]
+    fn test_parse_timezone() {
+        assert!(parse_timezone("Asia/Seoul").is_ok());
+        assert!(matches!(parse_timezone("Mars/Olympus"), Err(ApiError::Validation(_))));
+    }
+}
@@ -0,0 +1,32 @@
+//! URL이나 메일로 전달하는 비밀 토큰
+//!
+//! 원문은 발급할 때 한 번만 돌려주고, 저장과 조회에는 SHA-256 해시만 쓴다.
+//! 토큰 자체가 충분히 긴 난수라 해시에 솔트를 넣지 않는다.
+
+use sha2::{Digest, Sha256};
+use uuid::Uuid;
+
+/// UUID v4 두 개(244비트 난수)를 이어 붙인 64자 토큰
+pub fn generate_token() -> String {
+    format!("{}{}", Uuid::new_v4().simple(), Uuid::new_v4().simple())
+}
+
+/// 저장하고 조회할 때 쓰는 토큰 해시 (hex)
+pub fn hash_token(token: &str) -> String {
+    hex::encode(Sha256::digest(token.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tokens_are_unique_and_hash_is_stable() {
+        let token = generate_token();
+
+        assert_eq!(token.len(), 64);
+        assert_ne!(token, generate_token());
+        assert_eq!(hash_token(&token), hash_token(&token));
+        assert_ne!(hash_token(&token), token);
+    }
+}
@@ -6,6 +6,11 @@ pub static USERNAME_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(r"^[a-zA-Z0-9_]+$").expect("Invalid username regex")
 });
 
+/// Category color regex: `#RRGGBB`
+pub static COLOR_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^#[0-9A-Fa-f]{6}$").expect("Invalid color regex")
+});
+
 /// Email validation helper
 pub fn is_valid_email(email: &str) -> bool {
     email.contains('@') && email.len() <= 255